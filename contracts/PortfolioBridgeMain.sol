@@ -493,8 +493,8 @@ contract PortfolioBridgeMain is
      * @dev     It is packed as follows:
      * slot0: customdata(18), timestamp(4), nonce(8), transaction(1), XChainMsgType(1)
      * slot1: trader(32)
-     * slot1: tokenAddress(32)
-     * slot2: quantity(8)
+     * slot2: tokenAddress(32)
+     * slot3: quantity(32)
      * @param   _xfer  XFER message to encode
      * @return  message  Encoded XFERSolana message
      */
@@ -510,7 +510,7 @@ contract PortfolioBridgeMain is
         bytes32 tokenAddress = xChainAllowedDestinations[_xfer.symbol][SOL_CHAIN_ID];
         require(tokenAddress != bytes32(0) || supportedChainNative[SOL_CHAIN_ID] == _xfer.symbol, "PB-STNS-01");
         bytes32 slot2 = bytes32(tokenAddress);
-        bytes8 slot3 = bytes8(uint64(_xfer.quantity));
+        bytes32 slot3 = bytes32(_xfer.quantity);
         message = bytes.concat(slot0, slot1, slot2, slot3);
     }
 
@@ -46,9 +46,6 @@ pub struct CallDexalot<'info> {
     /// CHECK:
     #[account()]
     pub associated_token_program: UncheckedAccount<'info>,
-    /// CHECK:
-    #[account()]
-    pub token_list: UncheckedAccount<'info>,
     /// CHECK: the trader account
     #[account(mut)]
     pub trader: UncheckedAccount<'info>,
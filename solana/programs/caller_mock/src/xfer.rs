@@ -42,7 +42,7 @@ impl XFERSolana {
         let mut slot0 = [0u8; 32];
         let mut slot1 = [0u8; 32];
         let mut slot2 = [0u8; 32];
-        let mut slot3 = [0u8; 8];
+        let mut slot3 = [0u8; 32];
 
         slot0[0..18].copy_from_slice(&self.custom_data);
         slot0[18..22].copy_from_slice(&self.timestamp.to_be_bytes());
@@ -52,7 +52,7 @@ impl XFERSolana {
 
         slot1.copy_from_slice(&self.trader.to_bytes());
         slot2.copy_from_slice(&self.token_mint.as_ref());
-        slot3.copy_from_slice(&self.quantity.to_be_bytes());
+        slot3[24..].copy_from_slice(&self.quantity.to_be_bytes());
 
         Ok([
             slot0.to_vec(),
@@ -1,3 +1,5 @@
+use anchor_lang::prelude::Pubkey;
+
 // Anchor
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
 pub const SOLANA_CHAIN_ID: u32 = 40168;
@@ -10,26 +12,54 @@ pub const SOL_USER_FUNDS_VAULT_SEED: &[u8] = b"Soufv";
 pub const SPL_VAULT_SEED: &[u8] = b"Splv";
 pub const SPL_USER_FUNDS_VAULT_SEED: &[u8] = b"Sufv";
 pub const AIRDROP_VAULT_SEED: &[u8] = b"Adv";
+pub const LZ_FEE_VAULT_SEED: &[u8] = b"Lzfv";
 pub const REMOTE_SEED: &[u8] = b"Remote";
 pub const ADMIN_SEED: &[u8] = b"Admin";
 pub const REBALANCER_SEED: &[u8] = b"Rebalancer";
+pub const REBALANCER_LIMIT_SEED: &[u8] = b"RebalancerLimit";
+pub const VOLATILITY_ADMIN_SEED: &[u8] = b"VolatilityAdmin";
+pub const PAUSER_SEED: &[u8] = b"Pauser";
+pub const ROLE_REGISTRY_SEED: &[u8] = b"RoleRegistry";
+pub const MULTISIG_CONFIG_SEED: &[u8] = b"Multisig";
+pub const PROPOSAL_SEED: &[u8] = b"Proposal";
+pub const PENDING_CHANGE_SEED: &[u8] = b"PendingChange";
 pub const BANNED_ACCOUNT_SEED: &[u8] = b"Banned";
 pub const TOKEN_DETAILS_SEED: &[u8] = b"TokenDetails";
 pub const TOKEN_LIST_SEED: &[u8] = b"TokenList";
 pub const TOKEN_LIST_PAGE_1_SEED: &[u8] = b"0";
+pub const SYMBOL_SEED: &[u8] = b"Symbol";
 pub const CCTRADE_ALLOWED_DEST_SEED: &[u8] = b"Cads";
+pub const DEPOSIT_ALLOWED_DEST_SEED: &[u8] = b"Dads";
+pub const FAILED_MESSAGE_SEED: &[u8] = b"FailedMsg";
+pub const TOKEN_INVENTORY_SEED: &[u8] = b"Inventory";
+pub const TRUSTED_PROGRAM_SEED: &[u8] = b"TrustedProgram";
+pub const LZ_OPTIONS_SEED: &[u8] = b"LzOptions";
+pub const CHAIN_SEED: &[u8] = b"Chain";
 // Portfolio
-pub const MAX_TOKENS: usize = 20;
+pub const TOKEN_LIST_PAGE_CAPACITY: usize = 64;
 pub const DEFAULT_AIRDROP_AMOUNT: u64 = 10000; // two spl tranfers in lamports
 pub const SOL_NATIVE_SYMBOL: &[u8; 3] = b"SOL";
+pub const NATIVE_TOKEN_MINT: Pubkey = Pubkey::new_from_array([0; 32]); // key used for SOL in per-token accounts
+pub const MAX_CHAIN_NATIVES: usize = 4;
+pub const MAX_ROLE_MEMBERS: usize = 32;
+pub const MAX_SENSITIVE_ACTIONS: usize = 16;
+pub const MAX_PROPOSAL_DATA_LEN: usize = 160; // fits set_chain params with MAX_CHAIN_NATIVES symbols
+pub const MAX_CHANGE_DATA_LEN: usize = 160; // fits set_chain params with MAX_CHAIN_NATIVES symbols
+pub const MIN_TIMELOCK_DELAY: u32 = 24 * 60 * 60; // seconds
 // XFER
-pub const XFER_SIZE: usize = 104;
+pub const XFER_SIZE: usize = 128;
 
 // Layerzero
 pub const GAS_OPTIONS: [u8; 22] = [
     0, 3, 1, 0, 17, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 26, 128,
 ];
-pub const ENDPOINT_ID: &str = "76y77prsiCMvXMjuoZ5VRrhG5qYBrUMYTE5WgHqgjEn6";
+pub const OPTIONS_TYPE_3: u16 = 3;
+pub const EXECUTOR_WORKER_ID: u8 = 1;
+pub const EXECUTOR_OPTION_TYPE_LZRECEIVE: u8 = 1;
+pub const EXECUTOR_OPTION_TYPE_NATIVE_DROP: u8 = 2;
+pub const EXECUTOR_OPTION_TYPE_LZCOMPOSE: u8 = 3;
+pub const MAX_EXECUTOR_OPTIONS: usize = 4;
+pub const MAX_LZ_OPTIONS_LEN: usize = 2 + MAX_EXECUTOR_OPTIONS * 52; // type | (worker, size, option type, params of at most 48 bytes)
 pub const REGISTER_OAPP: &str = "register_oapp";
 pub const ENDPOINT_SEND: &str = "send";
 pub const ENDPOINT_QUOTE: &str = "quote";
@@ -38,6 +68,7 @@ pub const CLEAR_MIN_ACCOUNTS_LEN: usize = 8;
 pub const ENDPOINT_SEED: &[u8] = b"Endpoint";
 pub const NONCE_SEED: &[u8] = b"Nonce";
 pub const PAYLOAD_HASH_SEED: &[u8] = b"PayloadHash";
+pub const LZ_RECEIVE_TYPES_SEED: &[u8] = b"LzReceiveTypes";
 pub const OAPP_SEED: &[u8] = b"OApp";
 pub const EVENT_SEED: &[u8] = b"__event_authority";
 pub const QUOTE_REMAINING_ACCOUNTS_COUNT: usize = 18;
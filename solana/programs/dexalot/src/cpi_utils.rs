@@ -1,11 +1,22 @@
 use anchor_lang::prelude::*;
 use sha2::{Digest, Sha256};
 
+use anchor_lang::solana_program::{
+    instruction::Instruction,
+    program::{get_return_data, invoke, invoke_signed},
+};
+
 use crate::{
     consts::{
-        ANCHOR_DISCRIMINATOR, ENDPOINT_SEED, EVENT_SEED, NONCE_SEED, OAPP_SEED, PAYLOAD_HASH_SEED,
+        ANCHOR_DISCRIMINATOR, ENDPOINT_QUOTE, ENDPOINT_SEED, ENDPOINT_SEND, EVENT_SEED,
+        EXECUTOR_OPTION_TYPE_LZCOMPOSE, EXECUTOR_OPTION_TYPE_LZRECEIVE,
+        EXECUTOR_OPTION_TYPE_NATIVE_DROP, EXECUTOR_WORKER_ID, GAS_OPTIONS, LZ_FEE_VAULT_SEED,
+        MAX_EXECUTOR_OPTIONS, NONCE_SEED, OAPP_SEED, OPTIONS_TYPE_3, PAYLOAD_HASH_SEED,
+        PORTFOLIO_SEED, QUOTE_REMAINING_ACCOUNTS_COUNT,
     },
+    errors::DexalotError,
     instructions::LzAccount,
+    state::{LzOptions, Portfolio, Remote},
 };
 
 pub fn create_instruction_data<T>(params: &T, instruction_name: &str) -> Result<Vec<u8>>
@@ -66,6 +77,102 @@ pub struct MessagingFee {
     pub lz_token_fee: u64,
 }
 
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+pub enum ExecutorOption {
+    LzReceive { gas: u128, value: u128 },
+    NativeDrop { amount: u128, receiver: [u8; 32] },
+    LzCompose { index: u16, gas: u128, value: u128 },
+}
+
+/// Builds TYPE_3 LayerZero options out of executor options
+pub struct OptionsBuilder {
+    options: Vec<u8>,
+}
+
+impl Default for OptionsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OptionsBuilder {
+    pub fn new() -> Self {
+        OptionsBuilder {
+            options: OPTIONS_TYPE_3.to_be_bytes().to_vec(),
+        }
+    }
+
+    pub fn from_executor_options(executor_options: &[ExecutorOption]) -> Result<Self> {
+        require!(
+            executor_options.len() <= MAX_EXECUTOR_OPTIONS,
+            DexalotError::TooManyExecutorOptions
+        );
+        Ok(executor_options
+            .iter()
+            .fold(Self::new(), |builder, option| match *option {
+                ExecutorOption::LzReceive { gas, value } => {
+                    builder.add_executor_lz_receive_option(gas, value)
+                }
+                ExecutorOption::NativeDrop { amount, receiver } => {
+                    builder.add_executor_native_drop_option(amount, receiver)
+                }
+                ExecutorOption::LzCompose { index, gas, value } => {
+                    builder.add_executor_lz_compose_option(index, gas, value)
+                }
+            }))
+    }
+
+    /// Gas and msg.value for the lzReceive call on the destination, the value is omitted when 0
+    pub fn add_executor_lz_receive_option(self, gas: u128, value: u128) -> Self {
+        let mut params = gas.to_be_bytes().to_vec();
+        if value > 0 {
+            params.extend_from_slice(&value.to_be_bytes());
+        }
+        self.add_executor_option(EXECUTOR_OPTION_TYPE_LZRECEIVE, &params)
+    }
+
+    /// Native amount dropped to the receiver on the destination
+    pub fn add_executor_native_drop_option(self, amount: u128, receiver: [u8; 32]) -> Self {
+        let mut params = amount.to_be_bytes().to_vec();
+        params.extend_from_slice(&receiver);
+        self.add_executor_option(EXECUTOR_OPTION_TYPE_NATIVE_DROP, &params)
+    }
+
+    /// Gas and msg.value for the lzCompose call at `index`, the value is omitted when 0
+    pub fn add_executor_lz_compose_option(self, index: u16, gas: u128, value: u128) -> Self {
+        let mut params = index.to_be_bytes().to_vec();
+        params.extend_from_slice(&gas.to_be_bytes());
+        if value > 0 {
+            params.extend_from_slice(&value.to_be_bytes());
+        }
+        self.add_executor_option(EXECUTOR_OPTION_TYPE_LZCOMPOSE, &params)
+    }
+
+    // worker id | option size | option type | option params
+    fn add_executor_option(mut self, option_type: u8, params: &[u8]) -> Self {
+        self.options.push(EXECUTOR_WORKER_ID);
+        self.options
+            .extend_from_slice(&(params.len() as u16 + 1).to_be_bytes());
+        self.options.push(option_type);
+        self.options.extend_from_slice(params);
+        self
+    }
+
+    pub fn build(self) -> Vec<u8> {
+        self.options
+    }
+}
+
+/// Returns the options stored for the destination and message kind
+/// Falls back to the default gas options when none were set
+pub fn load_lz_options(lz_options: &AccountInfo, program_id: &Pubkey) -> Result<Vec<u8>> {
+    if lz_options.owner != program_id {
+        return Ok(GAS_OPTIONS.to_vec());
+    }
+    let lz_options = LzOptions::try_deserialize(&mut &lz_options.try_borrow_data()?[..])?;
+    Ok(lz_options.options)
+}
+
 #[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
 pub struct ClearParams {
     pub receiver: Pubkey,
@@ -156,6 +263,152 @@ pub fn get_accounts_for_clear(
     ]
 }
 
+/// Quotes and sends a message to the remote OApp on `dst_eid`
+/// The remaining accounts hold the quote accounts followed by the send accounts.
+/// When the protocol pays for the destination the LZ fee vault signs as the send payer,
+/// otherwise the fee paid by the user is capped by `max_native_fee`
+#[allow(clippy::too_many_arguments)]
+pub fn quote_and_send<'info>(
+    portfolio: &Account<Portfolio>,
+    remote: &Account<Remote>,
+    endpoint_program: &AccountInfo,
+    remaining_accounts: &[AccountInfo<'info>],
+    dst_eid: u32,
+    message: Vec<u8>,
+    options: Vec<u8>,
+    max_native_fee: u64,
+) -> Result<()> {
+    let fee = quote(
+        portfolio,
+        remote,
+        endpoint_program,
+        remaining_accounts,
+        dst_eid,
+        message.clone(),
+        options.clone(),
+    )?;
+
+    require!(fee.lz_token_fee == 0, DexalotError::PositiveLzTokenFee);
+    check_native_fee(remote.protocol_pays_fee, fee.native_fee, max_native_fee)?;
+
+    // Call Send
+    let send_remaining_accounts = &remaining_accounts[QUOTE_REMAINING_ACCOUNTS_COUNT..];
+    let send_params = EndpointSendParams {
+        dst_eid,
+        receiver: remote.address,
+        message,
+        options,
+        native_fee: fee.native_fee,
+        lz_token_fee: 0,
+    };
+
+    let portfolio_bump = &[portfolio.bump];
+    let (fee_vault_key, fee_vault_bump) =
+        Pubkey::find_program_address(&[LZ_FEE_VAULT_SEED], &crate::ID);
+    let fee_vault_bump = &[fee_vault_bump];
+    let portfolio_seeds: &[&[u8]] = &[PORTFOLIO_SEED, portfolio_bump];
+    let fee_vault_seeds: &[&[u8]] = &[LZ_FEE_VAULT_SEED, fee_vault_bump];
+    let send_seeds: &[&[&[u8]]] = if remote.protocol_pays_fee {
+        &[portfolio_seeds, fee_vault_seeds]
+    } else {
+        &[portfolio_seeds]
+    };
+
+    let send_cpi_data = create_instruction_data(&send_params, ENDPOINT_SEND)?;
+
+    let portfolio_key = portfolio.key();
+    let send_accounts_metas: Vec<AccountMeta> = send_remaining_accounts
+        .iter()
+        .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
+        .map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.key() == portfolio_key
+                || (remote.protocol_pays_fee && account.key() == fee_vault_key)
+                || account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+
+    // Invoke CPI send
+    if cfg!(not(test)) {
+        invoke_signed(
+            &Instruction {
+                program_id: endpoint_program.key(),
+                accounts: send_accounts_metas,
+                data: send_cpi_data,
+            },
+            send_remaining_accounts,
+            send_seeds,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Quotes the fee of sending a message to the remote OApp on `dst_eid`
+/// The remaining accounts start with the quote accounts
+pub fn quote<'info>(
+    portfolio: &Account<Portfolio>,
+    remote: &Account<Remote>,
+    endpoint_program: &AccountInfo,
+    remaining_accounts: &[AccountInfo<'info>],
+    dst_eid: u32,
+    message: Vec<u8>,
+    options: Vec<u8>,
+) -> Result<MessagingFee> {
+    require!(
+        remaining_accounts.len() >= QUOTE_REMAINING_ACCOUNTS_COUNT,
+        DexalotError::LzQuoteError
+    );
+    let quote_params = EndpointQuoteParams {
+        sender: portfolio.key(),
+        dst_eid,
+        receiver: remote.address,
+        message,
+        pay_in_lz_token: false,
+        options,
+    };
+
+    let quote_cpi_data = create_instruction_data(&quote_params, ENDPOINT_QUOTE)?;
+    let quote_remaining_accounts = &remaining_accounts[0..QUOTE_REMAINING_ACCOUNTS_COUNT];
+    let quote_accounts_metas: Vec<AccountMeta> = quote_remaining_accounts
+        .iter()
+        .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
+        .map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: false,
+        })
+        .collect();
+
+    // Invoke CPI quote
+    if cfg!(not(test)) {
+        invoke(
+            &Instruction {
+                program_id: endpoint_program.key(),
+                accounts: quote_accounts_metas,
+                data: quote_cpi_data,
+            },
+            quote_remaining_accounts,
+        )?;
+        let quote_return_data = get_return_data().ok_or(DexalotError::LzQuoteError)?;
+        Ok(MessagingFee::try_from_slice(&quote_return_data.1)?)
+    } else {
+        Ok(MessagingFee::default())
+    }
+}
+
+/// Checks the quoted fee against the cap set by the user, unless the protocol pays it
+pub fn check_native_fee(protocol_pays_fee: bool, native_fee: u64, max_native_fee: u64) -> Result<()> {
+    if !protocol_pays_fee {
+        require!(
+            native_fee <= max_native_fee,
+            DexalotError::LzFeeExceedsMax
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +530,60 @@ mod tests {
         let deserialized = ClearParams::try_from_slice(&serialized).unwrap();
         assert_eq!(clear_params, deserialized);
     }
+
+    #[test]
+    fn test_check_native_fee() {
+        assert!(check_native_fee(false, 100, 100).is_ok());
+        assert_eq!(
+            check_native_fee(false, 101, 100).unwrap_err(),
+            DexalotError::LzFeeExceedsMax.into()
+        );
+        // the user pays nothing when the protocol pays
+        assert!(check_native_fee(true, 101, 0).is_ok());
+    }
+
+    #[test]
+    fn test_options_builder() -> Result<()> {
+        // matches the default gas options
+        let options = OptionsBuilder::new()
+            .add_executor_lz_receive_option(400_000, 0)
+            .build();
+        assert_eq!(options, GAS_OPTIONS.to_vec());
+
+        let receiver = [9u8; 32];
+        let options = OptionsBuilder::from_executor_options(&[
+            ExecutorOption::LzReceive { gas: 1, value: 2 },
+            ExecutorOption::NativeDrop {
+                amount: 3,
+                receiver,
+            },
+            ExecutorOption::LzCompose {
+                index: 4,
+                gas: 5,
+                value: 0,
+            },
+        ])?
+        .build();
+
+        let mut expected = vec![0, 3];
+        expected.extend_from_slice(&[1, 0, 33, 1]);
+        expected.extend_from_slice(&1u128.to_be_bytes());
+        expected.extend_from_slice(&2u128.to_be_bytes());
+        expected.extend_from_slice(&[1, 0, 49, 2]);
+        expected.extend_from_slice(&3u128.to_be_bytes());
+        expected.extend_from_slice(&receiver);
+        expected.extend_from_slice(&[1, 0, 19, 3, 0, 4]);
+        expected.extend_from_slice(&5u128.to_be_bytes());
+        assert_eq!(options, expected);
+        assert!(options.len() <= crate::consts::MAX_LZ_OPTIONS_LEN);
+
+        let result = OptionsBuilder::from_executor_options(
+            &vec![ExecutorOption::LzReceive { gas: 1, value: 0 }; MAX_EXECUTOR_OPTIONS + 1],
+        );
+        assert_eq!(
+            result.err().unwrap(),
+            DexalotError::TooManyExecutorOptions.into()
+        );
+        Ok(())
+    }
 }
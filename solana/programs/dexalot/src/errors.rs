@@ -73,4 +73,90 @@ pub enum DexalotError {
     XFERError,
     #[msg("Destination not allowed")]
     DestinationNotAllowed,
+    #[msg("P-IBLF-01: Claim would take inventory below floor")]
+    InventoryBelowFloor,
+    #[msg("Invalid inventory account")]
+    InvalidInventoryAccount,
+    #[msg("Rebalance amount exceeds cap")]
+    RebalanceCapExceeded,
+    #[msg("P-RWLE-01: Rebalancer withdrawal limit exceeded")]
+    WithdrawalLimitExceeded,
+    #[msg("P-RWLN-01: No rebalancer withdrawal limit set for the token")]
+    WithdrawalLimitNotSet,
+    #[msg("P-DUTH-01: Deposit under threshold")]
+    DepositUnderThreshold,
+    #[msg("P-SCQT-01: Quantity not representable in target decimals")]
+    UnrepresentableQuantity,
+    #[msg("P-AOTC-01: Only trusted programs can deposit on behalf of traders")]
+    UntrustedProgram,
+    #[msg("PB-IUMF-01: LayerZero fee exceeds max native fee")]
+    LzFeeExceedsMax,
+    #[msg("Too many executor options")]
+    TooManyExecutorOptions,
+    #[msg("Invalid endpoint program")]
+    InvalidEndpointProgram,
+    #[msg("Too many native symbols for the chain")]
+    TooManyChainNatives,
+    #[msg("Token list page is not the next page")]
+    InvalidTokenListPage,
+    #[msg("Token list page already has a next page")]
+    TokenListPageLinked,
+    #[msg("P-TSAX-01: Token symbol is already added.")]
+    SymbolAlreadyAdded,
+    #[msg("P-TKNP-01: Token is paused")]
+    TokenPaused,
+    #[msg("P-TDNA-01: Deposits are not allowed for the token")]
+    TokenDepositsNotAllowed,
+    #[msg("P-TVNE-01: Token vault account is not empty")]
+    TokenVaultNotEmpty,
+    #[msg("Role is already granted to the account")]
+    RoleAlreadyGranted,
+    #[msg("Role is not granted to the account")]
+    RoleNotGranted,
+    #[msg("Role has too many members")]
+    RoleMembersFull,
+    #[msg("The last admin cannot be removed")]
+    LastAdminRemoval,
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+    #[msg("The action requires an approved proposal")]
+    ProposalRequired,
+    #[msg("Proposal does not match the action")]
+    ProposalMismatch,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalThresholdNotReached,
+    #[msg("Proposal is already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal is already approved by the admin")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal data is too long")]
+    ProposalDataTooLong,
+    #[msg("Invalid multisig threshold")]
+    InvalidMultisigThreshold,
+    #[msg("Too many protected actions")]
+    TooManyProtectedActions,
+    #[msg("Pending change does not match the action")]
+    PendingChangeMismatch,
+    #[msg("Pending change timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Pending change data is too long")]
+    ChangeDataTooLong,
+    #[msg("P-INSI-01: Inventory does not cover the amount")]
+    InsufficientInventory,
+    #[msg("Inventory amount overflows")]
+    InventoryOverflow,
+    #[msg("Token list page is not full")]
+    TokenListPageNotFull,
+    #[msg("Maker symbol is neither registered nor a native of the destination")]
+    InvalidMakerSymbol,
+    #[msg("Approver admin account does not match the approval")]
+    InvalidApproverAdmin,
+    #[msg("Timelock delay is below the minimum")]
+    TimelockDelayTooShort,
+    #[msg("Account is not in its legacy layout")]
+    AccountAlreadyMigrated,
+    #[msg("Token symbol does not match the token details")]
+    InvalidTokenSymbol,
+    #[msg("Bridge fees of the token are not collected")]
+    BridgeFeesNotCollected,
 }
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::instructions::PendingSwap;
-use crate::state::BanReason;
+use crate::instructions::{PendingSwap, RebalanceDirection};
+use crate::state::{BanReason, SensitiveAction, TimelockedAction};
 use crate::xfer::Tx;
 
 // BannedAccount events
@@ -23,7 +23,117 @@ pub struct RoleGrantedEvent {
 #[event]
 pub struct RoleRevokedEvent {
     pub role: [u8; 32],
+    pub account: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferProposedEvent {
     pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferredEvent {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+// Multisig events
+#[event]
+pub struct ProposalCreatedEvent {
+    pub id: u64,
+    pub action: SensitiveAction,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct ProposalApprovedEvent {
+    pub id: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ProposalExecutedEvent {
+    pub id: u64,
+    pub action: SensitiveAction,
+}
+
+#[event]
+pub struct MultisigConfigUpdatedEvent {
+    pub threshold: u8,
+    pub protected_actions: Vec<SensitiveAction>,
+}
+
+// Timelock events
+#[event]
+pub struct ParameterChangeQueued {
+    pub id: u64,
+    pub action: TimelockedAction,
+    pub data: Vec<u8>,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ParameterChangeExecuted {
+    pub id: u64,
+    pub action: TimelockedAction,
+}
+
+#[event]
+pub struct ParameterChangeCancelled {
+    pub id: u64,
+    pub action: TimelockedAction,
+}
+
+// Trusted program events
+// event AddressSet(string indexed name, string actionName, address oldAddress, address newAddress);
+#[event]
+pub struct TrustedProgramUpdatedEvent {
+    pub program: Pubkey,
+    pub organization: [u8; 32],
+    pub trusted: bool,
+}
+
+// event UserPaysFeeForDestinationUpdated(BridgeProvider bridge, uint32 destinationChainId, bool userPaysFee);
+#[event]
+pub struct UserPaysFeeForDestinationUpdated {
+    pub dst_eid: u32,
+    pub user_pays_fee: bool,
+}
+
+#[event]
+pub struct LzFeeVaultFundedEvent {
+    pub funder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct LzFeeVaultWithdrawnEvent {
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ChainUpdatedEvent {
+    pub chain_id: u32,
+    pub eid: u32,
+    pub native_symbols: Vec<[u8; 32]>,
+    pub supported: bool,
+}
+
+#[event]
+pub struct DepositDestinationUpdatedEvent {
+    pub chain_id: u32,
+    pub token_mint: Pubkey,
+    pub allowed: bool,
+}
+
+#[event]
+pub struct LzOptionsUpdatedEvent {
+    pub dst_eid: u32,
+    pub kind: u8,
+    pub options: Vec<u8>,
 }
 
 // Portfolio events
@@ -104,3 +214,43 @@ pub struct SolTransfer {
     pub transaction: SolTransferTransactions,
     pub transfer_type: SolTransferTypes,
 }
+
+// LayerZero non-blocking receive events
+#[event]
+pub struct MessageFailedEvent {
+    pub src_eid: u32,
+    pub nonce: u64,
+    pub guid: [u8; 32],
+    pub reason: String,
+}
+
+#[event]
+pub struct MessageRetriedEvent {
+    pub src_eid: u32,
+    pub nonce: u64,
+    pub guid: [u8; 32],
+}
+
+#[event]
+pub struct MessageDiscardedEvent {
+    pub src_eid: u32,
+    pub nonce: u64,
+    pub guid: [u8; 32],
+}
+
+#[event]
+pub struct InventoryReconciledEvent {
+    pub token_mint: Pubkey,
+    pub user_funds: u64,
+    pub rfq_inventory: u64,
+    pub bridge_fees: u64,
+}
+
+// Vault rebalancing events
+#[event]
+pub struct VaultsRebalancedEvent {
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub direction: RebalanceDirection,
+    pub rebalancer: Pubkey,
+}
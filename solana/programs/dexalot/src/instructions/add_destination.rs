@@ -3,12 +3,14 @@ use anchor_lang::prelude::*;
 use crate::{
     consts::{ADMIN_SEED, CCTRADE_ALLOWED_DEST_SEED},
     errors::DexalotError,
-    state::AllowedDestinationEntry,
+    instructions::consume_change,
+    state::{AllowedDestinationEntry, PendingChange, TimelockedAction},
 };
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub struct AddDestinationParams {
-    pub eid: u32,
+    // chainlist ID of the destination
+    pub chain_id: u32,
     pub token_address: Pubkey,
 }
 
@@ -22,7 +24,7 @@ pub struct AddDestination<'info> {
         init_if_needed,
         payer = payer,
         space = AllowedDestinationEntry::LEN,
-        seeds = [CCTRADE_ALLOWED_DEST_SEED, &params.eid.to_be_bytes(), &params.token_address.to_bytes()],
+        seeds = [CCTRADE_ALLOWED_DEST_SEED, &params.chain_id.to_be_bytes(), &params.token_address.to_bytes()],
         bump
     )]
     pub destination_entry: Account<'info, AllowedDestinationEntry>,
@@ -31,17 +33,24 @@ pub struct AddDestination<'info> {
             seeds = [ADMIN_SEED, payer.key().as_ref()],
             bump)]
     pub admin: AccountInfo<'info>,
+    #[account(mut, close = payer)]
+    pub pending_change: Account<'info, PendingChange>,
 }
 
 pub fn add_destination(
     ctx: &Context<AddDestination>,
-    _params: &AddDestinationParams,
+    params: &AddDestinationParams,
 ) -> Result<()> {
     let admin = &ctx.accounts.admin;
     require!(
         admin.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
+    consume_change(
+        &ctx.accounts.pending_change,
+        TimelockedAction::AddDestination,
+        params,
+    )?;
 
     Ok(())
 }
@@ -53,12 +62,13 @@ mod tests {
     use anchor_lang::solana_program::system_program;
     use crate::{
         state::AllowedDestinationEntry,
-        test_utils::create_account_info,
+        test_utils::{create_account_info, create_pending_change_data},
     };
 
     #[test]
     fn test_add_destination_success() -> Result<()> {
         let program_id = crate::id();
+        let token_address = Pubkey::new_unique();
         let payer_key = Pubkey::new_unique();
         let mut payer_data = vec![0u8; 100];
         let mut payer_lamports = 100;
@@ -112,15 +122,36 @@ mod tests {
             false,
             None,
         );
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = create_pending_change_data(
+            TimelockedAction::AddDestination,
+            &AddDestinationParams {
+                chain_id: 42,
+                token_address,
+            },
+            0,
+        );
+        let pending_change_info = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
         let mut add_dest_accounts = AddDestination {
             payer: Signer::try_from(&payer_info)?,
             system_program: Program::try_from(&system_info)?,
             destination_entry: Account::try_from(&dest_info)?,
             admin: admin_info,
+            pending_change: Account::try_from(&pending_change_info)?,
         };
         let add_params = AddDestinationParams {
-            eid: 42,
-            token_address: Pubkey::new_unique(),
+            chain_id: 42,
+            token_address,
         };
         let ctx = Context {
             accounts: &mut add_dest_accounts,
@@ -136,6 +167,7 @@ mod tests {
     #[test]
     fn test_add_destination_fail_wrong_admin() -> Result<()> {
         let program_id = crate::id();
+        let token_address = Pubkey::new_unique();
         let payer_key = Pubkey::new_unique();
         let mut payer_data = vec![0u8; 100];
         let mut payer_lamports = 100;
@@ -189,15 +221,36 @@ mod tests {
             false,
             None,
         );
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = create_pending_change_data(
+            TimelockedAction::AddDestination,
+            &AddDestinationParams {
+                chain_id: 42,
+                token_address,
+            },
+            0,
+        );
+        let pending_change_info = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
         let mut add_dest_accounts = AddDestination {
             payer: Signer::try_from(&payer_info)?,
             system_program: Program::try_from(&system_info)?,
             destination_entry: Account::try_from(&dest_info)?,
             admin: admin_info,
+            pending_change: Account::try_from(&pending_change_info)?,
         };
         let add_params = AddDestinationParams {
-            eid: 42,
-            token_address: Pubkey::new_unique(),
+            chain_id: 42,
+            token_address,
         };
         let ctx = Context {
             accounts: &mut add_dest_accounts,
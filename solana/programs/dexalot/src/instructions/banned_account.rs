@@ -4,6 +4,15 @@ use crate::events::BanStatusChangedEvent;
 use crate::state::{BanReason, BannedAccount};
 use anchor_lang::prelude::*;
 
+/// Fails if the banned account PDA derived from the user exists
+pub fn check_not_banned(banned_account: &AccountInfo, program_id: &Pubkey) -> Result<()> {
+    require!(
+        banned_account.owner != program_id,
+        DexalotError::AccountBanned
+    );
+    Ok(())
+}
+
 pub fn ban_account(ctx: &mut Context<BanAccount>, params: &BanAccountParams) -> Result<()> {
     let admin = &ctx.accounts.admin;
 
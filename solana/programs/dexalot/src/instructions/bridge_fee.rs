@@ -0,0 +1,746 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::token::{spl_token, Token, TokenAccount};
+
+use crate::consts::{
+    ADMIN_SEED, NATIVE_TOKEN_MINT, NATIVE_VAULT_MIN_THRESHOLD, SOL_USER_FUNDS_VAULT_SEED,
+    SPL_USER_FUNDS_VAULT_SEED, TOKEN_DETAILS_SEED, TOKEN_INVENTORY_SEED,
+};
+use crate::errors::DexalotError;
+use crate::events::ParameterUpdatedEvent;
+use crate::instructions::{check_ata_account, update_inventory, WriteConfig};
+use crate::state::{TokenDetails, TokenInventory};
+
+#[derive(Accounts)]
+#[instruction(params: SetBridgeFeeParams)]
+pub struct SetBridgeFee<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [TOKEN_DETAILS_SEED, params.token_mint.as_ref()],
+        bump
+    )]
+    pub token_details: Account<'info, TokenDetails>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetBridgeFeeParams {
+    pub token_mint: Pubkey,
+    pub fee: u64,
+}
+
+/// Sets the bridge fee withheld from every deposit of the token
+pub fn set_bridge_fee(ctx: &mut Context<SetBridgeFee>, params: &SetBridgeFeeParams) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let token_details = &mut ctx.accounts.token_details;
+    let old_fee = token_details.bridge_fee;
+    token_details.bridge_fee = params.fee;
+
+    emit!(ParameterUpdatedEvent {
+        pair: token_details.symbol,
+        parameter: "P-BRIDGEFEE".to_owned(),
+        old_value: old_fee,
+        new_value: params.fee
+    });
+
+    Ok(())
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetBridgeFeeNativeParams {
+    pub fee: u64,
+}
+
+/// Sets the bridge fee in lamports withheld from every SOL deposit
+pub fn set_bridge_fee_native(
+    ctx: &mut Context<WriteConfig>,
+    params: &SetBridgeFeeNativeParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let global_config = &mut ctx.accounts.portfolio.global_config;
+    let old_fee = global_config.bridge_fee_native;
+    global_config.bridge_fee_native = params.fee;
+
+    emit!(ParameterUpdatedEvent {
+        pair: NATIVE_TOKEN_MINT.to_bytes(),
+        parameter: "P-BRIDGEFEE".to_owned(),
+        old_value: old_fee,
+        new_value: params.fee
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: CollectBridgeFeesParams)]
+pub struct CollectBridgeFees<'info> {
+    /// The admin collecting the fees, receives them for SOL
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the sol user funds vault
+    #[account(
+        mut,
+        seeds = [SOL_USER_FUNDS_VAULT_SEED],
+        bump,
+    )]
+    pub sol_user_funds_vault: AccountInfo<'info>,
+    /// CHECK: spl user funds vault address
+    #[account(
+        constraint = spl_user_funds_vault.owner == __program_id @ DexalotError::InvalidVaultOwner,
+        seeds = [SPL_USER_FUNDS_VAULT_SEED],
+        bump,
+    )]
+    pub spl_user_funds_vault: AccountInfo<'info>,
+    /// CHECK: ATA or Zero PublicKey
+    #[account(mut)]
+    pub spl_user_funds_vault_ata: AccountInfo<'info>,
+    /// CHECK: token account of the authority or Zero PublicKey
+    #[account(mut)]
+    pub to: AccountInfo<'info>,
+    /// CHECK: the token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, params.token_mint.as_ref()],
+        bump,
+    )]
+    pub token_inventory: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct CollectBridgeFeesParams {
+    pub token_mint: Pubkey,
+}
+
+/// Transfers the bridge fees collected for the token to the admin
+/// Fees are held in the user funds vault and tracked by the token inventory's `bridge_fees` ledger
+pub fn collect_bridge_fees(
+    ctx: &Context<CollectBridgeFees>,
+    params: &CollectBridgeFeesParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let token_inventory = &ctx.accounts.token_inventory;
+    let fees = {
+        let data = token_inventory.try_borrow_data()?;
+        TokenInventory::try_deserialize(&mut &data[..])?.bridge_fees
+    };
+    if fees == 0 {
+        return Ok(());
+    }
+
+    if params.token_mint == NATIVE_TOKEN_MINT {
+        collect_native(ctx, fees)?;
+    } else {
+        collect_spl(ctx, params, fees)?;
+    }
+
+    update_inventory(token_inventory, |inventory| {
+        inventory.take_bridge_fees();
+        Ok(())
+    })
+}
+
+fn collect_native(ctx: &Context<CollectBridgeFees>, fees: u64) -> Result<()> {
+    let from = &ctx.accounts.sol_user_funds_vault;
+    let to = &ctx.accounts.authority;
+    let system_program = &ctx.accounts.system_program;
+
+    // check balance
+    require!(
+        from.lamports() >= fees + NATIVE_VAULT_MIN_THRESHOLD,
+        DexalotError::NotEnoughNativeBalance
+    );
+
+    let bump = &[ctx.bumps.sol_user_funds_vault];
+    let seeds: &[&[u8]] = &[SOL_USER_FUNDS_VAULT_SEED, bump];
+
+    let ix = system_instruction::transfer(&from.key(), &to.key(), fees);
+    if cfg!(not(test)) {
+        invoke_signed(
+            &ix,
+            &[
+                from.to_account_info(),
+                to.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[seeds], // sign with the PDA
+        )?;
+    }
+    Ok(())
+}
+
+fn collect_spl(
+    ctx: &Context<CollectBridgeFees>,
+    params: &CollectBridgeFeesParams,
+    fees: u64,
+) -> Result<()> {
+    let token_program = &ctx.accounts.token_program;
+    let spl_user_funds_vault = &ctx.accounts.spl_user_funds_vault;
+    let from = &ctx.accounts.spl_user_funds_vault_ata;
+    let to = &ctx.accounts.to;
+
+    check_ata_account(from, spl_user_funds_vault.key, &params.token_mint, true)?;
+    check_ata_account(to, ctx.accounts.authority.key, &params.token_mint, false)?;
+
+    // check balance
+    let from_amount = {
+        let mut data: &[u8] = &from.try_borrow_data()?;
+        TokenAccount::try_deserialize(&mut data)?.amount
+    };
+    require!(from_amount >= fees, DexalotError::NotEnoughSplTokenBalance);
+
+    let ix = spl_token::instruction::transfer(
+        &token_program.key(),
+        &from.key(),
+        &to.key(),
+        &spl_user_funds_vault.key(),
+        &[],
+        fees,
+    )?;
+
+    let bump = &[ctx.bumps.spl_user_funds_vault];
+    let seeds: &[&[u8]] = &[SPL_USER_FUNDS_VAULT_SEED, bump];
+
+    if cfg!(not(test)) {
+        invoke_signed(
+            &ix,
+            &[
+                from.to_account_info(),
+                to.to_account_info(),
+                spl_user_funds_vault.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            &[seeds],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::WriteConfigBumps;
+    use crate::state::Portfolio;
+    use crate::test_utils::{
+        create_account_info, create_packed_token_account, create_token_inventory_data,
+    };
+    use anchor_lang::{solana_program::system_program, Discriminator};
+    use anchor_spl::associated_token::get_associated_token_address;
+    use anchor_spl::token;
+
+    #[test]
+    fn test_set_bridge_fee() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = TokenDetails {
+            token_address: token_mint,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+
+        let mut accounts = SetBridgeFee {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            token_details: Account::try_from(&token_details_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetBridgeFeeBumps::default(),
+        };
+        let params = SetBridgeFeeParams {
+            token_mint,
+            fee: 25,
+        };
+
+        // not an admin
+        let result = set_bridge_fee(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        set_bridge_fee(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.token_details.bridge_fee, 25);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_bridge_fee_native() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let portfolio_key = Pubkey::new_unique();
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+
+        let mut accounts = WriteConfig {
+            authority: Signer::try_from(&authority_info)?,
+            portfolio: Account::try_from(&portfolio_info)?,
+            admin: admin_info,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: WriteConfigBumps::default(),
+        };
+        let params = SetBridgeFeeNativeParams { fee: 5000 };
+
+        set_bridge_fee_native(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.portfolio.global_config.bridge_fee_native, 5000);
+
+        // not an admin
+        let mut invalid_admin_lamports = 100;
+        let mut invalid_admin_data = vec![0u8; 10];
+        let invalid_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut invalid_admin_lamports,
+            &mut invalid_admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        ctx.accounts.admin = invalid_admin_info;
+        let result = set_bridge_fee_native(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_bridge_fees_native() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let sol_user_funds_vault_key = Pubkey::new_unique();
+        let mut sol_user_funds_vault_lamports = 300 + NATIVE_VAULT_MIN_THRESHOLD;
+        let mut sol_user_funds_vault_data = vec![];
+        let sol_user_funds_vault_info = create_account_info(
+            &sol_user_funds_vault_key,
+            false,
+            true,
+            &mut sol_user_funds_vault_lamports,
+            &mut sol_user_funds_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let generic_key = Pubkey::new_unique();
+        let mut generic_lamports = 100;
+        let mut generic_data = vec![0u8; 10];
+        let generic_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut generic_lamports,
+            &mut generic_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory {
+            bridge_fees: 500,
+            ..Default::default()
+        });
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut token_program_lamports = 100;
+        let mut token_program_data = vec![0u8; 10];
+        let token_program_info = create_account_info(
+            &token::ID,
+            false,
+            false,
+            &mut token_program_lamports,
+            &mut token_program_data,
+            &token::ID,
+            true,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = CollectBridgeFees {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            sol_user_funds_vault: sol_user_funds_vault_info,
+            spl_user_funds_vault: generic_info.clone(),
+            spl_user_funds_vault_ata: generic_info.clone(),
+            to: generic_info,
+            token_inventory: token_inventory_info.clone(),
+            token_program: Program::try_from(&token_program_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: CollectBridgeFeesBumps::default(),
+        };
+        let params = CollectBridgeFeesParams {
+            token_mint: NATIVE_TOKEN_MINT,
+        };
+
+        // not an admin
+        let result = collect_bridge_fees(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        // the vault does not hold the fees
+        let result = collect_bridge_fees(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::NotEnoughNativeBalance.into()
+        );
+
+        update_inventory(&token_inventory_info, |inventory| {
+            inventory.bridge_fees = 300;
+            Ok(())
+        })?;
+        collect_bridge_fees(&ctx, &params)?;
+        let inventory =
+            TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.bridge_fees, 0);
+
+        // nothing left to collect
+        collect_bridge_fees(&ctx, &params)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_bridge_fees_spl() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let spl_user_funds_vault_key = Pubkey::new_unique();
+        let mut spl_user_funds_vault_lamports = 100;
+        let mut spl_user_funds_vault_data = vec![0u8; 10];
+        let spl_user_funds_vault_info = create_account_info(
+            &spl_user_funds_vault_key,
+            false,
+            false,
+            &mut spl_user_funds_vault_lamports,
+            &mut spl_user_funds_vault_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let spl_user_funds_vault_ata_key =
+            get_associated_token_address(&spl_user_funds_vault_key, &token_mint);
+        let mut spl_user_funds_vault_ata_lamports = 100;
+        let mut spl_user_funds_vault_ata_data =
+            create_packed_token_account(token_mint, spl_user_funds_vault_key, 1000)?;
+        let spl_user_funds_vault_ata_info = create_account_info(
+            &spl_user_funds_vault_ata_key,
+            false,
+            true,
+            &mut spl_user_funds_vault_ata_lamports,
+            &mut spl_user_funds_vault_ata_data,
+            &token::ID,
+            false,
+            None,
+        );
+
+        let to_key = Pubkey::new_unique();
+        let mut to_lamports = 100;
+        let mut to_data = create_packed_token_account(token_mint, authority_key, 0)?;
+        let to_info = create_account_info(
+            &to_key,
+            false,
+            true,
+            &mut to_lamports,
+            &mut to_data,
+            &token::ID,
+            false,
+            None,
+        );
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory {
+            user_funds: 900,
+            bridge_fees: 100,
+            ..Default::default()
+        });
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut token_program_lamports = 100;
+        let mut token_program_data = vec![0u8; 10];
+        let token_program_info = create_account_info(
+            &token::ID,
+            false,
+            false,
+            &mut token_program_lamports,
+            &mut token_program_data,
+            &token::ID,
+            true,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = CollectBridgeFees {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            sol_user_funds_vault: spl_user_funds_vault_info.clone(),
+            spl_user_funds_vault: spl_user_funds_vault_info,
+            spl_user_funds_vault_ata: spl_user_funds_vault_ata_info.clone(),
+            to: spl_user_funds_vault_ata_info,
+            token_inventory: token_inventory_info.clone(),
+            token_program: Program::try_from(&token_program_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: CollectBridgeFeesBumps::default(),
+        };
+        let params = CollectBridgeFeesParams { token_mint };
+
+        // the fees can only go to the authority
+        let result = collect_bridge_fees(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidTokenOwner.into());
+
+        ctx.accounts.to = to_info;
+        collect_bridge_fees(&ctx, &params)?;
+        let inventory =
+            TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.bridge_fees, 0);
+        assert_eq!(inventory.user_funds, 900);
+        Ok(())
+    }
+}
@@ -0,0 +1,462 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{ADMIN_SEED, CHAIN_SEED, MAX_CHAIN_NATIVES, MULTISIG_CONFIG_SEED, REMOTE_SEED};
+use crate::errors::DexalotError;
+use crate::events::ChainUpdatedEvent;
+use crate::instructions::{authorize_action, consume_change, load_multisig_config};
+use crate::state::{ChainEntry, PendingChange, Proposal, Remote, SensitiveAction, TimelockedAction};
+
+#[derive(Accounts)]
+#[instruction(params: SetChainParams)]
+pub struct SetChain<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        seeds = [REMOTE_SEED, &params.eid.to_be_bytes()],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ChainEntry::LEN,
+        seeds = [CHAIN_SEED, &params.chain_id.to_be_bytes()],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(seeds = [MULTISIG_CONFIG_SEED], bump)]
+    pub multisig_config: AccountInfo<'info>,
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    #[account(mut, close = authority)]
+    pub pending_change: Account<'info, PendingChange>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetChainParams {
+    // chainlist ID, as used by the EVM side
+    pub chain_id: u32,
+    pub eid: u32,
+    pub native_symbols: Vec<[u8; 32]>,
+}
+
+/// Maps a chainlist ID to its LayerZero endpoint ID and supported natives
+/// The remote of the endpoint ID has to be set first and the change queued behind the timelock
+pub fn set_chain(ctx: &mut Context<SetChain>, params: &SetChainParams) -> Result<()> {
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::SetChain,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    require!(
+        params.native_symbols.len() <= MAX_CHAIN_NATIVES,
+        DexalotError::TooManyChainNatives
+    );
+    consume_change(&ctx.accounts.pending_change, TimelockedAction::SetChain, params)?;
+
+    let chain_entry = &mut ctx.accounts.chain_entry;
+    chain_entry.eid = params.eid;
+    chain_entry.native_symbols = params.native_symbols.clone();
+
+    emit!(ChainUpdatedEvent {
+        chain_id: params.chain_id,
+        eid: params.eid,
+        native_symbols: params.native_symbols.clone(),
+        supported: true,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: RemoveChainParams)]
+pub struct RemoveChain<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(mut)]
+    pub receiver: SystemAccount<'info>,
+    #[account(
+        mut,
+        close = receiver, // Refund lamports to the receiver
+        seeds = [CHAIN_SEED, &params.chain_id.to_be_bytes()],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(seeds = [MULTISIG_CONFIG_SEED], bump)]
+    pub multisig_config: AccountInfo<'info>,
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    #[account(mut, close = authority)]
+    pub pending_change: Account<'info, PendingChange>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct RemoveChainParams {
+    pub chain_id: u32,
+}
+
+/// Removes a chain from the registry
+/// Stops deposits and cross swaps to the chain, so the change is queued behind the timelock as well
+pub fn remove_chain(ctx: &mut Context<RemoveChain>, params: &RemoveChainParams) -> Result<()> {
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::RemoveChain,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    consume_change(&ctx.accounts.pending_change, TimelockedAction::RemoveChain, params)?;
+
+    let chain_entry = &ctx.accounts.chain_entry;
+    emit!(ChainUpdatedEvent {
+        chain_id: params.chain_id,
+        eid: chain_entry.eid,
+        native_symbols: chain_entry.native_symbols.clone(),
+        supported: false,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_account_info, create_pending_change_data};
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_set_chain() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let remote_key = Pubkey::new_unique();
+        let mut remote_lamports = 100;
+        let mut remote_data = vec![0u8; Remote::SIZE];
+        let remote_info = create_account_info(
+            &remote_key,
+            false,
+            false,
+            &mut remote_lamports,
+            &mut remote_data,
+            &program_id,
+            false,
+            Some(Remote::discriminator()),
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = vec![0u8; ChainEntry::LEN];
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            true,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut params = SetChainParams {
+            chain_id: 432204,
+            eid: 40267,
+            native_symbols: vec![[1; 32]],
+        };
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = create_pending_change_data(TimelockedAction::SetChain, &params, 0);
+        let pending_change_info = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = SetChain {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            remote: Account::try_from(&remote_info)?,
+            chain_entry: Account::try_from(&chain_entry_info)?,
+            multisig_config,
+            proposal: None,
+            pending_change: Account::try_from(&pending_change_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetChainBumps::default(),
+        };
+
+        // not an admin
+        let result = set_chain(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        // the queued change is for other params
+        let mut other_params = params.clone();
+        other_params.eid = 40268;
+        let result = set_chain(&mut ctx, &other_params);
+        assert_eq!(result.unwrap_err(), DexalotError::PendingChangeMismatch.into());
+
+        set_chain(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.chain_entry.eid, 40267);
+        assert_eq!(ctx.accounts.chain_entry.native_symbols, vec![[1; 32]]);
+
+        // too many natives
+        params.native_symbols = vec![[1; 32]; MAX_CHAIN_NATIVES + 1];
+        let result = set_chain(&mut ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::TooManyChainNatives.into()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_chain() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let receiver_key = Pubkey::new_unique();
+        let mut receiver_lamports = 100;
+        let mut receiver_data = vec![];
+        let receiver_info = create_account_info(
+            &receiver_key,
+            false,
+            true,
+            &mut receiver_lamports,
+            &mut receiver_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry {
+            eid: 40267,
+            native_symbols: vec![],
+        }
+        .try_to_vec()?;
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            true,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let params = RemoveChainParams { chain_id: 432204 };
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data =
+            create_pending_change_data(TimelockedAction::RemoveChain, &params, 0);
+        let pending_change_info = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = RemoveChain {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            receiver: SystemAccount::try_from(&receiver_info)?,
+            chain_entry: Account::try_from(&chain_entry_info)?,
+            multisig_config,
+            proposal: None,
+            pending_change: Account::try_from(&pending_change_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RemoveChainBumps::default(),
+        };
+
+        // not an admin
+        let result = remove_chain(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        // the queued change is for another chain
+        let result = remove_chain(&mut ctx, &RemoveChainParams { chain_id: 43114 });
+        assert_eq!(result.unwrap_err(), DexalotError::PendingChangeMismatch.into());
+
+        remove_chain(&mut ctx, &params)?;
+        Ok(())
+    }
+}
@@ -5,8 +5,18 @@ use anchor_lang::{
 use anchor_spl::token::{spl_token, Mint, Token, TokenAccount};
 
 use crate::{
-    consts::{ADMIN_SEED, AIRDROP_VAULT_SEED, NATIVE_VAULT_MIN_THRESHOLD, REBALANCER_SEED, SOL_VAULT_SEED, SPL_VAULT_SEED},
+    consts::{
+        ADMIN_SEED, AIRDROP_VAULT_SEED, MULTISIG_CONFIG_SEED, NATIVE_TOKEN_MINT,
+        NATIVE_VAULT_MIN_THRESHOLD, REBALANCER_LIMIT_SEED, REBALANCER_SEED, SOL_VAULT_SEED,
+        SPL_VAULT_SEED, TOKEN_INVENTORY_SEED,
+    },
     errors::DexalotError,
+    instructions::{
+        authorize_action, check_ata_account, load_multisig_config,
+        rebalancer_limit_address, record_rebalancer_withdrawal, token_inventory_address,
+        update_inventory,
+    },
+    state::{Proposal, SensitiveAction},
 };
 
 #[derive(Accounts, Clone)]
@@ -43,6 +53,20 @@ pub struct ClaimSplBalance<'info> {
         ) @ DexalotError::InvalidDestinationOwner,
     )]
     pub to: Account<'info, TokenAccount>,
+    /// CHECK: the token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub token_inventory: AccountInfo<'info>,
+    /// CHECK: the rebalancer's withdrawal limit for the token
+    #[account(
+        mut,
+        seeds = [REBALANCER_LIMIT_SEED, authority.key().as_ref(), mint.key().as_ref()],
+        bump,
+    )]
+    pub rebalancer_limit: AccountInfo<'info>,
 }
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
@@ -71,6 +95,10 @@ pub fn claim_spl_balance(
         from.amount >= params.amount,
         DexalotError::NotEnoughSplTokenBalance
     );
+    record_rebalancer_withdrawal(&ctx.accounts.rebalancer_limit, params.amount)?;
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.claim_rfq_inventory(params.amount)
+    })?;
 
     let ix = spl_token::instruction::transfer(
         &token_program.key(),
@@ -117,6 +145,20 @@ pub struct ClaimNativeBalance<'info> {
         bump,
     )]
     pub sol_vault: AccountInfo<'info>,
+    /// CHECK: the native token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, NATIVE_TOKEN_MINT.as_ref()],
+        bump,
+    )]
+    pub token_inventory: AccountInfo<'info>,
+    /// CHECK: the rebalancer's withdrawal limit for SOL
+    #[account(
+        mut,
+        seeds = [REBALANCER_LIMIT_SEED, authority.key().as_ref(), NATIVE_TOKEN_MINT.as_ref()],
+        bump,
+    )]
+    pub rebalancer_limit: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -144,6 +186,10 @@ pub fn claim_native_balance(
         sol_vault.lamports() >= params.amount + NATIVE_VAULT_MIN_THRESHOLD,
         DexalotError::NotEnoughNativeBalance
     );
+    record_rebalancer_withdrawal(&ctx.accounts.rebalancer_limit, params.amount)?;
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.claim_rfq_inventory(params.amount)
+    })?;
 
     let bump = &[ctx.bumps.sol_vault];
     let seeds: &[&[u8]] = &[SOL_VAULT_SEED.as_ref(), bump];
@@ -180,6 +226,14 @@ pub struct ClaimAirdropBalance<'info> {
         bump,
     )]
     pub airdrop_vault: AccountInfo<'info>,
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(
+        seeds = [MULTISIG_CONFIG_SEED],
+        bump,
+    )]
+    pub multisig_config: AccountInfo<'info>,
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -189,19 +243,31 @@ pub struct ClaimAirdropBalanceParams {
 }
 
 pub fn claim_airdrop_balance(
-    ctx: &Context<ClaimAirdropBalance>,
+    ctx: &mut Context<ClaimAirdropBalance>,
     params: &ClaimAirdropBalanceParams,
 ) -> Result<()> {
+    // check admin or approved proposal
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    let proposer = authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::ClaimAirdropBalance,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
     let authority = &ctx.accounts.authority;
     let airdrop_vault = &ctx.accounts.airdrop_vault;
-    let admin = &ctx.accounts.admin;
     let system_program = &ctx.accounts.system_program;
 
-    // check admin
-    require!(
-        admin.owner == ctx.program_id,
-        DexalotError::UnauthorizedSigner
-    );
+    // the funds go to the signer, so an approved claim is paid to its proposer only
+    if let Some(proposer) = proposer {
+        require!(
+            authority.key() == proposer,
+            DexalotError::UnauthorizedSigner
+        );
+    }
     // check balance
     require!(
         airdrop_vault.lamports() >= params.amount,
@@ -228,11 +294,185 @@ pub fn claim_airdrop_balance(
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct BatchClaimBalance<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: the rebalancer
+    #[account(
+            seeds = [REBALANCER_SEED, authority.key().as_ref()],
+            bump
+        )]
+    pub rebalancer: AccountInfo<'info>,
+    /// CHECK: spl vault address
+    #[account(
+        constraint = spl_vault.owner == __program_id @ DexalotError::InvalidVaultOwner,
+        seeds = [SPL_VAULT_SEED],
+        bump,
+    )]
+    pub spl_vault: AccountInfo<'info>,
+    /// CHECK: the sol vault
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED],
+        bump,
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    /// CHECK: the native token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, NATIVE_TOKEN_MINT.as_ref()],
+        bump,
+    )]
+    pub native_token_inventory: AccountInfo<'info>,
+    /// CHECK: the rebalancer's withdrawal limit for SOL
+    #[account(
+        mut,
+        seeds = [REBALANCER_LIMIT_SEED, authority.key().as_ref(), NATIVE_TOKEN_MINT.as_ref()],
+        bump,
+    )]
+    pub native_rebalancer_limit: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct BatchClaimBalanceParams {
+    pub tokens: Vec<ClaimSplBalanceParams>,
+    pub native_amount: Option<u64>,
+}
+
+/// Number of remaining accounts expected for every claimed token:
+/// the spl vault ATA, the authority ATA, the token inventory and the rebalancer limit
+pub const BATCH_CLAIM_ACCOUNTS_PER_TOKEN: usize = 4;
+
+/// Claims several SPL tokens and optionally SOL from the program's vaults in one call
+///
+/// Remaining accounts must hold one `[spl vault ATA, authority ATA, token inventory, rebalancer limit]`
+/// group per entry of `params.tokens`, in the same order.
+pub fn batch_claim_balance<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, BatchClaimBalance<'info>>,
+    params: &BatchClaimBalanceParams,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let rebalancer = &ctx.accounts.rebalancer;
+    let spl_vault = &ctx.accounts.spl_vault;
+    let sol_vault = &ctx.accounts.sol_vault;
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+
+    // check rebalancer
+    require!(
+        rebalancer.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(
+        ctx.remaining_accounts.len() == params.tokens.len() * BATCH_CLAIM_ACCOUNTS_PER_TOKEN,
+        DexalotError::AccountsNotProvided
+    );
+
+    let spl_vault_bump = &[ctx.bumps.spl_vault];
+    let spl_vault_seeds: &[&[u8]] = &[SPL_VAULT_SEED, spl_vault_bump];
+
+    for (claim, group) in params
+        .tokens
+        .iter()
+        .zip(ctx.remaining_accounts.chunks(BATCH_CLAIM_ACCOUNTS_PER_TOKEN))
+    {
+        let from = &group[0];
+        let to = &group[1];
+        let token_inventory = &group[2];
+        let rebalancer_limit = &group[3];
+
+        check_ata_account(from, spl_vault.key, &claim.token_address, true)?;
+        check_ata_account(to, authority.key, &claim.token_address, true)?;
+
+        let from_amount = {
+            let mut data: &[u8] = &from.try_borrow_data()?;
+            TokenAccount::try_deserialize(&mut data)?.amount
+        };
+        // check balance
+        require!(
+            from_amount >= claim.amount,
+            DexalotError::NotEnoughSplTokenBalance
+        );
+        require_keys_eq!(
+            token_inventory.key(),
+            token_inventory_address(&claim.token_address, ctx.program_id),
+            DexalotError::InvalidInventoryAccount
+        );
+        require_keys_eq!(
+            rebalancer_limit.key(),
+            rebalancer_limit_address(authority.key, &claim.token_address, ctx.program_id),
+            DexalotError::WithdrawalLimitNotSet
+        );
+        record_rebalancer_withdrawal(rebalancer_limit, claim.amount)?;
+        update_inventory(token_inventory, |inventory| {
+            inventory.claim_rfq_inventory(claim.amount)
+        })?;
+
+        let ix = spl_token::instruction::transfer(
+            &token_program.key(),
+            &from.key(),
+            &to.key(),
+            &spl_vault.key(),
+            &[],
+            claim.amount,
+        )?;
+
+        if cfg!(not(test)) {
+            invoke_signed(
+                &ix,
+                &[
+                    from.clone(),
+                    to.clone(),
+                    spl_vault.to_account_info(),
+                    token_program.to_account_info(),
+                ],
+                &[spl_vault_seeds],
+            )?;
+        }
+    }
+
+    if let Some(native_amount) = params.native_amount {
+        // check balance
+        require!(
+            sol_vault.lamports() >= native_amount + NATIVE_VAULT_MIN_THRESHOLD,
+            DexalotError::NotEnoughNativeBalance
+        );
+        record_rebalancer_withdrawal(&ctx.accounts.native_rebalancer_limit, native_amount)?;
+        update_inventory(&ctx.accounts.native_token_inventory, |inventory| {
+            inventory.claim_rfq_inventory(native_amount)
+        })?;
+
+        let sol_vault_bump = &[ctx.bumps.sol_vault];
+        let sol_vault_seeds: &[&[u8]] = &[SOL_VAULT_SEED, sol_vault_bump];
+
+        let ix = system_instruction::transfer(&sol_vault.key(), &authority.key(), native_amount);
+        if cfg!(not(test)) {
+            invoke_signed(
+                &ix,
+                &[
+                    sol_vault.to_account_info(),
+                    authority.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+                &[sol_vault_seeds], // sign with the PDA
+            )?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::create_account_info;
+    use crate::state::TokenInventory;
+    use crate::test_utils::{
+        create_account_info, create_funded_token_inventory_data, create_packed_token_account,
+        create_rebalancer_limit_data, create_token_inventory_data,
+    };
     use anchor_lang::solana_program::{program_pack::Pack, system_program};
+    use anchor_spl::associated_token::get_associated_token_address;
     use anchor_spl::token::{self, Mint, Token, TokenAccount};
     use spl_token::state::AccountState;
 
@@ -261,7 +501,7 @@ mod tests {
         let rebalancer_info = create_account_info(
             &rebalancer_key,
             false,
-            true,
+            false,
             &mut rebalancer_lamports,
             &mut rebalancer_data,
             &program_id,
@@ -269,6 +509,20 @@ mod tests {
             None,
         );
 
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(1000);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let spl_vault_key = Pubkey::new_unique();
         let mut spl_vault_lamports = 100;
         let mut spl_vault_data = vec![0u8; 10];
@@ -333,6 +587,20 @@ mod tests {
         );
         let spl_token_account: Account<TokenAccount> = Account::try_from(&spl_token_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = ClaimSplBalance {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info.clone(),
@@ -341,6 +609,8 @@ mod tests {
             mint: mint_account,
             from: spl_token_account.clone(),
             to: spl_token_account,
+            token_inventory: token_inventory_info.clone(),
+            rebalancer_limit: rebalancer_limit_info.clone(),
         };
 
         let params = ClaimSplBalanceParams {
@@ -385,7 +655,7 @@ mod tests {
         let mut rebalancer_info = create_account_info(
             &rebalancer_key,
             false,
-            true,
+            false,
             &mut rebalancer_lamports,
             &mut rebalancer_data,
             &program_id,
@@ -393,6 +663,20 @@ mod tests {
             None,
         );
 
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(1000);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let spl_vault_key = Pubkey::new_unique();
         let mut spl_vault_lamports = 100;
         let mut spl_vault_data = vec![0u8; 10];
@@ -457,6 +741,20 @@ mod tests {
         );
         let spl_token_account: Account<TokenAccount> = Account::try_from(&spl_token_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let accounts = ClaimSplBalance {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info.clone(),
@@ -465,6 +763,8 @@ mod tests {
             mint: mint_account,
             from: spl_token_account.clone(),
             to: spl_token_account,
+            token_inventory: token_inventory_info.clone(),
+            rebalancer_limit: rebalancer_limit_info.clone(),
         };
 
         let params = ClaimSplBalanceParams {
@@ -517,7 +817,7 @@ mod tests {
         let rebalancer_info = create_account_info(
             &rebalancer_key,
             false,
-            true,
+            false,
             &mut rebalancer_lamports,
             &mut rebalancer_data,
             &program_id,
@@ -525,6 +825,20 @@ mod tests {
             None,
         );
 
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(100);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let sol_vault_key = Pubkey::new_unique();
         let mut sol_vault_lamports = 100 + NATIVE_VAULT_MIN_THRESHOLD;
         let mut sol_vault_data = vec![0u8; 10];
@@ -553,11 +867,27 @@ mod tests {
         );
         let system_program = Program::<System>::try_from(&system_prog_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = ClaimNativeBalance {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info.clone(),
             sol_vault: sol_vault_info.clone(),
             system_program,
+            token_inventory: token_inventory_info.clone(),
+            rebalancer_limit: rebalancer_limit_info.clone(),
         };
 
         let params = ClaimNativeBalanceParams { amount: 50 };
@@ -571,6 +901,27 @@ mod tests {
 
         let result = claim_native_balance(&ctx, &params);
         assert!(result.is_ok());
+
+        // claim would take the inventory below its floor
+        let mut floored_inventory_lamports = 100;
+        let mut floored_inventory_data = create_token_inventory_data(&TokenInventory {
+            rfq_inventory: 100,
+            rfq_floor: 60,
+            ..Default::default()
+        });
+        let floored_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut floored_inventory_lamports,
+            &mut floored_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.token_inventory = floored_inventory_info;
+        let result = claim_native_balance(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InventoryBelowFloor.into());
         Ok(())
     }
 
@@ -599,7 +950,7 @@ mod tests {
         let mut rebalancer_info = create_account_info(
             &rebalancer_key,
             false,
-            true,
+            false,
             &mut rebalancer_lamports,
             &mut rebalancer_data,
             &program_id,
@@ -607,6 +958,20 @@ mod tests {
             None,
         );
 
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(100);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let sol_vault_key = Pubkey::new_unique();
         let mut sol_vault_lamports = 100 + NATIVE_VAULT_MIN_THRESHOLD;
         let mut sol_vault_data = vec![0u8; 10];
@@ -635,11 +1000,27 @@ mod tests {
         );
         let system_program = Program::<System>::try_from(&system_prog_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = ClaimNativeBalance {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info.clone(),
             sol_vault: sol_vault_info.clone(),
             system_program,
+            token_inventory: token_inventory_info.clone(),
+            rebalancer_limit: rebalancer_limit_info.clone(),
         };
 
         let params = ClaimNativeBalanceParams { amount: 5000 };
@@ -665,6 +1046,385 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_batch_claim_balance() -> Result<()> {
+        let program_id = crate::id();
+        let mint_key = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let rebalancer_key = Pubkey::new_unique();
+        let mut rebalancer_lamports = 100;
+        let mut rebalancer_data = vec![0u8; 10];
+        let rebalancer_info = create_account_info(
+            &rebalancer_key,
+            false,
+            false,
+            &mut rebalancer_lamports,
+            &mut rebalancer_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let rebalancer_limit_key =
+            rebalancer_limit_address(&authority_key, &mint_key, &program_id);
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(10000);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let native_rebalancer_limit_key =
+            rebalancer_limit_address(&authority_key, &NATIVE_TOKEN_MINT, &program_id);
+        let mut native_rebalancer_limit_lamports = 100;
+        let mut native_rebalancer_limit_data = create_rebalancer_limit_data(100);
+        let native_rebalancer_limit_info = create_account_info(
+            &native_rebalancer_limit_key,
+            false,
+            true,
+            &mut native_rebalancer_limit_lamports,
+            &mut native_rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let spl_vault_key = Pubkey::new_unique();
+        let mut spl_vault_lamports = 100;
+        let mut spl_vault_data = vec![0u8; 10];
+        let spl_vault_info = create_account_info(
+            &spl_vault_key,
+            false,
+            true,
+            &mut spl_vault_lamports,
+            &mut spl_vault_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let sol_vault_key = Pubkey::new_unique();
+        let mut sol_vault_lamports = 100 + NATIVE_VAULT_MIN_THRESHOLD;
+        let mut sol_vault_data = vec![0u8; 10];
+        let sol_vault_info = create_account_info(
+            &sol_vault_key,
+            false,
+            true,
+            &mut sol_vault_lamports,
+            &mut sol_vault_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut token_prog_lamports = 100;
+        let mut token_prog_data = vec![0u8; 10];
+        let token_prog_info = create_account_info(
+            &token::ID,
+            false,
+            false,
+            &mut token_prog_lamports,
+            &mut token_prog_data,
+            &token::ID,
+            true,
+            None,
+        );
+        let token_program = Program::<Token>::try_from(&token_prog_info)?;
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+        let system_program = Program::<System>::try_from(&system_prog_info)?;
+
+        let mut vault_ata_data = create_packed_token_account(mint_key, spl_vault_key, 1000)?;
+        let mut vault_ata_lamports = 100;
+        let vault_ata_key = get_associated_token_address(&spl_vault_key, &mint_key);
+        let vault_ata_info = create_account_info(
+            &vault_ata_key,
+            false,
+            true,
+            &mut vault_ata_lamports,
+            &mut vault_ata_data,
+            &token::ID,
+            false,
+            None,
+        );
+
+        let mut authority_ata_data = create_packed_token_account(mint_key, authority_key, 0)?;
+        let mut authority_ata_lamports = 100;
+        let authority_ata_key = get_associated_token_address(&authority_key, &mint_key);
+        let authority_ata_info = create_account_info(
+            &authority_ata_key,
+            false,
+            true,
+            &mut authority_ata_lamports,
+            &mut authority_ata_data,
+            &token::ID,
+            false,
+            None,
+        );
+
+        let token_inventory_key = token_inventory_address(&mint_key, &program_id);
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory {
+            rfq_inventory: 2000,
+            ..Default::default()
+        });
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut floored_inventory_lamports = 100;
+        let mut floored_inventory_data = create_token_inventory_data(&TokenInventory {
+            rfq_inventory: 1000,
+            rfq_floor: 600,
+            ..Default::default()
+        });
+        let floored_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut floored_inventory_lamports,
+            &mut floored_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let native_token_inventory_key = Pubkey::new_unique();
+        let mut native_token_inventory_lamports = 100;
+        let mut native_token_inventory_data = create_token_inventory_data(&TokenInventory {
+            rfq_inventory: 200,
+            ..Default::default()
+        });
+        let native_token_inventory_info = create_account_info(
+            &native_token_inventory_key,
+            false,
+            true,
+            &mut native_token_inventory_lamports,
+            &mut native_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = BatchClaimBalance {
+            authority: Signer::try_from(&authority_info)?,
+            rebalancer: rebalancer_info.clone(),
+            spl_vault: spl_vault_info.clone(),
+            sol_vault: sol_vault_info.clone(),
+            token_program,
+            system_program,
+            native_token_inventory: native_token_inventory_info.clone(),
+            native_rebalancer_limit: native_rebalancer_limit_info.clone(),
+        };
+
+        let mut params = BatchClaimBalanceParams {
+            tokens: vec![ClaimSplBalanceParams {
+                token_address: mint_key,
+                amount: 500,
+            }],
+            native_amount: Some(50),
+        };
+
+        let remaining_accounts = [
+            vault_ata_info.clone(),
+            authority_ata_info.clone(),
+            token_inventory_info.clone(),
+            rebalancer_limit_info.clone(),
+        ];
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &remaining_accounts,
+            program_id: &program_id,
+            bumps: BatchClaimBalanceBumps::default(),
+        };
+        let result = batch_claim_balance(&ctx, &params);
+        assert!(result.is_ok());
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.rfq_inventory, 1500);
+        let inventory = TokenInventory::try_deserialize(&mut &native_token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.rfq_inventory, 150);
+
+        // not enough spl balance
+        params.tokens[0].amount = 1001;
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::NotEnoughSplTokenBalance.into()
+        );
+        params.tokens[0].amount = 500;
+
+        // not enough native balance
+        params.native_amount = Some(101);
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::NotEnoughNativeBalance.into()
+        );
+
+        // over the rebalancer's native limit for the epoch
+        params.native_amount = Some(60);
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::WithdrawalLimitExceeded.into()
+        );
+        params.native_amount = None;
+
+        // missing account group
+        let ctx = Context {
+            accounts: ctx.accounts,
+            remaining_accounts: &remaining_accounts[..1],
+            program_id: &program_id,
+            bumps: BatchClaimBalanceBumps::default(),
+        };
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::AccountsNotProvided.into()
+        );
+
+        // inventory of another token
+        let wrong_inventory_accounts = [
+            vault_ata_info.clone(),
+            authority_ata_info.clone(),
+            native_token_inventory_info.clone(),
+            rebalancer_limit_info.clone(),
+        ];
+        let ctx = Context {
+            accounts: ctx.accounts,
+            remaining_accounts: &wrong_inventory_accounts,
+            program_id: &program_id,
+            bumps: BatchClaimBalanceBumps::default(),
+        };
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InvalidInventoryAccount.into()
+        );
+
+        // withdrawal limit of another token
+        let wrong_limit_accounts = [
+            vault_ata_info.clone(),
+            authority_ata_info.clone(),
+            token_inventory_info.clone(),
+            native_rebalancer_limit_info.clone(),
+        ];
+        let ctx = Context {
+            accounts: ctx.accounts,
+            remaining_accounts: &wrong_limit_accounts,
+            program_id: &program_id,
+            bumps: BatchClaimBalanceBumps::default(),
+        };
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::WithdrawalLimitNotSet.into()
+        );
+
+        // claim would take the inventory below its floor
+        let floored_accounts = [
+            vault_ata_info.clone(),
+            authority_ata_info.clone(),
+            floored_inventory_info.clone(),
+            rebalancer_limit_info.clone(),
+        ];
+        let ctx = Context {
+            accounts: ctx.accounts,
+            remaining_accounts: &floored_accounts,
+            program_id: &program_id,
+            bumps: BatchClaimBalanceBumps::default(),
+        };
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InventoryBelowFloor.into()
+        );
+
+        // groups passed in the wrong order
+        let swapped_accounts = [
+            authority_ata_info.clone(),
+            vault_ata_info.clone(),
+            token_inventory_info.clone(),
+            rebalancer_limit_info.clone(),
+        ];
+        let ctx = Context {
+            accounts: ctx.accounts,
+            remaining_accounts: &swapped_accounts,
+            program_id: &program_id,
+            bumps: BatchClaimBalanceBumps::default(),
+        };
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InvalidTokenOwner.into()
+        );
+
+        // unauthorized rebalancer
+        let mut invalid_rebalancer_lamports = 100;
+        let mut invalid_rebalancer_data = vec![0u8; 10];
+        let invalid_rebalancer_info = create_account_info(
+            &rebalancer_key,
+            false,
+            true,
+            &mut invalid_rebalancer_lamports,
+            &mut invalid_rebalancer_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        ctx.accounts.rebalancer = invalid_rebalancer_info;
+        let ctx = Context {
+            accounts: ctx.accounts,
+            remaining_accounts: &remaining_accounts,
+            program_id: &program_id,
+            bumps: BatchClaimBalanceBumps::default(),
+        };
+        let result = batch_claim_balance(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::UnauthorizedSigner.into()
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_claim_airdrop_balance_success() -> Result<()> {
         let program_id = crate::id();
@@ -720,20 +1480,37 @@ mod tests {
             true,
             None,
         );
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = ClaimAirdropBalance {
             authority: Signer::try_from(&authority_info)?,
             admin: admin_info,
+            multisig_config,
+            proposal: None,
             airdrop_vault: airdrop_vault_info,
             system_program: Program::try_from(&system_info)?,
         };
         let params = ClaimAirdropBalanceParams { amount: 500 };
-        let ctx = Context {
+        let mut ctx = Context {
             accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
             bumps: ClaimAirdropBalanceBumps::default(),
         };
-        let result = claim_airdrop_balance(&ctx, &params);
+        let result = claim_airdrop_balance(&mut ctx, &params);
         assert!(result.is_ok());
         Ok(())
     }
@@ -795,9 +1572,26 @@ mod tests {
         );
 
         let admin_clone = admin_info.clone();
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = ClaimAirdropBalance {
             authority: Signer::try_from(&authority_info)?,
             admin: admin_clone,
+            multisig_config,
+            proposal: None,
             airdrop_vault: airdrop_vault_info,
             system_program: Program::try_from(&system_info)?,
         };
@@ -809,14 +1603,14 @@ mod tests {
             bumps: ClaimAirdropBalanceBumps::default(),
         };
 
-        let result = claim_airdrop_balance(&ctx, &params);
+        let result = claim_airdrop_balance(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::NotEnoughNativeBalance.into());
 
         admin_info.owner = &admin_key;
         accounts.admin = admin_info;
         ctx.accounts = &mut accounts;
 
-        let result = claim_airdrop_balance(&ctx, &params);
+        let result = claim_airdrop_balance(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
         Ok(())
     }
@@ -1,17 +1,16 @@
 use crate::consts::{
-    ADMIN_SEED, AIRDROP_VAULT_SEED, BANNED_ACCOUNT_SEED, ENDPOINT_QUOTE, ENDPOINT_SEND,
-    GAS_OPTIONS, PORTFOLIO_SEED, QUOTE_REMAINING_ACCOUNTS_COUNT, REMOTE_SEED, SOL_NATIVE_SYMBOL,
-    SOL_USER_FUNDS_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, TOKEN_DETAILS_SEED,
+    ADMIN_SEED, AIRDROP_VAULT_SEED, BANNED_ACCOUNT_SEED, CHAIN_SEED, DEPOSIT_ALLOWED_DEST_SEED,
+    LZ_OPTIONS_SEED, NATIVE_TOKEN_MINT, PORTFOLIO_SEED, REMOTE_SEED, SOL_NATIVE_SYMBOL, SOL_USER_FUNDS_VAULT_SEED,
+    SPL_USER_FUNDS_VAULT_SEED, TOKEN_DETAILS_SEED, TOKEN_INVENTORY_SEED, TRUSTED_PROGRAM_SEED,
 };
-use crate::cpi_utils::{
-    create_instruction_data, EndpointQuoteParams, EndpointSendParams, MessagingFee,
-};
-use crate::xfer::{Tx, XFER};
+use crate::cpi_utils::{load_lz_options, quote_and_send};
+use crate::xfer::{scale_quantity, Tx, XFER};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::system_instruction;
 use anchor_lang::solana_program::{
-    instruction::Instruction,
-    program::{get_return_data, invoke, invoke_signed},
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+    program::invoke,
+    sysvar::instructions::{self as sysvar_instructions, get_instruction_relative},
 };
 use anchor_spl::token::{self, Token, TokenAccount, Transfer as SplTransfer};
 
@@ -19,7 +18,8 @@ use crate::errors::DexalotError;
 use crate::events::{
     PortfolioUpdatedEvent, SolTransfer, SolTransferTransactions, SolTransferTypes,
 };
-use crate::state::{Portfolio, Remote, TokenDetails};
+use crate::instructions::update_inventory;
+use crate::state::{ChainEntry, Portfolio, Remote, TokenDetails};
 
 /// Deposits token amount into the portfolio program
 /// Sends a cross-chain message to the Dexalot L1 contract to update the user's balance
@@ -30,7 +30,6 @@ pub fn deposit(ctx: &mut Context<Deposit>, params: &DepositParams) -> Result<()>
     let token_program = &ctx.accounts.token_program;
     let banned_account = &ctx.accounts.banned_account;
     let program_id = &ctx.program_id;
-    let remote = &ctx.accounts.remote;
     let global_config = &ctx.accounts.portfolio.global_config;
     let token_details = &ctx.accounts.token_details;
 
@@ -51,6 +50,38 @@ pub fn deposit(ctx: &mut Context<Deposit>, params: &DepositParams) -> Result<()>
         DexalotError::DepositsPaused
     );
 
+    // Check the token itself accepts deposits
+    require!(!token_details.paused, DexalotError::TokenPaused);
+    require!(
+        token_details.allow_deposit,
+        DexalotError::TokenDepositsNotAllowed
+    );
+
+    // Check the destination chain is allowed for the token
+    check_deposit_destination(
+        &ctx.accounts.deposit_destination,
+        deposit_dest_chain_id(
+            params.dest_chain_id,
+            token_details.default_dest_chain_id,
+            global_config.default_chain_id,
+        ),
+        global_config.default_chain_id,
+        program_id,
+    )?;
+
+    // Check the amount is above the token's minimum deposit
+    require!(
+        params.amount > token_details.min_deposit,
+        DexalotError::DepositUnderThreshold
+    );
+
+    // Check the amount covers the bridge fee withheld from it
+    let bridge_fee = token_details.bridge_fee;
+    require!(
+        params.amount > bridge_fee,
+        DexalotError::DepositUnderThreshold
+    );
+
     // Validate the amount is not greater than the user's balance
     require!(
         from.amount >= params.amount,
@@ -67,12 +98,17 @@ pub fn deposit(ctx: &mut Context<Deposit>, params: &DepositParams) -> Result<()>
 
     token::transfer(CpiContext::new(cpi_program, cpi_accounts), params.amount)?;
 
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.add_user_funds(params.amount - bridge_fee)?;
+        inventory.add_bridge_fees(bridge_fee)
+    })?;
+
     emit!(PortfolioUpdatedEvent {
         transaction: Tx::Deposit,
         wallet: from.key(),
         token_mint: token_details.token_address,
         quantity: params.amount,
-        fee_charged: 0,
+        fee_charged: bridge_fee,
         total: 0,
         available: 0,
         wallet_other: Pubkey::from([0; 32]),
@@ -83,91 +119,26 @@ pub fn deposit(ctx: &mut Context<Deposit>, params: &DepositParams) -> Result<()>
         Tx::Deposit,
         params.trader,
         token_details.symbol,
-        params.amount,
+        scale_quantity(
+            (params.amount - bridge_fee).into(),
+            token_details.decimals,
+            token_details.l1_decimals,
+        )?,
         if cfg!(not(test)) {Clock::get()?.unix_timestamp as u32} else {123},
         [0; 18],
         global_config.out_nonce,
     );
     let message = xfer.pack_xfer_message()?;
-
-    // Call Quote
-    let quote_params = EndpointQuoteParams {
-        sender: ctx.accounts.portfolio.key(),
-        dst_eid: global_config.default_chain_id,
-        receiver: remote.address,
-        message: message.clone(),
-        pay_in_lz_token: false,
-        options: GAS_OPTIONS.to_vec(),
-    };
-
-    let quote_cpi_data = create_instruction_data(&quote_params, ENDPOINT_QUOTE)?;
-    let quote_remaining_accounts = &ctx.remaining_accounts[0..QUOTE_REMAINING_ACCOUNTS_COUNT];
-    let quote_accounts_metas: Vec<AccountMeta> = quote_remaining_accounts
-        .iter()
-        .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
-        .map(|account| AccountMeta {
-            pubkey: *account.key,
-            is_signer: account.is_signer,
-            is_writable: false,
-        })
-        .collect();
-
-    // Invoke CPI quote
-    let fee = if cfg!(not(test)) {
-        invoke(
-            &Instruction {
-                program_id: ctx.accounts.endpoint_program.key(),
-                accounts: quote_accounts_metas,
-                data: quote_cpi_data,
-            },
-            quote_remaining_accounts,
-        )?;
-        let quote_return_data = get_return_data().ok_or(DexalotError::LzQuoteError)?;
-        MessagingFee::try_from_slice(&quote_return_data.1)?
-    } else {
-        MessagingFee::default()
-    };
-
-    require!(fee.lz_token_fee == 0, DexalotError::PositiveLzTokenFee);
-
-    // Call Send
-    let send_remaining_accounts = &ctx.remaining_accounts[QUOTE_REMAINING_ACCOUNTS_COUNT..];
-    let send_params = EndpointSendParams {
-        dst_eid: global_config.default_chain_id,
-        receiver: remote.address,
+    quote_and_send(
+        &ctx.accounts.portfolio,
+        &ctx.accounts.remote,
+        &ctx.accounts.endpoint_program,
+        ctx.remaining_accounts,
+        ctx.accounts.chain_entry.eid,
         message,
-        options: GAS_OPTIONS.to_vec(),
-        native_fee: fee.native_fee,
-        lz_token_fee: 0,
-    };
-
-    let send_seeds: &[&[&[u8]]] = &[&[PORTFOLIO_SEED, &[ctx.accounts.portfolio.bump]]];
-
-    let send_cpi_data = create_instruction_data(&send_params, ENDPOINT_SEND)?;
-
-    let portfolio_key = ctx.accounts.portfolio.key();
-    let send_accounts_metas: Vec<AccountMeta> = send_remaining_accounts
-        .iter()
-        .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
-        .map(|account| AccountMeta {
-            pubkey: *account.key,
-            is_signer: account.key() == portfolio_key || account.is_signer,
-            is_writable: account.is_writable,
-        })
-        .collect();
-
-    // Invoke CPI send
-    if cfg!(not(test)) {
-        invoke_signed(
-            &Instruction {
-                program_id: ctx.accounts.endpoint_program.key(),
-                accounts: send_accounts_metas,
-                data: send_cpi_data,
-            },
-            send_remaining_accounts,
-            send_seeds,
-        )?;
-    }
+        load_lz_options(&ctx.accounts.lz_options, ctx.program_id)?,
+        params.max_native_fee,
+    )?;
 
     // update nonce counter
     ctx.accounts.portfolio.global_config.out_nonce += 1;
@@ -183,7 +154,6 @@ pub fn deposit_native(
     let program_id = &ctx.program_id;
     let banned_account = &ctx.accounts.banned_account;
     let global_config = &ctx.accounts.portfolio.global_config;
-    let remote = &ctx.accounts.remote;
     let amount = params.amount;
     require!(
         banned_account.owner != *program_id,
@@ -204,6 +174,24 @@ pub fn deposit_native(
         !global_config.native_deposits_restricted,
         DexalotError::NativeDepositNotAllowed
     );
+    // Check the destination chain is allowed for SOL
+    check_deposit_destination(
+        &ctx.accounts.deposit_destination,
+        deposit_dest_chain_id(params.dest_chain_id, 0, global_config.default_chain_id),
+        global_config.default_chain_id,
+        program_id,
+    )?;
+    // Check the amount is above the minimum native deposit
+    require!(
+        amount > global_config.min_deposit_native,
+        DexalotError::DepositUnderThreshold
+    );
+    // Check the amount covers the bridge fee withheld from it
+    let bridge_fee = global_config.bridge_fee_native;
+    require!(
+        amount > bridge_fee,
+        DexalotError::DepositUnderThreshold
+    );
 
     // Transfer amount from user to program
     let from = &ctx.accounts.user;
@@ -229,12 +217,17 @@ pub fn deposit_native(
         )?;
     }
 
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.add_user_funds(amount - bridge_fee)?;
+        inventory.add_bridge_fees(bridge_fee)
+    })?;
+
     emit!(PortfolioUpdatedEvent {
         transaction: Tx::Deposit,
         wallet: from.key(),
         token_mint: Pubkey::default(),
         quantity: amount,
-        fee_charged: 0,
+        fee_charged: bridge_fee,
         total: 0,
         available: 0,
         wallet_other: Pubkey::from([0; 32]),
@@ -247,101 +240,210 @@ pub fn deposit_native(
         Tx::Deposit,
         params.trader,
         native_symbol,
-        amount,
+        (amount - bridge_fee).into(),
         if cfg!(not(test)) {Clock::get()?.unix_timestamp as u32} else {123},
         [0; 18],
         global_config.out_nonce,
     );
 
     let message = xfer.pack_xfer_message()?;
+    quote_and_send(
+        &ctx.accounts.portfolio,
+        &ctx.accounts.remote,
+        &ctx.accounts.endpoint_program,
+        ctx.remaining_accounts,
+        ctx.accounts.chain_entry.eid,
+        message,
+        load_lz_options(&ctx.accounts.lz_options, ctx.program_id)?,
+        params.max_native_fee,
+    )?;
 
-    // Call Quote
-    let quote_params = EndpointQuoteParams {
-        sender: ctx.accounts.portfolio.key(),
-        dst_eid: global_config.default_chain_id,
-        receiver: remote.address,
-        message: message.clone(),
-        pay_in_lz_token: false,
-        options: GAS_OPTIONS.to_vec(),
-    };
+    // update nonce counter
+    ctx.accounts.portfolio.global_config.out_nonce += 1;
+    Ok(())
+}
 
-    let quote_cpi_data = create_instruction_data(&quote_params, ENDPOINT_QUOTE)?;
-    let quote_remaining_accounts = &ctx.remaining_accounts[0..QUOTE_REMAINING_ACCOUNTS_COUNT];
-    let quote_accounts_metas: Vec<AccountMeta> = quote_remaining_accounts
-        .iter()
-        .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
-        .map(|account| AccountMeta {
-            pubkey: *account.key,
-            is_signer: account.is_signer,
-            is_writable: false,
-        })
-        .collect();
-
-    // Invoke CPI quote
-    let fee = if cfg!(not(test)) {
-        invoke(
-            &Instruction {
-                program_id: ctx.accounts.endpoint_program.key(),
-                accounts: quote_accounts_metas,
-                data: quote_cpi_data,
-            },
-            quote_remaining_accounts,
-        )?;
-        let quote_return_data = get_return_data().ok_or(DexalotError::LzQuoteError)?;
-        MessagingFee::try_from_slice(&quote_return_data.1)?
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct DepositNativeParams {
+    pub amount: u64,
+    pub trader: [u8; 32],
+    pub max_native_fee: u64,
+    // chainlist ID to deposit to, the default chain when not set
+    pub dest_chain_id: Option<u32>,
+}
+
+/// Returns the chainlist ID a deposit is sent to: the requested chain,
+/// else the token's default destination, else the global default chain
+pub fn deposit_dest_chain_id(
+    requested: Option<u32>,
+    token_default: u32,
+    default_chain_id: u32,
+) -> u32 {
+    match requested {
+        Some(chain_id) => chain_id,
+        None if token_default != 0 => token_default,
+        None => default_chain_id,
+    }
+}
+
+/// Deposits to a chain other than the default one need the token's allow list entry
+fn check_deposit_destination(
+    deposit_destination: &AccountInfo,
+    dest_chain_id: u32,
+    default_chain_id: u32,
+    program_id: &Pubkey,
+) -> Result<()> {
+    require!(
+        dest_chain_id == default_chain_id || deposit_destination.owner == program_id,
+        DexalotError::DestinationNotAllowed
+    );
+    Ok(())
+}
+
+/// Deposits token amount on behalf of a trader from a trusted program
+/// The trusted program has to invoke this instruction directly through CPI,
+/// which is verified through the instructions sysvar
+pub fn deposit_from_program(
+    ctx: &mut Context<DepositFromProgram>,
+    params: &DepositFromProgramParams,
+) -> Result<()> {
+    let depositor = &ctx.accounts.depositor;
+    let from = &ctx.accounts.from;
+    let to = &ctx.accounts.to;
+    let token_program = &ctx.accounts.token_program;
+    let banned_account = &ctx.accounts.banned_account;
+    let trusted_program = &ctx.accounts.trusted_program;
+    let program_id = &ctx.program_id;
+    let global_config = &ctx.accounts.portfolio.global_config;
+    let token_details = &ctx.accounts.token_details;
+
+    // Check the calling program is trusted and invoked this instruction itself
+    require!(
+        trusted_program.owner == *program_id,
+        DexalotError::UntrustedProgram
+    );
+    let caller = if cfg!(not(test)) {
+        require!(
+            get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT + 1,
+            DexalotError::UntrustedProgram
+        );
+        get_instruction_relative(0, &ctx.accounts.instructions)?.program_id
     } else {
-        MessagingFee::default()
+        params.program
     };
+    require_keys_eq!(caller, params.program, DexalotError::UntrustedProgram);
 
-    require!(fee.lz_token_fee == 0, DexalotError::PositiveLzTokenFee);
+    require!(
+        banned_account.owner != *program_id,
+        DexalotError::AccountBanned
+    );
 
-    // Call Send
-    let send_remaining_accounts = &ctx.remaining_accounts[QUOTE_REMAINING_ACCOUNTS_COUNT..];
-    let send_params = EndpointSendParams {
-        dst_eid: global_config.default_chain_id,
-        receiver: remote.address,
-        message,
-        options: GAS_OPTIONS.to_vec(),
-        native_fee: fee.native_fee,
-        lz_token_fee: 0,
+    // Check the program is not paused
+    require!(
+        !global_config.program_paused,
+        DexalotError::ProgramPaused
+    );
+
+    // Check if deposits are allowed
+    require!(
+        global_config.allow_deposit,
+        DexalotError::DepositsPaused
+    );
+
+    // Check the token itself accepts deposits
+    require!(!token_details.paused, DexalotError::TokenPaused);
+    require!(
+        token_details.allow_deposit,
+        DexalotError::TokenDepositsNotAllowed
+    );
+
+    // Check the destination chain is allowed for the token
+    check_deposit_destination(
+        &ctx.accounts.deposit_destination,
+        deposit_dest_chain_id(
+            params.dest_chain_id,
+            token_details.default_dest_chain_id,
+            global_config.default_chain_id,
+        ),
+        global_config.default_chain_id,
+        program_id,
+    )?;
+
+    // Check the amount is above the token's minimum deposit
+    require!(
+        params.amount > token_details.min_deposit,
+        DexalotError::DepositUnderThreshold
+    );
+
+    // Check the amount covers the bridge fee withheld from it
+    let bridge_fee = token_details.bridge_fee;
+    require!(
+        params.amount > bridge_fee,
+        DexalotError::DepositUnderThreshold
+    );
+
+    // Validate the amount is not greater than the depositor's balance
+    require!(
+        from.amount >= params.amount,
+        DexalotError::NotEnoughSplTokenBalance
+    );
+
+    // Transfer tokens from the depositor to the user funds vault
+    let cpi_accounts = SplTransfer {
+        from: from.to_account_info().clone(),
+        to: to.to_account_info().clone(),
+        authority: depositor.to_account_info().clone(),
     };
+    let cpi_program = token_program.to_account_info();
 
-    let send_seeds: &[&[&[u8]]] = &[&[PORTFOLIO_SEED, &[ctx.accounts.portfolio.bump]]];
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), params.amount)?;
 
-    let send_cpi_data = create_instruction_data(&send_params, ENDPOINT_SEND)?;
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.add_user_funds(params.amount - bridge_fee)?;
+        inventory.add_bridge_fees(bridge_fee)
+    })?;
 
-    let portfolio_key = ctx.accounts.portfolio.key();
-    let send_accounts_metas: Vec<AccountMeta> = send_remaining_accounts
-        .iter()
-        .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
-        .map(|account| AccountMeta {
-            pubkey: *account.key,
-            is_signer: account.key() == portfolio_key || account.is_signer,
-            is_writable: account.is_writable,
-        })
-        .collect();
+    emit!(PortfolioUpdatedEvent {
+        transaction: Tx::Deposit,
+        wallet: from.key(),
+        token_mint: token_details.token_address,
+        quantity: params.amount,
+        fee_charged: bridge_fee,
+        total: 0,
+        available: 0,
+        wallet_other: params.program,
+    });
+
+    //Create and send cross-chain message
+    let xfer = XFER::new(
+        Tx::Deposit,
+        params.trader,
+        token_details.symbol,
+        scale_quantity(
+            (params.amount - bridge_fee).into(),
+            token_details.decimals,
+            token_details.l1_decimals,
+        )?,
+        if cfg!(not(test)) {Clock::get()?.unix_timestamp as u32} else {123},
+        [0; 18],
+        global_config.out_nonce,
+    );
+    let message = xfer.pack_xfer_message()?;
+    quote_and_send(
+        &ctx.accounts.portfolio,
+        &ctx.accounts.remote,
+        &ctx.accounts.endpoint_program,
+        ctx.remaining_accounts,
+        ctx.accounts.chain_entry.eid,
+        message,
+        load_lz_options(&ctx.accounts.lz_options, ctx.program_id)?,
+        params.max_native_fee,
+    )?;
 
-    // Invoke CPI send
-    if cfg!(not(test)) {
-        invoke_signed(
-            &Instruction {
-                program_id: ctx.accounts.endpoint_program.key(),
-                accounts: send_accounts_metas,
-                data: send_cpi_data,
-            },
-            send_remaining_accounts,
-            send_seeds,
-        )?;
-    }
     // update nonce counter
     ctx.accounts.portfolio.global_config.out_nonce += 1;
-    Ok(())
-}
 
-#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
-pub struct DepositNativeParams {
-    pub amount: u64,
-    pub trader: [u8; 32],
+    Ok(())
 }
 
 /// Deposits SOL into the portfolio program airdrop vault from the sender's account
@@ -394,7 +496,7 @@ pub fn deposit_airdrop(
 }
 
 #[derive(Accounts, Clone)]
-#[instruction(token_mint: Pubkey)]
+#[instruction(params: DepositParams)]
 pub struct Deposit<'info> {
     pub user: Signer<'info>,
 
@@ -409,7 +511,7 @@ pub struct Deposit<'info> {
     // Because Solana will return a system error if the account is not found
     // And we can't use the custom error code "P-ETNS-01"
     #[account(
-        seeds = [TOKEN_DETAILS_SEED, token_mint.as_ref()],
+        seeds = [TOKEN_DETAILS_SEED, params.token_mint.as_ref()],
         bump,
         constraint = from.mint == token_details.token_address @ DexalotError::InvalidMint
     )]
@@ -439,6 +541,13 @@ pub struct Deposit<'info> {
         constraint = to.mint == token_details.token_address @ DexalotError::InvalidMint
     )]
     pub to: Account<'info, TokenAccount>,
+    /// CHECK: the token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, token_details.token_address.as_ref()],
+        bump,
+    )]
+    pub token_inventory: AccountInfo<'info>,
 
     pub token_program: Program<'info, Token>,
     ///CHECK
@@ -448,16 +557,55 @@ pub struct Deposit<'info> {
     )]
     pub banned_account: AccountInfo<'info>,
 
+    #[account(
+        seeds = [
+            CHAIN_SEED,
+            &deposit_dest_chain_id(
+                params.dest_chain_id,
+                token_details.default_dest_chain_id,
+                portfolio.global_config.default_chain_id
+            ).to_be_bytes()
+        ],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+    /// CHECK: the token's allow list entry of the destination chain
+    #[account(
+        seeds = [
+            DEPOSIT_ALLOWED_DEST_SEED,
+            &deposit_dest_chain_id(
+                params.dest_chain_id,
+                token_details.default_dest_chain_id,
+                portfolio.global_config.default_chain_id
+            ).to_be_bytes(),
+            params.token_mint.as_ref()
+        ],
+        bump
+    )]
+    pub deposit_destination: AccountInfo<'info>,
     #[account(
         seeds = [
             REMOTE_SEED,
-            &portfolio.global_config.default_chain_id.to_be_bytes()
+            &chain_entry.eid.to_be_bytes()
         ],
         bump = remote.bump
     )]
     pub remote: Account<'info, Remote>,
     /// CHECK: the endpoint program
+    #[account(
+        address = portfolio.global_config.endpoint_program @ DexalotError::InvalidEndpointProgram
+    )]
     pub endpoint_program: AccountInfo<'info>,
+    /// CHECK: the LZ options pda, default gas options are used when not set
+    #[account(
+        seeds = [
+            LZ_OPTIONS_SEED,
+            &chain_entry.eid.to_be_bytes(),
+            &[Tx::Deposit as u8]
+        ],
+        bump
+    )]
+    pub lz_options: AccountInfo<'info>,
 }
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
@@ -465,13 +613,16 @@ pub struct DepositParams {
     token_mint: Pubkey,
     amount: u64,
     trader: [u8; 32],
+    max_native_fee: u64,
+    // chainlist ID to deposit to, the token's default destination when not set
+    dest_chain_id: Option<u32>,
 }
 
 #[derive(Accounts, Clone)]
-pub struct DepositNative<'info> {
-    /// The user calling the function and paying the lamports.
-    #[account(mut)]
-    pub user: Signer<'info>,
+#[instruction(params: DepositFromProgramParams)]
+pub struct DepositFromProgram<'info> {
+    /// The trusted program's authority owning the deposited tokens
+    pub depositor: Signer<'info>,
 
     #[account(
         mut,
@@ -480,36 +631,131 @@ pub struct DepositNative<'info> {
     )]
     pub portfolio: Account<'info, Portfolio>,
 
-    /// The vault account that will hold the deposited SOL.
-    #[account(mut, seeds = [SOL_USER_FUNDS_VAULT_SEED], bump)]
-    pub sol_vault: SystemAccount<'info>,
+    /// CHECK: the trusted program pda, checked to be owned by the program
+    #[account(
+        seeds = [TRUSTED_PROGRAM_SEED, params.program.as_ref()],
+        bump,
+    )]
+    pub trusted_program: AccountInfo<'info>,
 
-    /// The program that can transfer lamports.
-    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [TOKEN_DETAILS_SEED, params.token_mint.as_ref()],
+        bump,
+        constraint = from.mint == token_details.token_address @ DexalotError::InvalidMint
+    )]
+    pub token_details: Account<'info, TokenDetails>,
+    /// CHECK: Used to set the program as authority for the associated token account
+    #[account(
+        constraint = spl_user_funds_vault.owner == __program_id @ DexalotError::InvalidVaultOwner,
+        seeds = [SPL_USER_FUNDS_VAULT_SEED],
+        bump,
+    )]
+    pub spl_user_funds_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = from.owner == depositor.key() @ DexalotError::InvalidTokenOwner,
+        constraint = from.mint == token_details.token_address @ DexalotError::InvalidMint
+    )]
+    pub from: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = to.key() == anchor_spl::associated_token::get_associated_token_address(
+            spl_user_funds_vault.key,
+            &token_details.token_address,
+        ) @ DexalotError::InvalidDestinationOwner,
+        constraint = &to.owner == spl_user_funds_vault.key @ DexalotError::InvalidDestinationOwner,
+        constraint = to.mint == token_details.token_address @ DexalotError::InvalidMint
+    )]
+    pub to: Account<'info, TokenAccount>,
+    /// CHECK: the token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, token_details.token_address.as_ref()],
+        bump,
+    )]
+    pub token_inventory: AccountInfo<'info>,
 
+    pub token_program: Program<'info, Token>,
+    /// CHECK: the trader's ban pda
+    #[account(
+        seeds = [BANNED_ACCOUNT_SEED, params.trader.as_ref()],
+        bump,
+    )]
+    pub banned_account: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+            CHAIN_SEED,
+            &deposit_dest_chain_id(
+                params.dest_chain_id,
+                token_details.default_dest_chain_id,
+                portfolio.global_config.default_chain_id
+            ).to_be_bytes()
+        ],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+    /// CHECK: the token's allow list entry of the destination chain
+    #[account(
+        seeds = [
+            DEPOSIT_ALLOWED_DEST_SEED,
+            &deposit_dest_chain_id(
+                params.dest_chain_id,
+                token_details.default_dest_chain_id,
+                portfolio.global_config.default_chain_id
+            ).to_be_bytes(),
+            params.token_mint.as_ref()
+        ],
+        bump
+    )]
+    pub deposit_destination: AccountInfo<'info>,
     #[account(
         seeds = [
             REMOTE_SEED,
-            &portfolio.global_config.default_chain_id.to_be_bytes()
+            &chain_entry.eid.to_be_bytes()
         ],
         bump = remote.bump
     )]
     pub remote: Account<'info, Remote>,
-
-    ///CHECK
+    /// CHECK: the endpoint program
     #[account(
-        seeds = [BANNED_ACCOUNT_SEED, user.key().as_ref()],
-        bump,
+        address = portfolio.global_config.endpoint_program @ DexalotError::InvalidEndpointProgram
     )]
-    pub banned_account: AccountInfo<'info>,
-    /// CHECK: the endpoint program
     pub endpoint_program: AccountInfo<'info>,
+    /// CHECK: the LZ options pda, default gas options are used when not set
+    #[account(
+        seeds = [
+            LZ_OPTIONS_SEED,
+            &chain_entry.eid.to_be_bytes(),
+            &[Tx::Deposit as u8]
+        ],
+        bump
+    )]
+    pub lz_options: AccountInfo<'info>,
+    /// CHECK: the instructions sysvar, used to find the calling program
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct DepositFromProgramParams {
+    pub program: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub trader: [u8; 32],
+    pub max_native_fee: u64,
+    // chainlist ID to deposit to, the token's default destination when not set
+    pub dest_chain_id: Option<u32>,
 }
 
 #[derive(Accounts, Clone)]
-pub struct DepositAirdrop<'info> {
+#[instruction(params: DepositNativeParams)]
+pub struct DepositNative<'info> {
+    /// The user calling the function and paying the lamports.
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub user: Signer<'info>,
 
     #[account(
         mut,
@@ -519,13 +765,98 @@ pub struct DepositAirdrop<'info> {
     pub portfolio: Account<'info, Portfolio>,
 
     /// The vault account that will hold the deposited SOL.
-    #[account(mut, seeds = [AIRDROP_VAULT_SEED], bump)]
-    pub airdrop_vault: SystemAccount<'info>,
+    #[account(mut, seeds = [SOL_USER_FUNDS_VAULT_SEED], bump)]
+    pub sol_vault: SystemAccount<'info>,
+
+    /// CHECK: the native token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, NATIVE_TOKEN_MINT.as_ref()],
+        bump,
+    )]
+    pub token_inventory: AccountInfo<'info>,
 
     /// The program that can transfer lamports.
     pub system_program: Program<'info, System>,
 
-    /// Confirm there is an admin account
+    #[account(
+        seeds = [
+            CHAIN_SEED,
+            &deposit_dest_chain_id(
+                params.dest_chain_id,
+                0,
+                portfolio.global_config.default_chain_id
+            ).to_be_bytes()
+        ],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+    /// CHECK: the SOL allow list entry of the destination chain
+    #[account(
+        seeds = [
+            DEPOSIT_ALLOWED_DEST_SEED,
+            &deposit_dest_chain_id(
+                params.dest_chain_id,
+                0,
+                portfolio.global_config.default_chain_id
+            ).to_be_bytes(),
+            NATIVE_TOKEN_MINT.as_ref()
+        ],
+        bump
+    )]
+    pub deposit_destination: AccountInfo<'info>,
+    #[account(
+        seeds = [
+            REMOTE_SEED,
+            &chain_entry.eid.to_be_bytes()
+        ],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+
+    ///CHECK
+    #[account(
+        seeds = [BANNED_ACCOUNT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub banned_account: AccountInfo<'info>,
+    /// CHECK: the endpoint program
+    #[account(
+        address = portfolio.global_config.endpoint_program @ DexalotError::InvalidEndpointProgram
+    )]
+    pub endpoint_program: AccountInfo<'info>,
+    /// CHECK: the LZ options pda, default gas options are used when not set
+    #[account(
+        seeds = [
+            LZ_OPTIONS_SEED,
+            &chain_entry.eid.to_be_bytes(),
+            &[Tx::Deposit as u8]
+        ],
+        bump
+    )]
+    pub lz_options: AccountInfo<'info>,
+}
+
+#[derive(Accounts, Clone)]
+pub struct DepositAirdrop<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PORTFOLIO_SEED],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    /// The vault account that will hold the deposited SOL.
+    #[account(mut, seeds = [AIRDROP_VAULT_SEED], bump)]
+    pub airdrop_vault: SystemAccount<'info>,
+
+    /// The program that can transfer lamports.
+    pub system_program: Program<'info, System>,
+
+    /// Confirm there is an admin account
     /// CHECK: Used to check if authority/signer is admin
     #[account(
         seeds = [ADMIN_SEED, authority.key().as_ref()],
@@ -546,9 +877,12 @@ mod tests {
     use anchor_lang::solana_program::program_pack::Pack;
     use anchor_spl::token::spl_token::state::AccountState;
     use anchor_spl::token::{spl_token, TokenAccount};
-    use crate::consts::UNUSED_ADDRESS_PUBLIC_KEY;
-    use crate::state::{GlobalConfig, Portfolio, Remote, TokenDetails};
-    use crate::test_utils::{create_account_info, create_dummy_account};
+    use crate::consts::{QUOTE_REMAINING_ACCOUNTS_COUNT, UNUSED_ADDRESS_PUBLIC_KEY};
+    use crate::state::{AllowedDestinationEntry, GlobalConfig, Portfolio, Remote, TokenDetails, TokenInventory, TrustedProgram};
+    use crate::test_utils::{
+        create_account_info, create_dummy_account, create_portfolio_data,
+        create_token_inventory_data,
+    };
 
     #[test]
     fn test_deposit_success() -> Result<()> {
@@ -592,20 +926,10 @@ mod tests {
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
+            ..Default::default()
         };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -676,7 +1000,11 @@ mod tests {
 
         let token_details_key = Pubkey::new_unique();
         let mut token_details_lamports = 100;
-        let mut token_details_data = vec![0u8; TokenDetails::LEN];
+        let mut token_details_data = TokenDetails {
+            allow_deposit: true,
+            ..Default::default()
+        }
+        .try_to_vec()?;
         let token_details_account = create_account_info(
             &token_details_key,
             false,
@@ -706,6 +1034,62 @@ mod tests {
         );
         let spl_token_account: Account<TokenAccount> = Account::try_from(&spl_token_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let deposit_destination_key = Pubkey::new_unique();
+        let mut deposit_destination_lamports = 0;
+        let mut deposit_destination_data = vec![];
+        let deposit_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut deposit_destination_lamports,
+            &mut deposit_destination_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
         let mut deposit_accounts = Deposit {
             user: Signer::try_from(&user_info)?,
             portfolio: portfolio_account,
@@ -716,50 +1100,415 @@ mod tests {
             remote: remote_account,
             banned_account: banned_info,
             endpoint_program: endpoint_program_info,
+            lz_options: lz_options_info,
+            deposit_destination: deposit_destination_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
+            token_program,
+            token_inventory: token_inventory_info.clone(),
+        };
+
+        let deposit_params = DepositParams { token_mint: Default::default(), amount: 50, trader: [0u8; 32], max_native_fee: 0, dest_chain_id: None };
+
+        let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
+        let remaining_accounts: Vec<AccountInfo<'static>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
+            .map(|_| create_dummy_account(program_id_static))
+            .collect();
+
+        let mut ctx = Context {
+            accounts: &mut deposit_accounts,
+            remaining_accounts: remaining_accounts.as_slice(),
+            program_id: &program_id,
+            bumps: DepositBumps::default(),
+        };
+
+        let result = deposit(&mut ctx, &deposit_params);
+        assert!(result.is_ok());
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 50);
+        Ok(())
+    }
+
+    #[test]
+    fn test_deposit_negative_cases() -> Result<()> {
+        let program_id = crate::id();
+        let generic_pubkey = Pubkey::new_unique();
+
+        let user_key = Pubkey::new_unique();
+        let portfolio_key = Pubkey::new_unique();
+        let remote_key = Pubkey::new_unique();
+        let banned_account_key = Pubkey::new_unique();
+        let endpoint_program_key = Pubkey::new_unique();
+
+        let mut user_lamports = 100;
+        let mut user_data = vec![0u8; 10];
+        let user_info = create_account_info(
+            &user_key,
+            true,
+            true,
+            &mut user_lamports,
+            &mut user_data,
+            &program_id,
+            false,
+            None
+        );
+
+        let mut generic_lamports = 100;
+        let mut generic_data = vec![0u8; 10];
+        let generic_info = create_account_info(
+            &generic_pubkey,
+            false,
+            false,
+            &mut generic_lamports,
+            &mut generic_data,
+            &program_id,
+            false,
+            None
+        );
+
+        let hex_str = UNUSED_ADDRESS_PUBLIC_KEY;
+        let bytes = hex::decode(hex_str).unwrap();
+        let address: [u8; 20] = bytes.try_into().unwrap();
+        let mut gc = GlobalConfig {
+            allow_deposit: true,
+            swap_signer: address,
+            ..Default::default()
+        };
+        let mut portfolio_data = create_portfolio_data(gc.clone());
+        let mut portfolio_lamports = 100;
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator())
+        );
+        let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
+
+        let mut remote_lamports = 100;
+        let mut remote_data = vec![0u8; Remote::SIZE];
+        let remote_info = create_account_info(
+            &remote_key,
+            false,
+            true,
+            &mut remote_lamports,
+            &mut remote_data,
+            &program_id,
+            false,
+            Some(Remote::discriminator())
+        );
+        let remote_account = Account::<Remote>::try_from(&remote_info)?;
+
+        let mut banned_lamports = 100;
+        let mut banned_data = vec![0u8; 10];
+        let banned_owner = Pubkey::new_unique();
+        let mut banned_info = create_account_info(
+            &banned_account_key,
+            false,
+            false,
+            &mut banned_lamports,
+            &mut banned_data,
+            &banned_owner,
+            false,
+            None
+        );
+
+        let mut ep_lamports = 100;
+        let mut ep_data = vec![0u8; 10];
+        let endpoint_program_info = create_account_info(
+            &endpoint_program_key,
+            false,
+            false,
+            &mut ep_lamports,
+            &mut ep_data,
+            &endpoint_program_key,
+            true,
+            None
+        );
+
+        let mut token_program_lamports = 100;
+        let mut token_program_data = vec![0u8; 10];
+        let token_program_info = create_account_info(
+            &token::ID,
+            false,
+            false,
+            &mut token_program_lamports,
+            &mut token_program_data,
+            &token::ID,
+            true,
+            None
+        );
+        let token_program = Program::<Token>::try_from(&token_program_info)?;
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = TokenDetails {
+            allow_deposit: true,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_account = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+
+        let mut default_token_account = spl_token::state::Account::default();
+        default_token_account.state = AccountState::Initialized;
+        let mut default_token_data = vec![0u8; spl_token::state::Account::LEN];
+        let mut default_token_lamports = 100;
+        spl_token::state::Account::pack_into_slice(&default_token_account, &mut default_token_data);
+        let spl_token_info = create_account_info(
+            &generic_pubkey,
+            false,
+            false,
+            &mut default_token_lamports,
+            &mut default_token_data,
+            &token::ID,
+            true,
+            None
+        );
+        let spl_token_account: Account<TokenAccount> = Account::try_from(&spl_token_info)?;
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let deposit_destination_key = Pubkey::new_unique();
+        let mut deposit_destination_lamports = 0;
+        let mut deposit_destination_data = vec![];
+        let deposit_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut deposit_destination_lamports,
+            &mut deposit_destination_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut deposit_accounts = Deposit {
+            user: Signer::try_from(&user_info)?,
+            portfolio: portfolio_account,
+            token_details: Account::try_from(&token_details_account)?,
+            spl_user_funds_vault: generic_info,
+            from: spl_token_account.clone(),
+            to: spl_token_account,
+            remote: remote_account,
+            banned_account: banned_info.clone(),
+            endpoint_program: endpoint_program_info,
+            lz_options: lz_options_info,
+            deposit_destination: deposit_destination_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
             token_program,
+            token_inventory: token_inventory_info.clone(),
         };
 
-        let deposit_params = DepositParams { token_mint: Default::default(), amount: 50, trader: [0u8; 32] };
+        let deposit_params = DepositParams { token_mint: Default::default(), amount: 50, trader: [0u8; 32], max_native_fee: 0, dest_chain_id: None };
+
+        let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
+        let remaining_accounts: Vec<AccountInfo<'static>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
+            .map(|_| create_dummy_account(program_id_static))
+            .collect();
+
+        let mut ctx = Context {
+            accounts: &mut deposit_accounts.clone(),
+            remaining_accounts: remaining_accounts.as_slice(),
+            program_id: &program_id,
+            bumps: DepositBumps::default(),
+        };
+
+        let result = deposit(&mut ctx, &deposit_params);
+        assert_eq!(result.unwrap_err(), DexalotError::NotEnoughSplTokenBalance.into());
+
+        // amount not above the token's minimum deposit
+        let mut min_token_details_data = TokenDetails {
+            min_deposit: 50,
+            allow_deposit: true,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let mut min_token_details_lamports = 100;
+        let min_token_details_account = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut min_token_details_lamports,
+            &mut min_token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+        let mut min_deposit_accounts = deposit_accounts.clone();
+        min_deposit_accounts.token_details = Account::try_from(&min_token_details_account)?;
+        ctx.accounts = &mut min_deposit_accounts;
+
+        let result = deposit(&mut ctx, &deposit_params);
+        assert_eq!(result.unwrap_err(), DexalotError::DepositUnderThreshold.into());
+
+        // the token doesn't accept deposits
+        let mut closed_token_details_data = TokenDetails::default().try_to_vec()?;
+        let mut closed_token_details_lamports = 100;
+        let closed_token_details_account = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut closed_token_details_lamports,
+            &mut closed_token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+        let mut closed_token_accounts = deposit_accounts.clone();
+        closed_token_accounts.token_details = Account::try_from(&closed_token_details_account)?;
+        ctx.accounts = &mut closed_token_accounts;
+
+        let result = deposit(&mut ctx, &deposit_params);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenDepositsNotAllowed.into());
+
+        // the token is paused
+        let mut paused_token_details_data = TokenDetails {
+            allow_deposit: true,
+            paused: true,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let mut paused_token_details_lamports = 100;
+        let paused_token_details_account = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut paused_token_details_lamports,
+            &mut paused_token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+        let mut paused_token_accounts = deposit_accounts.clone();
+        paused_token_accounts.token_details = Account::try_from(&paused_token_details_account)?;
+        ctx.accounts = &mut paused_token_accounts;
+
+        let result = deposit(&mut ctx, &deposit_params);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenPaused.into());
+
+        gc.allow_deposit = false;
+        let mut portfolio_data = create_portfolio_data(gc.clone());
+        let mut portfolio_lamports = 100;
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator())
+        );
+        let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
+
+        deposit_accounts.portfolio = portfolio_account;
+        let mut new_accounts = deposit_accounts.clone();
+        ctx.accounts = &mut new_accounts;
+
+        let result = deposit(&mut ctx, &deposit_params);
+        assert_eq!(result.unwrap_err(), DexalotError::DepositsPaused.into());
+
+        gc.program_paused = true;
+        let mut portfolio_data = create_portfolio_data(gc);
+        let mut portfolio_lamports = 100;
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator())
+        );
+        let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
+
+        deposit_accounts.portfolio = portfolio_account;
+        let mut new_accounts = deposit_accounts.clone();
+        ctx.accounts = &mut new_accounts;
 
-        let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
-        let remaining_accounts: Vec<AccountInfo<'static>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
-            .map(|_| create_dummy_account(program_id_static))
-            .collect();
+        let result = deposit(&mut ctx, &deposit_params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProgramPaused.into());
 
-        let mut ctx = Context {
-            accounts: &mut deposit_accounts,
-            remaining_accounts: remaining_accounts.as_slice(),
-            program_id: &program_id,
-            bumps: DepositBumps::default(),
-        };
+        banned_info.owner = &program_id;
+        deposit_accounts.banned_account = banned_info;
+        let mut new_accounts = deposit_accounts.clone();
+        ctx.accounts = &mut new_accounts;
 
         let result = deposit(&mut ctx, &deposit_params);
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap_err(), DexalotError::AccountBanned.into());
         Ok(())
     }
 
     #[test]
-    fn test_deposit_negative_cases() -> Result<()> {
+    fn test_deposit_from_program() -> Result<()> {
         let program_id = crate::id();
         let generic_pubkey = Pubkey::new_unique();
+        let caller_program = Pubkey::new_unique();
 
-        let user_key = Pubkey::new_unique();
-        let portfolio_key = Pubkey::new_unique();
-        let remote_key = Pubkey::new_unique();
-        let banned_account_key = Pubkey::new_unique();
-        let endpoint_program_key = Pubkey::new_unique();
-
-        let mut user_lamports = 100;
-        let mut user_data = vec![0u8; 10];
-        let user_info = create_account_info(
-            &user_key,
-            true,
+        let depositor_key = Pubkey::new_unique();
+        let mut depositor_lamports = 100;
+        let mut depositor_data = vec![0u8; 10];
+        let depositor_info = create_account_info(
+            &depositor_key,
             true,
-            &mut user_lamports,
-            &mut user_data,
-            &program_id,
             false,
-            None
+            &mut depositor_lamports,
+            &mut depositor_data,
+            &caller_program,
+            false,
+            None,
         );
 
         let mut generic_lamports = 100;
@@ -772,29 +1521,15 @@ mod tests {
             &mut generic_data,
             &program_id,
             false,
-            None
+            None,
         );
 
-        let hex_str = UNUSED_ADDRESS_PUBLIC_KEY;
-        let bytes = hex::decode(hex_str).unwrap();
-        let address: [u8; 20] = bytes.try_into().unwrap();
-        let mut gc = GlobalConfig {
-            allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
-            swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc.clone(),
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let portfolio_key = Pubkey::new_unique();
         let mut portfolio_lamports = 100;
+        let mut portfolio_data = create_portfolio_data(GlobalConfig {
+            allow_deposit: true,
+            ..Default::default()
+        });
         let portfolio_info = create_account_info(
             &portfolio_key,
             false,
@@ -803,38 +1538,54 @@ mod tests {
             &mut portfolio_data,
             &program_id,
             false,
-            Some(Portfolio::discriminator())
+            Some(Portfolio::discriminator()),
+        );
+
+        let trusted_program_key = Pubkey::new_unique();
+        let mut untrusted_lamports = 0;
+        let mut untrusted_data = vec![];
+        let untrusted_info = create_account_info(
+            &trusted_program_key,
+            false,
+            false,
+            &mut untrusted_lamports,
+            &mut untrusted_data,
+            &system_program::ID,
+            false,
+            None,
         );
-        let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
 
+        let remote_key = Pubkey::new_unique();
         let mut remote_lamports = 100;
         let mut remote_data = vec![0u8; Remote::SIZE];
         let remote_info = create_account_info(
             &remote_key,
             false,
-            true,
+            false,
             &mut remote_lamports,
             &mut remote_data,
             &program_id,
             false,
-            Some(Remote::discriminator())
+            Some(Remote::discriminator()),
         );
-        let remote_account = Account::<Remote>::try_from(&remote_info)?;
 
-        let mut banned_lamports = 100;
-        let mut banned_data = vec![0u8; 10];
-        let banned_owner = Pubkey::new_unique();
-        let mut banned_info = create_account_info(
+        let trader = Pubkey::new_unique();
+        let (banned_account_key, _) =
+            Pubkey::find_program_address(&[BANNED_ACCOUNT_SEED, trader.as_ref()], &program_id);
+        let mut banned_lamports = 0;
+        let mut banned_data = vec![];
+        let banned_info = create_account_info(
             &banned_account_key,
             false,
             false,
             &mut banned_lamports,
             &mut banned_data,
-            &banned_owner,
+            &system_program::ID,
             false,
-            None
+            None,
         );
 
+        let endpoint_program_key = Pubkey::new_unique();
         let mut ep_lamports = 100;
         let mut ep_data = vec![0u8; 10];
         let endpoint_program_info = create_account_info(
@@ -845,7 +1596,7 @@ mod tests {
             &mut ep_data,
             &endpoint_program_key,
             true,
-            None
+            None,
         );
 
         let mut token_program_lamports = 100;
@@ -858,17 +1609,20 @@ mod tests {
             &mut token_program_data,
             &token::ID,
             true,
-            None
+            None,
         );
-        let token_program = Program::<Token>::try_from(&token_program_info)?;
 
         let token_details_key = Pubkey::new_unique();
         let mut token_details_lamports = 100;
-        let mut token_details_data = vec![0u8; TokenDetails::LEN];
-        let token_details_account = create_account_info(
+        let mut token_details_data = TokenDetails {
+            allow_deposit: true,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_info = create_account_info(
             &token_details_key,
             false,
-            true,
+            false,
             &mut token_details_lamports,
             &mut token_details_data,
             &program_id,
@@ -876,113 +1630,165 @@ mod tests {
             Some(TokenDetails::discriminator()),
         );
 
-        let mut default_token_account = spl_token::state::Account::default();
-        default_token_account.state = AccountState::Initialized;
-        let mut default_token_data = vec![0u8; spl_token::state::Account::LEN];
-        let mut default_token_lamports = 100;
-        spl_token::state::Account::pack_into_slice(&default_token_account, &mut default_token_data);
+        let token_account = spl_token::state::Account {
+            state: AccountState::Initialized,
+            amount: 1000,
+            ..Default::default()
+        };
+        let mut token_data = vec![0u8; spl_token::state::Account::LEN];
+        let mut token_lamports = 100;
+        spl_token::state::Account::pack_into_slice(&token_account, &mut token_data);
         let spl_token_info = create_account_info(
             &generic_pubkey,
             false,
             false,
-            &mut default_token_lamports,
-            &mut default_token_data,
+            &mut token_lamports,
+            &mut token_data,
             &token::ID,
             true,
-            None
+            None,
         );
         let spl_token_account: Account<TokenAccount> = Account::try_from(&spl_token_info)?;
 
-        let mut deposit_accounts = Deposit {
-            user: Signer::try_from(&user_info)?,
-            portfolio: portfolio_account,
-            token_details: Account::try_from(&token_details_account)?,
-            spl_user_funds_vault: generic_info,
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let deposit_destination_key = Pubkey::new_unique();
+        let mut deposit_destination_lamports = 0;
+        let mut deposit_destination_data = vec![];
+        let deposit_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut deposit_destination_lamports,
+            &mut deposit_destination_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut accounts = DepositFromProgram {
+            depositor: Signer::try_from(&depositor_info)?,
+            portfolio: Account::try_from(&portfolio_info)?,
+            trusted_program: untrusted_info,
+            token_details: Account::try_from(&token_details_info)?,
+            spl_user_funds_vault: generic_info.clone(),
             from: spl_token_account.clone(),
             to: spl_token_account,
-            remote: remote_account,
-            banned_account: banned_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            token_program: Program::try_from(&token_program_info)?,
+            banned_account: banned_info,
+            remote: Account::try_from(&remote_info)?,
             endpoint_program: endpoint_program_info,
-            token_program,
+            lz_options: lz_options_info,
+            deposit_destination: deposit_destination_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
+            instructions: generic_info,
         };
 
-        let deposit_params = DepositParams { token_mint: Default::default(), amount: 50, trader: [0u8; 32] };
-
         let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
         let remaining_accounts: Vec<AccountInfo<'static>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
             .map(|_| create_dummy_account(program_id_static))
             .collect();
-
         let mut ctx = Context {
-            accounts: &mut deposit_accounts.clone(),
+            accounts: &mut accounts,
             remaining_accounts: remaining_accounts.as_slice(),
             program_id: &program_id,
-            bumps: DepositBumps::default(),
+            bumps: DepositFromProgramBumps::default(),
+        };
+        let params = DepositFromProgramParams {
+            program: caller_program,
+            token_mint: Default::default(),
+            amount: 50,
+            trader: trader.to_bytes(),
+            max_native_fee: 0,
+            dest_chain_id: None,
         };
 
-        let result = deposit(&mut ctx, &deposit_params);
-        assert_eq!(result.unwrap_err(), DexalotError::NotEnoughSplTokenBalance.into());
+        // the calling program is not trusted
+        let result = deposit_from_program(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UntrustedProgram.into());
 
-        gc.allow_deposit = false;
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc.clone(),
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
-        let mut portfolio_lamports = 100;
-        let portfolio_info = create_account_info(
-            &portfolio_key,
+        let mut trusted_lamports = 100;
+        let mut trusted_data = TrustedProgram::default().try_to_vec()?;
+        let trusted_info = create_account_info(
+            &trusted_program_key,
             false,
-            true,
-            &mut portfolio_lamports,
-            &mut portfolio_data,
+            false,
+            &mut trusted_lamports,
+            &mut trusted_data,
             &program_id,
             false,
-            Some(Portfolio::discriminator())
+            Some(TrustedProgram::discriminator()),
         );
-        let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
-
-        deposit_accounts.portfolio = portfolio_account;
-        let mut new_accounts = deposit_accounts.clone();
-        ctx.accounts = &mut new_accounts;
-
-        let result = deposit(&mut ctx, &deposit_params);
-        assert_eq!(result.unwrap_err(), DexalotError::DepositsPaused.into());
-
-        gc.program_paused = true;
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
-        let mut portfolio_lamports = 100;
-        let portfolio_info = create_account_info(
-            &portfolio_key,
+        ctx.accounts.trusted_program = trusted_info;
+        deposit_from_program(&mut ctx, &params)?;
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 50);
+        assert_eq!(ctx.accounts.portfolio.global_config.out_nonce, 1);
+
+        // the bridge fee is withheld from the deposit
+        ctx.accounts.token_details.bridge_fee = 10;
+        deposit_from_program(&mut ctx, &params)?;
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 90);
+        assert_eq!(inventory.bridge_fees, 10);
+
+        // the trader is banned while the depositor is not
+        assert_ne!(trader, depositor_key);
+        let mut program_banned_lamports = 100;
+        let mut program_banned_data = vec![0u8; 10];
+        let program_banned_info = create_account_info(
+            &banned_account_key,
             false,
-            true,
-            &mut portfolio_lamports,
-            &mut portfolio_data,
+            false,
+            &mut program_banned_lamports,
+            &mut program_banned_data,
             &program_id,
             false,
-            Some(Portfolio::discriminator())
+            None,
         );
-        let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
-
-        deposit_accounts.portfolio = portfolio_account;
-        let mut new_accounts = deposit_accounts.clone();
-        ctx.accounts = &mut new_accounts;
-
-        let result = deposit(&mut ctx, &deposit_params);
-        assert_eq!(result.unwrap_err(), DexalotError::ProgramPaused.into());
-
-        banned_info.owner = &program_id;
-        deposit_accounts.banned_account = banned_info;
-        let mut new_accounts = deposit_accounts.clone();
-        ctx.accounts = &mut new_accounts;
-
-        let result = deposit(&mut ctx, &deposit_params);
+        ctx.accounts.banned_account = program_banned_info;
+        let result = deposit_from_program(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::AccountBanned.into());
         Ok(())
     }
@@ -1016,20 +1822,10 @@ mod tests {
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -1112,6 +1908,62 @@ mod tests {
         );
         let system_program = Program::try_from(&system_program_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let deposit_destination_key = Pubkey::new_unique();
+        let mut deposit_destination_lamports = 0;
+        let mut deposit_destination_data = vec![];
+        let deposit_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut deposit_destination_lamports,
+            &mut deposit_destination_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
         let mut deposit_native_accounts = DepositNative {
             user: Signer::try_from(&user_info)?,
             portfolio: portfolio_account,
@@ -1120,9 +1972,13 @@ mod tests {
             remote: remote_account,
             banned_account: banned_info,
             endpoint_program: endpoint_program_info,
+            lz_options: lz_options_info,
+            deposit_destination: deposit_destination_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
+            token_inventory: token_inventory_info.clone(),
         };
 
-        let deposit_native_params = DepositNativeParams { amount: 50, trader: [0u8; 32] };
+        let deposit_native_params = DepositNativeParams { amount: 50, trader: [0u8; 32], max_native_fee: 0, dest_chain_id: None };
 
         let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
         let remaining_accounts: Vec<AccountInfo<'static>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
@@ -1138,6 +1994,49 @@ mod tests {
 
         let result = deposit_native(&mut ctx, &deposit_native_params);
         assert!(result.is_ok());
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 50);
+
+        // the bridge fee is withheld from the deposit
+        ctx.accounts.portfolio.global_config.bridge_fee_native = 10;
+        deposit_native(&mut ctx, &deposit_native_params)?;
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 90);
+        assert_eq!(inventory.bridge_fees, 10);
+
+        // a non-default chain has to be in the allow list
+        let other_chain_params = DepositNativeParams {
+            dest_chain_id: Some(1),
+            ..deposit_native_params.clone()
+        };
+        let result = deposit_native(&mut ctx, &other_chain_params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::DestinationNotAllowed.into()
+        );
+
+        let mut allowed_destination_lamports = 100;
+        let mut allowed_destination_data = vec![0u8; AllowedDestinationEntry::LEN];
+        let allowed_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut allowed_destination_lamports,
+            &mut allowed_destination_data,
+            &program_id,
+            false,
+            Some(AllowedDestinationEntry::discriminator()),
+        );
+        ctx.accounts.deposit_destination = allowed_destination_info;
+        deposit_native(&mut ctx, &other_chain_params)?;
+
+        // the deposit has to cover the bridge fee
+        ctx.accounts.portfolio.global_config.bridge_fee_native = 50;
+        let result = deposit_native(&mut ctx, &deposit_native_params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::DepositUnderThreshold.into()
+        );
         Ok(())
     }
 
@@ -1170,20 +2069,10 @@ mod tests {
         let address: [u8; 20] = bytes.try_into().unwrap();
         let mut gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc.clone(),
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc.clone());
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -1266,6 +2155,62 @@ mod tests {
         );
         let system_program = Program::try_from(&system_program_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let deposit_destination_key = Pubkey::new_unique();
+        let mut deposit_destination_lamports = 0;
+        let mut deposit_destination_data = vec![];
+        let deposit_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut deposit_destination_lamports,
+            &mut deposit_destination_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
         let mut deposit_native_accounts = DepositNative {
             user: Signer::try_from(&user_info)?,
             portfolio: portfolio_account,
@@ -1274,9 +2219,13 @@ mod tests {
             remote: remote_account,
             banned_account: banned_info.clone(),
             endpoint_program: endpoint_program_info,
+            lz_options: lz_options_info,
+            deposit_destination: deposit_destination_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
+            token_inventory: token_inventory_info.clone(),
         };
 
-        let deposit_native_params = DepositNativeParams { amount: 5000, trader: [0u8; 32] };
+        let deposit_native_params = DepositNativeParams { amount: 5000, trader: [0u8; 32], max_native_fee: 0, dest_chain_id: None };
 
         let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
         let remaining_accounts: Vec<AccountInfo<>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
@@ -1293,13 +2242,30 @@ mod tests {
         let result = deposit_native(&mut ctx, &deposit_native_params);
         assert_eq!(result.unwrap_err(), DexalotError::NotEnoughNativeBalance.into());
 
+        // amount not above the minimum native deposit
+        let mut min_gc = gc.clone();
+        min_gc.min_deposit_native = 5000;
+        let mut min_portfolio_data = create_portfolio_data(min_gc);
+        let mut min_portfolio_lamports = 100;
+        let min_portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut min_portfolio_lamports,
+            &mut min_portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator())
+        );
+        let mut min_deposit_accounts = deposit_native_accounts.clone();
+        min_deposit_accounts.portfolio = Account::<Portfolio>::try_from(&min_portfolio_info)?;
+        ctx.accounts = &mut min_deposit_accounts;
+
+        let result = deposit_native(&mut ctx, &deposit_native_params);
+        assert_eq!(result.unwrap_err(), DexalotError::DepositUnderThreshold.into());
+
         gc.native_deposits_restricted = true;
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc.clone(),
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc.clone());
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -1321,12 +2287,7 @@ mod tests {
         assert_eq!(result.unwrap_err(), DexalotError::NativeDepositNotAllowed.into());
 
         gc.allow_deposit = false;
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc.clone(),
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc.clone());
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -1348,12 +2309,7 @@ mod tests {
         assert_eq!(result.unwrap_err(), DexalotError::DepositsPaused.into());
 
         gc.program_paused = true;
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -1507,20 +2463,10 @@ mod tests {
         let address: [u8; 20] = bytes.try_into().unwrap();
         let mut gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc.clone(),
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc.clone());
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -1597,12 +2543,7 @@ mod tests {
         assert_eq!(result.unwrap_err(), DexalotError::NotEnoughNativeBalance.into());
 
         gc.native_deposits_restricted = true;
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc.clone(),
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc.clone());
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
@@ -1624,12 +2565,7 @@ mod tests {
         assert_eq!(result.unwrap_err(), DexalotError::NativeDepositNotAllowed.into());
 
         gc.program_paused = true;
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &portfolio_key,
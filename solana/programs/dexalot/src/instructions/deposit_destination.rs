@@ -0,0 +1,440 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{ADMIN_SEED, CHAIN_SEED, DEPOSIT_ALLOWED_DEST_SEED, TOKEN_DETAILS_SEED};
+use crate::errors::DexalotError;
+use crate::events::{DepositDestinationUpdatedEvent, ParameterUpdatedEvent};
+use crate::state::{AllowedDestinationEntry, ChainEntry, TokenDetails};
+
+#[derive(Accounts)]
+#[instruction(params: DepositDestinationParams)]
+pub struct AddDepositDestination<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        seeds = [CHAIN_SEED, &params.chain_id.to_be_bytes()],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AllowedDestinationEntry::LEN,
+        seeds = [DEPOSIT_ALLOWED_DEST_SEED, &params.chain_id.to_be_bytes(), params.token_mint.as_ref()],
+        bump
+    )]
+    pub deposit_destination: Account<'info, AllowedDestinationEntry>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct DepositDestinationParams {
+    // chainlist ID of the destination
+    pub chain_id: u32,
+    // `Pubkey::default()` for SOL
+    pub token_mint: Pubkey,
+}
+
+/// Allows deposits of the token to a registered chain
+pub fn add_deposit_destination(
+    ctx: &Context<AddDepositDestination>,
+    params: &DepositDestinationParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    emit!(DepositDestinationUpdatedEvent {
+        chain_id: params.chain_id,
+        token_mint: params.token_mint,
+        allowed: true,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: DepositDestinationParams)]
+pub struct RemoveDepositDestination<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(mut)]
+    pub receiver: SystemAccount<'info>,
+    #[account(
+        mut,
+        close = receiver, // Refund lamports to the receiver
+        seeds = [DEPOSIT_ALLOWED_DEST_SEED, &params.chain_id.to_be_bytes(), params.token_mint.as_ref()],
+        bump
+    )]
+    pub deposit_destination: Account<'info, AllowedDestinationEntry>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Disallows deposits of the token to a chain
+pub fn remove_deposit_destination(
+    ctx: &Context<RemoveDepositDestination>,
+    params: &DepositDestinationParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    emit!(DepositDestinationUpdatedEvent {
+        chain_id: params.chain_id,
+        token_mint: params.token_mint,
+        allowed: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: DepositDestinationParams)]
+pub struct SetDefaultDepositDestination<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [TOKEN_DETAILS_SEED, params.token_mint.as_ref()],
+        bump
+    )]
+    pub token_details: Account<'info, TokenDetails>,
+    /// CHECK: the token's allow list entry of the chain
+    #[account(
+        seeds = [DEPOSIT_ALLOWED_DEST_SEED, &params.chain_id.to_be_bytes(), params.token_mint.as_ref()],
+        bump
+    )]
+    pub deposit_destination: AccountInfo<'info>,
+}
+
+/// Sets the chain deposits of the token are sent to, 0 to use the global default chain
+/// The chain has to be in the token's allow list
+pub fn set_default_deposit_destination(
+    ctx: &mut Context<SetDefaultDepositDestination>,
+    params: &DepositDestinationParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(
+        params.chain_id == 0 || ctx.accounts.deposit_destination.owner == ctx.program_id,
+        DexalotError::DestinationNotAllowed
+    );
+
+    let token_details = &mut ctx.accounts.token_details;
+    let old_chain_id = token_details.default_dest_chain_id;
+    token_details.default_dest_chain_id = params.chain_id;
+
+    emit!(ParameterUpdatedEvent {
+        pair: token_details.symbol,
+        parameter: "P-DEFDEST".to_owned(),
+        old_value: old_chain_id.into(),
+        new_value: params.chain_id.into()
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_account_info;
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_add_remove_deposit_destination() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec()?;
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let deposit_destination_key = Pubkey::new_unique();
+        let mut deposit_destination_lamports = 100;
+        let mut deposit_destination_data = vec![0u8; AllowedDestinationEntry::LEN];
+        let deposit_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            true,
+            &mut deposit_destination_lamports,
+            &mut deposit_destination_data,
+            &program_id,
+            false,
+            Some(AllowedDestinationEntry::discriminator()),
+        );
+
+        let receiver_key = Pubkey::new_unique();
+        let mut receiver_lamports = 100;
+        let mut receiver_data = vec![];
+        let receiver_info = create_account_info(
+            &receiver_key,
+            false,
+            true,
+            &mut receiver_lamports,
+            &mut receiver_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let params = DepositDestinationParams {
+            chain_id: 432204,
+            token_mint: Pubkey::new_unique(),
+        };
+
+        let mut add_accounts = AddDepositDestination {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            chain_entry: Account::try_from(&chain_entry_info)?,
+            deposit_destination: Account::try_from(&deposit_destination_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let add_ctx = Context {
+            accounts: &mut add_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: AddDepositDestinationBumps::default(),
+        };
+
+        let mut remove_accounts = RemoveDepositDestination {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            receiver: SystemAccount::try_from(&receiver_info)?,
+            deposit_destination: Account::try_from(&deposit_destination_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let remove_ctx = Context {
+            accounts: &mut remove_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RemoveDepositDestinationBumps::default(),
+        };
+
+        // not an admin
+        let result = add_deposit_destination(&add_ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+        let result = remove_deposit_destination(&remove_ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        add_ctx.accounts.admin = program_admin_info.clone();
+        remove_ctx.accounts.admin = program_admin_info;
+        add_deposit_destination(&add_ctx, &params)?;
+        remove_deposit_destination(&remove_ctx, &params)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_default_deposit_destination() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = TokenDetails {
+            token_address: token_mint,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+
+        let deposit_destination_key = Pubkey::new_unique();
+        let mut deposit_destination_lamports = 0;
+        let mut deposit_destination_data = vec![];
+        let deposit_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut deposit_destination_lamports,
+            &mut deposit_destination_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut accounts = SetDefaultDepositDestination {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            token_details: Account::try_from(&token_details_info)?,
+            deposit_destination: deposit_destination_info,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetDefaultDepositDestinationBumps::default(),
+        };
+        let mut params = DepositDestinationParams {
+            chain_id: 432204,
+            token_mint,
+        };
+
+        // not an admin
+        let result = set_default_deposit_destination(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        // the chain is not in the allow list
+        let result = set_default_deposit_destination(&mut ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::DestinationNotAllowed.into()
+        );
+
+        let mut allowed_destination_lamports = 100;
+        let mut allowed_destination_data = vec![0u8; AllowedDestinationEntry::LEN];
+        let allowed_destination_info = create_account_info(
+            &deposit_destination_key,
+            false,
+            false,
+            &mut allowed_destination_lamports,
+            &mut allowed_destination_data,
+            &program_id,
+            false,
+            Some(AllowedDestinationEntry::discriminator()),
+        );
+        ctx.accounts.deposit_destination = allowed_destination_info;
+        set_default_deposit_destination(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.token_details.default_dest_chain_id, 432204);
+
+        // back to the global default chain
+        params.chain_id = 0;
+        set_default_deposit_destination(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.token_details.default_dest_chain_id, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,518 @@
+use crate::{
+    consts::{
+        ADMIN_SEED, AIRDROP_VAULT_SEED, BANNED_ACCOUNT_SEED, FAILED_MESSAGE_SEED, PENDING_SWAPS_SEED,
+        PORTFOLIO_SEED, SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED,
+        SPL_VAULT_SEED, TOKEN_DETAILS_SEED, TOKEN_INVENTORY_SEED,
+    },
+    errors::DexalotError,
+    events::{MessageDiscardedEvent, MessageRetriedEvent},
+    state::{FailedMessage, Portfolio},
+    xfer::{Tx, XFERSolana},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
+
+use super::*;
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct FailedMessageParams {
+    pub src_eid: u32,
+    pub nonce: u64,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(params: FailedMessageParams)]
+pub struct RetryMessage<'info> {
+    #[account(seeds = [PORTFOLIO_SEED], bump = portfolio.bump)]
+    pub portfolio: Account<'info, Portfolio>,
+    #[account(
+        mut,
+        close = airdrop_vault,
+        seeds = [FAILED_MESSAGE_SEED, &failed_message_key(params.src_eid, params.nonce)],
+        bump
+    )]
+    pub failed_message: Account<'info, FailedMessage>,
+    /// CHECK: Used to set the program as authority for the associated token account
+    #[account(
+        constraint = token_vault.owner == __program_id @ DexalotError::InvalidVaultOwner,
+        seeds = [if XFERSolana::unpack_xfer_message(&failed_message.message)?.transaction == Tx::CCTrade{SPL_VAULT_SEED}else{SPL_USER_FUNDS_VAULT_SEED}],
+        bump,
+    )]
+    pub token_vault: AccountInfo<'info>,
+    /// CHECK: the sol vault
+    #[account(
+        mut,
+        seeds = [if XFERSolana::unpack_xfer_message(&failed_message.message)?.transaction == Tx::CCTrade{SOL_VAULT_SEED}else{SOL_USER_FUNDS_VAULT_SEED}],
+        bump,
+    )]
+    pub native_vault: AccountInfo<'info>,
+    /// CHECK: ata or solvault
+    #[account(mut)]
+    pub from: AccountInfo<'info>,
+    /// CHECK: ata or trader
+    #[account(mut)]
+    pub to: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: the trader account
+    #[account(mut)]
+    pub trader: AccountInfo<'info>,
+    #[account(mut, seeds = [AIRDROP_VAULT_SEED], bump)]
+    pub airdrop_vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: token mint or default pubkey
+    pub token_mint: AccountInfo<'info>,
+    /// CHECK: when calling the instruction
+    #[account(mut,
+        seeds = [
+            PENDING_SWAPS_SEED,
+            &generate_map_entry_key(custom_data_to_nonce(XFERSolana::unpack_xfer_message(&failed_message.message)?.custom_data),
+            XFERSolana::unpack_xfer_message(&failed_message.message)?.trader)?], bump
+        )]
+    pub swap_queue_entry: AccountInfo<'info>,
+    /// CHECK: the token inventory pda
+    #[account(mut,
+        seeds = [TOKEN_INVENTORY_SEED, XFERSolana::unpack_xfer_message(&failed_message.message)?.token_mint.as_ref()],
+        bump
+    )]
+    pub token_inventory: AccountInfo<'info>,
+    /// CHECK: the token details pda, not created for SOL
+    #[account(
+        seeds = [TOKEN_DETAILS_SEED, XFERSolana::unpack_xfer_message(&failed_message.message)?.token_mint.as_ref()],
+        bump
+    )]
+    pub token_details: AccountInfo<'info>,
+    /// CHECK: the banned account pda of the trader, must not exist
+    #[account(
+        seeds = [BANNED_ACCOUNT_SEED, XFERSolana::unpack_xfer_message(&failed_message.message)?.trader.as_ref()],
+        bump
+    )]
+    pub banned_account: AccountInfo<'info>,
+}
+
+impl<'info> XferAccounts<'info> {
+    pub fn from_retry_message_context(ctx: &Context<RetryMessage<'info>>) -> Self {
+        Self {
+            token_vault: ctx.accounts.token_vault.clone(),
+            token_vault_bump: ctx.bumps.token_vault,
+            native_vault: ctx.accounts.native_vault.clone(),
+            native_vault_bump: ctx.bumps.native_vault,
+            from: ctx.accounts.from.clone(),
+            to: ctx.accounts.to.clone(),
+            token_program: ctx.accounts.token_program.clone(),
+            associated_token_program: ctx.accounts.associated_token_program.clone(),
+            trader: ctx.accounts.trader.clone(),
+            airdrop_vault: ctx.accounts.airdrop_vault.to_account_info(),
+            airdrop_vault_bump: ctx.bumps.airdrop_vault,
+            system_program: ctx.accounts.system_program.clone(),
+            token_mint: ctx.accounts.token_mint.clone(),
+            swap_queue_entry: ctx.accounts.swap_queue_entry.clone(),
+            token_inventory: ctx.accounts.token_inventory.clone(),
+        }
+    }
+}
+
+/// Re-delivers a stored failed message
+/// Anyone can retry since the payout always goes to the trader in the payload
+/// A banned trader's payout can only be retried once they're unbanned
+pub fn retry_message(ctx: &Context<RetryMessage>, _params: &FailedMessageParams) -> Result<()> {
+    let global_config = &ctx.accounts.portfolio.global_config;
+    let failed_message = &ctx.accounts.failed_message;
+
+    require!(
+        !global_config.program_paused,
+        DexalotError::ProgramPaused
+    );
+
+    let mut xfer = XFERSolana::unpack_xfer_message(&failed_message.message)?;
+
+    validate_xfer(
+        &xfer,
+        &ctx.accounts.token_details,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.to,
+        &ctx.accounts.banned_account,
+    )?;
+    scale_xfer_quantity(&mut xfer, &ctx.accounts.token_details)?;
+
+    let xfer_accounts = XferAccounts::from_retry_message_context(ctx);
+    execute_xfer(&xfer_accounts, global_config, &xfer)?;
+
+    emit!(MessageRetriedEvent {
+        src_eid: failed_message.src_eid,
+        nonce: failed_message.nonce,
+        guid: failed_message.guid,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: FailedMessageParams)]
+pub struct DiscardMessage<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: Used to check if authority is admin
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        mut,
+        close = airdrop_vault,
+        seeds = [FAILED_MESSAGE_SEED, &failed_message_key(params.src_eid, params.nonce)],
+        bump
+    )]
+    pub failed_message: Account<'info, FailedMessage>,
+    #[account(mut, seeds = [AIRDROP_VAULT_SEED], bump)]
+    pub airdrop_vault: SystemAccount<'info>,
+}
+
+/// Drops a stored failed message without delivering it
+/// The rent is returned to the airdrop vault
+pub fn discard_message(ctx: &Context<DiscardMessage>, _params: &FailedMessageParams) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    let failed_message = &ctx.accounts.failed_message;
+
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    emit!(MessageDiscardedEvent {
+        src_eid: failed_message.src_eid,
+        nonce: failed_message.nonce,
+        guid: failed_message.guid,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::NATIVE_VAULT_MIN_THRESHOLD;
+    use crate::state::GlobalConfig;
+    use crate::test_utils::{
+        create_account_info, create_funded_token_inventory_data, create_not_banned_account,
+    };
+    use anchor_lang::solana_program::system_program;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn test_retry_message_native() -> Result<()> {
+        let program_id = crate::id();
+        let generic_key = Pubkey::new_unique();
+        let trader_key = Pubkey::new_unique();
+
+        let xfer = XFERSolana::new(Tx::Withdraw, trader_key, Pubkey::default(), 1, 0, [0u8; 18], 1);
+        let failed_message = FailedMessage {
+            src_eid: 1,
+            sender: [0u8; 32],
+            nonce: 1,
+            guid: [0u8; 32],
+            message: xfer._pack_xfer_message()?,
+        };
+        let mut failed_message_data = failed_message.try_to_vec()?;
+        let mut failed_message_lamports = 100;
+        let failed_message_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut failed_message_lamports,
+            &mut failed_message_data,
+            &program_id,
+            false,
+            Some(FailedMessage::discriminator()),
+        );
+
+        let mut portfolio = Portfolio {
+            admin: Pubkey::new_unique(),
+            global_config: GlobalConfig::default(),
+            bump: 1,
+            pending_admin: Pubkey::default(),
+        };
+        portfolio.global_config.program_paused = true;
+        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_lamports = 100;
+        let portfolio_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+
+        let mut generic_data = vec![0u8; 100];
+        let mut generic_lamports = 100;
+        let generic_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut generic_lamports,
+            &mut generic_data,
+            &program_id,
+            false,
+            None,
+        );
+        let mut trader_data = vec![];
+        let mut trader_lamports = 0;
+        let trader_info = create_account_info(
+            &trader_key,
+            false,
+            true,
+            &mut trader_lamports,
+            &mut trader_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let mut native_vault_data = vec![];
+        let mut native_vault_lamports = 500 + NATIVE_VAULT_MIN_THRESHOLD;
+        let native_vault_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut native_vault_lamports,
+            &mut native_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let mut airdrop_vault_data = vec![];
+        let mut airdrop_vault_lamports = 1000;
+        let airdrop_vault_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut airdrop_vault_lamports,
+            &mut airdrop_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let mut token_program_data = vec![];
+        let mut token_program_lamports = 100;
+        let token_program_info = create_account_info(
+            &anchor_spl::token::ID,
+            false,
+            false,
+            &mut token_program_lamports,
+            &mut token_program_data,
+            &anchor_spl::token::ID,
+            true,
+            None,
+        );
+        let mut associated_token_data = vec![];
+        let mut associated_token_lamports = 100;
+        let associated_token_program_info = create_account_info(
+            &anchor_spl::associated_token::ID,
+            false,
+            false,
+            &mut associated_token_lamports,
+            &mut associated_token_data,
+            &anchor_spl::associated_token::ID,
+            true,
+            None,
+        );
+        let mut system_program_data = vec![];
+        let mut system_program_lamports = 100;
+        let system_program_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut system_program_lamports,
+            &mut system_program_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
+        let mut accounts = RetryMessage {
+            portfolio: Account::try_from(&portfolio_info)?,
+            failed_message: Account::try_from(&failed_message_info)?,
+            token_vault: generic_info.clone(),
+            native_vault: native_vault_info.clone(),
+            from: native_vault_info,
+            to: trader_info.clone(),
+            token_program: Program::try_from(&token_program_info)?,
+            associated_token_program: Program::try_from(&associated_token_program_info)?,
+            trader: trader_info,
+            airdrop_vault: SystemAccount::try_from(&airdrop_vault_info)?,
+            system_program: Program::try_from(&system_program_info)?,
+            token_mint: generic_info.clone(),
+            swap_queue_entry: generic_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            token_details: generic_info.clone(),
+            banned_account: banned_info.clone(),
+        };
+        let params = FailedMessageParams { src_eid: 1, nonce: 1 };
+
+        let ctx = Context {
+            accounts: &mut accounts.clone(),
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RetryMessageBumps::default(),
+        };
+        let result = retry_message(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProgramPaused.into());
+
+        portfolio.global_config.program_paused = false;
+        let mut unpaused_portfolio_data = portfolio.try_to_vec()?;
+        let mut unpaused_portfolio_lamports = 100;
+        let portfolio_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut unpaused_portfolio_lamports,
+            &mut unpaused_portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        accounts.portfolio = Account::try_from(&portfolio_info)?;
+
+        // the payout stays stored while the trader is banned
+        let mut banned_trader_accounts = accounts.clone();
+        banned_trader_accounts.banned_account = generic_info.clone();
+        let ctx = Context {
+            accounts: &mut banned_trader_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RetryMessageBumps::default(),
+        };
+        let result = retry_message(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::AccountBanned.into());
+
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RetryMessageBumps::default(),
+        };
+        let result = retry_message(&ctx, &params);
+        assert!(result.is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_discard_message() -> Result<()> {
+        let program_id = crate::id();
+        let generic_key = Pubkey::new_unique();
+
+        let failed_message = FailedMessage {
+            src_eid: 1,
+            sender: [0u8; 32],
+            nonce: 1,
+            guid: [0u8; 32],
+            message: vec![],
+        };
+        let mut failed_message_data = failed_message.try_to_vec()?;
+        let mut failed_message_lamports = 100;
+        let failed_message_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut failed_message_lamports,
+            &mut failed_message_data,
+            &program_id,
+            false,
+            Some(FailedMessage::discriminator()),
+        );
+        let mut authority_data = vec![];
+        let mut authority_lamports = 100;
+        let authority_info = create_account_info(
+            &generic_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let mut admin_data = vec![0u8; 8];
+        let mut admin_lamports = 100;
+        let admin_info = create_account_info(
+            &generic_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let mut airdrop_vault_data = vec![];
+        let mut airdrop_vault_lamports = 100;
+        let airdrop_vault_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut airdrop_vault_lamports,
+            &mut airdrop_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut accounts = DiscardMessage {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            failed_message: Account::try_from(&failed_message_info)?,
+            airdrop_vault: SystemAccount::try_from(&airdrop_vault_info)?,
+        };
+        let params = FailedMessageParams { src_eid: 1, nonce: 1 };
+
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: DiscardMessageBumps::default(),
+        };
+        let result = discard_message(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_data = vec![0u8; 8];
+        let mut program_admin_lamports = 100;
+        let program_admin_info = create_account_info(
+            &generic_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        accounts.admin = program_admin_info;
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: DiscardMessageBumps::default(),
+        };
+        let result = discard_message(&ctx, &params);
+        assert!(result.is_ok());
+        Ok(())
+    }
+}
@@ -5,8 +5,9 @@ use anchor_lang::{
 use anchor_spl::token::{self, Token, TokenAccount, Transfer as SplTransfer};
 
 use crate::{
-    consts::{REBALANCER_SEED, SOL_VAULT_SEED, SPL_VAULT_SEED},
+    consts::{NATIVE_TOKEN_MINT, REBALANCER_SEED, SOL_VAULT_SEED, SPL_VAULT_SEED, TOKEN_INVENTORY_SEED},
     errors::DexalotError,
+    instructions::update_inventory,
 };
 
 #[derive(Accounts, Clone)]
@@ -25,6 +26,13 @@ pub struct FundSol<'info> {
             bump,
         )]
     pub sol_vault: AccountInfo<'info>,
+    /// CHECK: the native token inventory pda
+    #[account(
+            mut,
+            seeds = [TOKEN_INVENTORY_SEED, NATIVE_TOKEN_MINT.as_ref()],
+            bump,
+        )]
+    pub token_inventory: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -63,6 +71,9 @@ pub fn fund_sol(ctx: &Context<FundSol>, params: &FundSolParams) -> Result<()> {
             ],
         )?;
     }
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.add_rfq_inventory(params.amount)
+    })?;
     Ok(())
 }
 
@@ -103,6 +114,13 @@ pub struct FundSpl<'info> {
         constraint = to.mint == params.token_mint @ DexalotError::InvalidMint
     )]
     pub to: Account<'info, TokenAccount>,
+    /// CHECK: the token inventory pda
+    #[account(
+            mut,
+            seeds = [TOKEN_INVENTORY_SEED, params.token_mint.as_ref()],
+            bump,
+        )]
+    pub token_inventory: AccountInfo<'info>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -140,6 +158,9 @@ pub fn fund_spl(ctx: &Context<FundSpl>, params: &FundSplParams) -> Result<()> {
     if cfg!(not(test)) {
         token::transfer(CpiContext::new(cpi_program, cpi_accounts), params.amount)?;
     }
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.add_rfq_inventory(params.amount)
+    })?;
     Ok(())
 }
 
@@ -149,7 +170,8 @@ mod tests {
     use anchor_lang::solana_program::{program_pack::Pack, system_program};
     use anchor_spl::token::spl_token;
     use crate::errors::DexalotError;
-    use crate::test_utils::create_account_info;
+    use crate::state::TokenInventory;
+    use crate::test_utils::{create_account_info, create_token_inventory_data};
 
     #[test]
     fn test_fund_sol_success() -> Result<()> {
@@ -212,11 +234,26 @@ mod tests {
         );
         let system_program_account = Program::<System>::try_from(&system_program_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = FundSol {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info,
             sol_vault: sol_vault_info,
             system_program: system_program_account,
+            token_inventory: token_inventory_info.clone(),
         };
 
         let ctx = Context {
@@ -229,6 +266,8 @@ mod tests {
         let params = FundSolParams { amount: 50 };
         let result = fund_sol(&ctx, &params);
         assert!(result.is_ok());
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.rfq_inventory, 50);
         Ok(())
     }
 
@@ -293,11 +332,26 @@ mod tests {
         );
         let system_program_account = Program::<System>::try_from(&system_program_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = FundSol {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info.clone(),
             sol_vault: sol_vault_info,
             system_program: system_program_account,
+            token_inventory: token_inventory_info.clone(),
         };
 
         let mut ctx = Context {
@@ -420,6 +474,20 @@ mod tests {
         );
         let token_program_account = Program::<Token>::try_from(&token_program_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = FundSpl {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info,
@@ -427,6 +495,7 @@ mod tests {
             from: from_account,
             to: to_account,
             token_program: token_program_account,
+            token_inventory: token_inventory_info.clone(),
         };
 
         let ctx = Context {
@@ -439,6 +508,8 @@ mod tests {
         let params = FundSplParams { token_mint, amount: 500 };
         let result = fund_spl(&ctx, &params);
         assert!(result.is_ok());
+        let inventory = TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.rfq_inventory, 500);
         Ok(())
     }
 
@@ -542,6 +613,20 @@ mod tests {
         );
         let token_program_account = Program::<Token>::try_from(&token_program_info)?;
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = FundSpl {
             authority: Signer::try_from(&authority_info)?,
             rebalancer: rebalancer_info.clone(),
@@ -549,6 +634,7 @@ mod tests {
             from: from_account,
             to: to_account,
             token_program: token_program_account,
+            token_inventory: token_inventory_info.clone(),
         };
 
         let mut ctx = Context {
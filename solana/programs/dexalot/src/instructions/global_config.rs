@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::consts::{ADMIN_SEED, PORTFOLIO_SEED};
+use crate::consts::{
+    ADMIN_SEED, MIN_TIMELOCK_DELAY, MULTISIG_CONFIG_SEED, PAUSER_SEED, PORTFOLIO_SEED,
+};
 use crate::errors::DexalotError;
-use crate::state::{GlobalConfig, Portfolio};
+use crate::instructions::{authorize_action, consume_change, load_multisig_config};
+use crate::state::{
+    GlobalConfig, PendingChange, Portfolio, Proposal, SensitiveAction, TimelockedAction,
+};
 
 #[derive(Accounts)]
 pub struct GetGlobalConfig<'info> {
@@ -43,11 +48,11 @@ pub fn set_allow_deposit(ctx: &mut Context<WriteConfig>, allow_deposit: bool) ->
     Ok(())
 }
 
-pub fn set_paused(ctx: &mut Context<WriteConfig>, paused: bool) -> Result<()> {
-    let admin = &ctx.accounts.admin;
+pub fn set_paused(ctx: &mut Context<SetPaused>, paused: bool) -> Result<()> {
+    let pauser = &ctx.accounts.pauser;
 
     require!(
-        admin.owner == ctx.program_id,
+        pauser.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
 
@@ -75,12 +80,17 @@ pub fn set_native_deposits_restricted(
     Ok(())
 }
 
-pub fn set_default_chain(ctx: &mut Context<WriteConfig>, params: &SetDefaultChainId) -> Result<()> {
+pub fn set_default_chain(ctx: &mut Context<SetDefaultChain>, params: &SetDefaultChainId) -> Result<()> {
     let admin = &ctx.accounts.admin;
     require!(
         admin.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
+    consume_change(
+        &ctx.accounts.pending_change,
+        TimelockedAction::SetDefaultChain,
+        params,
+    )?;
 
     ctx.accounts.portfolio.global_config.default_chain_id = params.chain_id;
 
@@ -92,30 +102,73 @@ pub struct SetDefaultChainId {
     pub chain_id: u32,
 }
 
-pub fn set_airdrop(ctx: &mut Context<WriteConfig>, params: &SetAirdropParams) -> Result<()> {
+/// Changes the delay of queued config changes, itself behind the current delay
+pub fn set_timelock_delay(
+    ctx: &mut Context<SetTimelockDelay>,
+    params: &SetTimelockDelayParams,
+) -> Result<()> {
     let admin = &ctx.accounts.admin;
     require!(
         admin.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
+    require!(
+        params.timelock_delay >= MIN_TIMELOCK_DELAY,
+        DexalotError::TimelockDelayTooShort
+    );
+    consume_change(
+        &ctx.accounts.pending_change,
+        TimelockedAction::SetTimelockDelay,
+        params,
+    )?;
 
-    ctx.accounts.portfolio.global_config.airdrop_amount = params.amount;
+    ctx.accounts.portfolio.global_config.timelock_delay = params.timelock_delay;
 
     Ok(())
 }
 
-#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
-pub struct SetAirdropParams {
-    amount: u64,
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetTimelockDelayParams {
+    pub timelock_delay: u32,
 }
 
-pub fn set_swap_signer(ctx: &mut Context<WriteConfig>, params: &SetSlapSignerParams) -> Result<()> {
+pub fn set_airdrop(ctx: &mut Context<WriteConfig>, params: &SetAirdropParams) -> Result<()> {
     let admin = &ctx.accounts.admin;
     require!(
         admin.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
 
+    ctx.accounts.portfolio.global_config.airdrop_amount = params.amount;
+
+    Ok(())
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetAirdropParams {
+    amount: u64,
+}
+
+pub fn set_swap_signer(
+    ctx: &mut Context<SetSwapSigner>,
+    params: &SetSlapSignerParams,
+) -> Result<()> {
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::SetSwapSigner,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    consume_change(
+        &ctx.accounts.pending_change,
+        TimelockedAction::SetSwapSigner,
+        params,
+    )?;
+
     ctx.accounts.portfolio.global_config.swap_signer = params.swap_signer;
 
     Ok(())
@@ -126,6 +179,40 @@ pub struct SetSlapSignerParams {
     swap_signer: [u8; 20],
 }
 
+pub fn set_endpoint_program(
+    ctx: &mut Context<SetEndpointProgram>,
+    params: &SetEndpointProgramParams,
+) -> Result<()> {
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::SetEndpointProgram,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    require!(
+        params.endpoint_program != Pubkey::default(),
+        DexalotError::ZeroAccount
+    );
+    consume_change(
+        &ctx.accounts.pending_change,
+        TimelockedAction::SetEndpointProgram,
+        params,
+    )?;
+
+    ctx.accounts.portfolio.global_config.endpoint_program = params.endpoint_program;
+
+    Ok(())
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetEndpointProgramParams {
+    endpoint_program: Pubkey,
+}
+
 #[derive(Accounts)]
 pub struct WriteConfig<'info> {
     pub authority: Signer<'info>,
@@ -144,11 +231,142 @@ pub struct WriteConfig<'info> {
     pub admin: AccountInfo<'info>,
 }
 
+/// Pause switch, held by the pauser role
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PORTFOLIO_SEED],
+        bump
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    /// CHECK: Used to check if authority is a pauser
+    #[account(
+        seeds = [PAUSER_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub pauser: AccountInfo<'info>,
+}
+
+/// Endpoint program update, executed once its queued change has matured
+#[derive(Accounts)]
+pub struct SetEndpointProgram<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PORTFOLIO_SEED],
+        bump
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    /// CHECK: Used to check if authority is admin
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(
+        seeds = [MULTISIG_CONFIG_SEED],
+        bump
+    )]
+    pub multisig_config: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    #[account(mut, close = authority)]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+/// Default chain update, executed once its queued change has matured
+#[derive(Accounts)]
+pub struct SetDefaultChain<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PORTFOLIO_SEED],
+        bump
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    /// CHECK: Used to check if authority is admin
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut, close = authority)]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+/// Timelock delay update, executed once its queued change has matured
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PORTFOLIO_SEED],
+        bump
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    /// CHECK: Used to check if authority is admin
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut, close = authority)]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+/// Swap signer update, executed once its queued change has matured
+#[derive(Accounts)]
+pub struct SetSwapSigner<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PORTFOLIO_SEED],
+        bump
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+
+    /// CHECK: Used to check if authority is admin
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(
+        seeds = [MULTISIG_CONFIG_SEED],
+        bump
+    )]
+    pub multisig_config: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    #[account(mut, close = authority)]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use anchor_lang::Discriminator;
-    use crate::test_utils::create_account_info;
+    use crate::test_utils::{create_account_info, create_pending_change_data};
 
     #[test]
     fn test_set_allow_deposit_success() {
@@ -220,15 +438,15 @@ mod tests {
     fn test_set_paused_success() {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let admin_key = Pubkey::new_unique();
+        let pauser_key = Pubkey::new_unique();
         let portfolio_key = Pubkey::new_unique();
 
         let mut authority_lamports = 100;
-        let mut admin_lamports = 100;
+        let mut pauser_lamports = 100;
         let mut portfolio_lamports = 100;
 
         let mut authority_data = vec![0u8; 100];
-        let mut admin_data = vec![0u8; 10];
+        let mut pauser_data = vec![0u8; 10];
         let mut portfolio_data = vec![0u8; Portfolio::LEN];
 
         let authority = create_account_info(
@@ -241,12 +459,12 @@ mod tests {
             false,
             None,
         );
-        let admin = create_account_info(
-            &admin_key,
+        let pauser = create_account_info(
+            &pauser_key,
             false,
             false,
-            &mut admin_lamports,
-            &mut admin_data,
+            &mut pauser_lamports,
+            &mut pauser_data,
             &program_id,
             false,
             None,
@@ -263,17 +481,17 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
 
-        let mut accounts = WriteConfig {
+        let mut accounts = SetPaused {
             authority: Signer::try_from(&authority).unwrap(),
             portfolio: portfolio_account,
-            admin,
+            pauser,
         };
 
         let mut ctx = Context {
             accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: WriteConfigBumps::default(),
+            bumps: SetPausedBumps::default(),
         };
 
         let result = set_paused(&mut ctx, true);
@@ -395,17 +613,33 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
 
-        let mut accounts = WriteConfig {
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data =
+            create_pending_change_data(TimelockedAction::SetDefaultChain, &SetDefaultChainId { chain_id: 42 }, 0);
+        let pending_change = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = SetDefaultChain {
             authority: Signer::try_from(&authority).unwrap(),
             portfolio: portfolio_account,
             admin,
+            pending_change: Account::try_from(&pending_change).unwrap(),
         };
 
         let mut ctx = Context {
             accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: WriteConfigBumps::default(),
+            bumps: SetDefaultChainBumps::default(),
         };
 
         let params = SetDefaultChainId { chain_id: 42 };
@@ -416,6 +650,96 @@ mod tests {
         assert_eq!(ctx.accounts.portfolio.global_config.default_chain_id, 42);
     }
 
+    #[test]
+    fn test_set_timelock_delay() {
+        let program_id = crate::id();
+        let authority_key = Pubkey::new_unique();
+        let admin_key = Pubkey::new_unique();
+        let portfolio_key = Pubkey::new_unique();
+
+        let mut authority_lamports = 100;
+        let mut admin_lamports = 100;
+        let mut portfolio_lamports = 100;
+
+        let mut authority_data = vec![0u8; 100];
+        let mut admin_data = vec![0u8; 10];
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+
+        let authority = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+        let admin = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        let portfolio = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
+
+        let params = SetTimelockDelayParams {
+            timelock_delay: MIN_TIMELOCK_DELAY,
+        };
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data =
+            create_pending_change_data(TimelockedAction::SetTimelockDelay, &params, 0);
+        let pending_change = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = SetTimelockDelay {
+            authority: Signer::try_from(&authority).unwrap(),
+            portfolio: portfolio_account,
+            admin,
+            pending_change: Account::try_from(&pending_change).unwrap(),
+        };
+
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetTimelockDelayBumps::default(),
+        };
+
+        // the delay can't be lowered below the minimum
+        let short_params = SetTimelockDelayParams {
+            timelock_delay: MIN_TIMELOCK_DELAY - 1,
+        };
+        let result = set_timelock_delay(&mut ctx, &short_params);
+        assert_eq!(result.unwrap_err(), DexalotError::TimelockDelayTooShort.into());
+
+        set_timelock_delay(&mut ctx, &params).unwrap();
+        assert_eq!(ctx.accounts.portfolio.global_config.timelock_delay, MIN_TIMELOCK_DELAY);
+    }
+
     #[test]
     fn test_set_airdrop_success() {
         let program_id = crate::id();
@@ -663,17 +987,50 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
 
-        let mut accounts = WriteConfig {
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data =
+            create_pending_change_data(TimelockedAction::SetSwapSigner, &SetSlapSignerParams { swap_signer: [1; 20] }, 0);
+        let pending_change = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = SetSwapSigner {
             authority: Signer::try_from(&authority).unwrap(),
             portfolio: portfolio_account,
             admin,
+            multisig_config,
+            proposal: None,
+            pending_change: Account::try_from(&pending_change).unwrap(),
         };
 
         let mut ctx = Context {
             accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: WriteConfigBumps::default(),
+            bumps: SetSwapSignerBumps::default(),
         };
 
         let new_swap_signer: [u8; 20] = [1; 20];
@@ -684,6 +1041,126 @@ mod tests {
         assert_eq!(ctx.accounts.portfolio.global_config.swap_signer, new_swap_signer);
     }
 
+    #[test]
+    fn test_set_endpoint_program() {
+        let program_id = crate::id();
+        let authority_key = Pubkey::new_unique();
+        let admin_key = Pubkey::new_unique();
+        let portfolio_key = Pubkey::new_unique();
+        let wrong_owner = Pubkey::new_unique();
+
+        let mut authority_lamports = 100;
+        let mut admin_lamports = 100;
+        let mut portfolio_lamports = 100;
+
+        let mut authority_data = vec![0u8; 100];
+        let mut admin_data = vec![0u8; 10];
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+
+        let authority = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+        let admin = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &wrong_owner,
+            false,
+            None,
+        );
+        let portfolio = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
+
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let endpoint_program = Pubkey::new_unique();
+        let mut params = SetEndpointProgramParams { endpoint_program };
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = create_pending_change_data(TimelockedAction::SetEndpointProgram, &params, 0);
+        let pending_change = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = SetEndpointProgram {
+            authority: Signer::try_from(&authority).unwrap(),
+            portfolio: portfolio_account,
+            admin,
+            multisig_config,
+            proposal: None,
+            pending_change: Account::try_from(&pending_change).unwrap(),
+        };
+
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetEndpointProgramBumps::default(),
+        };
+
+        // not an admin
+        let result = set_endpoint_program(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin;
+        set_endpoint_program(&mut ctx, &params).unwrap();
+        assert_eq!(ctx.accounts.portfolio.global_config.endpoint_program, endpoint_program);
+
+        params.endpoint_program = Pubkey::default();
+        let result = set_endpoint_program(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ZeroAccount.into());
+    }
+
     #[test]
     fn test_set_swap_signer_unauthorized() {
         let program_id = crate::id();
@@ -732,17 +1209,50 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
 
-        let mut accounts = WriteConfig {
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data =
+            create_pending_change_data(TimelockedAction::SetSwapSigner, &SetSlapSignerParams { swap_signer: [1; 20] }, 0);
+        let pending_change = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = SetSwapSigner {
             authority: Signer::try_from(&authority).unwrap(),
             portfolio: portfolio_account,
             admin,
+            multisig_config,
+            proposal: None,
+            pending_change: Account::try_from(&pending_change).unwrap(),
         };
 
         let mut ctx = Context {
             accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: WriteConfigBumps::default(),
+            bumps: SetSwapSignerBumps::default(),
         };
 
         let new_swap_signer: [u8; 20] = [1; 20];
@@ -815,14 +1325,14 @@ mod tests {
     fn test_set_paused_unauthorized() {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let admin_key = Pubkey::new_unique();
+        let pauser_key = Pubkey::new_unique();
         let portfolio_key = Pubkey::new_unique();
         let wrong_owner = Pubkey::new_unique();
         let mut authority_lamports = 100;
-        let mut admin_lamports = 100;
+        let mut pauser_lamports = 100;
         let mut portfolio_lamports = 100;
         let mut authority_data = vec![0u8; 100];
-        let mut admin_data = vec![0u8; 10];
+        let mut pauser_data = vec![0u8; 10];
         let mut portfolio_data = vec![0u8; Portfolio::LEN];
         let authority = create_account_info(
             &authority_key,
@@ -834,12 +1344,12 @@ mod tests {
             false,
             None,
         );
-        let admin = create_account_info(
-            &admin_key,
+        let pauser = create_account_info(
+            &pauser_key,
             false,
             false,
-            &mut admin_lamports,
-            &mut admin_data,
+            &mut pauser_lamports,
+            &mut pauser_data,
             &wrong_owner,
             false,
             None,
@@ -855,16 +1365,16 @@ mod tests {
             Some(Portfolio::discriminator()),
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
-        let mut accounts = WriteConfig {
+        let mut accounts = SetPaused {
             authority: Signer::try_from(&authority).unwrap(),
             portfolio: portfolio_account,
-            admin,
+            pauser,
         };
         let mut ctx = Context {
             accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: WriteConfigBumps::default(),
+            bumps: SetPausedBumps::default(),
         };
         let result = set_paused(&mut ctx, true);
         assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
@@ -973,16 +1483,32 @@ mod tests {
             Some(Portfolio::discriminator()),
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio).unwrap();
-        let mut accounts = WriteConfig {
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data =
+            create_pending_change_data(TimelockedAction::SetDefaultChain, &SetDefaultChainId { chain_id: 42 }, 0);
+        let pending_change = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = SetDefaultChain {
             authority: Signer::try_from(&authority).unwrap(),
             portfolio: portfolio_account,
             admin,
+            pending_change: Account::try_from(&pending_change).unwrap(),
         };
         let mut ctx = Context {
             accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: WriteConfigBumps::default(),
+            bumps: SetDefaultChainBumps::default(),
         };
         let params = SetDefaultChainId { chain_id: 42 };
         let result = set_default_chain(&mut ctx, &params);
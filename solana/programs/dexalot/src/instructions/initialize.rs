@@ -1,9 +1,12 @@
 use crate::consts::{
-    ADMIN_SEED, AIRDROP_VAULT_SEED, ANCHOR_DISCRIMINATOR, DEFAULT_AIRDROP_AMOUNT, PORTFOLIO_SEED, REGISTER_OAPP, SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED, TOKEN_LIST_PAGE_1_SEED, TOKEN_LIST_SEED
+    ADMIN_SEED, AIRDROP_VAULT_SEED, ROLE_REGISTRY_SEED, ANCHOR_DISCRIMINATOR, DEFAULT_AIRDROP_AMOUNT, LZ_RECEIVE_TYPES_SEED, PORTFOLIO_SEED, REGISTER_OAPP, SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED, TOKEN_LIST_PAGE_1_SEED, TOKEN_LIST_SEED,
+    NATIVE_TOKEN_MINT, TOKEN_INVENTORY_SEED, MIN_TIMELOCK_DELAY
 };
 use crate::cpi_utils::{create_instruction_data, RegisterOAppParams};
 use crate::errors::DexalotError;
-use crate::state::{Admin, Portfolio, TokenList};
+use crate::state::{
+    Admin, LzReceiveTypesAccounts, Portfolio, RoleRegistry, RoleType, TokenInventory, TokenList,
+};
 use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::program::invoke_signed;
 use anchor_lang::{
@@ -26,6 +29,20 @@ pub fn initialize(ctx: &mut Context<Initialize>, params: &InitializeParams) -> R
     portfolio.global_config.airdrop_amount = DEFAULT_AIRDROP_AMOUNT;
     portfolio.global_config.swap_signer = params.swap_signer;
     portfolio.global_config.out_nonce = 0;
+    portfolio.global_config.endpoint_program = ctx.accounts.endpoint_program.key();
+    require!(
+        params.timelock_delay >= MIN_TIMELOCK_DELAY,
+        DexalotError::TimelockDelayTooShort
+    );
+    portfolio.global_config.timelock_delay = params.timelock_delay;
+
+    // lets the executor pass the portfolio to lz_receive_types
+    ctx.accounts.lz_receive_types_accounts.portfolio = ctx.accounts.portfolio.key();
+
+    // the initializer is the first listed admin
+    let admin_registry = &mut ctx.accounts.admin_registry;
+    admin_registry.role = RoleType::Admin;
+    admin_registry.add_member(ctx.accounts.authority.key())?;
 
     // prepare CPI
     let register_params = RegisterOAppParams {
@@ -67,6 +84,7 @@ pub struct InitializeParams {
     pub src_chain_id: u16,
     pub default_chain_id: u32,
     pub swap_signer: [u8; 20],
+    pub timelock_delay: u32,
 }
 
 #[derive(Accounts)]
@@ -86,7 +104,7 @@ pub struct Initialize<'info> {
         seeds = [TOKEN_LIST_SEED, TOKEN_LIST_PAGE_1_SEED.as_ref()],
         bump
     )]
-    pub token_list: Box<Account<'info, TokenList>>,
+    pub token_list: AccountLoader<'info, TokenList>,
     #[account(
         init,
         payer = authority,
@@ -95,6 +113,22 @@ pub struct Initialize<'info> {
         bump
     )]
     pub admin: Box<Account<'info, Admin>>,
+    #[account(
+        init,
+        payer = authority,
+        space = RoleRegistry::LEN,
+        seeds = [ROLE_REGISTRY_SEED, ADMIN_SEED],
+        bump
+    )]
+    pub admin_registry: Box<Account<'info, RoleRegistry>>,
+    #[account(
+        init,
+        payer = authority,
+        space = LzReceiveTypesAccounts::LEN,
+        seeds = [LZ_RECEIVE_TYPES_SEED, portfolio.key().as_ref()],
+        bump
+    )]
+    pub lz_receive_types_accounts: Box<Account<'info, LzReceiveTypesAccounts>>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -183,6 +217,14 @@ pub struct InitializeSolVaults<'info>{
         bump
     )]
     pub admin: AccountInfo<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = TokenInventory::LEN,
+        seeds = [TOKEN_INVENTORY_SEED, NATIVE_TOKEN_MINT.as_ref()],
+        bump
+    )]
+    pub native_token_inventory: Account<'info, TokenInventory>,
     pub system_program: Program<'info, System>,
 }
 
@@ -253,7 +295,7 @@ mod tests {
             false,
             Some(TokenList::discriminator()),
         );
-        let token_list_account = Box::new(Account::<TokenList>::try_from(&token_list_info)?);
+        let token_list_account = AccountLoader::<TokenList>::try_from(&token_list_info)?;
 
         let mut admin_data = vec![0u8; Admin::LEN];
         let mut admin_lamports = 100;
@@ -269,6 +311,37 @@ mod tests {
         );
         let admin_account = Box::new(Account::<Admin>::try_from(&admin_info)?);
 
+        let admin_registry_key = Pubkey::new_unique();
+        let mut admin_registry_data = vec![0u8; RoleRegistry::LEN];
+        let mut admin_registry_lamports = 100;
+        let admin_registry_info = create_account_info(
+            &admin_registry_key,
+            false,
+            true,
+            &mut admin_registry_lamports,
+            &mut admin_registry_data,
+            &program_id,
+            false,
+            Some(RoleRegistry::discriminator()),
+        );
+        let admin_registry_account = Box::new(Account::<RoleRegistry>::try_from(&admin_registry_info)?);
+
+        let lz_receive_types_accounts_key = Pubkey::new_unique();
+        let mut lz_receive_types_accounts_data = vec![0u8; LzReceiveTypesAccounts::LEN];
+        let mut lz_receive_types_accounts_lamports = 100;
+        let lz_receive_types_accounts_info = create_account_info(
+            &lz_receive_types_accounts_key,
+            false,
+            true,
+            &mut lz_receive_types_accounts_lamports,
+            &mut lz_receive_types_accounts_data,
+            &program_id,
+            false,
+            Some(LzReceiveTypesAccounts::discriminator()),
+        );
+        let lz_receive_types_accounts =
+            Box::new(Account::<LzReceiveTypesAccounts>::try_from(&lz_receive_types_accounts_info)?);
+
         let mut system_program_lamports = 100;
         let mut system_program_data = vec![0u8; 10];
         let system_program_info = create_account_info(
@@ -300,6 +373,8 @@ mod tests {
             portfolio: Box::new(portfolio_account),
             token_list: token_list_account,
             admin: admin_account,
+            admin_registry: admin_registry_account,
+            lz_receive_types_accounts,
             authority: Signer::try_from(&authority_info)?,
             system_program: system_program.clone(),
             endpoint_program: endpoint_program_info,
@@ -322,7 +397,18 @@ mod tests {
             src_chain_id: 1,
             default_chain_id: 2,
             swap_signer,
+            timelock_delay: 48 * 60 * 60,
+        };
+
+        // queued changes need at least the minimum delay
+        let short_delay_params = InitializeParams {
+            src_chain_id: 1,
+            default_chain_id: 2,
+            swap_signer,
+            timelock_delay: MIN_TIMELOCK_DELAY - 1,
         };
+        let result = initialize(&mut ctx, &short_delay_params);
+        assert_eq!(result.unwrap_err(), DexalotError::TimelockDelayTooShort.into());
 
         let result = initialize(&mut ctx, &params);
         assert!(result.is_ok());
@@ -344,6 +430,10 @@ mod tests {
         );
         assert_eq!(portfolio.global_config.swap_signer, params.swap_signer);
         assert_eq!(portfolio.global_config.out_nonce, 0);
+        assert_eq!(portfolio.global_config.timelock_delay, params.timelock_delay);
+        assert_eq!(portfolio.pending_admin, Pubkey::default());
+        assert_eq!(ctx.accounts.admin_registry.members, vec![authority_key]);
+        assert_eq!(ctx.accounts.lz_receive_types_accounts.portfolio, portfolio_key);
 
         Ok(())
     }
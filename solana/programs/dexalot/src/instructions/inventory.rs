@@ -0,0 +1,660 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+
+use crate::consts::{
+    ADMIN_SEED, NATIVE_TOKEN_MINT, NATIVE_VAULT_MIN_THRESHOLD, SOL_USER_FUNDS_VAULT_SEED,
+    SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED, TOKEN_INVENTORY_SEED,
+};
+use crate::errors::DexalotError;
+use crate::events::{InventoryReconciledEvent, ParameterUpdatedEvent};
+use crate::state::TokenInventory;
+
+#[derive(Accounts)]
+#[instruction(params: SetInventoryFloorParams)]
+pub struct SetInventoryFloor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TokenInventory::LEN,
+        seeds = [TOKEN_INVENTORY_SEED, params.token_mint.as_ref()],
+        bump
+    )]
+    pub token_inventory: Account<'info, TokenInventory>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetInventoryFloorParams {
+    pub token_mint: Pubkey,
+    pub floor: u64,
+}
+
+/// Sets the minimum RFQ inventory that claims must leave for a token
+/// Creates the inventory account if the token does not have one yet
+pub fn set_inventory_floor(
+    ctx: &mut Context<SetInventoryFloor>,
+    params: &SetInventoryFloorParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let token_inventory = &mut ctx.accounts.token_inventory;
+    let old_floor = token_inventory.rfq_floor;
+    token_inventory.rfq_floor = params.floor;
+
+    emit!(ParameterUpdatedEvent {
+        pair: params.token_mint.to_bytes(),
+        parameter: "P-INVFLOOR".to_owned(),
+        old_value: old_floor,
+        new_value: params.floor
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetRebalanceCapParams)]
+pub struct SetRebalanceCap<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TokenInventory::LEN,
+        seeds = [TOKEN_INVENTORY_SEED, params.token_mint.as_ref()],
+        bump
+    )]
+    pub token_inventory: Account<'info, TokenInventory>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetRebalanceCapParams {
+    pub token_mint: Pubkey,
+    pub cap: u64,
+}
+
+/// Sets the maximum amount of a token moved by `rebalance_vaults` per epoch
+/// Creates the inventory account if the token does not have one yet
+pub fn set_rebalance_cap(
+    ctx: &mut Context<SetRebalanceCap>,
+    params: &SetRebalanceCapParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let token_inventory = &mut ctx.accounts.token_inventory;
+    let old_cap = token_inventory.rebalance_cap;
+    token_inventory.rebalance_cap = params.cap;
+
+    emit!(ParameterUpdatedEvent {
+        pair: params.token_mint.to_bytes(),
+        parameter: "P-REBALCAP".to_owned(),
+        old_value: old_cap,
+        new_value: params.cap
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: ReconcileInventoryParams)]
+pub struct ReconcileInventory<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TokenInventory::LEN,
+        seeds = [TOKEN_INVENTORY_SEED, params.token_mint.as_ref()],
+        bump
+    )]
+    pub token_inventory: Account<'info, TokenInventory>,
+    /// CHECK: the SOL user funds vault for SOL, the user funds vault ATA otherwise
+    pub user_funds_vault: AccountInfo<'info>,
+    /// CHECK: the SOL vault for SOL, the RFQ vault ATA otherwise
+    pub rfq_vault: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct ReconcileInventoryParams {
+    pub token_mint: Pubkey,
+}
+
+/// Seeds the inventory of a token from the balances held in its vaults
+/// Creates the inventory account of tokens that were added before inventories were tracked
+pub fn reconcile_inventory(
+    ctx: &mut Context<ReconcileInventory>,
+    params: &ReconcileInventoryParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let user_funds_balance = vault_balance(
+        &ctx.accounts.user_funds_vault,
+        &params.token_mint,
+        SOL_USER_FUNDS_VAULT_SEED,
+        SPL_USER_FUNDS_VAULT_SEED,
+        ctx.program_id,
+    )?;
+    let rfq_balance = vault_balance(
+        &ctx.accounts.rfq_vault,
+        &params.token_mint,
+        SOL_VAULT_SEED,
+        SPL_VAULT_SEED,
+        ctx.program_id,
+    )?;
+
+    let token_inventory = &mut ctx.accounts.token_inventory;
+    token_inventory.reconcile(user_funds_balance, rfq_balance)?;
+
+    emit!(InventoryReconciledEvent {
+        token_mint: params.token_mint,
+        user_funds: token_inventory.user_funds,
+        rfq_inventory: token_inventory.rfq_inventory,
+        bridge_fees: token_inventory.bridge_fees,
+    });
+
+    Ok(())
+}
+
+/// Returns the amount a vault can pay out, SOL vaults keep their rent-exempt minimum
+/// SPL vault ATAs that are not created yet hold nothing
+fn vault_balance(
+    vault: &AccountInfo,
+    token_mint: &Pubkey,
+    sol_vault_seed: &[u8],
+    spl_vault_seed: &[u8],
+    program_id: &Pubkey,
+) -> Result<u64> {
+    if *token_mint == NATIVE_TOKEN_MINT {
+        let (sol_vault, _) = Pubkey::find_program_address(&[sol_vault_seed], program_id);
+        require_keys_eq!(vault.key(), sol_vault, DexalotError::InvalidVaultOwner);
+        return Ok(vault.lamports().saturating_sub(NATIVE_VAULT_MIN_THRESHOLD));
+    }
+
+    let (spl_vault, _) = Pubkey::find_program_address(&[spl_vault_seed], program_id);
+    require_keys_eq!(
+        vault.key(),
+        get_associated_token_address(&spl_vault, token_mint),
+        DexalotError::InvalidVaultOwner
+    );
+    if vault.data_is_empty() {
+        return Ok(0);
+    }
+    let vault_ata = TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?;
+    Ok(vault_ata.amount)
+}
+
+/// Returns the inventory PDA of a token, `Pubkey::default()` being native SOL
+pub fn token_inventory_address(token_mint: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TOKEN_INVENTORY_SEED, token_mint.as_ref()], program_id).0
+}
+
+/// Loads a token inventory account, applies `update` to it and writes it back
+pub fn update_inventory(
+    token_inventory: &AccountInfo,
+    update: impl FnOnce(&mut TokenInventory) -> Result<()>,
+) -> Result<()> {
+    require_keys_eq!(
+        *token_inventory.owner,
+        crate::ID,
+        DexalotError::InvalidInventoryAccount
+    );
+    let mut data = token_inventory.try_borrow_mut_data()?;
+    let mut inventory = TokenInventory::try_deserialize(&mut &data[..])?;
+    update(&mut inventory)?;
+    inventory.try_serialize(&mut &mut data[..])
+}
+
+/// Applies `update` to a copy of a token inventory account, leaving the account untouched
+pub fn check_inventory_update(
+    token_inventory: &AccountInfo,
+    update: impl FnOnce(&mut TokenInventory) -> Result<()>,
+) -> Result<()> {
+    require_keys_eq!(
+        *token_inventory.owner,
+        crate::ID,
+        DexalotError::InvalidInventoryAccount
+    );
+    let mut inventory = TokenInventory::try_deserialize(&mut &token_inventory.try_borrow_data()?[..])?;
+    update(&mut inventory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        create_account_info, create_packed_token_account, create_token_inventory_data,
+    };
+    use anchor_lang::{solana_program::system_program, Discriminator};
+    use anchor_spl::token;
+
+    #[test]
+    fn test_set_inventory_config() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let inventory_key = token_inventory_address(&token_mint, &program_id);
+        let mut inventory_lamports = 100;
+        let mut inventory_data = vec![0u8; TokenInventory::LEN - 8];
+        let inventory_info = create_account_info(
+            &inventory_key,
+            false,
+            true,
+            &mut inventory_lamports,
+            &mut inventory_data,
+            &program_id,
+            false,
+            Some(TokenInventory::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = SetInventoryFloor {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            token_inventory: Account::try_from(&inventory_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let params = SetInventoryFloorParams {
+            token_mint,
+            floor: 500,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetInventoryFloorBumps::default(),
+        };
+
+        // not an admin
+        let result = set_inventory_floor(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info.clone();
+        set_inventory_floor(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.token_inventory.rfq_floor, 500);
+
+        let mut cap_accounts = SetRebalanceCap {
+            authority: Signer::try_from(&authority_info)?,
+            admin: program_admin_info,
+            token_inventory: Account::try_from(&inventory_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut cap_ctx = Context {
+            accounts: &mut cap_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetRebalanceCapBumps::default(),
+        };
+        let cap_params = SetRebalanceCapParams {
+            token_mint,
+            cap: 1000,
+        };
+        set_rebalance_cap(&mut cap_ctx, &cap_params)?;
+        assert_eq!(cap_ctx.accounts.token_inventory.rebalance_cap, 1000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconcile_inventory() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let inventory_key = token_inventory_address(&token_mint, &program_id);
+        let mut inventory_lamports = 100;
+        let mut inventory_data = create_token_inventory_data(&TokenInventory {
+            user_funds: 5,
+            rfq_inventory: 5,
+            rfq_floor: 50,
+            bridge_fees: 100,
+            ..Default::default()
+        });
+        let inventory_info = create_account_info(
+            &inventory_key,
+            false,
+            true,
+            &mut inventory_lamports,
+            &mut inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let (user_funds_vault, _) =
+            Pubkey::find_program_address(&[SPL_USER_FUNDS_VAULT_SEED], &program_id);
+        let user_funds_ata_key = get_associated_token_address(&user_funds_vault, &token_mint);
+        let mut user_funds_ata_lamports = 100;
+        let mut user_funds_ata_data =
+            create_packed_token_account(token_mint, user_funds_vault, 800)?;
+        let user_funds_ata_info = create_account_info(
+            &user_funds_ata_key,
+            false,
+            false,
+            &mut user_funds_ata_lamports,
+            &mut user_funds_ata_data,
+            &token::ID,
+            false,
+            None,
+        );
+
+        // the RFQ vault ATA is not created yet
+        let (rfq_vault, _) = Pubkey::find_program_address(&[SPL_VAULT_SEED], &program_id);
+        let rfq_ata_key = get_associated_token_address(&rfq_vault, &token_mint);
+        let mut rfq_ata_lamports = 0;
+        let mut rfq_ata_data = vec![];
+        let rfq_ata_info = create_account_info(
+            &rfq_ata_key,
+            false,
+            false,
+            &mut rfq_ata_lamports,
+            &mut rfq_ata_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = ReconcileInventory {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            token_inventory: Account::try_from(&inventory_info)?,
+            user_funds_vault: user_funds_ata_info.clone(),
+            rfq_vault: rfq_ata_info.clone(),
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut params = ReconcileInventoryParams { token_mint };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: ReconcileInventoryBumps::default(),
+        };
+
+        // not an admin
+        let result = reconcile_inventory(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        // vaults swapped
+        ctx.accounts.user_funds_vault = rfq_ata_info.clone();
+        ctx.accounts.rfq_vault = user_funds_ata_info.clone();
+        let result = reconcile_inventory(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidVaultOwner.into());
+        ctx.accounts.user_funds_vault = user_funds_ata_info.clone();
+        ctx.accounts.rfq_vault = rfq_ata_info.clone();
+
+        // the withheld bridge fees stay out of the user funds, the floor is kept
+        reconcile_inventory(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.token_inventory.user_funds, 700);
+        assert_eq!(ctx.accounts.token_inventory.rfq_inventory, 0);
+        assert_eq!(ctx.accounts.token_inventory.bridge_fees, 100);
+        assert_eq!(ctx.accounts.token_inventory.rfq_floor, 50);
+
+        // the user funds vault must hold the withheld bridge fees
+        ctx.accounts.token_inventory.bridge_fees = 801;
+        let result = reconcile_inventory(&mut ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InsufficientInventory.into()
+        );
+        ctx.accounts.token_inventory.bridge_fees = 0;
+
+        // native vaults keep their rent-exempt minimum
+        let (sol_user_funds_vault_key, _) =
+            Pubkey::find_program_address(&[SOL_USER_FUNDS_VAULT_SEED], &program_id);
+        let mut sol_user_funds_vault_lamports = NATIVE_VAULT_MIN_THRESHOLD + 300;
+        let mut sol_user_funds_vault_data = vec![];
+        let sol_user_funds_vault_info = create_account_info(
+            &sol_user_funds_vault_key,
+            false,
+            false,
+            &mut sol_user_funds_vault_lamports,
+            &mut sol_user_funds_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let (sol_vault_key, _) = Pubkey::find_program_address(&[SOL_VAULT_SEED], &program_id);
+        let mut sol_vault_lamports = NATIVE_VAULT_MIN_THRESHOLD - 1;
+        let mut sol_vault_data = vec![];
+        let sol_vault_info = create_account_info(
+            &sol_vault_key,
+            false,
+            false,
+            &mut sol_vault_lamports,
+            &mut sol_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        params.token_mint = NATIVE_TOKEN_MINT;
+        ctx.accounts.user_funds_vault = sol_user_funds_vault_info;
+        ctx.accounts.rfq_vault = sol_vault_info;
+        reconcile_inventory(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.token_inventory.user_funds, 300);
+        assert_eq!(ctx.accounts.token_inventory.rfq_inventory, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_inventory() -> Result<()> {
+        let program_id = crate::id();
+        let inventory_key = Pubkey::new_unique();
+        let mut inventory_lamports = 100;
+        let mut inventory_data = create_token_inventory_data(&TokenInventory {
+            user_funds: 0,
+            rfq_inventory: 1000,
+            rfq_floor: 400,
+            ..Default::default()
+        });
+        let inventory_info = create_account_info(
+            &inventory_key,
+            false,
+            true,
+            &mut inventory_lamports,
+            &mut inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        update_inventory(&inventory_info, |inventory| {
+            inventory.add_user_funds(300)?;
+            inventory.claim_rfq_inventory(600)
+        })?;
+        let inventory =
+            TokenInventory::try_deserialize(&mut &inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 300);
+        assert_eq!(inventory.rfq_inventory, 400);
+
+        // claiming below the floor is rejected and nothing is written
+        let result = update_inventory(&inventory_info, |inventory| {
+            inventory.remove_user_funds(300)?;
+            inventory.claim_rfq_inventory(1)
+        });
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InventoryBelowFloor.into()
+        );
+        let inventory =
+            TokenInventory::try_deserialize(&mut &inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 300);
+
+        // debits above the recorded amounts are rejected, even without a floor
+        let result = update_inventory(&inventory_info, |inventory| {
+            inventory.remove_user_funds(301)
+        });
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InsufficientInventory.into()
+        );
+        let result = update_inventory(&inventory_info, |inventory| {
+            inventory.rfq_floor = 0;
+            inventory.claim_rfq_inventory(401)
+        });
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InsufficientInventory.into()
+        );
+        let inventory =
+            TokenInventory::try_deserialize(&mut &inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 300);
+        assert_eq!(inventory.rfq_inventory, 400);
+
+        // the account must be owned by the program
+        let mut foreign_lamports = 100;
+        let mut foreign_data = create_token_inventory_data(&TokenInventory::default());
+        let foreign_info = create_account_info(
+            &inventory_key,
+            false,
+            true,
+            &mut foreign_lamports,
+            &mut foreign_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let result = update_inventory(&foreign_info, |_| Ok(()));
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InvalidInventoryAccount.into()
+        );
+        Ok(())
+    }
+}
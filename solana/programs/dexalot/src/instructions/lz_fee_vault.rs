@@ -0,0 +1,431 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::{invoke, invoke_signed},
+        system_instruction,
+    },
+};
+
+use crate::{
+    consts::{ADMIN_SEED, LZ_FEE_VAULT_SEED, MULTISIG_CONFIG_SEED, NATIVE_VAULT_MIN_THRESHOLD},
+    errors::DexalotError,
+    events::{LzFeeVaultFundedEvent, LzFeeVaultWithdrawnEvent},
+    instructions::{authorize_action, load_multisig_config},
+    state::{Proposal, SensitiveAction},
+};
+
+#[derive(Accounts)]
+pub struct FundLzFeeVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the LZ fee vault paying the protocol-paid messaging fees
+    #[account(
+        mut,
+        seeds = [LZ_FEE_VAULT_SEED],
+        bump,
+    )]
+    pub lz_fee_vault: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct FundLzFeeVaultParams {
+    pub amount: u64,
+}
+
+/// Tops up the LZ fee vault from the admin's wallet
+pub fn fund_lz_fee_vault(
+    ctx: &mut Context<FundLzFeeVault>,
+    params: &FundLzFeeVaultParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(params.amount > 0, DexalotError::ZeroXferAmount);
+
+    let authority = &ctx.accounts.authority;
+    let lz_fee_vault = &ctx.accounts.lz_fee_vault;
+    let system_program = &ctx.accounts.system_program;
+
+    // check balance
+    require!(
+        authority.lamports() >= params.amount,
+        DexalotError::NotEnoughNativeBalance
+    );
+
+    let ix = system_instruction::transfer(&authority.key(), &lz_fee_vault.key(), params.amount);
+    if cfg!(not(test)) {
+        invoke(
+            &ix,
+            &[
+                authority.to_account_info(),
+                lz_fee_vault.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    emit!(LzFeeVaultFundedEvent {
+        funder: authority.key(),
+        amount: params.amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawLzFeeVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the LZ fee vault paying the protocol-paid messaging fees
+    #[account(
+        mut,
+        seeds = [LZ_FEE_VAULT_SEED],
+        bump,
+    )]
+    pub lz_fee_vault: AccountInfo<'info>,
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(
+        seeds = [MULTISIG_CONFIG_SEED],
+        bump,
+    )]
+    pub multisig_config: AccountInfo<'info>,
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct WithdrawLzFeeVaultParams {
+    pub amount: u64,
+}
+
+/// Withdraws lamports from the LZ fee vault to the signer, keeping the vault rent exempt
+pub fn withdraw_lz_fee_vault(
+    ctx: &mut Context<WithdrawLzFeeVault>,
+    params: &WithdrawLzFeeVaultParams,
+) -> Result<()> {
+    // check admin or approved proposal
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    let proposer = authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::WithdrawLzFeeVault,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    let authority = &ctx.accounts.authority;
+    let lz_fee_vault = &ctx.accounts.lz_fee_vault;
+    let system_program = &ctx.accounts.system_program;
+
+    // the funds go to the signer, so an approved withdrawal is paid to its proposer only
+    if let Some(proposer) = proposer {
+        require!(
+            authority.key() == proposer,
+            DexalotError::UnauthorizedSigner
+        );
+    }
+    require!(params.amount > 0, DexalotError::ZeroXferAmount);
+    // check balance
+    require!(
+        lz_fee_vault.lamports() >= params.amount + NATIVE_VAULT_MIN_THRESHOLD,
+        DexalotError::NotEnoughNativeBalance
+    );
+
+    let bump = &[ctx.bumps.lz_fee_vault];
+    let seeds: &[&[u8]] = &[LZ_FEE_VAULT_SEED, bump];
+
+    let ix = system_instruction::transfer(&lz_fee_vault.key(), &authority.key(), params.amount);
+    if cfg!(not(test)) {
+        invoke_signed(
+            &ix,
+            &[
+                lz_fee_vault.to_account_info(),
+                authority.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[seeds], // sign with the PDA
+        )?;
+    }
+
+    emit!(LzFeeVaultWithdrawnEvent {
+        to: authority.key(),
+        amount: params.amount,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MultisigConfig;
+    use crate::test_utils::create_account_info;
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_fund_lz_fee_vault() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 1000;
+        let mut authority_data = vec![];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let lz_fee_vault_key = Pubkey::new_unique();
+        let mut lz_fee_vault_lamports = 0;
+        let mut lz_fee_vault_data = vec![];
+        let lz_fee_vault_info = create_account_info(
+            &lz_fee_vault_key,
+            false,
+            true,
+            &mut lz_fee_vault_lamports,
+            &mut lz_fee_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = FundLzFeeVault {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            lz_fee_vault: lz_fee_vault_info,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: FundLzFeeVaultBumps::default(),
+        };
+        let mut params = FundLzFeeVaultParams { amount: 500 };
+
+        // not an admin
+        let result = fund_lz_fee_vault(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        fund_lz_fee_vault(&mut ctx, &params)?;
+
+        // more than the admin holds
+        params.amount = 1001;
+        let result = fund_lz_fee_vault(&mut ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::NotEnoughNativeBalance.into()
+        );
+
+        params.amount = 0;
+        let result = fund_lz_fee_vault(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ZeroXferAmount.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_withdraw_lz_fee_vault() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let lz_fee_vault_key = Pubkey::new_unique();
+        let mut lz_fee_vault_lamports = 1000 + NATIVE_VAULT_MIN_THRESHOLD;
+        let mut lz_fee_vault_data = vec![];
+        let lz_fee_vault_info = create_account_info(
+            &lz_fee_vault_key,
+            false,
+            true,
+            &mut lz_fee_vault_lamports,
+            &mut lz_fee_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config_info = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = WithdrawLzFeeVault {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            lz_fee_vault: lz_fee_vault_info,
+            multisig_config: multisig_config_info,
+            proposal: None,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: WithdrawLzFeeVaultBumps::default(),
+        };
+        let mut params = WithdrawLzFeeVaultParams { amount: 1000 };
+
+        // not an admin
+        let result = withdraw_lz_fee_vault(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        withdraw_lz_fee_vault(&mut ctx, &params)?;
+
+        // the vault has to stay rent exempt
+        params.amount = 1001;
+        let result = withdraw_lz_fee_vault(&mut ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::NotEnoughNativeBalance.into()
+        );
+
+        params.amount = 0;
+        let result = withdraw_lz_fee_vault(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ZeroXferAmount.into());
+
+        // a protected withdrawal needs an approved proposal
+        let mut protected_config_lamports = 100;
+        let mut protected_config_data = MultisigConfig {
+            threshold: 2,
+            protected_actions: vec![SensitiveAction::WithdrawLzFeeVault],
+        }
+        .try_to_vec()?;
+        let protected_config_info = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut protected_config_lamports,
+            &mut protected_config_data,
+            &program_id,
+            false,
+            Some(MultisigConfig::discriminator()),
+        );
+        ctx.accounts.multisig_config = protected_config_info;
+        params.amount = 500;
+        let result = withdraw_lz_fee_vault(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalRequired.into());
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{ADMIN_SEED, LZ_OPTIONS_SEED};
+use crate::cpi_utils::{ExecutorOption, OptionsBuilder};
+use crate::errors::DexalotError;
+use crate::events::LzOptionsUpdatedEvent;
+use crate::state::{LzMessageKind, LzOptions};
+use crate::xfer::Tx;
+
+#[derive(Accounts)]
+#[instruction(params: SetLzOptionsParams)]
+pub struct SetLzOptions<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = LzOptions::LEN,
+        seeds = [LZ_OPTIONS_SEED, &params.dst_eid.to_be_bytes(), &[Tx::from(params.kind) as u8]],
+        bump
+    )]
+    pub lz_options: Account<'info, LzOptions>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetLzOptionsParams {
+    pub dst_eid: u32,
+    pub kind: LzMessageKind,
+    pub options: Vec<ExecutorOption>,
+}
+
+/// Sets the executor options sent with the messages of a kind to a destination
+pub fn set_lz_options(ctx: &mut Context<SetLzOptions>, params: &SetLzOptionsParams) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let options = OptionsBuilder::from_executor_options(&params.options)?.build();
+    ctx.accounts.lz_options.options = options.clone();
+
+    emit!(LzOptionsUpdatedEvent {
+        dst_eid: params.dst_eid,
+        kind: Tx::from(params.kind) as u8,
+        options,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{GAS_OPTIONS, MAX_EXECUTOR_OPTIONS};
+    use crate::test_utils::create_account_info;
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_set_lz_options() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 100;
+        let mut lz_options_data = vec![0u8; LzOptions::LEN];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            true,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &program_id,
+            false,
+            Some(LzOptions::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = SetLzOptions {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            lz_options: Account::try_from(&lz_options_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetLzOptionsBumps::default(),
+        };
+        let mut params = SetLzOptionsParams {
+            dst_eid: 1,
+            kind: LzMessageKind::Deposit,
+            options: vec![ExecutorOption::LzReceive {
+                gas: 400_000,
+                value: 0,
+            }],
+        };
+
+        // not an admin
+        let result = set_lz_options(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        set_lz_options(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.lz_options.options, GAS_OPTIONS.to_vec());
+
+        // too many options
+        params.options = vec![
+            ExecutorOption::LzReceive {
+                gas: 400_000,
+                value: 0,
+            };
+            MAX_EXECUTOR_OPTIONS + 1
+        ];
+        let result = set_lz_options(&mut ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::TooManyExecutorOptions.into()
+        );
+        Ok(())
+    }
+}
@@ -1,17 +1,16 @@
-use std::str::FromStr;
-
 use crate::{
     consts::{
-        AIRDROP_VAULT_SEED, CLEAR_MIN_ACCOUNTS_LEN, ENDPOINT_CLEAR, ENDPOINT_ID,
-        NATIVE_VAULT_MIN_THRESHOLD, PENDING_SWAPS_SEED, PORTFOLIO_SEED, SOL_USER_FUNDS_VAULT_SEED,
-        SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED, TOKEN_LIST_PAGE_1_SEED,
-        TOKEN_LIST_SEED,
+        AIRDROP_VAULT_SEED, BANNED_ACCOUNT_SEED, CLEAR_MIN_ACCOUNTS_LEN, ENDPOINT_CLEAR,
+        FAILED_MESSAGE_SEED, NATIVE_VAULT_MIN_THRESHOLD, PENDING_SWAPS_SEED, PORTFOLIO_SEED,
+        SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED,
+        TOKEN_DETAILS_SEED, TOKEN_INVENTORY_SEED,
     },
     cpi_utils::{create_instruction_data, ClearParams},
     errors::DexalotError,
-    events::{SolTransfer, SolTransferTransactions},
-    state::{Portfolio, TokenList},
-    xfer::Tx,
+    events::{MessageFailedEvent, SolTransfer, SolTransferTransactions},
+    map_utils::create_entry,
+    state::{FailedMessage, GlobalConfig, Portfolio, TokenDetails},
+    xfer::{scale_quantity, Tx},
     *,
 };
 use anchor_lang::{
@@ -20,9 +19,10 @@ use anchor_lang::{
 };
 use anchor_spl::{
     associated_token::{
+        get_associated_token_address,
         spl_associated_token_account::instruction::create_associated_token_account, AssociatedToken,
     },
-    token::Token,
+    token::{Token, TokenAccount},
 };
 
 #[derive(Accounts, Clone)]
@@ -52,11 +52,6 @@ pub struct LzReceive<'info> {
     pub to: AccountInfo<'info>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    #[account(
-        seeds = [TOKEN_LIST_SEED, TOKEN_LIST_PAGE_1_SEED.as_ref()],
-        bump
-    )]
-    pub token_list: Account<'info, TokenList>,
     /// CHECK: the trader account
     #[account(mut)]
     pub trader: AccountInfo<'info>,
@@ -73,15 +68,34 @@ pub struct LzReceive<'info> {
             XFERSolana::unpack_xfer_message(&params.message)?.trader)?], bump
         )]
     pub swap_queue_entry: AccountInfo<'info>,
+    /// CHECK: created only when the payload fails validation
+    #[account(mut,
+        seeds = [FAILED_MESSAGE_SEED, &failed_message_key(params.src_eid, params.nonce)],
+        bump
+    )]
+    pub failed_message: AccountInfo<'info>,
+    /// CHECK: the token inventory pda
+    #[account(mut,
+        seeds = [TOKEN_INVENTORY_SEED, XFERSolana::unpack_xfer_message(&params.message)?.token_mint.as_ref()],
+        bump
+    )]
+    pub token_inventory: AccountInfo<'info>,
+    /// CHECK: the token details pda, not created for SOL
+    #[account(
+        seeds = [TOKEN_DETAILS_SEED, XFERSolana::unpack_xfer_message(&params.message)?.token_mint.as_ref()],
+        bump
+    )]
+    pub token_details: AccountInfo<'info>,
+    /// CHECK: the banned account pda of the trader, a banned trader's payout is stored instead of delivered
+    #[account(
+        seeds = [BANNED_ACCOUNT_SEED, XFERSolana::unpack_xfer_message(&params.message)?.trader.as_ref()],
+        bump
+    )]
+    pub banned_account: AccountInfo<'info>,
 }
 
 pub fn lz_receive(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Result<()> {
     let global_config = &ctx.accounts.portfolio.global_config;
-    let airdrop_vault = &ctx.accounts.airdrop_vault;
-    let system_program = &ctx.accounts.system_program;
-    let trader = &ctx.accounts.trader;
-    let swap_queue_entry = &ctx.accounts.swap_queue_entry;
-    let native_vault = &ctx.accounts.native_vault;
 
     // check if program is paused
     require!(
@@ -99,6 +113,13 @@ pub fn lz_receive(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Res
         })
         .collect();
 
+    // the first clear account is the endpoint program
+    require_keys_eq!(
+        ctx.remaining_accounts[0].key(),
+        global_config.endpoint_program,
+        DexalotError::InvalidEndpointProgram
+    );
+
     let seeds: &[&[&[u8]]] = &[&[PORTFOLIO_SEED, &[ctx.accounts.portfolio.bump]]];
 
     let clear_params = ClearParams {
@@ -116,7 +137,7 @@ pub fn lz_receive(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Res
     if cfg!(not(test)) {
         invoke_signed(
             &Instruction {
-                program_id: Pubkey::from_str(ENDPOINT_ID).unwrap(), // we provide the correct publickey
+                program_id: global_config.endpoint_program,
                 accounts: accounts_metas,
                 data: cpi_data,
             },
@@ -125,24 +146,225 @@ pub fn lz_receive(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Res
         )?;
     }
     // Decode xfer
-    let xfer = XFERSolana::unpack_xfer_message(params.message.as_slice())?;
+    let mut xfer = XFERSolana::unpack_xfer_message(params.message.as_slice())?;
+    let xfer_accounts = XferAccounts::from_lz_receive_context(ctx);
+
+    // The message is already cleared, so a payload that can't be delivered is stored
+    // for a later retry instead of reverting and blocking the channel.
+    // Payout failures are caught up front, a failed transfer would revert the whole delivery
+    // A banned trader's payout stays escrowed in the vault the same way until they're unbanned
+    if let Err(error) = validate_xfer(
+        &xfer,
+        &ctx.accounts.token_details,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.to,
+        &ctx.accounts.banned_account,
+    )
+    .and_then(|_| scale_xfer_quantity(&mut xfer, &ctx.accounts.token_details))
+    .and_then(|_| check_xfer_payout(&xfer_accounts, global_config, &xfer))
+    {
+        store_failed_message(
+            &ctx.accounts.airdrop_vault,
+            ctx.bumps.airdrop_vault,
+            &ctx.accounts.failed_message,
+            &ctx.accounts.system_program,
+            params,
+        )?;
+
+        emit!(MessageFailedEvent {
+            src_eid: params.src_eid,
+            nonce: params.nonce,
+            guid: params.guid,
+            reason: error.to_string(),
+        });
+        return Ok(());
+    }
 
-    // check if token is supported
-    let token_list = &ctx.accounts.token_list;
+    execute_xfer(&xfer_accounts, global_config, &xfer)
+}
 
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct LzReceiveParams {
+    pub src_eid: u32,
+    pub sender: [u8; 32],
+    pub nonce: u64,
+    pub guid: [u8; 32],
+    pub message: Vec<u8>,
+    pub extra_data: Vec<u8>,
+}
+
+/// Generates the failed message map key from the LayerZero source eid and nonce
+pub fn failed_message_key(src_eid: u32, nonce: u64) -> [u8; 12] {
+    let mut key = [0u8; 12];
+    key[0..4].copy_from_slice(&src_eid.to_be_bytes());
+    key[4..12].copy_from_slice(&nonce.to_be_bytes());
+    key
+}
+
+/// Checks everything that can make an inbound XFER undeliverable
+///
+/// # Errors
+/// Returns error if:
+/// - Token is not supported, i.e. its token details PDA doesn't exist
+/// - Quantity is zero
+/// - Trader is the zero account
+/// - Token mint or trader ATA accounts don't match the payload
+/// - Trader is banned
+pub fn validate_xfer(
+    xfer: &XFERSolana,
+    token_details: &AccountInfo,
+    token_mint: &AccountInfo,
+    to: &AccountInfo,
+    banned_account: &AccountInfo,
+) -> Result<()> {
     let is_native_withdraw = xfer.token_mint == Pubkey::default();
 
-    // Check if token is supported
+    // Check if token is supported, whichever token list page holds it
     if !is_native_withdraw {
-        require!(
-            token_list.tokens.contains(&xfer.token_mint),
+        require_keys_eq!(
+            *token_details.owner,
+            crate::ID,
             DexalotError::TokenNotSupported
         );
     }
 
+    // xfer checks
+    require!(
+        xfer.quantity > 0u128,
+        DexalotError::ZeroTokenQuantity
+    );
+    require!(
+        xfer.trader != Pubkey::default(),
+        DexalotError::InvalidTrader
+    );
+
+    // the ATA creation would revert on wrong accounts
+    if !is_native_withdraw {
+        require_keys_eq!(
+            token_mint.key(),
+            xfer.token_mint,
+            DexalotError::InvalidMint
+        );
+        require_keys_eq!(
+            to.key(),
+            get_associated_token_address(&xfer.trader, &xfer.token_mint),
+            DexalotError::InvalidDestinationOwner
+        );
+    }
+    check_not_banned(banned_account, &crate::ID)?;
+    Ok(())
+}
+
+/// Converts the quantity of a withdrawal from the token's L1 decimals to its mint decimals
+/// SOL and CCTrade quantities are already in Solana units
+/// Fails for a paused token, so the withdrawal is stored for a retry
+pub fn scale_xfer_quantity(xfer: &mut XFERSolana, token_details: &AccountInfo) -> Result<()> {
+    if xfer.transaction != Tx::Withdraw || xfer.token_mint == Pubkey::default() {
+        return Ok(());
+    }
+    require_keys_eq!(
+        *token_details.owner,
+        crate::ID,
+        DexalotError::TokenNotSupported
+    );
+    let token_details = TokenDetails::try_deserialize(&mut &token_details.try_borrow_data()?[..])?;
+    require!(!token_details.paused, DexalotError::TokenPaused);
+    xfer.quantity = scale_quantity(
+        xfer.quantity,
+        token_details.l1_decimals,
+        token_details.decimals,
+    )?;
+    // the scaled quantity is paid out as a token amount
+    xfer.amount()?;
+    Ok(())
+}
+
+/// Stores an undeliverable message in its failed message PDA
+/// The rent is paid by the airdrop vault
+pub fn store_failed_message<'info>(
+    airdrop_vault: &AccountInfo<'info>,
+    airdrop_vault_bump: u8,
+    failed_message: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    params: &LzReceiveParams,
+) -> Result<()> {
+    let entry_data = FailedMessage {
+        src_eid: params.src_eid,
+        sender: params.sender,
+        nonce: params.nonce,
+        guid: params.guid,
+        message: params.message.clone(),
+    };
+    let airdrop_vault_signer_seeds: &[&[u8]] = &[AIRDROP_VAULT_SEED, &[airdrop_vault_bump]];
+
+    create_entry::<FailedMessage>(
+        airdrop_vault,
+        failed_message,
+        &entry_data,
+        FailedMessage::LEN,
+        FAILED_MESSAGE_SEED,
+        &failed_message_key(params.src_eid, params.nonce),
+        &crate::ID,
+        system_program,
+        Some(airdrop_vault_signer_seeds),
+    )
+}
+
+/// Accounts needed to deliver an inbound XFER to the trader
+pub struct XferAccounts<'info> {
+    pub token_vault: AccountInfo<'info>,
+    pub token_vault_bump: u8,
+    pub native_vault: AccountInfo<'info>,
+    pub native_vault_bump: u8,
+    pub from: AccountInfo<'info>,
+    pub to: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub trader: AccountInfo<'info>,
+    pub airdrop_vault: AccountInfo<'info>,
+    pub airdrop_vault_bump: u8,
+    pub system_program: Program<'info, System>,
+    pub token_mint: AccountInfo<'info>,
+    pub swap_queue_entry: AccountInfo<'info>,
+    pub token_inventory: AccountInfo<'info>,
+}
+
+impl<'info> XferAccounts<'info> {
+    pub fn from_lz_receive_context(ctx: &Context<LzReceive<'info>>) -> Self {
+        Self {
+            token_vault: ctx.accounts.token_vault.clone(),
+            token_vault_bump: ctx.bumps.token_vault,
+            native_vault: ctx.accounts.native_vault.clone(),
+            native_vault_bump: ctx.bumps.native_vault,
+            from: ctx.accounts.from.clone(),
+            to: ctx.accounts.to.clone(),
+            token_program: ctx.accounts.token_program.clone(),
+            associated_token_program: ctx.accounts.associated_token_program.clone(),
+            trader: ctx.accounts.trader.clone(),
+            airdrop_vault: ctx.accounts.airdrop_vault.to_account_info(),
+            airdrop_vault_bump: ctx.bumps.airdrop_vault,
+            system_program: ctx.accounts.system_program.clone(),
+            token_mint: ctx.accounts.token_mint.clone(),
+            swap_queue_entry: ctx.accounts.swap_queue_entry.clone(),
+            token_inventory: ctx.accounts.token_inventory.clone(),
+        }
+    }
+}
+
+/// Airdrops SOL if requested and releases the XFER quantity to the trader
+/// Must be called only after `validate_xfer`
+pub fn execute_xfer<'info>(
+    accounts: &XferAccounts<'info>,
+    global_config: &GlobalConfig,
+    xfer: &XFERSolana,
+) -> Result<()> {
+    let airdrop_vault = &accounts.airdrop_vault;
+    let system_program = &accounts.system_program;
+    let trader = &accounts.trader;
+
     // Start airdrop
     let should_airdrop = (xfer.custom_data[0] & 0x80) != 0; // check if the most significant bit is 1
-    let airdrop_vault_seeds: &[&[&[u8]]] = &[&[AIRDROP_VAULT_SEED, &[ctx.bumps.airdrop_vault]]];
+    let airdrop_vault_seeds: &[&[&[u8]]] = &[&[AIRDROP_VAULT_SEED, &[accounts.airdrop_vault_bump]]];
 
     if should_airdrop {
         require!(
@@ -175,47 +397,39 @@ pub fn lz_receive(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Res
         });
     }
 
-    // xfer checks
-    require!(
-        xfer.quantity > 0u64,
-        DexalotError::ZeroTokenQuantity
-    );
-    require!(
-        xfer.trader != Pubkey::default(),
-        DexalotError::InvalidTrader
-    );
-
-    if is_native_withdraw {
+    let swap_queue_entry = &accounts.swap_queue_entry;
+    let token_inventory = &accounts.token_inventory;
+    if xfer.token_mint == Pubkey::default() {
         // Start native withdraw
-        process_xfer_payload_native(
-            &xfer,
-            ctx.bumps.native_vault,
-            native_vault,
-            trader,
+        let native_vault_seed = if xfer.transaction == Tx::CCTrade {
+            SOL_VAULT_SEED
+        } else {
+            SOL_USER_FUNDS_VAULT_SEED
+        };
+        let payout = XferPayout {
+            vault: &accounts.native_vault,
+            vault_seed: native_vault_seed,
+            vault_bump: accounts.native_vault_bump,
+            from: &accounts.native_vault,
+            to: trader,
             swap_queue_entry,
-            system_program,
             airdrop_vault,
-            ctx.bumps.airdrop_vault,
-            false,
-        )?;
+            airdrop_vault_bump: accounts.airdrop_vault_bump,
+            system_program,
+            token_inventory,
+        };
+        process_xfer_payload_native(xfer, &payout, false)?;
     } else {
-        let from = &ctx.accounts.from;
-        let to = &ctx.accounts.to;
-        let token_program = &ctx.accounts.token_program;
-        let token_vault = &ctx.accounts.token_vault;
-
-        let trader = &ctx.accounts.trader;
-        let token_mint = &ctx.accounts.token_mint;
-        let associated_token_program = &ctx.accounts.associated_token_program;
+        let token_program = &accounts.token_program;
 
         // Create ATA if needed
         let create_ata_accounts = CreateATA::new(
-            to.clone(),
-            airdrop_vault.to_account_info(),
+            accounts.to.clone(),
+            airdrop_vault.clone(),
             trader.clone(),
-            token_mint.clone(),
+            accounts.token_mint.clone(),
             token_program.to_account_info(),
-            associated_token_program.to_account_info(),
+            accounts.associated_token_program.to_account_info(),
             system_program.to_account_info(),
         );
         create_ata_if_needed(create_ata_accounts, airdrop_vault_seeds)?;
@@ -225,34 +439,65 @@ pub fn lz_receive(ctx: &mut Context<LzReceive>, params: &LzReceiveParams) -> Res
         } else {
             SPL_USER_FUNDS_VAULT_SEED
         };
-
-        process_xfer_payload_spl(
-            &xfer,
-            ctx.bumps.token_vault,
-            token_vault_seeds,
-            token_vault,
-            ctx.bumps.airdrop_vault,
-            &airdrop_vault,
-            from,
-            to,
-            token_program,
+        let payout = XferPayout {
+            vault: &accounts.token_vault,
+            vault_seed: token_vault_seeds,
+            vault_bump: accounts.token_vault_bump,
+            from: &accounts.from,
+            to: &accounts.to,
             swap_queue_entry,
+            airdrop_vault,
+            airdrop_vault_bump: accounts.airdrop_vault_bump,
             system_program,
-            false,
-        )?;
+            token_inventory,
+        };
+        process_xfer_payload_spl(xfer, &payout, token_program, false)?;
     }
 
     Ok(())
 }
 
-#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
-pub struct LzReceiveParams {
-    pub src_eid: u32,
-    pub sender: [u8; 32],
-    pub nonce: u64,
-    pub guid: [u8; 32],
-    pub message: Vec<u8>,
-    pub extra_data: Vec<u8>,
+/// Checks that the payout of a validated XFER can't fail once `execute_xfer` starts transferring
+/// A vault too short for a CCTrade is not an error, the payout is queued instead
+///
+/// # Errors
+/// Returns error if:
+/// - Airdrop vault can't pay the requested airdrop
+/// - SPL vault can't pay a withdrawal
+/// - Paying vault's inventory doesn't cover the quantity
+pub fn check_xfer_payout(
+    accounts: &XferAccounts,
+    global_config: &GlobalConfig,
+    xfer: &XFERSolana,
+) -> Result<()> {
+    let should_airdrop = (xfer.custom_data[0] & 0x80) != 0;
+    if should_airdrop {
+        require!(
+            accounts.airdrop_vault.lamports() >= global_config.airdrop_amount + NATIVE_VAULT_MIN_THRESHOLD,
+            DexalotError::NotEnoughNativeBalance
+        );
+    }
+
+    let is_native_withdraw = xfer.token_mint == Pubkey::default();
+    let quantity = xfer.amount()?;
+    let vault_covers_quantity = if is_native_withdraw {
+        accounts.native_vault.lamports() >= quantity + NATIVE_VAULT_MIN_THRESHOLD
+    } else {
+        let from_ata = TokenAccount::try_deserialize(&mut &accounts.from.try_borrow_data()?[..])?;
+        from_ata.amount >= quantity
+    };
+    if !vault_covers_quantity {
+        // short native vaults queue every payout, short SPL vaults only queue CCTrades
+        require!(
+            is_native_withdraw || xfer.transaction == Tx::CCTrade,
+            DexalotError::NotEnoughSplTokenBalance
+        );
+        return Ok(());
+    }
+
+    check_inventory_update(&accounts.token_inventory, |inventory| {
+        release_xfer(inventory, xfer)
+    })
 }
 
 fn create_ata_if_needed<'info>(
@@ -328,8 +573,11 @@ mod tests {
     use anchor_spl::token::{spl_token, Token};
     use anchor_spl::associated_token::AssociatedToken;
     use anchor_spl::token::spl_token::state::AccountState;
-    use crate::state::{GlobalConfig, Portfolio, TokenList};
-    use crate::test_utils::{create_account_info, create_dummy_account};
+    use crate::state::{GlobalConfig, Portfolio, TokenInventory};
+    use crate::test_utils::{
+        create_account_info, create_dummy_account, create_funded_token_inventory_data,
+        create_not_banned_account, create_portfolio_data, create_token_inventory_data,
+    };
     use crate::xfer::XChainMsgType;
 
     #[test]
@@ -339,20 +587,11 @@ mod tests {
 
         let gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
             src_chain_id: 1,
             default_chain_id: 1,
-            airdrop_amount: 0,
-            swap_signer: [0; 20],
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Pubkey::new_unique(),
-            global_config: gc,
-            bump: 1,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &generic_key,
@@ -366,23 +605,9 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
 
-        let token_list = TokenList { next_page: None, tokens: vec![generic_key] };
-        let mut token_list_data = token_list.try_to_vec()?;
-        let mut token_list_lamports = 100;
-        let token_list_info = create_account_info(
-            &generic_key,
-            false,
-            true,
-            &mut token_list_lamports,
-            &mut token_list_data,
-            &program_id,
-            false,
-            Some(TokenList::discriminator()),
-        );
-        let token_list_account = Account::<TokenList>::try_from(&token_list_info)?;
-
         let mut default_token_account = spl_token::state::Account::default();
         default_token_account.state = AccountState::Initialized;
+        default_token_account.amount = 1;
         let mut default_token_data = vec![0u8; spl_token::state::Account::LEN];
         let mut default_token_lamports = 100;
         spl_token::state::Account::pack_into_slice(&default_token_account, &mut default_token_data);
@@ -397,10 +622,11 @@ mod tests {
             None
         );
 
+        let ata_key = get_associated_token_address(&generic_key, &generic_key);
         let mut to_data = vec![];
         let mut to_lamports = 100;
         let to_info = create_account_info(
-            &generic_key,
+            &ata_key,
             false,
             false,
             &mut to_lamports,
@@ -515,6 +741,22 @@ mod tests {
             .map(|_| create_dummy_account(&program_id_static))
             .collect();
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut lz_receive_accounts = LzReceive {
             portfolio: portfolio_account,
             token_vault: generic_info.clone(),
@@ -523,14 +765,19 @@ mod tests {
             to: to_info,
             token_program,
             associated_token_program,
-            token_list: token_list_account,
             trader: generic_info.clone(),
             airdrop_vault,
             system_program,
             token_mint: generic_info.clone(),
-            swap_queue_entry: generic_info,
+            swap_queue_entry: generic_info.clone(),
+            failed_message: generic_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            token_details: generic_info.clone(),
+            banned_account: banned_info.clone(),
         };
 
+        lz_receive_accounts.portfolio.global_config.endpoint_program = remaining_accounts[0].key();
+
         let mut ctx = Context {
             accounts: &mut lz_receive_accounts,
             remaining_accounts: remaining_accounts.as_slice(),
@@ -550,20 +797,11 @@ mod tests {
 
         let gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
             src_chain_id: 1,
             default_chain_id: 1,
-            airdrop_amount: 0,
-            swap_signer: [0; 20],
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Pubkey::new_unique(),
-            global_config: gc,
-            bump: 1,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &generic_key,
@@ -577,21 +815,6 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
 
-        let token_list = TokenList { next_page: None, tokens: vec![generic_key] };
-        let mut token_list_data = token_list.try_to_vec()?;
-        let mut token_list_lamports = 100;
-        let token_list_info = create_account_info(
-            &generic_key,
-            false,
-            true,
-            &mut token_list_lamports,
-            &mut token_list_data,
-            &program_id,
-            false,
-            Some(TokenList::discriminator()),
-        );
-        let token_list_account = Account::<TokenList>::try_from(&token_list_info)?;
-
         let mut default_token_account = spl_token::state::Account::default();
         default_token_account.state = AccountState::Initialized;
         default_token_account.amount = 2;
@@ -621,6 +844,19 @@ mod tests {
             false,
             None,
         );
+        let ata_key = get_associated_token_address(&generic_key, &generic_key);
+        let mut to_data = vec![0u8; 100];
+        let mut to_lamports = 100;
+        let to_info = create_account_info(
+            &ata_key,
+            false,
+            false,
+            &mut to_lamports,
+            &mut to_data,
+            &program_id,
+            false,
+            None,
+        );
         let mut native_vault_data = vec![0u8; 100];
         let mut native_vault_lamports = 500;
         let native_vault_info = create_account_info(
@@ -714,22 +950,43 @@ mod tests {
             .map(|_| create_dummy_account(&program_id_static))
             .collect();
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut lz_receive_accounts = LzReceive {
             portfolio: portfolio_account,
             token_vault: generic_info.clone(),
             native_vault: native_vault_info,
             from: spl_token_info,
-            to: generic_info.clone(),
+            to: to_info,
             token_program,
             associated_token_program,
-            token_list: token_list_account,
             trader: generic_info.clone(),
             airdrop_vault,
             system_program,
             token_mint: generic_info.clone(),
-            swap_queue_entry: generic_info,
+            swap_queue_entry: generic_info.clone(),
+            failed_message: generic_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            token_details: generic_info.clone(),
+            banned_account: banned_info.clone(),
         };
 
+        lz_receive_accounts.portfolio.global_config.endpoint_program = remaining_accounts[0].key();
+
         let mut ctx = Context {
             accounts: &mut lz_receive_accounts,
             remaining_accounts: remaining_accounts.as_slice(),
@@ -749,20 +1006,11 @@ mod tests {
 
         let gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
             src_chain_id: 1,
             default_chain_id: 1,
-            airdrop_amount: 0,
-            swap_signer: [0; 20],
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Pubkey::new_unique(),
-            global_config: gc,
-            bump: 1,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &generic_key,
@@ -776,23 +1024,9 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
 
-        let token_list = TokenList { next_page: None, tokens: vec![generic_key] };
-        let mut token_list_data = token_list.try_to_vec()?;
-        let mut token_list_lamports = 100;
-        let token_list_info = create_account_info(
-            &generic_key,
-            false,
-            true,
-            &mut token_list_lamports,
-            &mut token_list_data,
-            &program_id,
-            false,
-            Some(TokenList::discriminator()),
-        );
-        let token_list_account = Account::<TokenList>::try_from(&token_list_info)?;
-
         let mut default_token_account = spl_token::state::Account::default();
         default_token_account.state = AccountState::Initialized;
+        default_token_account.amount = 1;
         let mut default_token_data = vec![0u8; spl_token::state::Account::LEN];
         let mut default_token_lamports = 100;
         spl_token::state::Account::pack_into_slice(&default_token_account, &mut default_token_data);
@@ -925,6 +1159,22 @@ mod tests {
             .map(|_| create_dummy_account(&program_id_static))
             .collect();
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut lz_receive_accounts = LzReceive {
             portfolio: portfolio_account,
             token_vault: generic_info.clone(),
@@ -933,14 +1183,19 @@ mod tests {
             to: to_info,
             token_program,
             associated_token_program,
-            token_list: token_list_account,
             trader: generic_info.clone(),
             airdrop_vault,
             system_program,
             token_mint: generic_info.clone(),
-            swap_queue_entry: generic_info,
+            swap_queue_entry: generic_info.clone(),
+            failed_message: generic_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            token_details: generic_info.clone(),
+            banned_account: banned_info.clone(),
         };
 
+        lz_receive_accounts.portfolio.global_config.endpoint_program = remaining_accounts[0].key();
+
         let mut ctx = Context {
             accounts: &mut lz_receive_accounts,
             remaining_accounts: remaining_accounts.as_slice(),
@@ -960,20 +1215,11 @@ mod tests {
 
         let gc = GlobalConfig {
             allow_deposit: true,
-            program_paused: false,
-            native_deposits_restricted: false,
             src_chain_id: 1,
             default_chain_id: 1,
-            airdrop_amount: 0,
-            swap_signer: [0; 20],
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Pubkey::new_unique(),
-            global_config: gc,
-            bump: 1,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &generic_key,
@@ -987,21 +1233,6 @@ mod tests {
         );
         let portfolio_account = Account::<Portfolio>::try_from(&portfolio_info)?;
 
-        let token_list = TokenList { next_page: None, tokens: vec![generic_key] };
-        let mut token_list_data = token_list.try_to_vec()?;
-        let mut token_list_lamports = 100;
-        let token_list_info = create_account_info(
-            &generic_key,
-            false,
-            true,
-            &mut token_list_lamports,
-            &mut token_list_data,
-            &program_id,
-            false,
-            Some(TokenList::discriminator()),
-        );
-        let token_list_account = Account::<TokenList>::try_from(&token_list_info)?;
-
         let mut default_token_account = spl_token::state::Account::default();
         default_token_account.state = AccountState::Initialized;
         default_token_account.amount = 2;
@@ -1124,6 +1355,22 @@ mod tests {
             .map(|_| create_dummy_account(&program_id_static))
             .collect();
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut lz_receive_accounts = LzReceive {
             portfolio: portfolio_account,
             token_vault: generic_info.clone(),
@@ -1132,14 +1379,35 @@ mod tests {
             to: generic_info.clone(),
             token_program,
             associated_token_program,
-            token_list: token_list_account,
             trader: generic_info.clone(),
             airdrop_vault,
             system_program,
             token_mint: generic_info.clone(),
-            swap_queue_entry: generic_info,
+            swap_queue_entry: generic_info.clone(),
+            failed_message: generic_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            token_details: generic_info.clone(),
+            banned_account: banned_info.clone(),
         };
 
+        let failed_message_key =
+            Pubkey::find_program_address(&[FAILED_MESSAGE_SEED, &failed_message_key(1, 1)], &program_id).0;
+        let mut failed_message_data = vec![];
+        let mut failed_message_lamports = 0;
+        let failed_message_info = create_account_info(
+            &failed_message_key,
+            false,
+            true,
+            &mut failed_message_lamports,
+            &mut failed_message_data,
+            &program_id,
+            false,
+            None,
+        );
+        lz_receive_accounts.failed_message = failed_message_info;
+
+        lz_receive_accounts.portfolio.global_config.endpoint_program = remaining_accounts[0].key();
+
         let mut ctx = Context {
             accounts: &mut lz_receive_accounts.clone(),
             remaining_accounts: remaining_accounts.as_slice(),
@@ -1147,15 +1415,55 @@ mod tests {
             bumps: LzReceiveBumps::default(),
         };
 
-        let result = lz_receive(&mut ctx, &params);
+        // invalid payloads are stored instead of reverting
+        let result = validate_xfer(&xfer, &ctx.accounts.token_details, &ctx.accounts.token_mint, &ctx.accounts.to, &ctx.accounts.banned_account);
         assert_eq!(result.unwrap_err(), DexalotError::InvalidTrader.into());
+        let result = lz_receive(&mut ctx, &params);
+        assert!(result.is_ok());
 
         xfer.quantity = 0;
-        message = xfer._pack_xfer_message()?;
-        params.message = message;
+        let result = validate_xfer(&xfer, &ctx.accounts.token_details, &ctx.accounts.token_mint, &ctx.accounts.to, &ctx.accounts.banned_account);
+        assert_eq!(result.unwrap_err(), DexalotError::ZeroTokenQuantity.into());
+
+        xfer.quantity = 1;
+        xfer.trader = generic_key;
+        let result = validate_xfer(&xfer, &ctx.accounts.token_details, &ctx.accounts.token_mint, &ctx.accounts.to, &ctx.accounts.banned_account);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidDestinationOwner.into());
 
+        // clear accounts must start with the configured endpoint
+        let mut wrong_endpoint_accounts = lz_receive_accounts.clone();
+        wrong_endpoint_accounts.portfolio.global_config.endpoint_program = Pubkey::new_unique();
+        let mut wrong_endpoint_ctx = Context {
+            accounts: &mut wrong_endpoint_accounts,
+            remaining_accounts: remaining_accounts.as_slice(),
+            program_id: &program_id,
+            bumps: LzReceiveBumps::default(),
+        };
+        let result = lz_receive(&mut wrong_endpoint_ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidEndpointProgram.into());
+
+        // failed message PDA must match the source eid and nonce
+        params.nonce = 2;
         let result = lz_receive(&mut ctx, &params);
-        assert_eq!(result.unwrap_err(), DexalotError::ZeroTokenQuantity.into());
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidPDA.into());
+        params.nonce = 1;
+
+        let ata_key = get_associated_token_address(&generic_key, &generic_key);
+        let mut to_data = vec![0u8; 100];
+        let mut to_lamports = 100;
+        let to_info = create_account_info(
+            &ata_key,
+            false,
+            false,
+            &mut to_lamports,
+            &mut to_data,
+            &program_id,
+            false,
+            None,
+        );
+        lz_receive_accounts.to = to_info;
+        message = xfer._pack_xfer_message()?;
+        params.message = message;
 
         let mut airdrop_vault_lamports = 1000;
         let mut airdrop_vault_data = vec![0u8; 100];
@@ -1171,49 +1479,96 @@ mod tests {
         );
         airdrop_vault = SystemAccount::try_from(&airdrop_vault_info)?;
         lz_receive_accounts.airdrop_vault = airdrop_vault;
+        // a banned trader's payout is stored instead of delivered
+        let mut banned_trader_accounts = lz_receive_accounts.clone();
+        banned_trader_accounts.banned_account = generic_info.clone();
+        ctx.accounts = &mut banned_trader_accounts;
+        let result = validate_xfer(&xfer, &ctx.accounts.token_details, &ctx.accounts.token_mint, &ctx.accounts.to, &ctx.accounts.banned_account);
+        assert_eq!(result.unwrap_err(), DexalotError::AccountBanned.into());
+        let result = lz_receive(&mut ctx, &params);
+        assert!(result.is_ok());
+
+        // payouts that would fail are stored, a failed transfer would revert the delivery
         let mut lz_receive_accounts2 = lz_receive_accounts.clone();
         ctx.accounts = &mut lz_receive_accounts2;
+        let xfer_accounts = XferAccounts::from_lz_receive_context(&ctx);
+        let result = check_xfer_payout(&xfer_accounts, &ctx.accounts.portfolio.global_config, &xfer);
+        assert_eq!(result.unwrap_err(), DexalotError::NotEnoughNativeBalance.into());
+        let result = lz_receive(&mut ctx, &params);
+        assert!(result.is_ok());
 
+        // no airdrop, the user funds vault holds 2
+        xfer.custom_data = [0u8; 18];
+        xfer.transaction = Tx::IxferRec;
+        xfer.quantity = 3;
+        params.message = xfer._pack_xfer_message()?;
+        let xfer_accounts = XferAccounts::from_lz_receive_context(&ctx);
+        let result = check_xfer_payout(&xfer_accounts, &ctx.accounts.portfolio.global_config, &xfer);
+        assert_eq!(result.unwrap_err(), DexalotError::NotEnoughSplTokenBalance.into());
         let result = lz_receive(&mut ctx, &params);
-        assert_eq!(result.unwrap_err(), DexalotError::NotEnoughNativeBalance.into());
+        assert!(result.is_ok());
 
-        let token_list = TokenList { next_page: None, tokens: vec![] };
-        let mut token_list_data = token_list.try_to_vec()?;
-        let mut token_list_lamports = 100;
-        let token_list_info = create_account_info(
-            &generic_key,
+        // a short vault queues a CCTrade instead
+        xfer.transaction = Tx::CCTrade;
+        let xfer_accounts = XferAccounts::from_lz_receive_context(&ctx);
+        check_xfer_payout(&xfer_accounts, &ctx.accounts.portfolio.global_config, &xfer)?;
+
+        // the inventory doesn't cover the payout
+        let mut short_inventory_lamports = 100;
+        let mut short_inventory_data = create_token_inventory_data(&TokenInventory {
+            user_funds: 1,
+            ..Default::default()
+        });
+        let short_inventory_info = create_account_info(
+            &token_inventory_key,
             false,
             true,
-            &mut token_list_lamports,
-            &mut token_list_data,
+            &mut short_inventory_lamports,
+            &mut short_inventory_data,
             &program_id,
             false,
-            Some(TokenList::discriminator()),
+            None,
         );
-        let token_list_account = Account::<TokenList>::try_from(&token_list_info)?;
-        lz_receive_accounts.token_list = token_list_account;
+        ctx.accounts.token_inventory = short_inventory_info;
+        xfer.transaction = Tx::IxferRec;
+        xfer.quantity = 2;
+        params.message = xfer._pack_xfer_message()?;
+        let xfer_accounts = XferAccounts::from_lz_receive_context(&ctx);
+        let result = check_xfer_payout(&xfer_accounts, &ctx.accounts.portfolio.global_config, &xfer);
+        assert_eq!(result.unwrap_err(), DexalotError::InsufficientInventory.into());
+        let result = lz_receive(&mut ctx, &params);
+        assert!(result.is_ok());
+        xfer.quantity = 1;
+        params.message = xfer._pack_xfer_message()?;
+
+        // the token details PDA of the token is not created
+        let mut missing_token_details_data = vec![];
+        let mut missing_token_details_lamports = 0;
+        let missing_token_details_info = create_account_info(
+            &generic_key,
+            false,
+            false,
+            &mut missing_token_details_lamports,
+            &mut missing_token_details_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        lz_receive_accounts.token_details = missing_token_details_info;
         let mut lz_receive_accounts3 = lz_receive_accounts.clone();
         ctx.accounts = &mut lz_receive_accounts3;
 
-        let result = lz_receive(&mut ctx, &params);
+        let result = validate_xfer(&xfer, &ctx.accounts.token_details, &ctx.accounts.token_mint, &ctx.accounts.to, &ctx.accounts.banned_account);
         assert_eq!(result.unwrap_err(), DexalotError::TokenNotSupported.into());
 
         let gc = GlobalConfig {
             allow_deposit: true,
             program_paused: true,
-            native_deposits_restricted: false,
             src_chain_id: 1,
             default_chain_id: 1,
-            airdrop_amount: 0,
-            swap_signer: [0; 20],
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Pubkey::new_unique(),
-            global_config: gc,
-            bump: 1,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_info = create_account_info(
             &generic_key,
@@ -1235,4 +1590,101 @@ mod tests {
         assert_eq!(result.unwrap_err(), DexalotError::ProgramPaused.into());
         Ok(())
     }
+
+    #[test]
+    fn test_scale_xfer_quantity() -> Result<()> {
+        let program_id = id();
+        let token_mint = Pubkey::new_unique();
+        let trader = Pubkey::new_unique();
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = TokenDetails {
+            decimals: 6,
+            l1_decimals: 18,
+            token_address: token_mint,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+
+        // withdrawals are converted from the L1 decimals
+        let mut xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, 2_000_000_000_000, 0, [0u8; 18], 1);
+        scale_xfer_quantity(&mut xfer, &token_details_info)?;
+        assert_eq!(xfer.quantity, 2);
+
+        // withdrawals beyond a u64 in L1 decimals are scaled down
+        let mut xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, 100_000_000_000_000_000_000, 0, [0u8; 18], 1);
+        scale_xfer_quantity(&mut xfer, &token_details_info)?;
+        assert_eq!(xfer.quantity, 100_000_000);
+
+        // the scaled quantity must fit a token amount
+        let mut xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, u128::from(u64::MAX) * 1_000_000_000_000_000, 0, [0u8; 18], 1);
+        let result = scale_xfer_quantity(&mut xfer, &token_details_info);
+        assert_eq!(result.unwrap_err(), DexalotError::UnrepresentableQuantity.into());
+
+        // dust below the mint decimals is rejected
+        let mut xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, 2_000_000_000_001, 0, [0u8; 18], 1);
+        let result = scale_xfer_quantity(&mut xfer, &token_details_info);
+        assert_eq!(result.unwrap_err(), DexalotError::UnrepresentableQuantity.into());
+
+        // cross chain trades and SOL are left untouched
+        let mut xfer = XFERSolana::new(Tx::CCTrade, trader, token_mint, 5, 0, [0u8; 18], 1);
+        scale_xfer_quantity(&mut xfer, &token_details_info)?;
+        assert_eq!(xfer.quantity, 5);
+        let mut xfer = XFERSolana::new(Tx::Withdraw, trader, Pubkey::default(), 5, 0, [0u8; 18], 1);
+        scale_xfer_quantity(&mut xfer, &token_details_info)?;
+        assert_eq!(xfer.quantity, 5);
+
+        // the token must have been added
+        let mut missing_lamports = 0;
+        let mut missing_data = vec![];
+        let missing_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut missing_lamports,
+            &mut missing_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let mut xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, 5, 0, [0u8; 18], 1);
+        let result = scale_xfer_quantity(&mut xfer, &missing_info);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenNotSupported.into());
+
+        // withdrawals of a paused token are held back
+        let mut paused_lamports = 100;
+        let mut paused_data = TokenDetails {
+            decimals: 6,
+            l1_decimals: 18,
+            token_address: token_mint,
+            paused: true,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let paused_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut paused_lamports,
+            &mut paused_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+        let mut xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, 2_000_000_000_000, 0, [0u8; 18], 1);
+        let result = scale_xfer_quantity(&mut xfer, &paused_info);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenPaused.into());
+        Ok(())
+    }
 }
@@ -2,11 +2,12 @@ use crate::errors::DexalotError;
 use crate::xfer::Tx;
 use crate::{
     consts::{
-        AIRDROP_VAULT_SEED, ENDPOINT_ID, PENDING_SWAPS_SEED, PORTFOLIO_SEED,
-        SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED,
-        TOKEN_LIST_PAGE_1_SEED, TOKEN_LIST_SEED,
+        AIRDROP_VAULT_SEED, BANNED_ACCOUNT_SEED, FAILED_MESSAGE_SEED,
+        PENDING_SWAPS_SEED, PORTFOLIO_SEED, SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED,
+        SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED, TOKEN_DETAILS_SEED, TOKEN_INVENTORY_SEED,
     },
     cpi_utils::get_accounts_for_clear,
+    state::Portfolio,
     *,
 };
 use anchor_lang::solana_program::system_program;
@@ -14,15 +15,16 @@ use anchor_spl::{
     associated_token::{get_associated_token_address, ID as ASSOCIATED_TOKEN_PROGRAM_ID},
     token::spl_token::ID as TOKEN_PROGRAM_ID,
 };
-use std::str::FromStr;
 
 /// LzReceiveTypes instruction provides a list of accounts that are used in the LzReceive
 /// instruction. The list of accounts required by this LzReceiveTypes instruction can be found
 /// from the specific PDA account that is generated by the LZ_RECEIVE_TYPES_SEED.
 #[derive(Accounts)]
 #[instruction(params: LzReceiveParams)]
-pub struct LzReceiveTypes {
-    // Must be empty as per new layerzero executor update
+pub struct LzReceiveTypes<'info> {
+    // the endpoint used for the clear accounts is read from the global config
+    #[account(seeds = [PORTFOLIO_SEED], bump = portfolio.bump)]
+    pub portfolio: Account<'info, Portfolio>,
 }
 
 pub fn lz_receive_types(
@@ -46,9 +48,6 @@ pub fn lz_receive_types(
     let (sol_user_funds_vault, _) =
         Pubkey::find_program_address(&sol_user_funds_vault_seeds, ctx.program_id);
 
-    let token_list_seeds = [TOKEN_LIST_SEED, TOKEN_LIST_PAGE_1_SEED.as_ref()];
-    let (token_list, _) = Pubkey::find_program_address(&token_list_seeds, ctx.program_id);
-
     let xfer_message = XFERSolana::unpack_xfer_message(&params.message)?;
 
     let token_mint_address = xfer_message.token_mint;
@@ -99,6 +98,23 @@ pub fn lz_receive_types(
     let (pending_swaps_entry, _) =
         Pubkey::find_program_address(&pending_swap_entry_seeds, ctx.program_id);
 
+    let failed_message_seeds = [
+        FAILED_MESSAGE_SEED,
+        &failed_message_key(params.src_eid, params.nonce),
+    ];
+    let (failed_message, _) =
+        Pubkey::find_program_address(&failed_message_seeds, ctx.program_id);
+
+    let token_inventory_seeds = [TOKEN_INVENTORY_SEED, token_mint_address.as_ref()];
+    let (token_inventory, _) =
+        Pubkey::find_program_address(&token_inventory_seeds, ctx.program_id);
+
+    let token_details_seeds = [TOKEN_DETAILS_SEED, token_mint_address.as_ref()];
+    let (token_details, _) = Pubkey::find_program_address(&token_details_seeds, ctx.program_id);
+
+    let banned_account_seeds = [BANNED_ACCOUNT_SEED, trader.as_ref()];
+    let (banned_account, _) = Pubkey::find_program_address(&banned_account_seeds, ctx.program_id);
+
     let token_vault = if xfer_message.transaction == Tx::CCTrade {
         spl_vault
     } else {
@@ -141,11 +157,6 @@ pub fn lz_receive_types(
             is_signer: false,
             is_writable: false,
         },
-        LzAccount {
-            pubkey: token_list,
-            is_signer: false,
-            is_writable: false,
-        },
         LzAccount {
             pubkey: trader,
             is_signer: false,
@@ -171,11 +182,29 @@ pub fn lz_receive_types(
             is_signer: false,
             is_writable: true,
         },
+        LzAccount {
+            pubkey: failed_message,
+            is_signer: false,
+            is_writable: true,
+        },
+        LzAccount {
+            pubkey: token_inventory,
+            is_signer: false,
+            is_writable: true,
+        },
+        LzAccount {
+            pubkey: token_details,
+            is_signer: false,
+            is_writable: false,
+        },
+        LzAccount {
+            pubkey: banned_account,
+            is_signer: false,
+            is_writable: false,
+        },
     ];
-    let endpoint_id = Pubkey::from_str(ENDPOINT_ID).unwrap(); // we provide a correct hard-coded value
-
     let accounts_for_clear = get_accounts_for_clear(
-        endpoint_id,
+        ctx.accounts.portfolio.global_config.endpoint_program,
         &portfolio,
         params.src_eid,
         &params.sender,
@@ -201,24 +230,45 @@ mod tests {
         SPL_VAULT_SEED,
     };
     use crate::errors::DexalotError;
+    use crate::state::GlobalConfig;
+    use crate::test_utils::{create_account_info, create_portfolio_data};
     use crate::xfer::Tx;
     use anchor_lang::prelude::*;
+    use anchor_lang::Discriminator;
     use anchor_lang::solana_program::pubkey::Pubkey;
     use anchor_spl::associated_token::get_associated_token_address;
 
     #[test]
     fn test_lz_receive_types_cctrade_default() -> Result<()> {
         let program_id = id();
+        let (portfolio_key, _) = Pubkey::find_program_address(&[PORTFOLIO_SEED], &program_id);
+        let endpoint_program = Pubkey::new_unique();
         let trader = Pubkey::new_unique();
         let token_mint = Pubkey::default();
         let xfer = XFERSolana::new(Tx::CCTrade, trader, token_mint, 50, 123, [0u8; 18], 1);
         let params = create_params(xfer);
-        let mut dummy_accounts = LzReceiveTypes {};
-        let remaining_accounts: Vec<AccountInfo> = vec![];
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = create_portfolio_data(GlobalConfig {
+            endpoint_program,
+            ..Default::default()
+        });
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        let mut accounts = LzReceiveTypes {
+            portfolio: Account::try_from(&portfolio_info)?,
+        };
         let ctx = Context {
             program_id: &program_id,
-            accounts: &mut dummy_accounts,
-            remaining_accounts: &remaining_accounts,
+            accounts: &mut accounts,
+            remaining_accounts: &[],
             bumps: LzReceiveTypesBumps::default(),
         };
 
@@ -233,23 +283,43 @@ mod tests {
         assert_eq!(accounts[2].pubkey, expected_sol_vault);
         assert_eq!(accounts[3].pubkey, expected_sol_vault);
         assert_eq!(accounts[4].pubkey, trader);
-        assert_eq!(accounts[11].pubkey, Pubkey::default());
+        assert_eq!(accounts[10].pubkey, Pubkey::default());
+        // the clear accounts belong to the configured endpoint
+        assert_eq!(accounts[16].pubkey, endpoint_program);
         Ok(())
     }
 
     #[test]
     fn test_lz_receive_types_cctrade_nondefault() -> Result<()> {
         let program_id = id();
+        let (portfolio_key, _) = Pubkey::find_program_address(&[PORTFOLIO_SEED], &program_id);
+        let endpoint_program = Pubkey::new_unique();
         let trader = Pubkey::new_unique();
         let token_mint = Pubkey::new_unique();
         let xfer = XFERSolana::new(Tx::CCTrade, trader, token_mint, 50, 123, [1u8; 18], 1);
         let params = create_params(xfer);
-        let mut dummy_accounts = LzReceiveTypes {};
-        let remaining_accounts: Vec<AccountInfo> = vec![];
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = create_portfolio_data(GlobalConfig {
+            endpoint_program,
+            ..Default::default()
+        });
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        let mut accounts = LzReceiveTypes {
+            portfolio: Account::try_from(&portfolio_info)?,
+        };
         let ctx = Context {
             program_id: &program_id,
-            accounts: &mut dummy_accounts,
-            remaining_accounts: &remaining_accounts,
+            accounts: &mut accounts,
+            remaining_accounts: &[],
             bumps: LzReceiveTypesBumps::default(),
         };
 
@@ -266,23 +336,51 @@ mod tests {
         assert_eq!(accounts[2].pubkey, expected_sol_vault);
         assert_eq!(accounts[3].pubkey, expected_from);
         assert_eq!(accounts[4].pubkey, expected_to);
-        assert_eq!(accounts[11].pubkey, token_mint);
+        assert_eq!(accounts[10].pubkey, token_mint);
+        let (expected_failed_message, _) =
+            Pubkey::find_program_address(&[FAILED_MESSAGE_SEED, &failed_message_key(10, 1)], &program_id);
+        assert_eq!(accounts[12].pubkey, expected_failed_message);
+        assert_eq!(accounts[13].pubkey, token_inventory_address(&token_mint, &program_id));
+        let (expected_token_details, _) =
+            Pubkey::find_program_address(&[TOKEN_DETAILS_SEED, token_mint.as_ref()], &program_id);
+        assert_eq!(accounts[14].pubkey, expected_token_details);
+        let (expected_banned_account, _) =
+            Pubkey::find_program_address(&[BANNED_ACCOUNT_SEED, trader.as_ref()], &program_id);
+        assert_eq!(accounts[15].pubkey, expected_banned_account);
         Ok(())
     }
 
     #[test]
     fn test_lz_receive_types_withdraw_default() -> Result<()> {
         let program_id = id();
+        let (portfolio_key, _) = Pubkey::find_program_address(&[PORTFOLIO_SEED], &program_id);
+        let endpoint_program = Pubkey::new_unique();
         let trader = Pubkey::new_unique();
         let token_mint = Pubkey::default();
         let xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, 75, 456, [2u8; 18], 1);
         let params = create_params(xfer);
-        let mut dummy_accounts = LzReceiveTypes {};
-        let remaining_accounts: Vec<AccountInfo> = vec![];
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = create_portfolio_data(GlobalConfig {
+            endpoint_program,
+            ..Default::default()
+        });
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        let mut accounts = LzReceiveTypes {
+            portfolio: Account::try_from(&portfolio_info)?,
+        };
         let ctx = Context {
             program_id: &program_id,
-            accounts: &mut dummy_accounts,
-            remaining_accounts: &remaining_accounts,
+            accounts: &mut accounts,
+            remaining_accounts: &[],
             bumps: LzReceiveTypesBumps::default(),
         };
 
@@ -299,23 +397,41 @@ mod tests {
         assert_eq!(accounts[2].pubkey, expected_sol_user_vault);
         assert_eq!(accounts[3].pubkey, expected_sol_user_vault);
         assert_eq!(accounts[4].pubkey, trader);
-        assert_eq!(accounts[11].pubkey, Pubkey::default());
+        assert_eq!(accounts[10].pubkey, Pubkey::default());
         Ok(())
     }
 
     #[test]
     fn test_lz_receive_types_withdraw_nondefault() -> Result<()> {
         let program_id = id();
+        let (portfolio_key, _) = Pubkey::find_program_address(&[PORTFOLIO_SEED], &program_id);
+        let endpoint_program = Pubkey::new_unique();
         let trader = Pubkey::new_unique();
         let token_mint = Pubkey::new_unique();
         let xfer = XFERSolana::new(Tx::Withdraw, trader, token_mint, 75, 456, [3u8; 18], 1);
         let params = create_params(xfer);
-        let mut dummy_accounts = LzReceiveTypes {};
-        let remaining_accounts: Vec<AccountInfo> = vec![];
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = create_portfolio_data(GlobalConfig {
+            endpoint_program,
+            ..Default::default()
+        });
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        let mut accounts = LzReceiveTypes {
+            portfolio: Account::try_from(&portfolio_info)?,
+        };
         let ctx = Context {
             program_id: &program_id,
-            accounts: &mut dummy_accounts,
-            remaining_accounts: &remaining_accounts,
+            accounts: &mut accounts,
+            remaining_accounts: &[],
             bumps: LzReceiveTypesBumps::default(),
         };
 
@@ -334,23 +450,41 @@ mod tests {
         assert_eq!(accounts[2].pubkey, expected_sol_user_vault);
         assert_eq!(accounts[3].pubkey, expected_from);
         assert_eq!(accounts[4].pubkey, expected_to);
-        assert_eq!(accounts[11].pubkey, token_mint);
+        assert_eq!(accounts[10].pubkey, token_mint);
         Ok(())
     }
 
     #[test]
-    fn test_lz_receive_types_unsupported_transaction() {
+    fn test_lz_receive_types_unsupported_transaction() -> Result<()> {
         let program_id = id();
+        let (portfolio_key, _) = Pubkey::find_program_address(&[PORTFOLIO_SEED], &program_id);
+        let endpoint_program = Pubkey::new_unique();
         let trader = Pubkey::new_unique();
         let token_mint = Pubkey::default();
         let xfer = XFERSolana::new(Tx::Deposit, trader, token_mint, 50, 123, [4u8; 18], 1);
         let params = create_params(xfer);
-        let mut dummy_accounts = LzReceiveTypes {};
-        let remaining_accounts: Vec<AccountInfo> = vec![];
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = create_portfolio_data(GlobalConfig {
+            endpoint_program,
+            ..Default::default()
+        });
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+        let mut accounts = LzReceiveTypes {
+            portfolio: Account::try_from(&portfolio_info)?,
+        };
         let ctx = Context {
             program_id: &program_id,
-            accounts: &mut dummy_accounts,
-            remaining_accounts: &remaining_accounts,
+            accounts: &mut accounts,
+            remaining_accounts: &[],
             bumps: LzReceiveTypesBumps::default(),
         };
 
@@ -359,6 +493,7 @@ mod tests {
             result.unwrap_err(),
             DexalotError::UnsupportedTransaction.into()
         );
+        Ok(())
     }
 
     fn create_params(xfer: XFERSolana) -> LzReceiveParams {
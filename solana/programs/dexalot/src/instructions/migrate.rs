@@ -0,0 +1,890 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+    Discriminator,
+};
+
+use crate::{
+    consts::{
+        ADMIN_SEED, ANCHOR_DISCRIMINATOR, LZ_RECEIVE_TYPES_SEED, MIN_TIMELOCK_DELAY,
+        PORTFOLIO_SEED, REMOTE_SEED, SYMBOL_SEED, TOKEN_DETAILS_SEED, TOKEN_LIST_PAGE_1_SEED,
+        TOKEN_LIST_SEED,
+    },
+    errors::DexalotError,
+    state::{
+        GlobalConfig, LzReceiveTypesAccounts, Portfolio, Remote, SymbolEntry, TokenDetails,
+        TokenList,
+    },
+};
+
+// Layouts written by the first release, accounts still at their length are migrated below
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default, Debug)]
+pub struct GlobalConfigV0 {
+    pub allow_deposit: bool,
+    pub program_paused: bool,
+    pub native_deposits_restricted: bool,
+    pub src_chain_id: u16,
+    pub default_chain_id: u32, // Dexalot L1 endpoint ID
+    pub airdrop_amount: u64,
+    pub swap_signer: [u8; 20],
+    pub out_nonce: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default, Debug)]
+pub struct PortfolioV0 {
+    pub admin: Pubkey,
+    pub global_config: GlobalConfigV0,
+    pub bump: u8,
+}
+
+impl PortfolioV0 {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + 32 + 45 + 1;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default, Debug)]
+pub struct RemoteV0 {
+    pub address: [u8; 32],
+    pub bump: u8,
+}
+
+impl RemoteV0 {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + 32 + 1;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default, Debug)]
+pub struct TokenDetailsV0 {
+    pub decimals: u8,
+    pub symbol: [u8; 32],
+    pub token_address: Pubkey,
+}
+
+impl TokenDetailsV0 {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + 1 + 32 + 32;
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Default, Debug)]
+pub struct TokenListV0 {
+    pub next_page: Option<Pubkey>,
+    pub tokens: Vec<Pubkey>,
+}
+
+impl TokenListV0 {
+    pub const MAX_TOKENS: usize = 20;
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + 33 + 4 + 32 * Self::MAX_TOKENS;
+}
+
+#[derive(Accounts)]
+pub struct MigratePortfolio<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the portfolio in its legacy layout, checked by the handler
+    #[account(
+        mut,
+        seeds = [PORTFOLIO_SEED],
+        bump
+    )]
+    pub portfolio: AccountInfo<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = LzReceiveTypesAccounts::LEN,
+        seeds = [LZ_RECEIVE_TYPES_SEED, portfolio.key().as_ref()],
+        bump
+    )]
+    pub lz_receive_types_accounts: Box<Account<'info, LzReceiveTypesAccounts>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct MigratePortfolioParams {
+    // chainlist ID of the Dexalot L1, the legacy config holds its endpoint ID
+    pub default_chain_id: u32,
+    pub endpoint_program: Pubkey,
+    pub timelock_delay: u32,
+}
+
+/// Grows a legacy portfolio to the current layout and creates its lz_receive_types accounts
+pub fn migrate_portfolio(
+    ctx: &mut Context<MigratePortfolio>,
+    params: &MigratePortfolioParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(
+        params.endpoint_program != Pubkey::default(),
+        DexalotError::InvalidEndpointProgram
+    );
+    require!(
+        params.timelock_delay >= MIN_TIMELOCK_DELAY,
+        DexalotError::TimelockDelayTooShort
+    );
+
+    let portfolio_info = &ctx.accounts.portfolio;
+    let legacy: PortfolioV0 = read_legacy_account(
+        portfolio_info,
+        PortfolioV0::LEN,
+        Portfolio::discriminator(),
+        ctx.program_id,
+    )?;
+    let legacy_config = legacy.global_config;
+    let portfolio = Portfolio {
+        admin: legacy.admin,
+        global_config: GlobalConfig {
+            allow_deposit: legacy_config.allow_deposit,
+            program_paused: legacy_config.program_paused,
+            native_deposits_restricted: legacy_config.native_deposits_restricted,
+            src_chain_id: legacy_config.src_chain_id,
+            default_chain_id: params.default_chain_id,
+            airdrop_amount: legacy_config.airdrop_amount,
+            swap_signer: legacy_config.swap_signer,
+            out_nonce: legacy_config.out_nonce,
+            endpoint_program: params.endpoint_program,
+            timelock_delay: params.timelock_delay,
+            ..Default::default()
+        },
+        bump: legacy.bump,
+        pending_admin: Pubkey::default(),
+    };
+
+    realloc_legacy_account(
+        portfolio_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        Portfolio::LEN,
+    )?;
+    portfolio.try_serialize(&mut &mut portfolio_info.try_borrow_mut_data()?[..])?;
+
+    // lets the executor pass the portfolio to lz_receive_types
+    ctx.accounts.lz_receive_types_accounts.portfolio = portfolio_info.key();
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateRemoteParams)]
+pub struct MigrateRemote<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the remote in its legacy layout, checked by the handler
+    #[account(
+        mut,
+        seeds = [REMOTE_SEED, &params.dst_eid.to_be_bytes()],
+        bump
+    )]
+    pub remote: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct MigrateRemoteParams {
+    pub dst_eid: u32,
+}
+
+/// Grows a legacy remote to the current layout, the user keeps paying the messaging fee
+pub fn migrate_remote(ctx: &mut Context<MigrateRemote>, _params: &MigrateRemoteParams) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let remote_info = &ctx.accounts.remote;
+    let legacy: RemoteV0 = read_legacy_account(
+        remote_info,
+        RemoteV0::LEN,
+        Remote::discriminator(),
+        ctx.program_id,
+    )?;
+    let remote = Remote {
+        address: legacy.address,
+        bump: legacy.bump,
+        protocol_pays_fee: false,
+    };
+
+    realloc_legacy_account(
+        remote_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        Remote::SIZE,
+    )?;
+    remote.try_serialize(&mut &mut remote_info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateTokenDetailsParams)]
+pub struct MigrateTokenDetails<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the token details in their legacy layout, checked by the handler
+    #[account(
+        mut,
+        seeds = [TOKEN_DETAILS_SEED, params.token_address.as_ref()],
+        bump
+    )]
+    pub token_details: AccountInfo<'info>,
+    // tokens added before the symbol index have no entry
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SymbolEntry::LEN,
+        seeds = [SYMBOL_SEED, params.symbol.as_ref()],
+        bump
+    )]
+    pub symbol_entry: Box<Account<'info, SymbolEntry>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct MigrateTokenDetailsParams {
+    pub token_address: Pubkey,
+    // the symbol stored in the legacy token details
+    pub symbol: [u8; 32],
+    pub l1_decimals: u8,
+}
+
+/// Grows legacy token details to the current layout and indexes their symbol
+pub fn migrate_token_details(
+    ctx: &mut Context<MigrateTokenDetails>,
+    params: &MigrateTokenDetailsParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let token_details_info = &ctx.accounts.token_details;
+    let legacy: TokenDetailsV0 = read_legacy_account(
+        token_details_info,
+        TokenDetailsV0::LEN,
+        TokenDetails::discriminator(),
+        ctx.program_id,
+    )?;
+    require!(
+        legacy.symbol == params.symbol,
+        DexalotError::InvalidTokenSymbol
+    );
+    // deposits stay open as before the per-token settings
+    let token_details = TokenDetails {
+        decimals: legacy.decimals,
+        l1_decimals: params.l1_decimals,
+        symbol: legacy.symbol,
+        token_address: legacy.token_address,
+        allow_deposit: true,
+        ..Default::default()
+    };
+
+    realloc_legacy_account(
+        token_details_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        TokenDetails::LEN,
+    )?;
+    token_details.try_serialize(&mut &mut token_details_info.try_borrow_mut_data()?[..])?;
+
+    let symbol_entry = &mut ctx.accounts.symbol_entry;
+    require!(
+        symbol_entry.token_mint == Pubkey::default()
+            || symbol_entry.token_mint == legacy.token_address,
+        DexalotError::SymbolAlreadyAdded
+    );
+    symbol_entry.token_mint = legacy.token_address;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateTokenList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the first token list page in its legacy layout, checked by the handler
+    #[account(
+        mut,
+        seeds = [TOKEN_LIST_SEED, TOKEN_LIST_PAGE_1_SEED],
+        bump
+    )]
+    pub token_list: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites the legacy first token list page as a sorted zero-copy page
+pub fn migrate_token_list(ctx: &mut Context<MigrateTokenList>) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let token_list_info = &ctx.accounts.token_list;
+    let legacy: TokenListV0 = read_legacy_account(
+        token_list_info,
+        TokenListV0::LEN,
+        TokenList::discriminator(),
+        ctx.program_id,
+    )?;
+    let mut token_list: TokenList = bytemuck::Zeroable::zeroed();
+    token_list.next_page = legacy.next_page.unwrap_or_default();
+    for token_mint in legacy.tokens {
+        token_list.insert(token_mint)?;
+    }
+
+    realloc_legacy_account(
+        token_list_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        TokenList::LEN,
+    )?;
+    let mut data = token_list_info.try_borrow_mut_data()?;
+    data[ANCHOR_DISCRIMINATOR..].copy_from_slice(bytemuck::bytes_of(&token_list));
+    Ok(())
+}
+
+/// Deserializes an account still at its legacy length
+fn read_legacy_account<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    legacy_len: usize,
+    discriminator: [u8; 8],
+    program_id: &Pubkey,
+) -> Result<T> {
+    require!(
+        account.owner == program_id && account.data_len() == legacy_len,
+        DexalotError::AccountAlreadyMigrated
+    );
+    let data = account.try_borrow_data()?;
+    require!(
+        data[..ANCHOR_DISCRIMINATOR] == discriminator,
+        DexalotError::AccountAlreadyMigrated
+    );
+    Ok(T::deserialize(&mut &data[ANCHOR_DISCRIMINATOR..])?)
+}
+
+/// Tops up the rent of the larger layout from the authority and grows the account
+fn realloc_legacy_account<'info>(
+    account: &AccountInfo<'info>,
+    authority: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    if cfg!(not(test)) {
+        let rent = Rent::get()?.minimum_balance(new_len);
+        let top_up = rent.saturating_sub(account.lamports());
+        if top_up > 0 {
+            let ix = system_instruction::transfer(&authority.key(), &account.key(), top_up);
+            invoke(
+                &ix,
+                &[
+                    authority.to_account_info(),
+                    account.to_account_info(),
+                    system_program.to_account_info(),
+                ],
+            )?;
+        }
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_account_info, create_reallocable_account_info};
+    use anchor_lang::solana_program::system_program;
+
+    fn legacy_data<T: AnchorSerialize>(discriminator: [u8; 8], account: &T, len: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        account.serialize(&mut data).unwrap();
+        data.resize(len, 0);
+        data
+    }
+
+    #[test]
+    fn test_legacy_layouts() {
+        assert_eq!(PortfolioV0::LEN, 86);
+        assert_eq!(RemoteV0::LEN, 41);
+        assert_eq!(TokenDetailsV0::LEN, 73);
+        assert_eq!(TokenListV0::LEN, 685);
+        // fixed-size legacy accounts fill their whole length
+        assert_eq!(
+            ANCHOR_DISCRIMINATOR + PortfolioV0::default().try_to_vec().unwrap().len(),
+            PortfolioV0::LEN
+        );
+        assert_eq!(
+            ANCHOR_DISCRIMINATOR + RemoteV0::default().try_to_vec().unwrap().len(),
+            RemoteV0::LEN
+        );
+        assert_eq!(
+            ANCHOR_DISCRIMINATOR + TokenDetailsV0::default().try_to_vec().unwrap().len(),
+            TokenDetailsV0::LEN
+        );
+    }
+
+    #[test]
+    fn test_migrate_portfolio() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let legacy_admin = Pubkey::new_unique();
+        let legacy = PortfolioV0 {
+            admin: legacy_admin,
+            global_config: GlobalConfigV0 {
+                allow_deposit: true,
+                program_paused: true,
+                native_deposits_restricted: true,
+                src_chain_id: 40168,
+                default_chain_id: 40204,
+                airdrop_amount: 10000,
+                swap_signer: [7; 20],
+                out_nonce: 42,
+            },
+            bump: 254,
+        };
+        let portfolio_key = Pubkey::new_unique();
+        let portfolio_info = create_reallocable_account_info(
+            portfolio_key,
+            100,
+            &legacy_data(Portfolio::discriminator(), &legacy, PortfolioV0::LEN),
+            &program_id,
+        );
+
+        let lz_receive_types_key = Pubkey::new_unique();
+        let mut lz_receive_types_lamports = 100;
+        let mut lz_receive_types_data = vec![0u8; LzReceiveTypesAccounts::LEN];
+        let lz_receive_types_info = create_account_info(
+            &lz_receive_types_key,
+            false,
+            true,
+            &mut lz_receive_types_lamports,
+            &mut lz_receive_types_data,
+            &program_id,
+            false,
+            Some(LzReceiveTypesAccounts::discriminator()),
+        );
+
+        let mut system_program_lamports = 100;
+        let mut system_program_data = vec![0u8; 10];
+        let system_program_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut system_program_lamports,
+            &mut system_program_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = MigratePortfolio {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            portfolio: portfolio_info.clone(),
+            lz_receive_types_accounts: Box::new(Account::try_from(&lz_receive_types_info)?),
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: MigratePortfolioBumps::default(),
+        };
+        let endpoint_program = Pubkey::new_unique();
+        let mut params = MigratePortfolioParams {
+            default_chain_id: 432204,
+            endpoint_program,
+            timelock_delay: MIN_TIMELOCK_DELAY,
+        };
+
+        // not an admin
+        let result = migrate_portfolio(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        params.timelock_delay = MIN_TIMELOCK_DELAY - 1;
+        let result = migrate_portfolio(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::TimelockDelayTooShort.into());
+        params.timelock_delay = MIN_TIMELOCK_DELAY;
+
+        migrate_portfolio(&mut ctx, &params)?;
+        assert_eq!(portfolio_info.data_len(), Portfolio::LEN);
+        assert_eq!(ctx.accounts.lz_receive_types_accounts.portfolio, portfolio_key);
+
+        let portfolio = Account::<Portfolio>::try_from(&portfolio_info)?;
+        assert_eq!(portfolio.admin, legacy_admin);
+        assert_eq!(portfolio.bump, 254);
+        assert_eq!(portfolio.pending_admin, Pubkey::default());
+        let global_config = &portfolio.global_config;
+        assert!(global_config.allow_deposit);
+        assert!(global_config.program_paused);
+        assert!(global_config.native_deposits_restricted);
+        assert_eq!(global_config.src_chain_id, 40168);
+        assert_eq!(global_config.default_chain_id, 432204);
+        assert_eq!(global_config.airdrop_amount, 10000);
+        assert_eq!(global_config.swap_signer, [7; 20]);
+        assert_eq!(global_config.out_nonce, 42);
+        assert_eq!(global_config.endpoint_program, endpoint_program);
+        assert_eq!(global_config.timelock_delay, MIN_TIMELOCK_DELAY);
+        assert_eq!(global_config.min_deposit_native, 0);
+        assert_eq!(global_config.bridge_fee_native, 0);
+
+        // the portfolio is only migrated once
+        let result = migrate_portfolio(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::AccountAlreadyMigrated.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_remote() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let legacy = RemoteV0 {
+            address: [9; 32],
+            bump: 253,
+        };
+        let remote_info = create_reallocable_account_info(
+            Pubkey::new_unique(),
+            100,
+            &legacy_data(Remote::discriminator(), &legacy, RemoteV0::LEN),
+            &program_id,
+        );
+
+        let mut system_program_lamports = 100;
+        let mut system_program_data = vec![0u8; 10];
+        let system_program_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut system_program_lamports,
+            &mut system_program_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = MigrateRemote {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            remote: remote_info.clone(),
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: MigrateRemoteBumps::default(),
+        };
+        let params = MigrateRemoteParams { dst_eid: 40204 };
+
+        migrate_remote(&mut ctx, &params)?;
+        assert_eq!(remote_info.data_len(), Remote::SIZE);
+        let remote = Account::<Remote>::try_from(&remote_info)?;
+        assert_eq!(remote.address, [9; 32]);
+        assert_eq!(remote.bump, 253);
+        assert!(!remote.protocol_pays_fee);
+
+        let result = migrate_remote(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::AccountAlreadyMigrated.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_token_details() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let token_address = Pubkey::new_unique();
+        let mut symbol = [0u8; 32];
+        symbol[..4].copy_from_slice(b"USDC");
+        let legacy = TokenDetailsV0 {
+            decimals: 6,
+            symbol,
+            token_address,
+        };
+        let token_details_info = create_reallocable_account_info(
+            Pubkey::new_unique(),
+            100,
+            &legacy_data(TokenDetails::discriminator(), &legacy, TokenDetailsV0::LEN),
+            &program_id,
+        );
+
+        let symbol_entry_key = Pubkey::new_unique();
+        let mut symbol_entry_lamports = 100;
+        let mut symbol_entry_data = vec![0u8; SymbolEntry::LEN];
+        let symbol_entry_info = create_account_info(
+            &symbol_entry_key,
+            false,
+            true,
+            &mut symbol_entry_lamports,
+            &mut symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        let mut system_program_lamports = 100;
+        let mut system_program_data = vec![0u8; 10];
+        let system_program_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut system_program_lamports,
+            &mut system_program_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = MigrateTokenDetails {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            token_details: token_details_info.clone(),
+            symbol_entry: Box::new(Account::try_from(&symbol_entry_info)?),
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: MigrateTokenDetailsBumps::default(),
+        };
+        let mut params = MigrateTokenDetailsParams {
+            token_address,
+            symbol: [1; 32],
+            l1_decimals: 18,
+        };
+
+        // the symbol has to match the stored one
+        let result = migrate_token_details(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidTokenSymbol.into());
+        assert_eq!(token_details_info.data_len(), TokenDetailsV0::LEN);
+
+        params.symbol = symbol;
+        migrate_token_details(&mut ctx, &params)?;
+        assert_eq!(token_details_info.data_len(), TokenDetails::LEN);
+        assert_eq!(ctx.accounts.symbol_entry.token_mint, token_address);
+
+        let token_details = Account::<TokenDetails>::try_from(&token_details_info)?;
+        assert_eq!(token_details.decimals, 6);
+        assert_eq!(token_details.l1_decimals, 18);
+        assert_eq!(token_details.symbol, symbol);
+        assert_eq!(token_details.token_address, token_address);
+        assert!(token_details.allow_deposit);
+        assert!(!token_details.paused);
+        assert_eq!(token_details.min_deposit, 0);
+        assert_eq!(token_details.bridge_fee, 0);
+        assert_eq!(token_details.default_dest_chain_id, 0);
+
+        let result = migrate_token_details(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::AccountAlreadyMigrated.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_token_list() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        // the legacy page keeps mints in insertion order
+        let mut tokens: Vec<Pubkey> = (0..TokenListV0::MAX_TOKENS)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        tokens.reverse();
+        let legacy = TokenListV0 {
+            next_page: None,
+            tokens: tokens.clone(),
+        };
+        let token_list_info = create_reallocable_account_info(
+            Pubkey::new_unique(),
+            100,
+            &legacy_data(TokenList::discriminator(), &legacy, TokenListV0::LEN),
+            &program_id,
+        );
+
+        let mut system_program_lamports = 100;
+        let mut system_program_data = vec![0u8; 10];
+        let system_program_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut system_program_lamports,
+            &mut system_program_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = MigrateTokenList {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            token_list: token_list_info.clone(),
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: MigrateTokenListBumps::default(),
+        };
+
+        migrate_token_list(&mut ctx)?;
+        assert_eq!(token_list_info.data_len(), TokenList::LEN);
+        {
+            let token_list_loader = AccountLoader::<TokenList>::try_from(&token_list_info)?;
+            let token_list = token_list_loader.load()?;
+            tokens.sort();
+            assert_eq!(token_list.tokens(), &tokens[..]);
+            assert_eq!(token_list.next_page(), None);
+        }
+
+        let result = migrate_token_list(&mut ctx);
+        assert_eq!(result.unwrap_err(), DexalotError::AccountAlreadyMigrated.into());
+        Ok(())
+    }
+}
@@ -0,0 +1,374 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{ADMIN_SEED, NATIVE_TOKEN_MINT, PORTFOLIO_SEED, TOKEN_DETAILS_SEED};
+use crate::errors::DexalotError;
+use crate::events::ParameterUpdatedEvent;
+use crate::instructions::WriteConfig;
+use crate::state::{Portfolio, TokenDetails};
+
+#[derive(Accounts)]
+#[instruction(params: SetMinDepositParams)]
+pub struct SetMinDeposit<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [TOKEN_DETAILS_SEED, params.token_mint.as_ref()],
+        bump
+    )]
+    pub token_details: Account<'info, TokenDetails>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetMinDepositParams {
+    pub token_mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Sets the amount a token deposit has to exceed
+pub fn set_min_deposit(
+    ctx: &mut Context<SetMinDeposit>,
+    params: &SetMinDepositParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let token_details = &mut ctx.accounts.token_details;
+    let old_amount = token_details.min_deposit;
+    token_details.min_deposit = params.amount;
+
+    emit!(ParameterUpdatedEvent {
+        pair: token_details.symbol,
+        parameter: "P-MINDEP".to_owned(),
+        old_value: old_amount,
+        new_value: params.amount
+    });
+
+    Ok(())
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetMinDepositNativeParams {
+    pub amount: u64,
+}
+
+/// Sets the amount in lamports a SOL deposit has to exceed
+pub fn set_min_deposit_native(
+    ctx: &mut Context<WriteConfig>,
+    params: &SetMinDepositNativeParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let global_config = &mut ctx.accounts.portfolio.global_config;
+    let old_amount = global_config.min_deposit_native;
+    global_config.min_deposit_native = params.amount;
+
+    emit!(ParameterUpdatedEvent {
+        pair: NATIVE_TOKEN_MINT.to_bytes(),
+        parameter: "P-MINDEP".to_owned(),
+        old_value: old_amount,
+        new_value: params.amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(token_mint: Pubkey)]
+pub struct GetMinDeposit<'info> {
+    #[account(
+        seeds = [PORTFOLIO_SEED],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+    /// CHECK: the token details pda, not created for SOL
+    #[account(
+        seeds = [TOKEN_DETAILS_SEED, token_mint.as_ref()],
+        bump,
+    )]
+    pub token_details: AccountInfo<'info>,
+}
+
+/// Returns the amount a deposit of the token has to exceed, `Pubkey::default()` being native SOL
+pub fn get_min_deposit(ctx: &Context<GetMinDeposit>, token_mint: Pubkey) -> Result<u64> {
+    if token_mint == NATIVE_TOKEN_MINT {
+        return Ok(ctx.accounts.portfolio.global_config.min_deposit_native);
+    }
+
+    let token_details = &ctx.accounts.token_details;
+    require!(
+        token_details.owner == ctx.program_id,
+        DexalotError::TokenNotFound
+    );
+    let token_details = TokenDetails::try_deserialize(&mut &token_details.try_borrow_data()?[..])?;
+
+    Ok(token_details.min_deposit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::WriteConfigBumps;
+    use crate::state::GlobalConfig;
+    use crate::test_utils::{create_account_info, create_portfolio_data};
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_set_min_deposit() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = TokenDetails {
+            token_address: token_mint,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+
+        let mut accounts = SetMinDeposit {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            token_details: Account::try_from(&token_details_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetMinDepositBumps::default(),
+        };
+        let params = SetMinDepositParams {
+            token_mint,
+            amount: 1000,
+        };
+
+        // not an admin
+        let result = set_min_deposit(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        set_min_deposit(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.token_details.min_deposit, 1000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_min_deposit_native() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let portfolio_key = Pubkey::new_unique();
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+
+        let mut accounts = WriteConfig {
+            authority: Signer::try_from(&authority_info)?,
+            portfolio: Account::try_from(&portfolio_info)?,
+            admin: admin_info,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: WriteConfigBumps::default(),
+        };
+        let params = SetMinDepositNativeParams { amount: 5000 };
+
+        set_min_deposit_native(&mut ctx, &params)?;
+        assert_eq!(
+            ctx.accounts.portfolio.global_config.min_deposit_native,
+            5000
+        );
+
+        // not an admin
+        let mut invalid_admin_lamports = 100;
+        let mut invalid_admin_data = vec![0u8; 10];
+        let invalid_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut invalid_admin_lamports,
+            &mut invalid_admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        ctx.accounts.admin = invalid_admin_info;
+        let result = set_min_deposit_native(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_min_deposit() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let portfolio_key = Pubkey::new_unique();
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = create_portfolio_data(GlobalConfig {
+            min_deposit_native: 2000,
+            ..Default::default()
+        });
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = TokenDetails {
+            token_address: token_mint,
+            min_deposit: 1000,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+
+        let mut accounts = GetMinDeposit {
+            portfolio: Account::try_from(&portfolio_info)?,
+            token_details: token_details_info,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: GetMinDepositBumps::default(),
+        };
+
+        assert_eq!(get_min_deposit(&ctx, token_mint)?, 1000);
+        assert_eq!(get_min_deposit(&ctx, NATIVE_TOKEN_MINT)?, 2000);
+
+        // token that was never added
+        let mut missing_lamports = 0;
+        let mut missing_data = vec![];
+        let missing_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut missing_lamports,
+            &mut missing_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        ctx.accounts.token_details = missing_info;
+        let result = get_min_deposit(&ctx, token_mint);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenNotFound.into());
+        Ok(())
+    }
+}
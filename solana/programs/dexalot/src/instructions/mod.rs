@@ -1,33 +1,63 @@
 mod add_destination;
 mod banned_account;
+mod bridge_fee;
+mod chain;
 mod claim_balance;
 mod create_account;
 mod create_ata;
 mod deposit;
+mod deposit_destination;
+mod failed_message;
 mod fund;
 mod global_config;
 mod initialize;
+mod inventory;
+mod lz_fee_vault;
+mod lz_options;
 mod lz_receive;
 mod lz_receive_types;
+mod migrate;
+mod min_deposit;
+mod multisig;
 mod process_xfer_payload;
+mod quote;
+mod rebalance;
+mod rebalancer_limit;
 mod roles;
 mod set_remote;
 mod swap;
+mod timelock;
 mod token;
+mod trusted_program;
 pub use add_destination::*;
 pub use banned_account::*;
+pub use bridge_fee::*;
+pub use chain::*;
 pub use claim_balance::*;
 pub use create_account::*;
 pub use create_ata::*;
 pub use deposit::*;
+pub use deposit_destination::*;
+pub use failed_message::*;
 pub use fund::*;
 pub use global_config::*;
 pub use initialize::*;
+pub use inventory::*;
+pub use lz_fee_vault::*;
+pub use lz_options::*;
 pub use lz_receive::*;
 pub use lz_receive_types::*;
+pub use migrate::*;
+pub use min_deposit::*;
+pub use multisig::*;
 pub use process_xfer_payload::*;
+pub use quote::*;
+pub use rebalance::*;
+pub use rebalancer_limit::*;
 
 pub use roles::*;
 pub use set_remote::*;
 pub use swap::*;
+pub use timelock::*;
 pub use token::*;
+pub use trusted_program::*;
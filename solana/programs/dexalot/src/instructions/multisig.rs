@@ -0,0 +1,677 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{
+    ADMIN_SEED, MAX_PROPOSAL_DATA_LEN, MAX_ROLE_MEMBERS, MAX_SENSITIVE_ACTIONS,
+    MULTISIG_CONFIG_SEED, PROPOSAL_SEED, ROLE_REGISTRY_SEED,
+};
+use crate::errors::DexalotError;
+use crate::events::{
+    MultisigConfigUpdatedEvent, ProposalApprovedEvent, ProposalCreatedEvent,
+    ProposalExecutedEvent,
+};
+use crate::map_utils::entry_exists;
+use crate::state::{MultisigConfig, Proposal, RoleRegistry, SensitiveAction};
+
+/// Reads the multisig config, actions are unprotected until it is created
+pub fn load_multisig_config(multisig_config: &AccountInfo) -> Result<MultisigConfig> {
+    if !entry_exists(multisig_config) {
+        return Ok(MultisigConfig::default());
+    }
+    let data = multisig_config.try_borrow_data()?;
+    MultisigConfig::try_deserialize(&mut &data[..])
+}
+
+/// Authorizes a sensitive admin action
+/// Protected actions need a proposal of the same action and params with enough approvals,
+/// it is marked executed and anyone can submit it. Other actions need an admin signer.
+/// Approvals only count while the approver still holds the admin role, so the admin PDAs
+/// of the approvers are passed first in `approver_admins`, in approval order.
+///
+/// # Returns
+/// The proposer of the executed proposal, if any
+pub fn authorize_action<T: AnchorSerialize>(
+    admin: &AccountInfo,
+    config: &MultisigConfig,
+    proposal: Option<&mut Proposal>,
+    action: SensitiveAction,
+    params: &T,
+    approver_admins: &[AccountInfo],
+    program_id: &Pubkey,
+) -> Result<Option<Pubkey>> {
+    if !config.requires_proposal(action) {
+        require!(
+            admin.owner == program_id,
+            DexalotError::UnauthorizedSigner
+        );
+        return Ok(None);
+    }
+
+    let proposal = proposal.ok_or(DexalotError::ProposalRequired)?;
+    require!(!proposal.executed, DexalotError::ProposalAlreadyExecuted);
+    require!(
+        proposal.action == action && proposal.data == params.try_to_vec()?,
+        DexalotError::ProposalMismatch
+    );
+    require!(
+        approver_admins.len() >= proposal.approvals.len(),
+        DexalotError::AccountsNotProvided
+    );
+    let mut approvals = 0;
+    for (approver, approver_admin) in proposal.approvals.iter().zip(approver_admins) {
+        let (admin_pda, _) =
+            Pubkey::find_program_address(&[ADMIN_SEED, approver.as_ref()], program_id);
+        require_keys_eq!(
+            approver_admin.key(),
+            admin_pda,
+            DexalotError::InvalidApproverAdmin
+        );
+        if approver_admin.owner == program_id && !approver_admin.data_is_empty() {
+            approvals += 1;
+        }
+    }
+    require!(
+        approvals >= config.threshold as usize,
+        DexalotError::ProposalThresholdNotReached
+    );
+    proposal.executed = true;
+
+    emit!(ProposalExecutedEvent {
+        id: proposal.id,
+        action,
+    });
+
+    Ok(Some(proposal.proposer))
+}
+
+pub fn set_multisig_config(
+    ctx: &mut Context<SetMultisigConfig>,
+    params: &SetMultisigConfigParams,
+) -> Result<()> {
+    authorize_action(
+        &ctx.accounts.admin,
+        &ctx.accounts.multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::SetMultisigConfig,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    // M-of-N over the listed admins
+    require!(
+        params.threshold as u32 <= ctx.accounts.admin_registry.member_count(),
+        DexalotError::InvalidMultisigThreshold
+    );
+    require!(
+        params.protected_actions.len() <= MAX_SENSITIVE_ACTIONS,
+        DexalotError::TooManyProtectedActions
+    );
+
+    let multisig_config = &mut ctx.accounts.multisig_config;
+    multisig_config.threshold = params.threshold;
+    multisig_config.protected_actions = params.protected_actions.clone();
+
+    emit!(MultisigConfigUpdatedEvent {
+        threshold: params.threshold,
+        protected_actions: params.protected_actions.clone(),
+    });
+
+    Ok(())
+}
+
+pub fn create_proposal(
+    ctx: &mut Context<CreateProposal>,
+    params: &CreateProposalParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(
+        params.data.len() <= MAX_PROPOSAL_DATA_LEN,
+        DexalotError::ProposalDataTooLong
+    );
+
+    // the proposer approves their own proposal
+    let proposer = ctx.accounts.authority.key();
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.id = params.id;
+    proposal.proposer = proposer;
+    proposal.action = params.action;
+    proposal.data = params.data.clone();
+    proposal.approvals = vec![proposer];
+    proposal.executed = false;
+
+    emit!(ProposalCreatedEvent {
+        id: params.id,
+        action: params.action,
+        proposer,
+    });
+
+    Ok(())
+}
+
+pub fn approve_proposal(
+    ctx: &mut Context<ApproveProposal>,
+    params: &ApproveProposalParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let approver = ctx.accounts.authority.key();
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.executed, DexalotError::ProposalAlreadyExecuted);
+    require!(
+        !proposal.approvals.contains(&approver),
+        DexalotError::ProposalAlreadyApproved
+    );
+    require!(
+        proposal.approvals.len() < MAX_ROLE_MEMBERS,
+        DexalotError::RoleMembersFull
+    );
+    proposal.approvals.push(approver);
+
+    emit!(ProposalApprovedEvent {
+        id: params.id,
+        approver,
+        approvals: proposal.approvals.len() as u8,
+    });
+
+    Ok(())
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetMultisigConfigParams {
+    pub threshold: u8,
+    pub protected_actions: Vec<SensitiveAction>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct CreateProposalParams {
+    pub id: u64,
+    pub action: SensitiveAction,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct ApproveProposalParams {
+    pub id: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetMultisigConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MultisigConfig::LEN,
+        seeds = [MULTISIG_CONFIG_SEED],
+        bump
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, ADMIN_SEED],
+        bump
+    )]
+    pub admin_registry: Account<'info, RoleRegistry>,
+
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateProposalParams)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Proposal::LEN,
+        seeds = [PROPOSAL_SEED, &params.id.to_be_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ApproveProposalParams)]
+pub struct ApproveProposal<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, &params.id.to_be_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::PORTFOLIO_SEED;
+    use crate::instructions::{
+        set_swap_signer, SetSlapSignerParams, SetSwapSigner, SetSwapSignerBumps,
+    };
+    use crate::state::{Admin, Portfolio, TimelockedAction};
+    use crate::test_utils::{create_account_info, create_pending_change_data};
+    use anchor_lang::{system_program, Discriminator};
+
+    fn create_proposal_data(action: SensitiveAction, data: Vec<u8>, approvals: Vec<Pubkey>) -> Vec<u8> {
+        let proposal = Proposal {
+            id: 7,
+            proposer: approvals[0],
+            action,
+            data,
+            approvals,
+            executed: false,
+        };
+        let mut proposal_data = vec![0u8; Proposal::LEN];
+        proposal
+            .try_serialize(&mut &mut proposal_data[..])
+            .expect("Proposal serializes");
+        proposal_data
+    }
+
+    #[test]
+    fn test_requires_proposal() {
+        let mut config = MultisigConfig {
+            threshold: 1,
+            protected_actions: vec![SensitiveAction::SetRemote],
+        };
+        // a single approval is the same as an admin call
+        assert!(!config.requires_proposal(SensitiveAction::SetRemote));
+        assert!(!config.requires_proposal(SensitiveAction::SetMultisigConfig));
+
+        config.threshold = 2;
+        assert!(config.requires_proposal(SensitiveAction::SetRemote));
+        assert!(!config.requires_proposal(SensitiveAction::AddToken));
+        // the config can't be loosened by a single admin
+        assert!(config.requires_proposal(SensitiveAction::SetMultisigConfig));
+    }
+
+    #[test]
+    fn test_load_multisig_config() -> Result<()> {
+        let program_id = crate::id();
+        let key = Pubkey::new_unique();
+
+        let mut lamports = 0;
+        let mut data = vec![];
+        let uninitialized = create_account_info(&key, false, false, &mut lamports, &mut data, &program_id, false, None);
+        let config = load_multisig_config(&uninitialized)?;
+        assert_eq!(config.threshold, 0);
+        assert!(config.protected_actions.is_empty());
+
+        let stored = MultisigConfig {
+            threshold: 3,
+            protected_actions: vec![SensitiveAction::SetSwapSigner, SensitiveAction::GrantRole],
+        };
+        let mut stored_lamports = 100;
+        let mut stored_data = vec![0u8; MultisigConfig::LEN];
+        stored.try_serialize(&mut &mut stored_data[..])?;
+        let initialized = create_account_info(&key, false, false, &mut stored_lamports, &mut stored_data, &program_id, false, None);
+        let config = load_multisig_config(&initialized)?;
+        assert_eq!(config.threshold, 3);
+        assert_eq!(config.protected_actions, stored.protected_actions);
+        Ok(())
+    }
+
+    #[test]
+    fn test_authorize_action() -> Result<()> {
+        let program_id = crate::id();
+        let admin_key = Pubkey::new_unique();
+        let not_admin_owner = Pubkey::new_unique();
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let first_admin_key = Pubkey::find_program_address(&[ADMIN_SEED, first.as_ref()], &program_id).0;
+        let second_admin_key = Pubkey::find_program_address(&[ADMIN_SEED, second.as_ref()], &program_id).0;
+
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let not_admin = create_account_info(&admin_key, false, false, &mut admin_lamports, &mut admin_data, &not_admin_owner, false, None);
+
+        let mut first_admin_lamports = 100;
+        let mut first_admin_data = vec![0u8; Admin::LEN];
+        let first_admin = create_account_info(&first_admin_key, false, false, &mut first_admin_lamports, &mut first_admin_data, &program_id, false, Some(Admin::discriminator()));
+        let mut second_admin_lamports = 100;
+        let mut second_admin_data = vec![0u8; Admin::LEN];
+        let second_admin = create_account_info(&second_admin_key, false, false, &mut second_admin_lamports, &mut second_admin_data, &program_id, false, Some(Admin::discriminator()));
+        // the second approver's role was revoked after approving
+        let mut revoked_admin_lamports = 0;
+        let mut revoked_admin_data = vec![];
+        let revoked_admin = create_account_info(&second_admin_key, false, false, &mut revoked_admin_lamports, &mut revoked_admin_data, &system_program::ID, false, None);
+
+        let config = MultisigConfig {
+            threshold: 2,
+            protected_actions: vec![SensitiveAction::ClaimAirdropBalance],
+        };
+        let params = ApproveProposalParams { id: 1 };
+
+        // unprotected actions need an admin
+        let result = authorize_action(&not_admin, &config, None, SensitiveAction::AddToken, &params, &[], &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let result = authorize_action(&not_admin, &config, None, SensitiveAction::ClaimAirdropBalance, &params, &[], &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalRequired.into());
+
+        let mut proposal = Proposal {
+            id: 7,
+            proposer: first,
+            action: SensitiveAction::ClaimAirdropBalance,
+            data: ApproveProposalParams { id: 2 }.try_to_vec()?,
+            approvals: vec![first],
+            executed: false,
+        };
+        let result = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::ClaimAirdropBalance, &params, &[], &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalMismatch.into());
+
+        proposal.data = params.try_to_vec()?;
+        // proposals don't authorize unprotected actions
+        let result = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::SetRemote, &params, &[], &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+        let result = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::ClaimAirdropBalance, &params, std::slice::from_ref(&first_admin), &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalThresholdNotReached.into());
+
+        proposal.approvals.push(second);
+        // every approver's admin PDA is passed in approval order
+        let result = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::ClaimAirdropBalance, &params, std::slice::from_ref(&first_admin), &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::AccountsNotProvided.into());
+        let result = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::ClaimAirdropBalance, &params, &[second_admin.clone(), first_admin.clone()], &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidApproverAdmin.into());
+
+        // approvals of revoked admins don't count
+        let result = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::ClaimAirdropBalance, &params, &[first_admin.clone(), revoked_admin], &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalThresholdNotReached.into());
+        assert!(!proposal.executed);
+
+        // anyone can execute once approved
+        let approver_admins = [first_admin, second_admin];
+        let proposer = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::ClaimAirdropBalance, &params, &approver_admins, &program_id)?;
+        assert_eq!(proposer, Some(first));
+        assert!(proposal.executed);
+
+        let result = authorize_action(&not_admin, &config, Some(&mut proposal), SensitiveAction::ClaimAirdropBalance, &params, &approver_admins, &program_id);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalAlreadyExecuted.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_and_approve_proposal() -> Result<()> {
+        let program_id = crate::id();
+        let proposer_key = Pubkey::new_unique();
+        let approver_key = Pubkey::new_unique();
+        let proposal_key = Pubkey::find_program_address(&[PROPOSAL_SEED, &7u64.to_be_bytes()], &program_id).0;
+
+        let mut proposer_lamports = 100;
+        let mut proposer_data = vec![0u8; 10];
+        let proposer_info = create_account_info(&proposer_key, true, true, &mut proposer_lamports, &mut proposer_data, &program_id, false, None);
+
+        let mut approver_lamports = 100;
+        let mut approver_data = vec![0u8; 10];
+        let approver_info = create_account_info(&approver_key, true, false, &mut approver_lamports, &mut approver_data, &program_id, false, None);
+
+        let not_admin_owner = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_key = Pubkey::default();
+        let admin_info = create_account_info(&admin_key, false, false, &mut admin_lamports, &mut admin_data, &not_admin_owner, false, None);
+
+        let mut proposal_lamports = 100;
+        let mut proposal_data = vec![0u8; Proposal::LEN];
+        let proposal_info = create_account_info(&proposal_key, false, true, &mut proposal_lamports, &mut proposal_data, &program_id, false, Some(Proposal::discriminator()));
+
+        let system_program_key = system_program::ID;
+        let mut sp_lamports = 100;
+        let mut sp_data = vec![0u8; 10];
+        let system_program_info = create_account_info(&system_program_key, false, false, &mut sp_lamports, &mut sp_data, &system_program_key, true, None);
+
+        let mut create_accounts = CreateProposal {
+            authority: Signer::try_from(&proposer_info)?,
+            admin: admin_info.clone(),
+            proposal: Account::try_from(&proposal_info)?,
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut create_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: CreateProposalBumps::default(),
+        };
+
+        let swap_signer = SetSlapSignerParams::try_from_slice(&[9; 20])?;
+        let mut params = CreateProposalParams {
+            id: 7,
+            action: SensitiveAction::SetSwapSigner,
+            data: swap_signer.try_to_vec()?,
+        };
+
+        let result = create_proposal(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        admin_info.assign(&program_id);
+        params.data = vec![0; MAX_PROPOSAL_DATA_LEN + 1];
+        let result = create_proposal(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalDataTooLong.into());
+
+        params.data = swap_signer.try_to_vec()?;
+        create_proposal(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.proposal.proposer, proposer_key);
+        assert_eq!(ctx.accounts.proposal.approvals, vec![proposer_key]);
+        assert!(!ctx.accounts.proposal.executed);
+
+        let proposal = (*ctx.accounts.proposal).clone();
+        let mut stored_data = vec![0u8; Proposal::LEN];
+        proposal.try_serialize(&mut &mut stored_data[..])?;
+        let mut stored_lamports = 100;
+        let stored_info = create_account_info(&proposal_key, false, true, &mut stored_lamports, &mut stored_data, &program_id, false, None);
+
+        let mut approve_accounts = ApproveProposal {
+            authority: Signer::try_from(&approver_info)?,
+            admin: admin_info.clone(),
+            proposal: Account::try_from(&stored_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut approve_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: ApproveProposalBumps::default(),
+        };
+
+        let params = ApproveProposalParams { id: 7 };
+        approve_proposal(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.proposal.approvals, vec![proposer_key, approver_key]);
+
+        let result = approve_proposal(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalAlreadyApproved.into());
+
+        ctx.accounts.proposal.executed = true;
+        ctx.accounts.proposal.approvals.pop();
+        let result = approve_proposal(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalAlreadyExecuted.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_multisig_config() -> Result<()> {
+        let program_id = crate::id();
+        let authority_key = Pubkey::new_unique();
+        let other_admin = Pubkey::new_unique();
+
+        let mut auth_lamports = 100;
+        let mut auth_data = vec![0u8; 10];
+        let authority_info = create_account_info(&authority_key, true, true, &mut auth_lamports, &mut auth_data, &program_id, false, None);
+
+        let admin_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(&admin_key, false, false, &mut admin_lamports, &mut admin_data, &program_id, false, None);
+
+        let config_key = Pubkey::find_program_address(&[MULTISIG_CONFIG_SEED], &program_id).0;
+        let mut config_lamports = 100;
+        let mut config_data = vec![0u8; MultisigConfig::LEN];
+        let config_info = create_account_info(&config_key, false, true, &mut config_lamports, &mut config_data, &program_id, false, Some(MultisigConfig::discriminator()));
+
+        let registry = RoleRegistry {
+            role: crate::state::RoleType::Admin,
+            members: vec![authority_key, other_admin],
+        };
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        registry.try_serialize(&mut &mut registry_data[..])?;
+        let registry_key = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, ADMIN_SEED], &program_id).0;
+        let mut registry_lamports = 100;
+        let registry_info = create_account_info(&registry_key, false, false, &mut registry_lamports, &mut registry_data, &program_id, false, None);
+
+        let system_program_key = system_program::ID;
+        let mut sp_lamports = 100;
+        let mut sp_data = vec![0u8; 10];
+        let system_program_info = create_account_info(&system_program_key, false, false, &mut sp_lamports, &mut sp_data, &system_program_key, true, None);
+
+        let mut accounts = SetMultisigConfig {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            multisig_config: Account::try_from(&config_info)?,
+            admin_registry: Account::try_from(&registry_info)?,
+            proposal: None,
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetMultisigConfigBumps::default(),
+        };
+
+        // more approvals than admins
+        let mut params = SetMultisigConfigParams {
+            threshold: 3,
+            protected_actions: vec![SensitiveAction::SetSwapSigner],
+        };
+        let result = set_multisig_config(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidMultisigThreshold.into());
+
+        params.threshold = 2;
+        set_multisig_config(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.multisig_config.threshold, 2);
+        assert_eq!(ctx.accounts.multisig_config.protected_actions, vec![SensitiveAction::SetSwapSigner]);
+
+        // a single admin can no longer change it
+        params.threshold = 1;
+        let result = set_multisig_config(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalRequired.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_swap_signer_through_proposal() -> Result<()> {
+        let program_id = crate::id();
+        let executor_key = Pubkey::new_unique();
+        let proposer = Pubkey::new_unique();
+        let approver = Pubkey::new_unique();
+
+        let mut executor_lamports = 100;
+        let mut executor_data = vec![0u8; 10];
+        let executor_info = create_account_info(&executor_key, true, false, &mut executor_lamports, &mut executor_data, &program_id, false, None);
+
+        // the executor is not an admin
+        let admin_key = Pubkey::find_program_address(&[ADMIN_SEED, executor_key.as_ref()], &program_id).0;
+        let system_program_key = system_program::ID;
+        let mut admin_lamports = 0;
+        let mut admin_data = vec![];
+        let admin_info = create_account_info(&admin_key, false, false, &mut admin_lamports, &mut admin_data, &system_program_key, false, None);
+
+        let portfolio_key = Pubkey::find_program_address(&[PORTFOLIO_SEED], &program_id).0;
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+        let portfolio_info = create_account_info(&portfolio_key, false, true, &mut portfolio_lamports, &mut portfolio_data, &program_id, false, Some(Portfolio::discriminator()));
+
+        let config = MultisigConfig {
+            threshold: 2,
+            protected_actions: vec![SensitiveAction::SetSwapSigner],
+        };
+        let config_key = Pubkey::find_program_address(&[MULTISIG_CONFIG_SEED], &program_id).0;
+        let mut config_lamports = 100;
+        let mut config_data = vec![0u8; MultisigConfig::LEN];
+        config.try_serialize(&mut &mut config_data[..])?;
+        let config_info = create_account_info(&config_key, false, false, &mut config_lamports, &mut config_data, &program_id, false, None);
+
+        let params = SetSlapSignerParams::try_from_slice(&[5; 20])?;
+        let proposal_key = Pubkey::find_program_address(&[PROPOSAL_SEED, &7u64.to_be_bytes()], &program_id).0;
+        let mut proposal_lamports = 100;
+        let mut proposal_data = create_proposal_data(SensitiveAction::SetSwapSigner, params.try_to_vec()?, vec![proposer, approver]);
+        let proposal_info = create_account_info(&proposal_key, false, true, &mut proposal_lamports, &mut proposal_data, &program_id, false, None);
+
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = create_pending_change_data(TimelockedAction::SetSwapSigner, &params, 0);
+        let pending_change_info = create_account_info(&pending_change_key, false, true, &mut pending_change_lamports, &mut pending_change_data, &program_id, false, None);
+
+        let proposer_admin_key = Pubkey::find_program_address(&[ADMIN_SEED, proposer.as_ref()], &program_id).0;
+        let mut proposer_admin_lamports = 100;
+        let mut proposer_admin_data = vec![0u8; Admin::LEN];
+        let proposer_admin = create_account_info(&proposer_admin_key, false, false, &mut proposer_admin_lamports, &mut proposer_admin_data, &program_id, false, Some(Admin::discriminator()));
+        let approver_admin_key = Pubkey::find_program_address(&[ADMIN_SEED, approver.as_ref()], &program_id).0;
+        let mut approver_admin_lamports = 100;
+        let mut approver_admin_data = vec![0u8; Admin::LEN];
+        let approver_admin = create_account_info(&approver_admin_key, false, false, &mut approver_admin_lamports, &mut approver_admin_data, &program_id, false, Some(Admin::discriminator()));
+        let approver_admins = [proposer_admin, approver_admin];
+
+        let mut accounts = SetSwapSigner {
+            authority: Signer::try_from(&executor_info)?,
+            portfolio: Account::try_from(&portfolio_info)?,
+            admin: admin_info,
+            multisig_config: config_info,
+            proposal: None,
+            pending_change: Account::try_from(&pending_change_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetSwapSignerBumps::default(),
+        };
+
+        let result = set_swap_signer(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ProposalRequired.into());
+
+        ctx.accounts.proposal = Some(Account::try_from(&proposal_info)?);
+        ctx.remaining_accounts = &approver_admins;
+        set_swap_signer(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.portfolio.global_config.swap_signer, [5; 20]);
+        assert!(ctx.accounts.proposal.as_ref().unwrap().executed);
+        Ok(())
+    }
+}
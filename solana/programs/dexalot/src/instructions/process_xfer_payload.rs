@@ -1,9 +1,10 @@
 use super::*;
 use crate::{
-    consts::{NATIVE_VAULT_MIN_THRESHOLD, SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED},
+    consts::NATIVE_VAULT_MIN_THRESHOLD,
     errors::DexalotError,
     events::XChainFinalized,
-    instructions::{add_to_swap_queue, PendingSwap},
+    instructions::{add_to_swap_queue, update_inventory, PendingSwap},
+    state::TokenInventory,
     xfer::{Tx, XFERSolana},
 };
 use anchor_lang::{
@@ -12,21 +13,34 @@ use anchor_lang::{
 };
 use anchor_spl::token::{spl_token, Token, TokenAccount};
 
-pub fn process_xfer_payload_native<'info>(
+/// Accounts paying an XFER out of one of the program vaults
+pub struct XferPayout<'a, 'info> {
+    /// PDA signing the transfer, the SOL vault itself for native payouts
+    pub vault: &'a AccountInfo<'info>,
+    pub vault_seed: &'a [u8],
+    pub vault_bump: u8,
+    /// SOL vault or vault ATA
+    pub from: &'a AccountInfo<'info>,
+    /// trader or trader ATA
+    pub to: &'a AccountInfo<'info>,
+    pub swap_queue_entry: &'a AccountInfo<'info>,
+    /// pays the swap queue entry when the vault is short
+    pub airdrop_vault: &'a AccountInfo<'info>,
+    pub airdrop_vault_bump: u8,
+    pub system_program: &'a Program<'info, System>,
+    pub token_inventory: &'a AccountInfo<'info>,
+}
+
+pub fn process_xfer_payload_native(
     xfer: &XFERSolana,
-    sol_vault_bump: u8,
-    sol_vault: &AccountInfo<'info>,
-    trader: &AccountInfo<'info>,
-    swap_queue_entry: &AccountInfo<'info>,
-    system_program: &Program<'info, System>,
-    airdrop_vault: &AccountInfo<'info>,
-    airdrop_vault_bump: u8,
+    payout: &XferPayout,
     is_remove: bool,
 ) -> Result<()> {
-    let from = sol_vault;
-    let to = trader;
+    let from = payout.from;
+    let to = payout.to;
+    let quantity = xfer.amount()?;
     require!(
-        xfer.quantity > 0,
+        quantity > 0,
         DexalotError::ZeroXferAmount
     );
     require!(
@@ -35,60 +49,54 @@ pub fn process_xfer_payload_native<'info>(
     );
 
     if !is_remove {
-        if from.lamports() < xfer.quantity + NATIVE_VAULT_MIN_THRESHOLD {
+        if from.lamports() < quantity + NATIVE_VAULT_MIN_THRESHOLD {
             let pending_swap = PendingSwap {
                 trader: xfer.trader,
-                quantity: xfer.quantity,
+                quantity,
                 token_mint: Pubkey::default(), // native asset
             };
             add_to_swap_queue(
-                airdrop_vault,
-                swap_queue_entry,
+                payout.airdrop_vault,
+                payout.swap_queue_entry,
                 pending_swap,
-                system_program,
+                payout.system_program,
                 custom_data_to_nonce(xfer.custom_data),
                 xfer.trader,
-                airdrop_vault_bump,
+                payout.airdrop_vault_bump,
             )?;
             return Ok(());
         }
     } else {
         require!(
-            from.lamports() >= xfer.quantity + NATIVE_VAULT_MIN_THRESHOLD,
+            from.lamports() >= quantity + NATIVE_VAULT_MIN_THRESHOLD,
             DexalotError::NotEnoughNativeBalance
         );
     }
 
-    let bump = &[sol_vault_bump];
-    let seeds: &[&[u8]] = &[
-        if xfer.transaction == Tx::CCTrade {
-            SOL_VAULT_SEED
-        } else {
-            SOL_USER_FUNDS_VAULT_SEED
-        },
-        bump,
-    ];
+    let bump = &[payout.vault_bump];
+    let seeds: &[&[u8]] = &[payout.vault_seed, bump];
     let signer_seeds = &[&seeds[..]];
 
     // Transfer the native SOL from the program to the user
-    let ix = system_instruction::transfer(&from.key(), &to.key(), xfer.quantity);
+    let ix = system_instruction::transfer(&from.key(), &to.key(), quantity);
     if cfg!(not(test)){
         invoke_signed(
             &ix,
             &[
                 from.to_account_info().clone(),
                 to.to_account_info().clone(),
-                system_program.to_account_info().clone(),
+                payout.system_program.to_account_info().clone(),
             ],
             signer_seeds, // sign with the PDA
         )?;
     }
+    release_from_inventory(payout.token_inventory, xfer)?;
 
     emit!(XChainFinalized {
         nonce: xfer.nonce,
         trader: xfer.trader,
         token_mint: xfer.token_mint,
-        amount: xfer.quantity,
+        amount: quantity,
         timestamp: xfer.timestamp
     });
     Ok(())
@@ -96,18 +104,15 @@ pub fn process_xfer_payload_native<'info>(
 
 pub fn process_xfer_payload_spl<'info>(
     xfer: &XFERSolana,
-    token_vault_bump: u8,
-    token_vault_seeds: &[u8],
-    token_vault: &AccountInfo<'info>,
-    airdrop_vault_bump: u8,
-    airdrop_vault: &AccountInfo<'info>,
-    from: &AccountInfo<'info>,
-    to: &AccountInfo<'info>,
+    payout: &XferPayout<'_, 'info>,
     token_program: &Program<'info, Token>,
-    swap_queue_entry: &AccountInfo<'info>,
-    system_program: &Program<'info, System>,
     is_remove: bool, // flag is true when executed during remove from pending swap queue
 ) -> Result<()> {
+    let from = payout.from;
+    let to = payout.to;
+    let token_vault = payout.vault;
+    let quantity = xfer.amount()?;
+
     // Check if swap can be completed
     let from_ata_amount = {
         let mut data: &[u8] = &from.try_borrow_data()?;
@@ -117,26 +122,26 @@ pub fn process_xfer_payload_spl<'info>(
 
     if !is_remove {
         // we check the type of xfer message and if we have the amount
-        if xfer.transaction == Tx::CCTrade && from_ata_amount < xfer.quantity {
+        if xfer.transaction == Tx::CCTrade && from_ata_amount < quantity {
             let pending_swap = PendingSwap {
                 trader: xfer.trader,
-                quantity: xfer.quantity,
+                quantity,
                 token_mint: xfer.token_mint,
             };
             add_to_swap_queue(
-                airdrop_vault,
-                swap_queue_entry,
+                payout.airdrop_vault,
+                payout.swap_queue_entry,
                 pending_swap,
-                system_program,
+                payout.system_program,
                 custom_data_to_nonce(xfer.custom_data),
                 xfer.trader,
-                airdrop_vault_bump,
+                payout.airdrop_vault_bump,
             )?;
             return Ok(());
         }
     } else {
         require!(
-            from_ata_amount >= xfer.quantity,
+            from_ata_amount >= quantity,
             DexalotError::NotEnoughSplTokenBalance
         );
     }
@@ -147,10 +152,10 @@ pub fn process_xfer_payload_spl<'info>(
         &to.key(),
         &token_vault.key(),
         &[],
-        xfer.quantity,
+        quantity,
     )?;
-    let bump = &[token_vault_bump];
-    let seeds: &[&[u8]] = &[token_vault_seeds, bump];
+    let bump = &[payout.vault_bump];
+    let seeds: &[&[u8]] = &[payout.vault_seed, bump];
     let signer_seeds = &[&seeds[..]];
 
     // Transfer the tokens from dexalot to the user
@@ -166,30 +171,62 @@ pub fn process_xfer_payload_spl<'info>(
             signer_seeds,
         )?;
     }
+    release_from_inventory(payout.token_inventory, xfer)?;
 
     emit!(XChainFinalized {
         nonce: xfer.nonce,
         trader: xfer.trader,
         token_mint: xfer.token_mint,
-        amount: xfer.quantity,
+        amount: quantity,
         timestamp: xfer.timestamp
     });
 
     Ok(())
 }
 
+/// Records a released XFER against the vault it was paid from
+fn release_from_inventory(token_inventory: &AccountInfo, xfer: &XFERSolana) -> Result<()> {
+    update_inventory(token_inventory, |inventory| release_xfer(inventory, xfer))
+}
+
+/// Debits the XFER quantity from the ledger of the vault paying it
+pub fn release_xfer(inventory: &mut TokenInventory, xfer: &XFERSolana) -> Result<()> {
+    let quantity = xfer.amount()?;
+    if xfer.transaction == Tx::CCTrade {
+        inventory.remove_rfq_inventory(quantity)
+    } else {
+        inventory.remove_user_funds(quantity)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use anchor_lang::system_program;
-    use crate::consts::PENDING_SWAPS_SEED;
-    use crate::test_utils::{create_account_info, create_packed_token_account};
+    use crate::consts::{PENDING_SWAPS_SEED, SOL_VAULT_SEED};
+    use crate::test_utils::{
+        create_account_info, create_funded_token_inventory_data, create_packed_token_account,
+    };
     use crate::xfer::XChainMsgType;
 
     #[test]
     fn test_process_xfer_payload_native_success() -> Result<()> {
         let program_id = crate::id();
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let trader_key = Pubkey::new_unique();
         let mut xfer = XFERSolana {
             quantity: 1000,
@@ -275,31 +312,35 @@ mod tests {
 
         let system_program = Program::try_from(&system_program_ai)?;
 
-        let result = process_xfer_payload_native(
-            &xfer,
-            42,
-            &sol_vault,
-            &trader,
-            &swap_queue_entry,
-            &system_program,
-            &airdrop_vault_info,
-            42,
-            false,
-        );
+        let payout = XferPayout {
+            vault: &sol_vault,
+            vault_seed: SOL_VAULT_SEED,
+            vault_bump: 42,
+            from: &sol_vault,
+            to: &trader,
+            swap_queue_entry: &swap_queue_entry,
+            airdrop_vault: &airdrop_vault_info,
+            airdrop_vault_bump: 42,
+            system_program: &system_program,
+            token_inventory: &token_inventory_info,
+        };
+        let result = process_xfer_payload_native(&xfer, &payout, false);
         assert!(result.is_ok());
 
         xfer.transaction = Tx::CCTrade;
-        let result = process_xfer_payload_native(
-            &xfer,
-            42,
-            &sol_vault,
-            &trader,
-            &swap_queue_entry,
-            &system_program,
-            &airdrop_vault_info,
-            42,
-            true,
-        );
+        let payout = XferPayout {
+            vault: &sol_vault,
+            vault_seed: SOL_VAULT_SEED,
+            vault_bump: 42,
+            from: &sol_vault,
+            to: &trader,
+            swap_queue_entry: &swap_queue_entry,
+            airdrop_vault: &airdrop_vault_info,
+            airdrop_vault_bump: 42,
+            system_program: &system_program,
+            token_inventory: &token_inventory_info,
+        };
+        let result = process_xfer_payload_native(&xfer, &payout, true);
         assert!(result.is_ok());
         Ok(())
     }
@@ -308,6 +349,20 @@ mod tests {
     fn test_process_xfer_payload_native_negative_cases() -> Result<()> {
         let program_id = crate::id();
 
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let trader_key = Pubkey::new_unique();
         let mut xfer = XFERSolana {
             quantity: 1000,
@@ -393,47 +448,53 @@ mod tests {
 
         let system_program = Program::try_from(&system_program_ai)?;
 
-        let result = process_xfer_payload_native(
-            &xfer,
-            42,
-            &sol_vault,
-            &trader,
-            &swap_queue_entry,
-            &system_program,
-            &airdrop_vault_info,
-            42,
-            true,
-        );
+        let payout = XferPayout {
+            vault: &sol_vault,
+            vault_seed: SOL_VAULT_SEED,
+            vault_bump: 42,
+            from: &sol_vault,
+            to: &trader,
+            swap_queue_entry: &swap_queue_entry,
+            airdrop_vault: &airdrop_vault_info,
+            airdrop_vault_bump: 42,
+            system_program: &system_program,
+            token_inventory: &token_inventory_info,
+        };
+        let result = process_xfer_payload_native(&xfer, &payout, true);
         assert_eq!(result.unwrap_err(), DexalotError::NotEnoughNativeBalance.into());
 
         trader.key = &sys_key;
 
-        let result = process_xfer_payload_native(
-            &xfer,
-            42,
-            &sol_vault,
-            &trader,
-            &swap_queue_entry,
-            &system_program,
-            &airdrop_vault_info,
-            42,
-            true,
-        );
+        let payout = XferPayout {
+            vault: &sol_vault,
+            vault_seed: SOL_VAULT_SEED,
+            vault_bump: 42,
+            from: &sol_vault,
+            to: &trader,
+            swap_queue_entry: &swap_queue_entry,
+            airdrop_vault: &airdrop_vault_info,
+            airdrop_vault_bump: 42,
+            system_program: &system_program,
+            token_inventory: &token_inventory_info,
+        };
+        let result = process_xfer_payload_native(&xfer, &payout, true);
         assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
 
         xfer.quantity = 0;
 
-        let result = process_xfer_payload_native(
-            &xfer,
-            42,
-            &sol_vault,
-            &trader,
-            &swap_queue_entry,
-            &system_program,
-            &airdrop_vault_info,
-            42,
-            true,
-        );
+        let payout = XferPayout {
+            vault: &sol_vault,
+            vault_seed: SOL_VAULT_SEED,
+            vault_bump: 42,
+            from: &sol_vault,
+            to: &trader,
+            swap_queue_entry: &swap_queue_entry,
+            airdrop_vault: &airdrop_vault_info,
+            airdrop_vault_bump: 42,
+            system_program: &system_program,
+            token_inventory: &token_inventory_info,
+        };
+        let result = process_xfer_payload_native(&xfer, &payout, true);
         assert_eq!(result.unwrap_err(), DexalotError::ZeroXferAmount.into());
         Ok(())
     }
@@ -441,6 +502,20 @@ mod tests {
     #[test]
     fn test_process_xfer_payload_spl_success() -> Result<()> {
         let program_id = crate::id();
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
         let trader_key = Pubkey::new_unique();
         let token_mint = Pubkey::new_unique();
         let xfer = XFERSolana {
@@ -557,20 +632,19 @@ mod tests {
 
         let token_vault_seeds: &[u8] = b"vault_seed";
 
-        let result = process_xfer_payload_spl(
-            &xfer,
-            42,
-            token_vault_seeds,
-            &token_vault,
-            42,
-            &airdrop_vault,
-            &from,
-            &to,
-            &token_program,
-            &swap_queue_entry,
-            &system_program,
-            false,
-        );
+        let payout = XferPayout {
+            vault: &token_vault,
+            vault_seed: token_vault_seeds,
+            vault_bump: 42,
+            from: &from,
+            to: &to,
+            swap_queue_entry: &swap_queue_entry,
+            airdrop_vault: &airdrop_vault,
+            airdrop_vault_bump: 42,
+            system_program: &system_program,
+            token_inventory: &token_inventory_info,
+        };
+        let result = process_xfer_payload_spl(&xfer, &payout, &token_program, false);
         assert!(result.is_ok());
 
         Ok(())
@@ -579,6 +653,20 @@ mod tests {
     #[test]
     fn test_process_xfer_payload_spl_negative_cases() -> Result<()> {
         let program_id = crate::id();
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
         let trader_key = Pubkey::new_unique();
         let token_mint = Pubkey::new_unique();
         let xfer = XFERSolana {
@@ -695,20 +783,19 @@ mod tests {
 
         let token_vault_seeds: &[u8] = b"vault_seed";
 
-        let result = process_xfer_payload_spl(
-            &xfer,
-            42,
-            token_vault_seeds,
-            &token_vault,
-            42,
-            &airdrop_vault,
-            &from,
-            &to,
-            &token_program,
-            &swap_queue_entry,
-            &system_program,
-            true,
-        );
+        let payout = XferPayout {
+            vault: &token_vault,
+            vault_seed: token_vault_seeds,
+            vault_bump: 42,
+            from: &from,
+            to: &to,
+            swap_queue_entry: &swap_queue_entry,
+            airdrop_vault: &airdrop_vault,
+            airdrop_vault_bump: 42,
+            system_program: &system_program,
+            token_inventory: &token_inventory_info,
+        };
+        let result = process_xfer_payload_spl(&xfer, &payout, &token_program, true);
         assert_eq!(result.unwrap_err(), DexalotError::NotEnoughSplTokenBalance.into());
 
         Ok(())
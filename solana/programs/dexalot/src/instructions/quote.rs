@@ -0,0 +1,523 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{
+    CHAIN_SEED, LZ_OPTIONS_SEED, NATIVE_TOKEN_MINT, PORTFOLIO_SEED, REMOTE_SEED, SOL_NATIVE_SYMBOL,
+    TOKEN_DETAILS_SEED,
+};
+use crate::cpi_utils::{load_lz_options, quote, MessagingFee};
+use crate::errors::DexalotError;
+use crate::instructions::{deposit_dest_chain_id, XChainSwap};
+use crate::state::{ChainEntry, Portfolio, Remote, TokenDetails};
+use crate::xfer::{scale_quantity, Tx, XFER};
+
+#[derive(Accounts)]
+#[instruction(params: QuoteDepositParams)]
+pub struct QuoteDeposit<'info> {
+    #[account(
+        seeds = [PORTFOLIO_SEED],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+    /// CHECK: the token details pda, not created for SOL
+    #[account(
+        seeds = [TOKEN_DETAILS_SEED, params.token_mint.as_ref()],
+        bump,
+    )]
+    pub token_details: AccountInfo<'info>,
+    // checked against the resolved destination chain of the deposit
+    pub chain_entry: Account<'info, ChainEntry>,
+    #[account(
+        seeds = [
+            REMOTE_SEED,
+            &chain_entry.eid.to_be_bytes()
+        ],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+    /// CHECK: the endpoint program
+    #[account(
+        address = portfolio.global_config.endpoint_program @ DexalotError::InvalidEndpointProgram
+    )]
+    pub endpoint_program: AccountInfo<'info>,
+    /// CHECK: the LZ options pda, default gas options are used when not set
+    #[account(
+        seeds = [
+            LZ_OPTIONS_SEED,
+            &chain_entry.eid.to_be_bytes(),
+            &[Tx::Deposit as u8]
+        ],
+        bump
+    )]
+    pub lz_options: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteDepositParams {
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub trader: [u8; 32],
+    // chainlist ID to deposit to, the token's default destination when not set
+    pub dest_chain_id: Option<u32>,
+}
+
+/// Returns the LayerZero fee of a deposit, `Pubkey::default()` being native SOL
+/// The remaining accounts hold the quote accounts
+pub fn quote_deposit(
+    ctx: &Context<QuoteDeposit>,
+    params: &QuoteDepositParams,
+) -> Result<MessagingFee> {
+    let global_config = &ctx.accounts.portfolio.global_config;
+
+    let (symbol, bridge_fee, decimals, l1_decimals, token_default) = if params.token_mint
+        == NATIVE_TOKEN_MINT
+    {
+        let mut native_symbol = [0; 32];
+        native_symbol[0..3].copy_from_slice(SOL_NATIVE_SYMBOL);
+        (native_symbol, global_config.bridge_fee_native, 0, 0, 0)
+    } else {
+        let token_details = &ctx.accounts.token_details;
+        require!(
+            token_details.owner == ctx.program_id,
+            DexalotError::TokenNotFound
+        );
+        let token_details =
+            TokenDetails::try_deserialize(&mut &token_details.try_borrow_data()?[..])?;
+        (
+            token_details.symbol,
+            token_details.bridge_fee,
+            token_details.decimals,
+            token_details.l1_decimals,
+            token_details.default_dest_chain_id,
+        )
+    };
+
+    let dest_chain_id = deposit_dest_chain_id(
+        params.dest_chain_id,
+        token_default,
+        global_config.default_chain_id,
+    );
+    let (chain_entry_key, _) =
+        Pubkey::find_program_address(&[CHAIN_SEED, &dest_chain_id.to_be_bytes()], ctx.program_id);
+    require_keys_eq!(
+        ctx.accounts.chain_entry.key(),
+        chain_entry_key,
+        DexalotError::DestinationNotAllowed
+    );
+
+    require!(
+        params.amount > bridge_fee,
+        DexalotError::DepositUnderThreshold
+    );
+
+    let xfer = XFER::new(
+        Tx::Deposit,
+        params.trader,
+        symbol,
+        scale_quantity((params.amount - bridge_fee).into(), decimals, l1_decimals)?,
+        if cfg!(not(test)) {
+            Clock::get()?.unix_timestamp as u32
+        } else {
+            123
+        },
+        [0; 18],
+        global_config.out_nonce,
+    );
+    let message = xfer.pack_xfer_message()?;
+
+    quote(
+        &ctx.accounts.portfolio,
+        &ctx.accounts.remote,
+        &ctx.accounts.endpoint_program,
+        ctx.remaining_accounts,
+        ctx.accounts.chain_entry.eid,
+        message,
+        load_lz_options(&ctx.accounts.lz_options, ctx.program_id)?,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(params: QuoteCrossSwapParams)]
+pub struct QuoteCrossSwap<'info> {
+    #[account(
+        seeds = [PORTFOLIO_SEED],
+        bump,
+    )]
+    pub portfolio: Account<'info, Portfolio>,
+    #[account(
+        seeds = [CHAIN_SEED, &params.order.dest_chain_id.to_be_bytes()],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
+    #[account(
+        seeds = [
+            REMOTE_SEED,
+            &chain_entry.eid.to_be_bytes()
+        ],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+    /// CHECK: the endpoint program
+    #[account(
+        address = portfolio.global_config.endpoint_program @ DexalotError::InvalidEndpointProgram
+    )]
+    pub endpoint_program: AccountInfo<'info>,
+    /// CHECK: the LZ options pda, default gas options are used when not set
+    #[account(
+        seeds = [
+            LZ_OPTIONS_SEED,
+            &chain_entry.eid.to_be_bytes(),
+            &[Tx::CCTrade as u8]
+        ],
+        bump
+    )]
+    pub lz_options: AccountInfo<'info>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteCrossSwapParams {
+    pub order: XChainSwap,
+}
+
+/// Returns the LayerZero fee of the cross chain trade sent by a cross swap
+/// The remaining accounts hold the quote accounts
+pub fn quote_cross_swap(
+    ctx: &Context<QuoteCrossSwap>,
+    params: &QuoteCrossSwapParams,
+) -> Result<MessagingFee> {
+    let portfolio = &ctx.accounts.portfolio;
+    let message = params
+        .order
+        .pack_cross_chain_trade(portfolio.global_config.out_nonce)?;
+
+    quote(
+        portfolio,
+        &ctx.accounts.remote,
+        &ctx.accounts.endpoint_program,
+        ctx.remaining_accounts,
+        ctx.accounts.chain_entry.eid,
+        message,
+        load_lz_options(&ctx.accounts.lz_options, ctx.program_id)?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::QUOTE_REMAINING_ACCOUNTS_COUNT;
+    use crate::test_utils::{create_account_info, create_dummy_account};
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_quote_deposit() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let portfolio_key = Pubkey::new_unique();
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = Portfolio::default().try_to_vec()?;
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = vec![0u8; 10];
+        let token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let remote_key = Pubkey::new_unique();
+        let mut remote_lamports = 100;
+        let mut remote_data = vec![0u8; Remote::SIZE];
+        let remote_info = create_account_info(
+            &remote_key,
+            false,
+            false,
+            &mut remote_lamports,
+            &mut remote_data,
+            &program_id,
+            false,
+            Some(Remote::discriminator()),
+        );
+
+        let endpoint_program_key = Pubkey::new_unique();
+        let mut ep_lamports = 100;
+        let mut ep_data = vec![0u8; 10];
+        let endpoint_program_info = create_account_info(
+            &endpoint_program_key,
+            false,
+            false,
+            &mut ep_lamports,
+            &mut ep_data,
+            &endpoint_program_key,
+            true,
+            None,
+        );
+
+        let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
+        let remaining_accounts: Vec<AccountInfo<'static>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
+            .map(|_| create_dummy_account(program_id_static))
+            .collect();
+
+        // the default chain of the portfolio
+        let (chain_entry_key, _) =
+            Pubkey::find_program_address(&[CHAIN_SEED, &0u32.to_be_bytes()], &program_id);
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut accounts = QuoteDeposit {
+            portfolio: Account::try_from(&portfolio_info)?,
+            token_details: token_details_info,
+            remote: Account::try_from(&remote_info)?,
+            endpoint_program: endpoint_program_info,
+            lz_options: lz_options_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: remaining_accounts.as_slice(),
+            program_id: &program_id,
+            bumps: QuoteDepositBumps::default(),
+        };
+        let mut params = QuoteDepositParams {
+            token_mint: NATIVE_TOKEN_MINT,
+            amount: 100,
+            trader: [1; 32],
+            dest_chain_id: None,
+        };
+
+        // SOL deposit
+        let fee = quote_deposit(&ctx, &params)?;
+        assert_eq!(fee, MessagingFee::default());
+
+        // the chain entry is not the one of the requested chain
+        params.dest_chain_id = Some(1);
+        let result = quote_deposit(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::DestinationNotAllowed.into()
+        );
+        params.dest_chain_id = None;
+
+        // the deposit has to cover the bridge fee
+        ctx.accounts.portfolio.global_config.bridge_fee_native = 100;
+        let result = quote_deposit(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::DepositUnderThreshold.into()
+        );
+
+        // token not added
+        params.token_mint = token_mint;
+        let result = quote_deposit(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenNotFound.into());
+
+        let mut added_token_details_lamports = 100;
+        let mut added_token_details_data = TokenDetails {
+            token_address: token_mint,
+            decimals: 6,
+            l1_decimals: 18,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let added_token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut added_token_details_lamports,
+            &mut added_token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+        ctx.accounts.token_details = added_token_details_info;
+        let fee = quote_deposit(&ctx, &params)?;
+        assert_eq!(fee, MessagingFee::default());
+
+        // the token's default destination is used when none is requested
+        let mut default_dest_token_details_lamports = 100;
+        let mut default_dest_token_details_data = TokenDetails {
+            token_address: token_mint,
+            decimals: 6,
+            l1_decimals: 18,
+            default_dest_chain_id: 1,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let default_dest_token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            false,
+            &mut default_dest_token_details_lamports,
+            &mut default_dest_token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+        ctx.accounts.token_details = default_dest_token_details_info;
+        let result = quote_deposit(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::DestinationNotAllowed.into()
+        );
+        params.dest_chain_id = Some(0);
+        let fee = quote_deposit(&ctx, &params)?;
+        assert_eq!(fee, MessagingFee::default());
+
+        // not enough remaining accounts for the quote
+        ctx.remaining_accounts = &remaining_accounts[1..];
+        let result = quote_deposit(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::LzQuoteError.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_quote_cross_swap() -> Result<()> {
+        let program_id = crate::id();
+
+        let portfolio_key = Pubkey::new_unique();
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = Portfolio::default().try_to_vec()?;
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            false,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            Some(Portfolio::discriminator()),
+        );
+
+        let remote_key = Pubkey::new_unique();
+        let mut remote_lamports = 100;
+        let mut remote_data = vec![0u8; Remote::SIZE];
+        let remote_info = create_account_info(
+            &remote_key,
+            false,
+            false,
+            &mut remote_lamports,
+            &mut remote_data,
+            &program_id,
+            false,
+            Some(Remote::discriminator()),
+        );
+
+        let endpoint_program_key = Pubkey::new_unique();
+        let mut ep_lamports = 100;
+        let mut ep_data = vec![0u8; 10];
+        let endpoint_program_info = create_account_info(
+            &endpoint_program_key,
+            false,
+            false,
+            &mut ep_lamports,
+            &mut ep_data,
+            &endpoint_program_key,
+            true,
+            None,
+        );
+
+        let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
+        let remaining_accounts: Vec<AccountInfo<'static>> = (0..QUOTE_REMAINING_ACCOUNTS_COUNT)
+            .map(|_| create_dummy_account(program_id_static))
+            .collect();
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut accounts = QuoteCrossSwap {
+            portfolio: Account::try_from(&portfolio_info)?,
+            remote: Account::try_from(&remote_info)?,
+            endpoint_program: endpoint_program_info,
+            lz_options: lz_options_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: remaining_accounts.as_slice(),
+            program_id: &program_id,
+            bumps: QuoteCrossSwapBumps::default(),
+        };
+        let params = QuoteCrossSwapParams {
+            order: XChainSwap {
+                taker: Pubkey::new_unique(),
+                dest_trader: Pubkey::new_unique(),
+                maker_symbol: [1; 32],
+                maker_asset: Pubkey::new_unique(),
+                taker_asset: Pubkey::new_unique(),
+                maker_amount: 100,
+                taker_amount: 100,
+                nonce: [0; 12],
+                expiry: 1000,
+                dest_chain_id: 1,
+            },
+        };
+
+        let fee = quote_cross_swap(&ctx, &params)?;
+        assert_eq!(fee, MessagingFee::default());
+        Ok(())
+    }
+}
@@ -0,0 +1,733 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use anchor_spl::token::{spl_token, Token, TokenAccount};
+
+use crate::{
+    consts::{
+        NATIVE_TOKEN_MINT, NATIVE_VAULT_MIN_THRESHOLD, REBALANCER_LIMIT_SEED, REBALANCER_SEED,
+        SOL_USER_FUNDS_VAULT_SEED, SOL_VAULT_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED,
+        TOKEN_INVENTORY_SEED,
+    },
+    errors::DexalotError,
+    events::VaultsRebalancedEvent,
+    instructions::{
+        check_ata_account, current_epoch, record_rebalancer_withdrawal, update_inventory,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(params: RebalanceVaultsParams)]
+pub struct RebalanceVaults<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: the rebalancer
+    #[account(
+            seeds = [REBALANCER_SEED, authority.key().as_ref()],
+            bump
+        )]
+    pub rebalancer: AccountInfo<'info>,
+    /// CHECK: the sol vault
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED],
+        bump,
+    )]
+    pub sol_vault: AccountInfo<'info>,
+    /// CHECK: the sol user funds vault
+    #[account(
+        mut,
+        seeds = [SOL_USER_FUNDS_VAULT_SEED],
+        bump,
+    )]
+    pub sol_user_funds_vault: AccountInfo<'info>,
+    /// CHECK: spl vault address
+    #[account(
+        constraint = spl_vault.owner == __program_id @ DexalotError::InvalidVaultOwner,
+        seeds = [SPL_VAULT_SEED],
+        bump,
+    )]
+    pub spl_vault: AccountInfo<'info>,
+    /// CHECK: spl user funds vault address
+    #[account(
+        constraint = spl_user_funds_vault.owner == __program_id @ DexalotError::InvalidVaultOwner,
+        seeds = [SPL_USER_FUNDS_VAULT_SEED],
+        bump,
+    )]
+    pub spl_user_funds_vault: AccountInfo<'info>,
+    /// CHECK: ATA or Zero PublicKey
+    #[account(mut)]
+    pub spl_vault_ata: AccountInfo<'info>,
+    /// CHECK: ATA or Zero PublicKey
+    #[account(mut)]
+    pub spl_user_funds_vault_ata: AccountInfo<'info>,
+    /// CHECK: the token inventory pda
+    #[account(
+        mut,
+        seeds = [TOKEN_INVENTORY_SEED, params.token_mint.as_ref()],
+        bump,
+    )]
+    pub token_inventory: AccountInfo<'info>,
+    /// CHECK: the rebalancer's withdrawal limit for the token
+    #[account(
+        mut,
+        seeds = [REBALANCER_LIMIT_SEED, authority.key().as_ref(), params.token_mint.as_ref()],
+        bump,
+    )]
+    pub rebalancer_limit: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorDeserialize, AnchorSerialize)]
+#[repr(u8)]
+pub enum RebalanceDirection {
+    UserFundsToRfq,
+    RfqToUserFunds,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct RebalanceVaultsParams {
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub direction: RebalanceDirection,
+}
+
+/// Moves funds between the user funds vaults and the RFQ vaults
+/// The moved amounts are limited per epoch by the token's rebalance cap and the rebalancer's
+/// withdrawal limit, RFQ withdrawals respect the inventory floor
+pub fn rebalance_vaults(
+    ctx: &Context<RebalanceVaults>,
+    params: &RebalanceVaultsParams,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let rebalancer = &ctx.accounts.rebalancer;
+
+    // check rebalancer
+    require!(
+        rebalancer.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(params.amount > 0, DexalotError::ZeroXferAmount);
+
+    if params.token_mint == NATIVE_TOKEN_MINT {
+        rebalance_native(ctx, params)?;
+    } else {
+        rebalance_spl(ctx, params)?;
+    }
+
+    let epoch = current_epoch()?;
+    update_inventory(&ctx.accounts.token_inventory, |inventory| {
+        inventory.record_rebalance(params.amount, epoch)?;
+        match params.direction {
+            RebalanceDirection::UserFundsToRfq => {
+                inventory.remove_user_funds(params.amount)?;
+                inventory.add_rfq_inventory(params.amount)?;
+            }
+            RebalanceDirection::RfqToUserFunds => {
+                inventory.claim_rfq_inventory(params.amount)?;
+                inventory.add_user_funds(params.amount)?;
+            }
+        }
+        Ok(())
+    })?;
+    record_rebalancer_withdrawal(&ctx.accounts.rebalancer_limit, params.amount)?;
+
+    emit!(VaultsRebalancedEvent {
+        token_mint: params.token_mint,
+        amount: params.amount,
+        direction: params.direction,
+        rebalancer: authority.key(),
+    });
+
+    Ok(())
+}
+
+fn rebalance_native(ctx: &Context<RebalanceVaults>, params: &RebalanceVaultsParams) -> Result<()> {
+    let system_program = &ctx.accounts.system_program;
+    let (from, to, from_seed, from_bump) = match params.direction {
+        RebalanceDirection::UserFundsToRfq => (
+            &ctx.accounts.sol_user_funds_vault,
+            &ctx.accounts.sol_vault,
+            SOL_USER_FUNDS_VAULT_SEED,
+            ctx.bumps.sol_user_funds_vault,
+        ),
+        RebalanceDirection::RfqToUserFunds => (
+            &ctx.accounts.sol_vault,
+            &ctx.accounts.sol_user_funds_vault,
+            SOL_VAULT_SEED,
+            ctx.bumps.sol_vault,
+        ),
+    };
+
+    // check balance
+    require!(
+        from.lamports() >= params.amount + NATIVE_VAULT_MIN_THRESHOLD,
+        DexalotError::NotEnoughNativeBalance
+    );
+
+    let bump = &[from_bump];
+    let seeds: &[&[u8]] = &[from_seed, bump];
+
+    let ix = system_instruction::transfer(&from.key(), &to.key(), params.amount);
+    if cfg!(not(test)) {
+        invoke_signed(
+            &ix,
+            &[
+                from.to_account_info(),
+                to.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[seeds], // sign with the PDA
+        )?;
+    }
+    Ok(())
+}
+
+fn rebalance_spl(ctx: &Context<RebalanceVaults>, params: &RebalanceVaultsParams) -> Result<()> {
+    let token_program = &ctx.accounts.token_program;
+    let spl_vault = &ctx.accounts.spl_vault;
+    let spl_user_funds_vault = &ctx.accounts.spl_user_funds_vault;
+    let spl_vault_ata = &ctx.accounts.spl_vault_ata;
+    let spl_user_funds_vault_ata = &ctx.accounts.spl_user_funds_vault_ata;
+
+    check_ata_account(spl_vault_ata, spl_vault.key, &params.token_mint, true)?;
+    check_ata_account(
+        spl_user_funds_vault_ata,
+        spl_user_funds_vault.key,
+        &params.token_mint,
+        true,
+    )?;
+
+    let (from, to, from_authority, from_seed, from_bump) = match params.direction {
+        RebalanceDirection::UserFundsToRfq => (
+            spl_user_funds_vault_ata,
+            spl_vault_ata,
+            spl_user_funds_vault,
+            SPL_USER_FUNDS_VAULT_SEED,
+            ctx.bumps.spl_user_funds_vault,
+        ),
+        RebalanceDirection::RfqToUserFunds => (
+            spl_vault_ata,
+            spl_user_funds_vault_ata,
+            spl_vault,
+            SPL_VAULT_SEED,
+            ctx.bumps.spl_vault,
+        ),
+    };
+
+    // check balance
+    let from_amount = {
+        let mut data: &[u8] = &from.try_borrow_data()?;
+        TokenAccount::try_deserialize(&mut data)?.amount
+    };
+    require!(
+        from_amount >= params.amount,
+        DexalotError::NotEnoughSplTokenBalance
+    );
+
+    let ix = spl_token::instruction::transfer(
+        &token_program.key(),
+        &from.key(),
+        &to.key(),
+        &from_authority.key(),
+        &[],
+        params.amount,
+    )?;
+
+    let bump = &[from_bump];
+    let seeds: &[&[u8]] = &[from_seed, bump];
+
+    if cfg!(not(test)) {
+        invoke_signed(
+            &ix,
+            &[
+                from.to_account_info(),
+                to.to_account_info(),
+                from_authority.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            &[seeds],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{RebalancerLimit, TokenInventory};
+    use crate::test_utils::{
+        create_account_info, create_packed_token_account, create_rebalancer_limit_data,
+        create_token_inventory_data,
+    };
+    use anchor_lang::solana_program::system_program;
+    use anchor_spl::associated_token::get_associated_token_address;
+    use anchor_spl::token;
+
+    #[test]
+    fn test_rebalance_vaults_native() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let rebalancer_key = Pubkey::new_unique();
+        let mut rebalancer_lamports = 100;
+        let mut rebalancer_data = vec![0u8; 10];
+        let rebalancer_info = create_account_info(
+            &rebalancer_key,
+            false,
+            false,
+            &mut rebalancer_lamports,
+            &mut rebalancer_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let sol_vault_key = Pubkey::new_unique();
+        let mut sol_vault_lamports = 1000 + NATIVE_VAULT_MIN_THRESHOLD;
+        let mut sol_vault_data = vec![];
+        let sol_vault_info = create_account_info(
+            &sol_vault_key,
+            false,
+            true,
+            &mut sol_vault_lamports,
+            &mut sol_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let sol_user_funds_vault_key = Pubkey::new_unique();
+        let mut sol_user_funds_vault_lamports = 500 + NATIVE_VAULT_MIN_THRESHOLD;
+        let mut sol_user_funds_vault_data = vec![];
+        let sol_user_funds_vault_info = create_account_info(
+            &sol_user_funds_vault_key,
+            false,
+            true,
+            &mut sol_user_funds_vault_lamports,
+            &mut sol_user_funds_vault_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let generic_key = Pubkey::new_unique();
+        let mut generic_lamports = 100;
+        let mut generic_data = vec![0u8; 10];
+        let generic_info = create_account_info(
+            &generic_key,
+            false,
+            true,
+            &mut generic_lamports,
+            &mut generic_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory {
+            user_funds: 500,
+            rfq_inventory: 100,
+            rfq_floor: 50,
+            rebalance_cap: 600,
+            ..Default::default()
+        });
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(300);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut token_program_lamports = 100;
+        let mut token_program_data = vec![0u8; 10];
+        let token_program_info = create_account_info(
+            &token::ID,
+            false,
+            false,
+            &mut token_program_lamports,
+            &mut token_program_data,
+            &token::ID,
+            true,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = RebalanceVaults {
+            authority: Signer::try_from(&authority_info)?,
+            rebalancer: rebalancer_info.clone(),
+            sol_vault: sol_vault_info.clone(),
+            sol_user_funds_vault: sol_user_funds_vault_info.clone(),
+            spl_vault: generic_info.clone(),
+            spl_user_funds_vault: generic_info.clone(),
+            spl_vault_ata: generic_info.clone(),
+            spl_user_funds_vault_ata: generic_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            rebalancer_limit: rebalancer_limit_info.clone(),
+            token_program: Program::try_from(&token_program_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RebalanceVaultsBumps::default(),
+        };
+
+        let mut params = RebalanceVaultsParams {
+            token_mint: NATIVE_TOKEN_MINT,
+            amount: 200,
+            direction: RebalanceDirection::UserFundsToRfq,
+        };
+        rebalance_vaults(&ctx, &params)?;
+        let inventory =
+            TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 300);
+        assert_eq!(inventory.rfq_inventory, 300);
+
+        // more than the user funds held for the token
+        params.amount = 301;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InsufficientInventory.into()
+        );
+
+        // the cap is cumulative over the epoch
+        params.amount = 401;
+        params.direction = RebalanceDirection::RfqToUserFunds;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::RebalanceCapExceeded.into()
+        );
+
+        // rfq inventory would go below the floor
+        params.amount = 251;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::InventoryBelowFloor.into()
+        );
+
+        // the sol vault does not hold enough lamports
+        params.amount = 1001;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::NotEnoughNativeBalance.into()
+        );
+
+        params.amount = 100;
+        rebalance_vaults(&ctx, &params)?;
+        let inventory =
+            TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.rebalance_epoch, 1);
+        assert_eq!(inventory.rebalanced, 300);
+
+        // rebalances count towards the rebalancer's withdrawal limit
+        params.amount = 1;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::WithdrawalLimitExceeded.into()
+        );
+
+        params.amount = 0;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ZeroXferAmount.into());
+
+        // no withdrawal limit set for the token
+        let mut missing_limit_lamports = 0;
+        let mut missing_limit_data = vec![];
+        let missing_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut missing_limit_lamports,
+            &mut missing_limit_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        ctx.accounts.rebalancer_limit = missing_limit_info;
+        params.amount = 1;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::WithdrawalLimitNotSet.into()
+        );
+
+        // the cap resets on a new epoch
+        let mut inventory =
+            TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        inventory.record_rebalance(600, 2)?;
+        assert_eq!(inventory.rebalance_epoch, 2);
+        assert_eq!(inventory.rebalanced, 600);
+
+        // not a rebalancer
+        let mut invalid_rebalancer_lamports = 100;
+        let mut invalid_rebalancer_data = vec![0u8; 10];
+        let invalid_rebalancer_info = create_account_info(
+            &rebalancer_key,
+            false,
+            false,
+            &mut invalid_rebalancer_lamports,
+            &mut invalid_rebalancer_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        ctx.accounts.rebalancer = invalid_rebalancer_info;
+        params.amount = 100;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rebalance_vaults_spl() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let rebalancer_key = Pubkey::new_unique();
+        let mut rebalancer_lamports = 100;
+        let mut rebalancer_data = vec![0u8; 10];
+        let rebalancer_info = create_account_info(
+            &rebalancer_key,
+            false,
+            false,
+            &mut rebalancer_lamports,
+            &mut rebalancer_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let spl_vault_key = Pubkey::new_unique();
+        let mut spl_vault_lamports = 100;
+        let mut spl_vault_data = vec![0u8; 10];
+        let spl_vault_info = create_account_info(
+            &spl_vault_key,
+            false,
+            false,
+            &mut spl_vault_lamports,
+            &mut spl_vault_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let spl_user_funds_vault_key = Pubkey::new_unique();
+        let mut spl_user_funds_vault_lamports = 100;
+        let mut spl_user_funds_vault_data = vec![0u8; 10];
+        let spl_user_funds_vault_info = create_account_info(
+            &spl_user_funds_vault_key,
+            false,
+            false,
+            &mut spl_user_funds_vault_lamports,
+            &mut spl_user_funds_vault_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let spl_vault_ata_key = get_associated_token_address(&spl_vault_key, &token_mint);
+        let mut spl_vault_ata_lamports = 100;
+        let mut spl_vault_ata_data = create_packed_token_account(token_mint, spl_vault_key, 100)?;
+        let spl_vault_ata_info = create_account_info(
+            &spl_vault_ata_key,
+            false,
+            true,
+            &mut spl_vault_ata_lamports,
+            &mut spl_vault_ata_data,
+            &token::ID,
+            false,
+            None,
+        );
+
+        let spl_user_funds_vault_ata_key =
+            get_associated_token_address(&spl_user_funds_vault_key, &token_mint);
+        let mut spl_user_funds_vault_ata_lamports = 100;
+        let mut spl_user_funds_vault_ata_data =
+            create_packed_token_account(token_mint, spl_user_funds_vault_key, 1000)?;
+        let spl_user_funds_vault_ata_info = create_account_info(
+            &spl_user_funds_vault_ata_key,
+            false,
+            true,
+            &mut spl_user_funds_vault_ata_lamports,
+            &mut spl_user_funds_vault_ata_data,
+            &token::ID,
+            false,
+            None,
+        );
+
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_token_inventory_data(&TokenInventory {
+            user_funds: 1000,
+            rfq_inventory: 100,
+            rfq_floor: 0,
+            rebalance_cap: 5000,
+            ..Default::default()
+        });
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(RebalancerLimit::UNLIMITED);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut token_program_lamports = 100;
+        let mut token_program_data = vec![0u8; 10];
+        let token_program_info = create_account_info(
+            &token::ID,
+            false,
+            false,
+            &mut token_program_lamports,
+            &mut token_program_data,
+            &token::ID,
+            true,
+            None,
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = RebalanceVaults {
+            authority: Signer::try_from(&authority_info)?,
+            rebalancer: rebalancer_info.clone(),
+            sol_vault: spl_vault_info.clone(),
+            sol_user_funds_vault: spl_user_funds_vault_info.clone(),
+            spl_vault: spl_vault_info.clone(),
+            spl_user_funds_vault: spl_user_funds_vault_info.clone(),
+            spl_vault_ata: spl_vault_ata_info.clone(),
+            spl_user_funds_vault_ata: spl_user_funds_vault_ata_info.clone(),
+            token_inventory: token_inventory_info.clone(),
+            rebalancer_limit: rebalancer_limit_info.clone(),
+            token_program: Program::try_from(&token_program_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RebalanceVaultsBumps::default(),
+        };
+
+        let mut params = RebalanceVaultsParams {
+            token_mint,
+            amount: 1000,
+            direction: RebalanceDirection::UserFundsToRfq,
+        };
+        rebalance_vaults(&ctx, &params)?;
+        let inventory =
+            TokenInventory::try_deserialize(&mut &token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.user_funds, 0);
+        assert_eq!(inventory.rfq_inventory, 1100);
+
+        // the ata balances are untouched in tests, so the RFQ ATA still holds 100
+        params.direction = RebalanceDirection::RfqToUserFunds;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::NotEnoughSplTokenBalance.into()
+        );
+
+        // atas passed in the wrong order
+        ctx.accounts.spl_vault_ata = spl_user_funds_vault_ata_info.clone();
+        ctx.accounts.spl_user_funds_vault_ata = spl_vault_ata_info.clone();
+        params.amount = 50;
+        let result = rebalance_vaults(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidTokenOwner.into());
+        Ok(())
+    }
+}
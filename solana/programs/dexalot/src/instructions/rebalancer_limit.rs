@@ -0,0 +1,318 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{ADMIN_SEED, REBALANCER_LIMIT_SEED, REBALANCER_SEED};
+use crate::errors::DexalotError;
+use crate::events::ParameterUpdatedEvent;
+use crate::state::RebalancerLimit;
+
+#[derive(Accounts)]
+#[instruction(params: SetRebalancerLimitParams)]
+pub struct SetRebalancerLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    /// CHECK: the rebalancer pda
+    #[account(
+        seeds = [REBALANCER_SEED, params.account.as_ref()],
+        bump
+    )]
+    pub rebalancer: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RebalancerLimit::LEN,
+        seeds = [REBALANCER_LIMIT_SEED, params.account.as_ref(), params.token_mint.as_ref()],
+        bump
+    )]
+    pub rebalancer_limit: Account<'info, RebalancerLimit>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct SetRebalancerLimitParams {
+    pub account: Pubkey,
+    pub token_mint: Pubkey,
+    pub limit: u64,
+}
+
+/// Sets how much of a token a rebalancer can claim or move between the vaults per epoch
+/// `RebalancerLimit::UNLIMITED` lets the rebalancer claim the token without a cap
+pub fn set_rebalancer_limit(
+    ctx: &mut Context<SetRebalancerLimit>,
+    params: &SetRebalancerLimitParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    let rebalancer = &ctx.accounts.rebalancer;
+
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(
+        rebalancer.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let rebalancer_limit = &mut ctx.accounts.rebalancer_limit;
+    let old_limit = rebalancer_limit.limit;
+    rebalancer_limit.limit = params.limit;
+
+    emit!(ParameterUpdatedEvent {
+        pair: params.token_mint.to_bytes(),
+        parameter: "P-REBALLIMIT".to_owned(),
+        old_value: old_limit,
+        new_value: params.limit
+    });
+
+    Ok(())
+}
+
+/// Returns the withdrawal limit PDA of a rebalancer for a token, `Pubkey::default()` being native SOL
+pub fn rebalancer_limit_address(
+    rebalancer: &Pubkey,
+    token_mint: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[REBALANCER_LIMIT_SEED, rebalancer.as_ref(), token_mint.as_ref()],
+        program_id,
+    )
+    .0
+}
+
+/// Records a claim against the rebalancer's limit for the token in the current epoch
+/// Tokens whose limit was never set cannot be claimed
+pub fn record_rebalancer_withdrawal(rebalancer_limit: &AccountInfo, amount: u64) -> Result<()> {
+    require_keys_eq!(
+        *rebalancer_limit.owner,
+        crate::ID,
+        DexalotError::WithdrawalLimitNotSet
+    );
+    let epoch = current_epoch()?;
+    let mut data = rebalancer_limit.try_borrow_mut_data()?;
+    let mut state = RebalancerLimit::try_deserialize(&mut &data[..])?;
+    state.record_withdrawal(amount, epoch)?;
+    state.try_serialize(&mut &mut data[..])
+}
+
+/// Returns the epoch the per epoch limits are tracked in
+pub fn current_epoch() -> Result<u64> {
+    if cfg!(not(test)) {
+        Ok(Clock::get()?.epoch)
+    } else {
+        Ok(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{create_account_info, create_rebalancer_limit_data};
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_set_rebalancer_limit() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let rebalancer_key = Pubkey::new_unique();
+        let mut rebalancer_lamports = 100;
+        let mut rebalancer_data = vec![0u8; 10];
+        let rebalancer_info = create_account_info(
+            &rebalancer_key,
+            false,
+            false,
+            &mut rebalancer_lamports,
+            &mut rebalancer_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = vec![0u8; RebalancerLimit::LEN - 8];
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            Some(RebalancerLimit::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = SetRebalancerLimit {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            rebalancer: rebalancer_info.clone(),
+            rebalancer_limit: Account::try_from(&rebalancer_limit_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetRebalancerLimitBumps::default(),
+        };
+        let mut params = SetRebalancerLimitParams {
+            account: Pubkey::new_unique(),
+            token_mint,
+            limit: 500,
+        };
+
+        // not an admin
+        let result = set_rebalancer_limit(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        set_rebalancer_limit(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.rebalancer_limit.limit, 500);
+
+        // updating keeps the withdrawn amount of the epoch
+        ctx.accounts.rebalancer_limit.record_withdrawal(200, 1)?;
+        params.limit = RebalancerLimit::UNLIMITED;
+        set_rebalancer_limit(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.rebalancer_limit.limit, RebalancerLimit::UNLIMITED);
+        assert_eq!(ctx.accounts.rebalancer_limit.withdrawn, 200);
+
+        // the account is not a rebalancer
+        let mut not_rebalancer_lamports = 100;
+        let mut not_rebalancer_data = vec![];
+        let not_rebalancer_info = create_account_info(
+            &rebalancer_key,
+            false,
+            false,
+            &mut not_rebalancer_lamports,
+            &mut not_rebalancer_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        ctx.accounts.rebalancer = not_rebalancer_info;
+        let result = set_rebalancer_limit(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_rebalancer_withdrawal() -> Result<()> {
+        let program_id = crate::id();
+
+        let rebalancer_limit_key = Pubkey::new_unique();
+        let mut rebalancer_limit_lamports = 100;
+        let mut rebalancer_limit_data = create_rebalancer_limit_data(1000);
+        let rebalancer_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut rebalancer_limit_lamports,
+            &mut rebalancer_limit_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        record_rebalancer_withdrawal(&rebalancer_limit_info, 600)?;
+        record_rebalancer_withdrawal(&rebalancer_limit_info, 400)?;
+        let result = record_rebalancer_withdrawal(&rebalancer_limit_info, 1);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::WithdrawalLimitExceeded.into()
+        );
+
+        // tokens whose limit was never set cannot be claimed
+        let mut missing_limit_lamports = 0;
+        let mut missing_limit_data = vec![];
+        let missing_limit_info = create_account_info(
+            &rebalancer_limit_key,
+            false,
+            true,
+            &mut missing_limit_lamports,
+            &mut missing_limit_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let result = record_rebalancer_withdrawal(&missing_limit_info, 1);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::WithdrawalLimitNotSet.into()
+        );
+
+        // the withdrawn amount resets on a new epoch
+        let mut state =
+            RebalancerLimit::try_deserialize(&mut &rebalancer_limit_info.try_borrow_data()?[..])?;
+        state.record_withdrawal(1000, 2)?;
+        assert_eq!(state.epoch, 2);
+        let result = state.record_withdrawal(1, 2);
+        assert_eq!(
+            result.unwrap_err(),
+            DexalotError::WithdrawalLimitExceeded.into()
+        );
+
+        // an unlimited rebalancer is only bounded by the vaults
+        state.limit = RebalancerLimit::UNLIMITED;
+        state.record_withdrawal(u64::MAX / 2, 2)?;
+        Ok(())
+    }
+}
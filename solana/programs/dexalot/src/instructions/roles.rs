@@ -1,85 +1,258 @@
 use anchor_lang::prelude::*;
 
-use crate::consts::{ADMIN_SEED, REBALANCER_SEED};
+use crate::consts::{ADMIN_SEED, MULTISIG_CONFIG_SEED, PORTFOLIO_SEED, ROLE_REGISTRY_SEED};
 use crate::errors::DexalotError;
-use crate::events::{RoleGrantedEvent, RoleRevokedEvent};
-use crate::state::{Admin, Rebalancer};
-
-pub fn add_admin(ctx: &Context<AddAdmin>, params: &AdminParams) -> Result<()> {
+use crate::events::{
+    AdminTransferProposedEvent, AdminTransferredEvent, RoleGrantedEvent, RoleRevokedEvent,
+};
+use crate::instructions::{authorize_action, load_multisig_config, WriteConfig};
+use crate::map_utils::{create_entry, entry_exists};
+use crate::state::{
+    Admin, Portfolio, Proposal, Rebalancer, Role, RoleRegistry, RoleType, SensitiveAction,
+};
+
+pub fn grant_role(ctx: &mut Context<GrantRole>, params: &RoleParams) -> Result<()> {
     require!(
         params.account != Pubkey::default(),
         DexalotError::ZeroAccount
     );
 
-    let admin = &ctx.accounts.admin;
-    // Verify that user is an admin by checking their PDA.
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::GrantRole,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    let role_member = &ctx.accounts.role_member;
     require!(
-        admin.owner == ctx.program_id,
-        DexalotError::UnauthorizedSigner
+        !entry_exists(role_member),
+        DexalotError::RoleAlreadyGranted
     );
 
+    // the member PDA holds the role's own data
+    let payer = ctx.accounts.authority.to_account_info();
+    let seed = params.role.seed();
+    let account = params.account.as_ref();
+    let system_program = &ctx.accounts.system_program;
+    match params.role {
+        RoleType::Admin => create_entry(
+            &payer,
+            role_member,
+            &Admin {},
+            Admin::LEN,
+            seed,
+            account,
+            ctx.program_id,
+            system_program,
+            None,
+        )?,
+        RoleType::Rebalancer => create_entry(
+            &payer,
+            role_member,
+            &Rebalancer {},
+            Rebalancer::LEN,
+            seed,
+            account,
+            ctx.program_id,
+            system_program,
+            None,
+        )?,
+        RoleType::VolatilityAdmin | RoleType::Pauser => create_entry(
+            &payer,
+            role_member,
+            &Role {},
+            Role::LEN,
+            seed,
+            account,
+            ctx.program_id,
+            system_program,
+            None,
+        )?,
+    }
+
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.role = params.role;
+    role_registry.add_member(params.account)?;
+
     emit!(RoleGrantedEvent {
-        role: [0; 32], // 0 for admin
+        role: params.role.role_id(),
         account: params.account,
     });
 
     Ok(())
 }
 
-#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
-pub struct AdminParams {
-    account: Pubkey,
-}
-
-pub fn remove_admin(ctx: &Context<RemoveAdmin>, params: &AdminParams) -> Result<()> {
+pub fn revoke_role(ctx: &mut Context<RevokeRole>, params: &RoleParams) -> Result<()> {
     require!(
         params.account != Pubkey::default(),
         DexalotError::ZeroAccount
     );
 
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::RevokeRole,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    let role_member = &ctx.accounts.role_member;
+    require!(
+        role_member.owner == ctx.program_id && !role_member.data_is_empty(),
+        DexalotError::RoleNotGranted
+    );
+
+    // a listed admin has to remain to manage the program, enough to reach the multisig threshold
+    if params.role == RoleType::Admin {
+        let remaining_admins = ctx
+            .accounts
+            .role_registry
+            .members
+            .iter()
+            .filter(|member| **member != params.account)
+            .count();
+        require!(remaining_admins > 0, DexalotError::LastAdminRemoval);
+        require!(
+            remaining_admins >= multisig_config.threshold as usize,
+            DexalotError::InvalidMultisigThreshold
+        );
+    }
+
+    // Refund lamports to the receiver and wipe the data so the runtime purges the account
+    let lamports = role_member.lamports();
+    ctx.accounts.receiver.add_lamports(lamports)?;
+    role_member.sub_lamports(lamports)?;
+    role_member.try_borrow_mut_data()?.fill(0);
+
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.role = params.role;
+    role_registry.remove_member(&params.account);
+
+    emit!(RoleRevokedEvent {
+        role: params.role.role_id(),
+        account: params.account,
+    });
+
+    Ok(())
+}
+
+/// Lists a member granted before the role registries existed, the member PDA has to be live
+pub fn register_role_member(
+    ctx: &mut Context<RegisterRoleMember>,
+    params: &RoleParams,
+) -> Result<()> {
     let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
 
+    let role_member = &ctx.accounts.role_member;
+    require!(
+        role_member.owner == ctx.program_id && !role_member.data_is_empty(),
+        DexalotError::RoleNotGranted
+    );
+
+    let role_registry = &mut ctx.accounts.role_registry;
+    role_registry.role = params.role;
+    role_registry.add_member(params.account)?;
+
+    Ok(())
+}
+
+/// Proposes a new holder of `Portfolio.admin`, the default key cancels the proposal
+pub fn propose_admin(ctx: &mut Context<WriteConfig>, params: &ProposeAdminParams) -> Result<()> {
+    let admin = &ctx.accounts.admin;
     require!(
         admin.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
 
-    emit!(RoleRevokedEvent {
-        role: [0; 32],
-        admin: params.account,
+    let authority = ctx.accounts.authority.key();
+    let portfolio = &mut ctx.accounts.portfolio;
+    require!(
+        authority == portfolio.admin,
+        DexalotError::UnauthorizedSigner
+    );
+
+    portfolio.pending_admin = params.new_admin;
+
+    emit!(AdminTransferProposedEvent {
+        admin: portfolio.admin,
+        pending_admin: params.new_admin,
     });
 
     Ok(())
 }
 
+/// Completes the handover, the pending admin has to hold the admin role
+pub fn accept_admin(ctx: &mut Context<AcceptAdmin>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let portfolio = &mut ctx.accounts.portfolio;
+    require!(
+        portfolio.pending_admin != Pubkey::default() && portfolio.pending_admin == authority,
+        DexalotError::NotPendingAdmin
+    );
+
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let previous_admin = portfolio.admin;
+    portfolio.admin = authority;
+    portfolio.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferredEvent {
+        previous_admin,
+        new_admin: authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct ProposeAdminParams {
+    pub new_admin: Pubkey,
+}
+
 #[derive(Accounts)]
-#[instruction(account: Pubkey)]
-pub struct AddAdmin<'info> {
-    #[account(mut)]
+pub struct AcceptAdmin<'info> {
     pub authority: Signer<'info>,
 
-    /// CHECK: Verify that user is an admin by checking their PDA.
     #[account(
-        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        mut,
+        seeds = [PORTFOLIO_SEED],
         bump
     )]
-    pub admin: AccountInfo<'info>,
+    pub portfolio: Account<'info, Portfolio>,
 
+    /// CHECK: Verify that the pending admin holds the admin role
     #[account(
-        init,
-        payer = authority,
-        space = Admin::LEN,
-        seeds = [ADMIN_SEED, account.as_ref()],
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
         bump
     )]
-    pub new_admin: Account<'info, Admin>,
+    pub admin: AccountInfo<'info>,
+}
 
-    pub system_program: Program<'info, System>,
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct RoleParams {
+    pub role: RoleType,
+    pub account: Pubkey,
 }
 
 #[derive(Accounts)]
-#[instruction(account: Pubkey)]
-pub struct RemoveAdmin<'info> {
+#[instruction(params: RoleParams)]
+pub struct GrantRole<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -90,23 +263,39 @@ pub struct RemoveAdmin<'info> {
     )]
     pub admin: AccountInfo<'info>,
 
-    #[account(mut)]
-    pub receiver: SystemAccount<'info>,
-
+    /// CHECK: Created in the instruction with the role's data
     #[account(
         mut,
-        close = receiver, // Refund lamports to the receiver
-        seeds = [ADMIN_SEED, account.as_ref()],
+        seeds = [params.role.seed(), params.account.as_ref()],
+        bump
+    )]
+    pub role_member: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = RoleRegistry::LEN,
+        seeds = [ROLE_REGISTRY_SEED, params.role.seed()],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(
+        seeds = [MULTISIG_CONFIG_SEED],
         bump
     )]
-    pub admin_to_remove: Account<'info, Admin>,
+    pub multisig_config: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(account: Pubkey)]
-pub struct AddRebalancer<'info> {
+#[instruction(params: RoleParams)]
+pub struct RevokeRole<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -117,21 +306,43 @@ pub struct AddRebalancer<'info> {
     )]
     pub admin: AccountInfo<'info>,
 
+    #[account(mut)]
+    pub receiver: SystemAccount<'info>,
+
+    /// CHECK: Closed in the instruction, owner is checked
     #[account(
-        init,
+        mut,
+        seeds = [params.role.seed(), params.account.as_ref()],
+        bump
+    )]
+    pub role_member: AccountInfo<'info>,
+
+    // members granted before registries existed are listed with register_role_member
+    #[account(
+        init_if_needed,
         payer = authority,
-        space = Rebalancer::LEN,
-        seeds = [REBALANCER_SEED, account.as_ref()],
+        space = RoleRegistry::LEN,
+        seeds = [ROLE_REGISTRY_SEED, params.role.seed()],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(
+        seeds = [MULTISIG_CONFIG_SEED],
         bump
     )]
-    pub new_rebalancer: Account<'info, Rebalancer>,
+    pub multisig_config: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(account: Pubkey)]
-pub struct RemoveRebalancer<'info> {
+#[instruction(params: RoleParams)]
+pub struct RegisterRoleMember<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -142,79 +353,42 @@ pub struct RemoveRebalancer<'info> {
     )]
     pub admin: AccountInfo<'info>,
 
-    #[account(mut)]
-    pub receiver: SystemAccount<'info>,
+    /// CHECK: Owner and data are checked in the instruction
+    #[account(
+        seeds = [params.role.seed(), params.account.as_ref()],
+        bump
+    )]
+    pub role_member: AccountInfo<'info>,
 
     #[account(
-        mut,
-        close = receiver, // Refund lamports to the receiver
-        seeds = [REBALANCER_SEED, account.as_ref()],
+        init_if_needed,
+        payer = authority,
+        space = RoleRegistry::LEN,
+        seeds = [ROLE_REGISTRY_SEED, params.role.seed()],
         bump
     )]
-    pub rebalancer_to_remove: Account<'info, Rebalancer>,
+    pub role_registry: Account<'info, RoleRegistry>,
 
     pub system_program: Program<'info, System>,
 }
 
-#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
-pub struct RebalancerParams {
-    account: Pubkey,
-}
-
-pub fn add_rebalancer(ctx: &Context<AddRebalancer>, params: &RebalancerParams) -> Result<()> {
-    require!(
-        params.account != Pubkey::default(),
-        DexalotError::ZeroAccount
-    );
-    let admin = &ctx.accounts.admin;
-
-    //CHECK: Verify that user is an admin by checking their PDA.
-    require!(
-        admin.owner == ctx.program_id,
-        DexalotError::UnauthorizedSigner
-    );
-
-    emit!(RoleGrantedEvent {
-        role: [1; 32], // 1 for rebalancer
-        account: params.account,
-    });
-
-    Ok(())
-}
-
-pub fn remove_rebalancer(ctx: &Context<RemoveRebalancer>, params: &RebalancerParams) -> Result<()> {
-    require!(
-        params.account != Pubkey::default(),
-        DexalotError::ZeroAccount
-    );
-    let admin = &ctx.accounts.admin;
-
-    require!(
-        admin.owner == ctx.program_id,
-        DexalotError::UnauthorizedSigner
-    );
-
-    emit!(RoleRevokedEvent {
-        role: [1; 32], // 1 for rebalancer
-        admin: params.account,
-    });
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use anchor_lang::{system_program, Discriminator};
     use super::*;
+    use crate::instructions::WriteConfigBumps;
+    use crate::consts::{MAX_ROLE_MEMBERS, PAUSER_SEED, REBALANCER_SEED};
+    use crate::state::MultisigConfig;
     use crate::test_utils::create_account_info;
 
     #[test]
-    fn test_add_admin_success() -> Result<()> {
+    fn test_grant_role_success() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let new_admin_param = Pubkey::new_unique();
+        let new_pauser = Pubkey::new_unique();
         let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let new_admin_pda = Pubkey::find_program_address(&[ADMIN_SEED, new_admin_param.as_ref()], &program_id).0;
+        let role_member_pda = Pubkey::find_program_address(&[PAUSER_SEED, new_pauser.as_ref()], &program_id).0;
+        let role_registry_pda = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, PAUSER_SEED], &program_id).0;
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
@@ -242,17 +416,30 @@ mod tests {
             None,
         );
 
-        let mut new_admin_lamports = 0;
-        let mut new_admin_data = vec![0u8; Admin::LEN];
-        let new_admin_info = create_account_info(
-            &new_admin_pda,
+        let mut role_member_lamports = 0;
+        let mut role_member_data = vec![];
+        let role_member_info = create_account_info(
+            &role_member_pda,
+            false,
+            true,
+            &mut role_member_lamports,
+            &mut role_member_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut registry_lamports = 100;
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        let role_registry_info = create_account_info(
+            &role_registry_pda,
             false,
             true,
-            &mut new_admin_lamports,
-            &mut new_admin_data,
+            &mut registry_lamports,
+            &mut registry_data,
             &program_id,
             false,
-            Some(Admin::discriminator())
+            Some(RoleRegistry::discriminator()),
         );
 
         let system_program_key = system_program::ID;
@@ -269,36 +456,73 @@ mod tests {
             None,
         );
 
-        let mut add_admin_accounts = AddAdmin {
+        // the multisig config is not created, an admin is enough
+
+        let multisig_config_key = Pubkey::new_unique();
+
+        let mut multisig_config_lamports = 0;
+
+        let mut multisig_config_data = vec![];
+
+        let multisig_config = create_account_info(
+
+            &multisig_config_key,
+
+            false,
+
+            false,
+
+            &mut multisig_config_lamports,
+
+            &mut multisig_config_data,
+
+            &program_id,
+
+            false,
+
+            None,
+
+        );
+
+
+        let mut grant_role_accounts = GrantRole {
             authority: Signer::try_from(&authority_info)?,
             admin: admin_info,
-            new_admin: Account::try_from(&new_admin_info)?,
+            multisig_config,
+            proposal: None,
+            role_member: role_member_info,
+            role_registry: Account::try_from(&role_registry_info)?,
             system_program: Program::try_from(&system_program_info)?,
+
         };
 
-        let params = AdminParams {
-            account: new_admin_param,
+        let params = RoleParams {
+            role: RoleType::Pauser,
+            account: new_pauser,
         };
 
         let mut ctx = Context {
-            accounts: &mut add_admin_accounts,
+            accounts: &mut grant_role_accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: AddAdminBumps::default(),
+            bumps: GrantRoleBumps::default(),
         };
 
-        let result = add_admin(&mut ctx, &params);
-        assert!(result.is_ok());
+        grant_role(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.role_registry.role, RoleType::Pauser);
+        assert_eq!(ctx.accounts.role_registry.members, vec![new_pauser]);
+        assert_eq!(ctx.accounts.role_registry.member_count(), 1);
         Ok(())
     }
 
     #[test]
-    fn test_add_admin_negative_cases() -> Result<()> {
+    fn test_grant_role_negative_cases() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let new_admin_param = Pubkey::new_unique();
+        let new_admin = Pubkey::new_unique();
         let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let new_admin_pda = Pubkey::find_program_address(&[ADMIN_SEED, new_admin_param.as_ref()], &program_id).0;
+        let role_member_pda = Pubkey::find_program_address(&[ADMIN_SEED, new_admin.as_ref()], &program_id).0;
+        let role_registry_pda = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, ADMIN_SEED], &program_id).0;
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
@@ -326,17 +550,31 @@ mod tests {
             None,
         );
 
-        let mut new_admin_lamports = 0;
-        let mut new_admin_data = vec![0u8; Admin::LEN];
-        let new_admin_info = create_account_info(
-            &new_admin_pda,
+        // the account is already an admin
+        let mut role_member_lamports = 100;
+        let mut role_member_data = vec![0u8; Admin::LEN];
+        let role_member_info = create_account_info(
+            &role_member_pda,
             false,
             true,
-            &mut new_admin_lamports,
-            &mut new_admin_data,
+            &mut role_member_lamports,
+            &mut role_member_data,
             &program_id,
             false,
-            Some(Admin::discriminator())
+            Some(Admin::discriminator()),
+        );
+
+        let mut registry_lamports = 100;
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        let role_registry_info = create_account_info(
+            &role_registry_pda,
+            false,
+            true,
+            &mut registry_lamports,
+            &mut registry_data,
+            &program_id,
+            false,
+            Some(RoleRegistry::discriminator()),
         );
 
         let system_program_key = system_program::ID;
@@ -353,41 +591,81 @@ mod tests {
             None,
         );
 
-        let mut add_admin_accounts = AddAdmin {
+        // the multisig config is not created, an admin is enough
+
+        let multisig_config_key = Pubkey::new_unique();
+
+        let mut multisig_config_lamports = 0;
+
+        let mut multisig_config_data = vec![];
+
+        let multisig_config = create_account_info(
+
+            &multisig_config_key,
+
+            false,
+
+            false,
+
+            &mut multisig_config_lamports,
+
+            &mut multisig_config_data,
+
+            &program_id,
+
+            false,
+
+            None,
+
+        );
+
+
+        let mut grant_role_accounts = GrantRole {
             authority: Signer::try_from(&authority_info)?,
-            admin: admin_info,
-            new_admin: Account::try_from(&new_admin_info)?,
+            admin: admin_info.clone(),
+            multisig_config,
+            proposal: None,
+            role_member: role_member_info,
+            role_registry: Account::try_from(&role_registry_info)?,
             system_program: Program::try_from(&system_program_info)?,
+
         };
 
-        let mut params = AdminParams {
-            account: new_admin_param,
+        let mut params = RoleParams {
+            role: RoleType::Admin,
+            account: new_admin,
         };
 
         let mut ctx = Context {
-            accounts: &mut add_admin_accounts,
+            accounts: &mut grant_role_accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: AddAdminBumps::default(),
+            bumps: GrantRoleBumps::default(),
         };
 
-        let result = add_admin(&mut ctx, &params);
+        let result = grant_role(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
 
-        params.account = Pubkey::default();
+        admin_info.assign(&program_id);
+        let result = grant_role(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::RoleAlreadyGranted.into());
 
-        let result = add_admin(&mut ctx, &params);
+        params.account = Pubkey::default();
+        let result = grant_role(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::ZeroAccount.into());
+        assert_eq!(ctx.accounts.role_registry.member_count(), 0);
         Ok(())
     }
 
     #[test]
-    fn test_remove_admin_success() -> Result<()> {
+    fn test_revoke_role_success() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let admin_to_remove_param = Pubkey::new_unique();
+        let pauser = Pubkey::new_unique();
+        let other_pauser = Pubkey::new_unique();
         let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let admin_to_remove_pda = Pubkey::find_program_address(&[ADMIN_SEED, admin_to_remove_param.as_ref()], &program_id).0;
+        let role_member_pda = Pubkey::find_program_address(&[PAUSER_SEED, pauser.as_ref()], &program_id).0;
+        let role_registry_pda = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, PAUSER_SEED], &program_id).0;
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
@@ -415,19 +693,6 @@ mod tests {
             None,
         );
 
-        let mut remove_admin_lamports = 100;
-        let mut remove_admin_data = vec![0u8; Admin::LEN];
-        let admin_to_remove_info = create_account_info(
-            &admin_to_remove_pda,
-            false,
-            true,
-            &mut remove_admin_lamports,
-            &mut remove_admin_data,
-            &program_id,
-            false,
-            Some(Admin::discriminator())
-        );
-
         let receiver_key = Pubkey::new_unique();
         let mut recv_lamports = 100;
         let mut recv_data = vec![0u8; 10];
@@ -442,6 +707,37 @@ mod tests {
             None,
         );
 
+        let mut role_member_lamports = 50;
+        let mut role_member_data = vec![0u8; Role::LEN];
+        let role_member_info = create_account_info(
+            &role_member_pda,
+            false,
+            true,
+            &mut role_member_lamports,
+            &mut role_member_data,
+            &program_id,
+            false,
+            Some(Role::discriminator()),
+        );
+
+        let registry = RoleRegistry {
+            role: RoleType::Pauser,
+            members: vec![pauser, other_pauser],
+        };
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        registry.try_serialize(&mut &mut registry_data[..])?;
+        let mut registry_lamports = 100;
+        let role_registry_info = create_account_info(
+            &role_registry_pda,
+            false,
+            true,
+            &mut registry_lamports,
+            &mut registry_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let system_program_key = system_program::ID;
         let mut sp_lamports = 100;
         let mut sp_data = vec![0u8; 10];
@@ -456,37 +752,76 @@ mod tests {
             None,
         );
 
-        let mut remove_admin_accounts = RemoveAdmin {
+        // the multisig config is not created, an admin is enough
+
+        let multisig_config_key = Pubkey::new_unique();
+
+        let mut multisig_config_lamports = 0;
+
+        let mut multisig_config_data = vec![];
+
+        let multisig_config = create_account_info(
+
+            &multisig_config_key,
+
+            false,
+
+            false,
+
+            &mut multisig_config_lamports,
+
+            &mut multisig_config_data,
+
+            &program_id,
+
+            false,
+
+            None,
+
+        );
+
+
+        let mut revoke_role_accounts = RevokeRole {
             authority: Signer::try_from(&authority_info)?,
             admin: admin_info,
+            multisig_config,
+            proposal: None,
             receiver: SystemAccount::try_from(&receiver_info)?,
-            admin_to_remove: Account::try_from(&admin_to_remove_info)?,
+            role_member: role_member_info.clone(),
+            role_registry: Account::try_from(&role_registry_info)?,
             system_program: Program::try_from(&system_program_info)?,
+
         };
 
-        let params = AdminParams {
-            account: admin_to_remove_param,
+        let params = RoleParams {
+            role: RoleType::Pauser,
+            account: pauser,
         };
 
-        let ctx = Context {
-            accounts: &mut remove_admin_accounts,
+        let mut ctx = Context {
+            accounts: &mut revoke_role_accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: RemoveAdminBumps::default(),
+            bumps: RevokeRoleBumps::default(),
         };
 
-        let result = remove_admin(&ctx, &params);
-        assert!(result.is_ok());
+        revoke_role(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.role_registry.members, vec![other_pauser]);
+        assert_eq!(ctx.accounts.role_registry.member_count(), 1);
+        assert_eq!(ctx.accounts.receiver.lamports(), 150);
+        assert_eq!(role_member_info.lamports(), 0);
+        assert!(role_member_info.try_borrow_data()?.iter().all(|byte| *byte == 0));
         Ok(())
     }
 
     #[test]
-    fn test_remove_admin_negative_cases() -> Result<()> {
+    fn test_revoke_role_negative_cases() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let admin_to_remove_param = Pubkey::new_unique();
+        let rebalancer = Pubkey::new_unique();
         let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let admin_to_remove_pda = Pubkey::find_program_address(&[ADMIN_SEED, admin_to_remove_param.as_ref()], &program_id).0;
+        let role_member_pda = Pubkey::find_program_address(&[REBALANCER_SEED, rebalancer.as_ref()], &program_id).0;
+        let role_registry_pda = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, REBALANCER_SEED], &program_id).0;
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
@@ -514,19 +849,6 @@ mod tests {
             None,
         );
 
-        let mut remove_admin_lamports = 100;
-        let mut remove_admin_data = vec![0u8; Admin::LEN];
-        let admin_to_remove_info = create_account_info(
-            &admin_to_remove_pda,
-            false,
-            true,
-            &mut remove_admin_lamports,
-            &mut remove_admin_data,
-            &program_id,
-            false,
-            Some(Admin::discriminator())
-        );
-
         let receiver_key = Pubkey::new_unique();
         let mut recv_lamports = 100;
         let mut recv_data = vec![0u8; 10];
@@ -541,6 +863,33 @@ mod tests {
             None,
         );
 
+        // the account is not a rebalancer
+        let mut role_member_lamports = 0;
+        let mut role_member_data = vec![];
+        let role_member_info = create_account_info(
+            &role_member_pda,
+            false,
+            true,
+            &mut role_member_lamports,
+            &mut role_member_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let mut registry_lamports = 100;
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        let role_registry_info = create_account_info(
+            &role_registry_pda,
+            false,
+            true,
+            &mut registry_lamports,
+            &mut registry_data,
+            &program_id,
+            false,
+            Some(RoleRegistry::discriminator()),
+        );
+
         let system_program_key = system_program::ID;
         let mut sp_lamports = 100;
         let mut sp_data = vec![0u8; 10];
@@ -555,42 +904,113 @@ mod tests {
             None,
         );
 
-        let mut remove_admin_accounts = RemoveAdmin {
+        // the multisig config is not created, an admin is enough
+
+        let multisig_config_key = Pubkey::new_unique();
+
+        let mut multisig_config_lamports = 0;
+
+        let mut multisig_config_data = vec![];
+
+        let multisig_config = create_account_info(
+
+            &multisig_config_key,
+
+            false,
+
+            false,
+
+            &mut multisig_config_lamports,
+
+            &mut multisig_config_data,
+
+            &program_id,
+
+            false,
+
+            None,
+
+        );
+
+
+        let mut revoke_role_accounts = RevokeRole {
             authority: Signer::try_from(&authority_info)?,
-            admin: admin_info,
+            admin: admin_info.clone(),
+            multisig_config,
+            proposal: None,
             receiver: SystemAccount::try_from(&receiver_info)?,
-            admin_to_remove: Account::try_from(&admin_to_remove_info)?,
+            role_member: role_member_info,
+            role_registry: Account::try_from(&role_registry_info)?,
             system_program: Program::try_from(&system_program_info)?,
+
         };
 
-        let mut params = AdminParams {
-            account: admin_to_remove_param,
+        let mut params = RoleParams {
+            role: RoleType::Rebalancer,
+            account: rebalancer,
         };
 
-        let ctx = Context {
-            accounts: &mut remove_admin_accounts,
+        let mut ctx = Context {
+            accounts: &mut revoke_role_accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: RemoveAdminBumps::default(),
+            bumps: RevokeRoleBumps::default(),
         };
 
-        let result = remove_admin(&ctx, &params);
+        let result = revoke_role(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
 
-        params.account = Pubkey::default();
+        admin_info.assign(&program_id);
+        let result = revoke_role(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::RoleNotGranted.into());
 
-        let result = remove_admin(&ctx, &params);
+        params.account = Pubkey::default();
+        let result = revoke_role(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::ZeroAccount.into());
         Ok(())
     }
 
     #[test]
-    fn test_add_rebalancer_success() -> Result<()> {
+    fn test_role_registry_members() -> Result<()> {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let mut registry = RoleRegistry {
+            role: RoleType::VolatilityAdmin,
+            members: vec![],
+        };
+
+        registry.add_member(first)?;
+        registry.add_member(second)?;
+        registry.add_member(first)?;
+        assert_eq!(registry.members, vec![first, second]);
+
+        // members missing from the registry are ignored
+        registry.remove_member(&Pubkey::new_unique());
+        registry.remove_member(&first);
+        assert_eq!(registry.members, vec![second]);
+        assert_eq!(registry.member_count(), 1);
+
+        registry.members = vec![Pubkey::default(); MAX_ROLE_MEMBERS];
+        let result = registry.add_member(first);
+        assert_eq!(result.unwrap_err(), DexalotError::RoleMembersFull.into());
+
+        assert_eq!(RoleType::Admin.role_id(), [0; 32]);
+        // keccak256("PAUSER_ROLE")
+        assert_eq!(
+            hex::encode(RoleType::Pauser.role_id()),
+            "65d7a28e3265b37a6474929f336521b332c1681b933f6cb9f3376673440d862a"
+        );
+        assert_eq!(RoleType::Pauser.seed(), PAUSER_SEED);
+        Ok(())
+    }
+
+    #[test]
+    fn test_revoke_last_admin() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let new_rebalancer_param = Pubkey::new_unique();
+        let other_admin = Pubkey::new_unique();
         let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let new_rebalancer_pda = Pubkey::find_program_address(&[REBALANCER_SEED, new_rebalancer_param.as_ref()], &program_id).0;
+        let role_registry_pda = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, ADMIN_SEED], &program_id).0;
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
@@ -605,8 +1025,9 @@ mod tests {
             None,
         );
 
+        // the admin removes their own role
         let mut admin_lamports = 100;
-        let mut admin_data = vec![0u8; 10];
+        let mut admin_data = vec![0u8; Admin::LEN];
         let admin_info = create_account_info(
             &admin_pda_key,
             false,
@@ -615,20 +1036,39 @@ mod tests {
             &mut admin_data,
             &program_id,
             false,
+            Some(Admin::discriminator()),
+        );
+
+        let receiver_key = Pubkey::new_unique();
+        let mut recv_lamports = 100;
+        let mut recv_data = vec![0u8; 10];
+        let receiver_info = create_account_info(
+            &receiver_key,
+            false,
+            true,
+            &mut recv_lamports,
+            &mut recv_data,
+            &system_program::ID,
+            false,
             None,
         );
 
-        let mut new_rebalancer_lamports = 0;
-        let mut new_rebalancer_data = vec![0u8; Rebalancer::LEN];
-        let new_rebalancer_info = create_account_info(
-            &new_rebalancer_pda,
+        let registry = RoleRegistry {
+            role: RoleType::Admin,
+            members: vec![authority_key],
+        };
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        registry.try_serialize(&mut &mut registry_data[..])?;
+        let mut registry_lamports = 100;
+        let role_registry_info = create_account_info(
+            &role_registry_pda,
             false,
             true,
-            &mut new_rebalancer_lamports,
-            &mut new_rebalancer_data,
+            &mut registry_lamports,
+            &mut registry_data,
             &program_id,
             false,
-            Some(Rebalancer::discriminator())
+            None,
         );
 
         let system_program_key = system_program::ID;
@@ -645,36 +1085,77 @@ mod tests {
             None,
         );
 
-        let mut add_rebalancer_accounts = AddRebalancer {
+        // the multisig config is not created, an admin is enough
+
+        let multisig_config_key = Pubkey::new_unique();
+
+        let mut multisig_config_lamports = 0;
+
+        let mut multisig_config_data = vec![];
+
+        let multisig_config = create_account_info(
+
+            &multisig_config_key,
+
+            false,
+
+            false,
+
+            &mut multisig_config_lamports,
+
+            &mut multisig_config_data,
+
+            &program_id,
+
+            false,
+
+            None,
+
+        );
+
+
+        let mut revoke_role_accounts = RevokeRole {
             authority: Signer::try_from(&authority_info)?,
-            admin: admin_info,
-            new_rebalancer: Account::try_from(&new_rebalancer_info)?,
+            admin: admin_info.clone(),
+            multisig_config,
+            proposal: None,
+            receiver: SystemAccount::try_from(&receiver_info)?,
+            role_member: admin_info,
+            role_registry: Account::try_from(&role_registry_info)?,
             system_program: Program::try_from(&system_program_info)?,
+
         };
 
-        let params = RebalancerParams {
-            account: new_rebalancer_param,
+        let params = RoleParams {
+            role: RoleType::Admin,
+            account: authority_key,
         };
 
         let mut ctx = Context {
-            accounts: &mut add_rebalancer_accounts,
+            accounts: &mut revoke_role_accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: AddRebalancerBumps::default(),
+            bumps: RevokeRoleBumps::default(),
         };
 
-        let result = add_rebalancer(&mut ctx, &params);
-        assert!(result.is_ok());
+        let result = revoke_role(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::LastAdminRemoval.into());
+        assert_eq!(ctx.accounts.role_registry.member_count(), 1);
+
+        ctx.accounts.role_registry.members.push(other_admin);
+        revoke_role(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.role_registry.members, vec![other_admin]);
         Ok(())
     }
 
     #[test]
-    fn test_add_rebalancer_negative_cases() -> Result<()> {
+    fn test_revoke_admin_from_empty_registry() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let new_rebalancer_param = Pubkey::new_unique();
+        let other_admin = Pubkey::new_unique();
         let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let new_rebalancer_pda = Pubkey::find_program_address(&[REBALANCER_SEED, new_rebalancer_param.as_ref()], &program_id).0;
+        let other_admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, other_admin.as_ref()], &program_id).0;
+        let role_registry_pda = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, ADMIN_SEED], &program_id).0;
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
@@ -689,30 +1170,73 @@ mod tests {
             None,
         );
 
+        // both admins were granted before the registry existed
         let mut admin_lamports = 100;
-        let mut admin_data = vec![0u8; 10];
+        let mut admin_data = vec![0u8; Admin::LEN];
         let admin_info = create_account_info(
             &admin_pda_key,
             false,
             true,
             &mut admin_lamports,
             &mut admin_data,
-            &authority_key,
+            &program_id,
+            false,
+            Some(Admin::discriminator()),
+        );
+
+        let mut other_admin_lamports = 100;
+        let mut other_admin_data = vec![0u8; Admin::LEN];
+        let other_admin_info = create_account_info(
+            &other_admin_pda_key,
+            false,
+            true,
+            &mut other_admin_lamports,
+            &mut other_admin_data,
+            &program_id,
+            false,
+            Some(Admin::discriminator()),
+        );
+
+        let not_granted_key = Pubkey::new_unique();
+        let mut not_granted_lamports = 0;
+        let mut not_granted_data = vec![];
+        let not_granted_info = create_account_info(
+            &not_granted_key,
+            false,
+            false,
+            &mut not_granted_lamports,
+            &mut not_granted_data,
+            &system_program::ID,
             false,
             None,
         );
 
-        let mut new_rebalancer_lamports = 0;
-        let mut new_rebalancer_data = vec![0u8; Rebalancer::LEN];
-        let new_rebalancer_info = create_account_info(
-            &new_rebalancer_pda,
+        let receiver_key = Pubkey::new_unique();
+        let mut recv_lamports = 100;
+        let mut recv_data = vec![0u8; 10];
+        let receiver_info = create_account_info(
+            &receiver_key,
             false,
             true,
-            &mut new_rebalancer_lamports,
-            &mut new_rebalancer_data,
+            &mut recv_lamports,
+            &mut recv_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        // the registry was just created by init_if_needed
+        let mut registry_lamports = 100;
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        let role_registry_info = create_account_info(
+            &role_registry_pda,
+            false,
+            true,
+            &mut registry_lamports,
+            &mut registry_data,
             &program_id,
             false,
-            Some(Rebalancer::discriminator())
+            Some(RoleRegistry::discriminator()),
         );
 
         let system_program_key = system_program::ID;
@@ -729,41 +1253,121 @@ mod tests {
             None,
         );
 
-        let mut add_rebalancer_accounts = AddRebalancer {
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let revoke_other_admin = RoleParams {
+            role: RoleType::Admin,
+            account: other_admin,
+        };
+
+        // unlisted admins are not counted, so nobody can be revoked yet
+        let mut revoke_role_accounts = RevokeRole {
             authority: Signer::try_from(&authority_info)?,
-            admin: admin_info,
-            new_rebalancer: Account::try_from(&new_rebalancer_info)?,
+            admin: admin_info.clone(),
+            multisig_config: multisig_config.clone(),
+            proposal: None,
+            receiver: SystemAccount::try_from(&receiver_info)?,
+            role_member: other_admin_info.clone(),
+            role_registry: Account::try_from(&role_registry_info)?,
             system_program: Program::try_from(&system_program_info)?,
         };
-
-        let mut params = RebalancerParams {
-            account: new_rebalancer_param,
+        let mut ctx = Context {
+            accounts: &mut revoke_role_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RevokeRoleBumps::default(),
         };
+        let result = revoke_role(&mut ctx, &revoke_other_admin);
+        assert_eq!(result.unwrap_err(), DexalotError::LastAdminRemoval.into());
 
-        let ctx = Context {
-            accounts: &mut add_rebalancer_accounts,
+        // members without a live PDA can't be listed
+        let mut register_accounts = RegisterRoleMember {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            role_member: not_granted_info,
+            role_registry: Account::try_from(&role_registry_info)?,
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut register_ctx = Context {
+            accounts: &mut register_accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: AddRebalancerBumps::default(),
+            bumps: RegisterRoleMemberBumps::default(),
         };
-
-        let result = add_rebalancer(&ctx, &params);
-        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
-
-        params.account = Pubkey::default();
-
-        let result = add_rebalancer(&ctx, &params);
-        assert_eq!(result.unwrap_err(), DexalotError::ZeroAccount.into());
+        let result = register_role_member(
+            &mut register_ctx,
+            &RoleParams {
+                role: RoleType::Admin,
+                account: Pubkey::new_unique(),
+            },
+        );
+        assert_eq!(result.unwrap_err(), DexalotError::RoleNotGranted.into());
+
+        // the remaining admin is listed
+        register_ctx.accounts.role_member = admin_info.clone();
+        register_role_member(
+            &mut register_ctx,
+            &RoleParams {
+                role: RoleType::Admin,
+                account: authority_key,
+            },
+        )?;
+        assert_eq!(register_ctx.accounts.role_registry.members, vec![authority_key]);
+        register_ctx.accounts.role_registry.exit(&program_id)?;
+
+        let mut revoke_role_accounts = RevokeRole {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            multisig_config,
+            proposal: None,
+            receiver: SystemAccount::try_from(&receiver_info)?,
+            role_member: other_admin_info,
+            role_registry: Account::try_from(&role_registry_info)?,
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut revoke_role_accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RevokeRoleBumps::default(),
+        };
+        revoke_role(&mut ctx, &revoke_other_admin)?;
+        assert_eq!(ctx.accounts.role_registry.members, vec![authority_key]);
+
+        // the listed admin is the last one
+        ctx.accounts.role_member = admin_info;
+        let result = revoke_role(
+            &mut ctx,
+            &RoleParams {
+                role: RoleType::Admin,
+                account: authority_key,
+            },
+        );
+        assert_eq!(result.unwrap_err(), DexalotError::LastAdminRemoval.into());
         Ok(())
     }
 
     #[test]
-    fn test_remove_rebalancer_success() -> Result<()> {
+    fn test_revoke_admin_below_multisig_threshold() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let rebalancer_to_remove_param = Pubkey::new_unique();
+        let other_admin = Pubkey::new_unique();
+        let revoked_admin = Pubkey::new_unique();
         let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let rebalancer_to_remove_pda = Pubkey::find_program_address(&[REBALANCER_SEED, rebalancer_to_remove_param.as_ref()], &program_id).0;
+        let revoked_admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, revoked_admin.as_ref()], &program_id).0;
+        let role_registry_pda = Pubkey::find_program_address(&[ROLE_REGISTRY_SEED, ADMIN_SEED], &program_id).0;
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
@@ -779,29 +1383,29 @@ mod tests {
         );
 
         let mut admin_lamports = 100;
-        let mut admin_data = vec![0u8; 10];
+        let mut admin_data = vec![0u8; Admin::LEN];
         let admin_info = create_account_info(
             &admin_pda_key,
             false,
-            true,
+            false,
             &mut admin_lamports,
             &mut admin_data,
             &program_id,
             false,
-            None,
+            Some(Admin::discriminator()),
         );
 
-        let mut rebalancer_lamports = 100;
-        let mut rebalancer_data = vec![0u8; Rebalancer::LEN];
-        let rebalancer_info = create_account_info(
-            &rebalancer_to_remove_pda,
+        let mut revoked_admin_lamports = 100;
+        let mut revoked_admin_data = vec![0u8; Admin::LEN];
+        let revoked_admin_info = create_account_info(
+            &revoked_admin_pda_key,
             false,
             true,
-            &mut rebalancer_lamports,
-            &mut rebalancer_data,
+            &mut revoked_admin_lamports,
+            &mut revoked_admin_data,
             &program_id,
             false,
-            Some(Rebalancer::discriminator())
+            Some(Admin::discriminator()),
         );
 
         let receiver_key = Pubkey::new_unique();
@@ -818,6 +1422,24 @@ mod tests {
             None,
         );
 
+        let registry = RoleRegistry {
+            role: RoleType::Admin,
+            members: vec![authority_key, other_admin, revoked_admin],
+        };
+        let mut registry_data = vec![0u8; RoleRegistry::LEN];
+        registry.try_serialize(&mut &mut registry_data[..])?;
+        let mut registry_lamports = 100;
+        let role_registry_info = create_account_info(
+            &role_registry_pda,
+            false,
+            true,
+            &mut registry_lamports,
+            &mut registry_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let system_program_key = system_program::ID;
         let mut sp_lamports = 100;
         let mut sp_data = vec![0u8; 10];
@@ -832,44 +1454,67 @@ mod tests {
             None,
         );
 
-        let mut remove_rebalancer_accounts = RemoveRebalancer {
+        // 3 of 3 approvals, revoking roles is left to a single admin
+        let config = MultisigConfig {
+            threshold: 3,
+            protected_actions: vec![],
+        };
+        let multisig_config_key = Pubkey::find_program_address(&[MULTISIG_CONFIG_SEED], &program_id).0;
+        let mut multisig_config_lamports = 100;
+        let mut multisig_config_data = vec![0u8; MultisigConfig::LEN];
+        config.try_serialize(&mut &mut multisig_config_data[..])?;
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut revoke_role_accounts = RevokeRole {
             authority: Signer::try_from(&authority_info)?,
             admin: admin_info,
+            multisig_config,
+            proposal: None,
             receiver: SystemAccount::try_from(&receiver_info)?,
-            rebalancer_to_remove: Account::try_from(&rebalancer_info)?,
+            role_member: revoked_admin_info,
+            role_registry: Account::try_from(&role_registry_info)?,
             system_program: Program::try_from(&system_program_info)?,
         };
-
-        let params = RebalancerParams {
-            account: rebalancer_to_remove_param,
-        };
-
-        let ctx = Context {
-            accounts: &mut remove_rebalancer_accounts,
+        let mut ctx = Context {
+            accounts: &mut revoke_role_accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: RemoveRebalancerBumps::default(),
+            bumps: RevokeRoleBumps::default(),
+        };
+        let params = RoleParams {
+            role: RoleType::Admin,
+            account: revoked_admin,
         };
 
-        let result = remove_rebalancer(&ctx, &params);
-        assert!(result.is_ok());
+        // the 2 remaining admins could not reach the threshold
+        let result = revoke_role(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidMultisigThreshold.into());
+        assert_eq!(ctx.accounts.role_registry.member_count(), 3);
         Ok(())
     }
 
     #[test]
-    fn test_remove_rebalancer_negative_cases() -> Result<()> {
+    fn test_propose_admin() -> Result<()> {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
-        let rebalancer_to_remove_param = Pubkey::new_unique();
-        let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
-        let rebalancer_to_remove_pda = Pubkey::find_program_address(&[REBALANCER_SEED, rebalancer_to_remove_param.as_ref()], &program_id).0;
+        let new_admin = Pubkey::new_unique();
+        let portfolio_key = Pubkey::new_unique();
 
         let mut auth_lamports = 100;
         let mut auth_data = vec![0u8; 10];
         let authority_info = create_account_info(
             &authority_key,
             true,
-            true,
+            false,
             &mut auth_lamports,
             &mut auth_data,
             &program_id,
@@ -877,86 +1522,144 @@ mod tests {
             None,
         );
 
+        let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, authority_key.as_ref()], &program_id).0;
         let mut admin_lamports = 100;
         let mut admin_data = vec![0u8; 10];
         let admin_info = create_account_info(
             &admin_pda_key,
             false,
-            true,
+            false,
             &mut admin_lamports,
             &mut admin_data,
-            &authority_key,
+            &program_id,
             false,
             None,
         );
 
-        let mut rebalancer_lamports = 100;
-        let mut rebalancer_data = vec![0u8; Rebalancer::LEN];
-        let rebalancer_info = create_account_info(
-            &rebalancer_to_remove_pda,
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+        let portfolio_info = create_account_info(
+            &portfolio_key,
             false,
             true,
-            &mut rebalancer_lamports,
-            &mut rebalancer_data,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
             &program_id,
             false,
-            Some(Rebalancer::discriminator())
+            Some(Portfolio::discriminator()),
         );
 
-        let receiver_key = Pubkey::new_unique();
-        let mut recv_lamports = 100;
-        let mut recv_data = vec![0u8; 10];
-        let receiver_info = create_account_info(
-            &receiver_key,
-            false,
+        let mut accounts = WriteConfig {
+            authority: Signer::try_from(&authority_info)?,
+            portfolio: Account::try_from(&portfolio_info)?,
+            admin: admin_info,
+        };
+
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: WriteConfigBumps::default(),
+        };
+
+        let params = ProposeAdminParams { new_admin };
+
+        // an admin that does not hold Portfolio.admin
+        let result = propose_admin(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        ctx.accounts.portfolio.admin = authority_key;
+        propose_admin(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.portfolio.pending_admin, new_admin);
+        assert_eq!(ctx.accounts.portfolio.admin, authority_key);
+
+        propose_admin(&mut ctx, &ProposeAdminParams { new_admin: Pubkey::default() })?;
+        assert_eq!(ctx.accounts.portfolio.pending_admin, Pubkey::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_accept_admin() -> Result<()> {
+        let program_id = crate::id();
+        let current_admin = Pubkey::new_unique();
+        let new_admin = Pubkey::new_unique();
+        let portfolio_key = Pubkey::new_unique();
+
+        let mut auth_lamports = 100;
+        let mut auth_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &new_admin,
             true,
-            &mut recv_lamports,
-            &mut recv_data,
-            &system_program::ID,
+            false,
+            &mut auth_lamports,
+            &mut auth_data,
+            &program_id,
             false,
             None,
         );
 
+        // the pending admin has not been granted the admin role yet
+        let admin_pda_key = Pubkey::find_program_address(&[ADMIN_SEED, new_admin.as_ref()], &program_id).0;
         let system_program_key = system_program::ID;
-        let mut sp_lamports = 100;
-        let mut sp_data = vec![0u8; 10];
-        let system_program_info = create_account_info(
-            &system_program_key,
+        let mut admin_lamports = 0;
+        let mut admin_data = vec![];
+        let admin_info = create_account_info(
+            &admin_pda_key,
             false,
             false,
-            &mut sp_lamports,
-            &mut sp_data,
+            &mut admin_lamports,
+            &mut admin_data,
             &system_program_key,
-            true,
+            false,
             None,
         );
 
-        let mut remove_rebalancer_accounts = RemoveRebalancer {
-            authority: Signer::try_from(&authority_info)?,
-            admin: admin_info,
-            receiver: SystemAccount::try_from(&receiver_info)?,
-            rebalancer_to_remove: Account::try_from(&rebalancer_info)?,
-            system_program: Program::try_from(&system_program_info)?,
+        let portfolio = Portfolio {
+            admin: current_admin,
+            ..Default::default()
         };
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+        portfolio.try_serialize(&mut &mut portfolio_data[..])?;
+        let mut portfolio_lamports = 100;
+        let portfolio_info = create_account_info(
+            &portfolio_key,
+            false,
+            true,
+            &mut portfolio_lamports,
+            &mut portfolio_data,
+            &program_id,
+            false,
+            None,
+        );
 
-        let mut params = RebalancerParams {
-            account: rebalancer_to_remove_param,
+        let mut accounts = AcceptAdmin {
+            authority: Signer::try_from(&authority_info)?,
+            portfolio: Account::try_from(&portfolio_info)?,
+            admin: admin_info.clone(),
         };
 
-        let ctx = Context {
-            accounts: &mut remove_rebalancer_accounts,
+        let mut ctx = Context {
+            accounts: &mut accounts,
             remaining_accounts: &[],
             program_id: &program_id,
-            bumps: RemoveRebalancerBumps::default(),
+            bumps: AcceptAdminBumps::default(),
         };
 
-        let result = remove_rebalancer(&ctx, &params);
-        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+        let result = accept_admin(&mut ctx);
+        assert_eq!(result.unwrap_err(), DexalotError::NotPendingAdmin.into());
 
-        params.account = Pubkey::default();
+        ctx.accounts.portfolio.pending_admin = Pubkey::new_unique();
+        let result = accept_admin(&mut ctx);
+        assert_eq!(result.unwrap_err(), DexalotError::NotPendingAdmin.into());
 
-        let result = remove_rebalancer(&ctx, &params);
-        assert_eq!(result.unwrap_err(), DexalotError::ZeroAccount.into());
+        ctx.accounts.portfolio.pending_admin = new_admin;
+        let result = accept_admin(&mut ctx);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        admin_info.assign(&program_id);
+        accept_admin(&mut ctx)?;
+        assert_eq!(ctx.accounts.portfolio.admin, new_admin);
+        assert_eq!(ctx.accounts.portfolio.pending_admin, Pubkey::default());
         Ok(())
     }
 }
@@ -1,7 +1,9 @@
 use crate::{
-    consts::{ADMIN_SEED, PORTFOLIO_SEED, REMOTE_SEED},
+    consts::{ADMIN_SEED, MULTISIG_CONFIG_SEED, PORTFOLIO_SEED, REMOTE_SEED},
     errors::DexalotError,
-    state::{Portfolio, Remote},
+    events::UserPaysFeeForDestinationUpdated,
+    instructions::{authorize_action, consume_change, load_multisig_config},
+    state::{PendingChange, Portfolio, Proposal, Remote, SensitiveAction, TimelockedAction},
     *,
 };
 
@@ -26,24 +28,80 @@ pub struct SetRemote<'info> {
             bump
         )]
     pub admin: AccountInfo<'info>,
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(seeds = [MULTISIG_CONFIG_SEED], bump)]
+    pub multisig_config: AccountInfo<'info>,
+    #[account(mut)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    #[account(mut, close = payer)]
+    pub pending_change: Account<'info, PendingChange>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn set_remote(ctx: &mut Context<SetRemote>, params: &SetRemoteParams) -> Result<()> {
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut(),
+        SensitiveAction::SetRemote,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+    consume_change(&ctx.accounts.pending_change, TimelockedAction::SetRemote, params)?;
+    ctx.accounts.remote.address = params.remote;
+    ctx.accounts.remote.bump = ctx.bumps.remote;
+    Ok(())
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetRemoteParams {
+    pub dst_eid: u32,
+    pub remote: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetUserPaysFeeParams)]
+pub struct SetUserPaysFee<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+            seeds = [ADMIN_SEED, authority.key().as_ref()],
+            bump
+        )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [REMOTE_SEED, &params.dst_eid.to_be_bytes()],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+}
+
+/// Sets whether the user or the LZ fee vault pays the messaging fee for the destination
+pub fn set_user_pays_fee(
+    ctx: &mut Context<SetUserPaysFee>,
+    params: &SetUserPaysFeeParams,
+) -> Result<()> {
     let admin = &ctx.accounts.admin;
     require!(
         admin.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
-    ctx.accounts.remote.address = params.remote;
-    ctx.accounts.remote.bump = ctx.bumps.remote;
+    ctx.accounts.remote.protocol_pays_fee = !params.user_pays_fee;
+
+    emit!(UserPaysFeeForDestinationUpdated {
+        dst_eid: params.dst_eid,
+        user_pays_fee: params.user_pays_fee,
+    });
     Ok(())
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
-pub struct SetRemoteParams {
+pub struct SetUserPaysFeeParams {
     pub dst_eid: u32,
-    pub remote: [u8; 32],
+    pub user_pays_fee: bool,
 }
 
 #[cfg(test)]
@@ -51,7 +109,7 @@ mod tests {
     use super::*;
     use anchor_lang::prelude::*;
     use anchor_lang::{system_program, Discriminator};
-    use crate::test_utils::create_account_info;
+    use crate::test_utils::{create_account_info, create_pending_change_data};
 
     #[test]
     fn test_set_remote() {
@@ -127,12 +185,52 @@ mod tests {
             None
         );
 
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = create_pending_change_data(
+            TimelockedAction::SetRemote,
+            &SetRemoteParams {
+                dst_eid: 42,
+                remote: [42; 32],
+            },
+            0,
+        );
+        let pending_change = create_account_info(
+            &pending_change_key,
+            false,
+            true,
+            &mut pending_change_lamports,
+            &mut pending_change_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut accounts = SetRemote {
             payer: Signer::try_from(&payer_account).unwrap(),
             admin: admin_account,
+            multisig_config,
+            proposal: None,
+            pending_change: Account::try_from(&pending_change).unwrap(),
             remote: Account::try_from(&remote_account).unwrap(),
             portfolio: Account::try_from(&portfolio_account).unwrap(),
             system_program: Program::try_from(&system_program).unwrap(),
+
         };
 
         let params = SetRemoteParams {
@@ -152,4 +250,92 @@ mod tests {
         assert_eq!(accounts.remote.address, [42; 32]);
         assert_eq!(accounts.remote.bump, 255);
     }
+
+    #[test]
+    fn test_set_user_pays_fee() -> Result<()> {
+        let program_id = id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            false,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let remote_key = Pubkey::new_unique();
+        let mut remote_lamports = 100;
+        let mut remote_data = vec![0u8; Remote::SIZE];
+        let remote_info = create_account_info(
+            &remote_key,
+            false,
+            true,
+            &mut remote_lamports,
+            &mut remote_data,
+            &program_id,
+            false,
+            Some(Remote::discriminator()),
+        );
+
+        let mut accounts = SetUserPaysFee {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            remote: Account::try_from(&remote_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: SetUserPaysFeeBumps::default(),
+        };
+        let mut params = SetUserPaysFeeParams {
+            dst_eid: 42,
+            user_pays_fee: false,
+        };
+
+        // not an admin
+        let result = set_user_pays_fee(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        set_user_pays_fee(&mut ctx, &params)?;
+        assert!(ctx.accounts.remote.protocol_pays_fee);
+
+        params.user_pays_fee = true;
+        set_user_pays_fee(&mut ctx, &params)?;
+        assert!(!ctx.accounts.remote.protocol_pays_fee);
+        Ok(())
+    }
 }
@@ -1,28 +1,15 @@
 use super::*;
-use crate::consts::{ENDPOINT_SEND, PORTFOLIO_SEED};
-use crate::cpi_utils::{
-    create_instruction_data, EndpointQuoteParams, EndpointSendParams, MessagingFee,
-};
+use crate::cpi_utils::{load_lz_options, quote_and_send};
 use crate::xfer::XFER;
 use crate::{
-    consts::{
-        COMPLETED_SWAPS_SEED, CROSS_SWAP_TYPE, ENDPOINT_QUOTE, GAS_OPTIONS,
-        QUOTE_REMAINING_ACCOUNTS_COUNT,
-    },
+    consts::{COMPLETED_SWAPS_SEED, CROSS_SWAP_TYPE},
     errors::DexalotError,
     events::SwapExecuted,
     map_utils::{create_entry, entry_exists},
     state::CompletedSwapsEntry,
     xfer::Tx,
 };
-use anchor_lang::solana_program::program::invoke_signed;
-use anchor_lang::{
-    prelude::*,
-    solana_program::{
-        instruction::Instruction,
-        program::{get_return_data, invoke},
-    },
-};
+use anchor_lang::prelude::*;
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub struct XChainSwap {
@@ -131,102 +118,40 @@ impl XChainSwap {
         Ok(())
     }
 
-    pub fn send_cross_chain_trade(&self, ctx: &mut Context<CrossSwap>) -> Result<()> {
-        let portfolio = &ctx.accounts.portfolio;
-        let remote = &ctx.accounts.remote;
-        let endpoint_program = &ctx.accounts.endpoint_program;
-        let out_nonce = portfolio.global_config.out_nonce;
-
+    /// Packs the XFER message crediting the destination trader on the destination chain
+    pub fn pack_cross_chain_trade(&self, out_nonce: u64) -> Result<Vec<u8>> {
         let xfer = XFER::new(
             Tx::CCTrade,
             self.dest_trader.to_bytes(),
             self.maker_symbol,
-            self.maker_amount,
+            self.maker_amount.into(),
             self.expiry as u32,
             nonce_to_custom_data(self.nonce),
             out_nonce,
         );
 
-        let message = xfer.pack_xfer_message()?;
-
-        // Call Quote
-        let quote_params = EndpointQuoteParams {
-            sender: portfolio.key(),
-            dst_eid: self.dest_chain_id as u32,
-            receiver: remote.address,
-            message: message.clone(),
-            pay_in_lz_token: false,
-            options: GAS_OPTIONS.to_vec(),
-        };
-
-        let quote_cpi_data = create_instruction_data(&quote_params, ENDPOINT_QUOTE)?;
-        let quote_remaining_accounts = &ctx.remaining_accounts[0..QUOTE_REMAINING_ACCOUNTS_COUNT];
-        let quote_accounts_metas: Vec<AccountMeta> = quote_remaining_accounts
-            .iter()
-            .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
-            .map(|account| AccountMeta {
-                pubkey: *account.key,
-                is_signer: account.is_signer,
-                is_writable: false,
-            })
-            .collect();
-
-        // Invoke CPI quote
-        let fee = if cfg!(not(test)) {
-            invoke(
-                &Instruction {
-                    program_id: endpoint_program.key(),
-                    accounts: quote_accounts_metas,
-                    data: quote_cpi_data,
-                },
-                quote_remaining_accounts,
-            )?;
-            let quote_return_data = get_return_data().ok_or(DexalotError::LzQuoteError)?;
-            MessagingFee::try_from_slice(&quote_return_data.1)?
-        } else {
-            MessagingFee::default()
-        };
-
-        require!(fee.lz_token_fee == 0, DexalotError::PositiveLzTokenFee);
+        xfer.pack_xfer_message()
+    }
 
-        // Call Send
-        let send_remaining_accounts = &ctx.remaining_accounts[QUOTE_REMAINING_ACCOUNTS_COUNT..];
-        let send_params = EndpointSendParams {
-            dst_eid: self.dest_chain_id as u32,
-            receiver: remote.address,
+    pub fn send_cross_chain_trade(
+        &self,
+        ctx: &mut Context<CrossSwap>,
+        max_native_fee: u64,
+    ) -> Result<()> {
+        let portfolio = &ctx.accounts.portfolio;
+        let remote = &ctx.accounts.remote;
+        let endpoint_program = &ctx.accounts.endpoint_program;
+        let message = self.pack_cross_chain_trade(portfolio.global_config.out_nonce)?;
+        quote_and_send(
+            portfolio,
+            remote,
+            endpoint_program,
+            ctx.remaining_accounts,
+            ctx.accounts.chain_entry.eid,
             message,
-            options: GAS_OPTIONS.to_vec(),
-            native_fee: fee.native_fee,
-            lz_token_fee: 0,
-        };
-
-        let send_seeds: &[&[&[u8]]] = &[&[PORTFOLIO_SEED, &[ctx.accounts.portfolio.bump]]];
-
-        let send_cpi_data = create_instruction_data(&send_params, ENDPOINT_SEND)?;
-
-        let portfolio_key = ctx.accounts.portfolio.key();
-        let send_accounts_metas: Vec<AccountMeta> = send_remaining_accounts
-            .iter()
-            .skip(1) // an account is skipped because we don't use layerzero cpi utils so it's not needed
-            .map(|account| AccountMeta {
-                pubkey: *account.key,
-                is_signer: account.key() == portfolio_key || account.is_signer,
-                is_writable: account.is_writable,
-            })
-            .collect();
-
-        // Invoke CPI send
-        if cfg!(not(test)) {
-            invoke_signed(
-                &Instruction {
-                    program_id: endpoint_program.key(),
-                    accounts: send_accounts_metas,
-                    data: send_cpi_data,
-                },
-                send_remaining_accounts,
-                send_seeds,
-            )?;
-        }
+            load_lz_options(&ctx.accounts.lz_options, ctx.program_id)?,
+            max_native_fee,
+        )?;
 
         // update out_nonce counter
         ctx.accounts.portfolio.global_config.out_nonce += 1;
@@ -237,9 +162,15 @@ impl XChainSwap {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::consts::UNUSED_ADDRESS_PUBLIC_KEY;
-    use crate::state::{AllowedDestinationEntry, GlobalConfig, Portfolio, Remote};
-    use crate::test_utils::{create_account_info, create_dummy_account, generate_valid_signature};
+    use crate::consts::{QUOTE_REMAINING_ACCOUNTS_COUNT, UNUSED_ADDRESS_PUBLIC_KEY};
+    use crate::state::{
+        AllowedDestinationEntry, ChainEntry, GlobalConfig, Portfolio, Remote, SymbolEntry,
+        TokenInventory,
+    };
+    use crate::test_utils::{
+        create_account_info, create_dummy_account, create_not_banned_account,
+        create_portfolio_data, create_token_inventory_data, generate_valid_signature,
+    };
     use anchor_lang::{system_program, Discriminator};
     use anchor_spl::token::Token;
     use bincode::serialize;
@@ -340,21 +271,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
+            ..Default::default()
         };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_pubkey,
@@ -452,12 +372,75 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
+        let maker_symbol_entry_key = Pubkey::new_unique();
+        let mut maker_symbol_entry_lamports = 100;
+        let mut maker_symbol_entry_data = SymbolEntry {
+            token_mint: Pubkey::new_unique(),
+        }
+        .try_to_vec()?;
+        let maker_symbol_entry_info = create_account_info(
+            &maker_symbol_entry_key,
+            false,
+            false,
+            &mut maker_symbol_entry_lamports,
+            &mut maker_symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
         let mut accounts = CrossSwap {
             clock: Sysvar::from_account_info(&clock_account)?,
             sender: Signer::try_from(&sender_account)?,
             taker: generic_account.clone(),
             dest_trader: generic_account.clone(),
             completed_swaps_entry: generic_account2,
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_account.clone(),
@@ -468,7 +451,11 @@ mod tests {
             token_program,
             remote: remote_account,
             endpoint_program: generic_account,
+            lz_options: lz_options_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
             destination_entry: destination_entry_account,
+            maker_symbol_entry: maker_symbol_entry_info,
+            src_token_inventory: src_token_inventory_info.clone(),
         };
 
         let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
@@ -557,21 +544,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_pubkey,
@@ -669,12 +645,75 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
+        let maker_symbol_entry_key = Pubkey::new_unique();
+        let mut maker_symbol_entry_lamports = 100;
+        let mut maker_symbol_entry_data = SymbolEntry {
+            token_mint: Pubkey::new_unique(),
+        }
+        .try_to_vec()?;
+        let maker_symbol_entry_info = create_account_info(
+            &maker_symbol_entry_key,
+            false,
+            false,
+            &mut maker_symbol_entry_lamports,
+            &mut maker_symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
         let mut accounts = CrossSwap {
             clock: Sysvar::from_account_info(&clock_account)?,
             sender: Signer::try_from(&sender_account)?,
             taker: generic_account.clone(),
             dest_trader: generic_account.clone(),
             completed_swaps_entry: generic_account2,
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_account.clone(),
@@ -685,7 +724,11 @@ mod tests {
             token_program,
             remote: remote_account,
             endpoint_program: generic_account,
+            lz_options: lz_options_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
             destination_entry: destination_entry_account,
+            maker_symbol_entry: maker_symbol_entry_info,
+            src_token_inventory: src_token_inventory_info.clone(),
         };
 
         let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
@@ -864,12 +907,75 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
+        let maker_symbol_entry_key = Pubkey::new_unique();
+        let mut maker_symbol_entry_lamports = 100;
+        let mut maker_symbol_entry_data = SymbolEntry {
+            token_mint: Pubkey::new_unique(),
+        }
+        .try_to_vec()?;
+        let maker_symbol_entry_info = create_account_info(
+            &maker_symbol_entry_key,
+            false,
+            false,
+            &mut maker_symbol_entry_lamports,
+            &mut maker_symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
         let mut accounts = CrossSwap {
             clock: Sysvar::from_account_info(&clock_account)?,
             sender: Signer::try_from(&sender_account)?,
             taker: generic_account.clone(),
             dest_trader: generic_account.clone(),
             completed_swaps_entry: generic_account.clone(),
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_account.clone(),
@@ -880,7 +986,11 @@ mod tests {
             token_program,
             remote: remote_account,
             endpoint_program: generic_account,
+            lz_options: lz_options_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
             destination_entry: destination_entry_account,
+            maker_symbol_entry: maker_symbol_entry_info,
+            src_token_inventory: src_token_inventory_info.clone(),
         };
 
         let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
@@ -1042,12 +1152,75 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
+        let maker_symbol_entry_key = Pubkey::new_unique();
+        let mut maker_symbol_entry_lamports = 100;
+        let mut maker_symbol_entry_data = SymbolEntry {
+            token_mint: Pubkey::new_unique(),
+        }
+        .try_to_vec()?;
+        let maker_symbol_entry_info = create_account_info(
+            &maker_symbol_entry_key,
+            false,
+            false,
+            &mut maker_symbol_entry_lamports,
+            &mut maker_symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
         let mut accounts = CrossSwap {
             clock: Sysvar::from_account_info(&clock_account)?,
             sender: Signer::try_from(&sender_account)?,
             taker: generic_account.clone(),
             dest_trader: generic_account.clone(),
             completed_swaps_entry: generic_account.clone(),
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_account.clone(),
@@ -1058,7 +1231,11 @@ mod tests {
             token_program,
             remote: remote_account,
             endpoint_program: generic_account,
+            lz_options: lz_options_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
             destination_entry: destination_entry_account,
+            maker_symbol_entry: maker_symbol_entry_info,
+            src_token_inventory: src_token_inventory_info.clone(),
         };
 
         let program_id_static: &'static Pubkey = Box::leak(Box::new(crate::id()));
@@ -1072,7 +1249,7 @@ mod tests {
             program_id: &program_id,
             bumps: CrossSwapBumps::default(),
         };
-        let result = swap.send_cross_chain_trade(&mut ctx);
+        let result = swap.send_cross_chain_trade(&mut ctx, 0);
 
         assert!(result.is_ok());
 
@@ -137,7 +137,10 @@ mod tests {
     use solana_program::clock::UnixTimestamp;
     use crate::consts::UNUSED_ADDRESS_PUBLIC_KEY;
     use crate::state::{GlobalConfig, Portfolio};
-    use crate::test_utils::{create_account_info, generate_valid_signature};
+    use crate::test_utils::{
+        create_account_info, create_funded_token_inventory_data, create_not_banned_account,
+        create_portfolio_data, generate_valid_signature,
+    };
 
     #[test]
     fn test_validate_order_success() -> Result<()> {
@@ -148,21 +151,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -284,12 +276,44 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_funded_token_inventory_data();
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let dest_token_inventory_key = Pubkey::new_unique();
+        let mut dest_token_inventory_lamports = 100;
+        let mut dest_token_inventory_data = create_funded_token_inventory_data();
+        let dest_token_inventory_info = create_account_info(
+            &dest_token_inventory_key,
+            false,
+            true,
+            &mut dest_token_inventory_lamports,
+            &mut dest_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut swap_accounts = Swap {
             clock: Sysvar::from_account_info(&clock_info)?,
             sender: Signer::try_from(&sender_info)?,
             taker: generic_info.clone(),
             dest_trader: generic_info.clone(),
             completed_swaps_entry: completed_swaps_info.clone(),
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_info)?,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_info.clone(),
@@ -303,6 +327,8 @@ mod tests {
             spl_vault_dest_asset_ata: generic_info.clone(),
             spl_vault_src_asset_ata: generic_info.clone(),
             token_program,
+            src_token_inventory: src_token_inventory_info.clone(),
+            dest_token_inventory: dest_token_inventory_info.clone(),
         };
 
         let ctx = Context {
@@ -328,21 +354,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -466,12 +481,44 @@ mod tests {
         let sender_clone = sender_info.clone();
         let clock_clone = clock_info.clone();
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_funded_token_inventory_data();
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let dest_token_inventory_key = Pubkey::new_unique();
+        let mut dest_token_inventory_lamports = 100;
+        let mut dest_token_inventory_data = create_funded_token_inventory_data();
+        let dest_token_inventory_info = create_account_info(
+            &dest_token_inventory_key,
+            false,
+            true,
+            &mut dest_token_inventory_lamports,
+            &mut dest_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut swap_accounts = Swap {
             clock: Sysvar::from_account_info(&clock_clone)?,
             sender: Signer::try_from(&sender_clone)?,
             taker: generic_info.clone(),
             dest_trader: generic_info.clone(),
             completed_swaps_entry: completed_swaps_info,
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_info)?,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_info.clone(),
@@ -485,6 +532,8 @@ mod tests {
             spl_vault_dest_asset_ata: generic_info.clone(),
             spl_vault_src_asset_ata: generic_info.clone(),
             token_program,
+            src_token_inventory: src_token_inventory_info.clone(),
+            dest_token_inventory: dest_token_inventory_info.clone(),
         };
 
         let mut ctx = Context {
@@ -542,21 +591,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -678,12 +716,44 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_funded_token_inventory_data();
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let dest_token_inventory_key = Pubkey::new_unique();
+        let mut dest_token_inventory_lamports = 100;
+        let mut dest_token_inventory_data = create_funded_token_inventory_data();
+        let dest_token_inventory_info = create_account_info(
+            &dest_token_inventory_key,
+            false,
+            true,
+            &mut dest_token_inventory_lamports,
+            &mut dest_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut swap_accounts = Swap {
             clock: Sysvar::from_account_info(&clock_info)?,
             sender: Signer::try_from(&sender_info)?,
             taker: generic_info.clone(),
             dest_trader: generic_info.clone(),
             completed_swaps_entry: completed_swaps_info.clone(),
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_info)?,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_info.clone(),
@@ -697,6 +767,8 @@ mod tests {
             spl_vault_dest_asset_ata: generic_info.clone(),
             spl_vault_src_asset_ata: generic_info.clone(),
             token_program,
+            src_token_inventory: src_token_inventory_info.clone(),
+            dest_token_inventory: dest_token_inventory_info.clone(),
         };
 
         let ctx = Context {
@@ -1,11 +1,14 @@
 use crate::{
     consts::{
-        CCTRADE_ALLOWED_DEST_SEED, COMPLETED_SWAPS_SEED, PORTFOLIO_SEED, REMOTE_SEED,
-        SOL_VAULT_SEED, SPL_VAULT_SEED,
+        BANNED_ACCOUNT_SEED, CCTRADE_ALLOWED_DEST_SEED, CHAIN_SEED, COMPLETED_SWAPS_SEED,
+        LZ_OPTIONS_SEED, PORTFOLIO_SEED, REMOTE_SEED, SOL_VAULT_SEED, SPL_VAULT_SEED,
+        SYMBOL_SEED, TOKEN_INVENTORY_SEED,
     },
     errors::DexalotError,
+    instructions::check_not_banned,
     map_utils::entry_exists,
-    state::{Portfolio, Remote},
+    state::{ChainEntry, Portfolio, Remote},
+    xfer::Tx,
 };
 
 use super::*;
@@ -15,12 +18,24 @@ use anchor_spl::token::Token;
 pub fn cross_swap(ctx: &mut Context<CrossSwap>, params: &CrossSwapParams) -> Result<()> {
     let global_config = &ctx.accounts.portfolio.global_config;
     require!(!global_config.program_paused, DexalotError::ProgramPaused);
+    check_not_banned(&ctx.accounts.taker_banned_account, ctx.program_id)?;
+    check_not_banned(&ctx.accounts.dest_trader_banned_account, ctx.program_id)?;
 
     let destination_entry = &ctx.accounts.destination_entry;
     require!(
         entry_exists(destination_entry),
         DexalotError::DestinationNotAllowed
     );
+    // the maker asset is an address on the destination chain, not a Solana mint
+    require!(
+        entry_exists(&ctx.accounts.maker_symbol_entry)
+            || ctx
+                .accounts
+                .chain_entry
+                .native_symbols
+                .contains(&params.order.maker_symbol),
+        DexalotError::InvalidMakerSymbol
+    );
 
     check_atas(&ctx, &params)?;
     let order = params.order.clone();
@@ -29,7 +44,7 @@ pub fn cross_swap(ctx: &mut Context<CrossSwap>, params: &CrossSwapParams) -> Res
 
     order.execute_cross_swap(ctx)?;
 
-    order.send_cross_chain_trade(ctx)?;
+    order.send_cross_chain_trade(ctx, params.max_native_fee)?;
 
     Ok(())
 }
@@ -48,6 +63,12 @@ pub struct CrossSwap<'info> {
     /// CHECK: when calling the instruction
     #[account(mut, seeds = [COMPLETED_SWAPS_SEED, &generate_map_entry_key(params.order.nonce, params.order.dest_trader)?], bump)]
     pub completed_swaps_entry: AccountInfo<'info>,
+    /// CHECK: banned account PDA of the taker, must not exist
+    #[account(seeds = [BANNED_ACCOUNT_SEED, params.order.taker.as_ref()], bump)]
+    pub taker_banned_account: AccountInfo<'info>,
+    /// CHECK: banned account PDA of the dest trader, must not exist
+    #[account(seeds = [BANNED_ACCOUNT_SEED, params.order.dest_trader.as_ref()], bump)]
+    pub dest_trader_banned_account: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
     /// Sysvar for accessing block time
     pub clock: Sysvar<'info, Clock>,
@@ -73,29 +94,57 @@ pub struct CrossSwap<'info> {
     /// CHECK: ATA or Zero PublicKey
     #[account(mut)]
     pub spl_vault_src_asset_ata: AccountInfo<'info>,
+    /// CHECK: inventory of the asset paid by the taker
+    #[account(mut, seeds = [TOKEN_INVENTORY_SEED, params.order.taker_asset.as_ref()], bump)]
+    pub src_token_inventory: AccountInfo<'info>,
     pub token_program: Program<'info, Token>,
+    #[account(
+        seeds = [CHAIN_SEED, &params.order.dest_chain_id.to_be_bytes()],
+        bump
+    )]
+    pub chain_entry: Account<'info, ChainEntry>,
     #[account(
         seeds = [
             REMOTE_SEED,
-            &params.order.dest_chain_id.to_be_bytes()
+            &chain_entry.eid.to_be_bytes()
         ],
         bump = remote.bump
     )]
     pub remote: Account<'info, Remote>,
     /// CHECK: the endpoint program
+    #[account(
+        address = portfolio.global_config.endpoint_program @ DexalotError::InvalidEndpointProgram
+    )]
     pub endpoint_program: AccountInfo<'info>,
+    /// CHECK: the LZ options pda, default gas options are used when not set
+    #[account(
+        seeds = [
+            LZ_OPTIONS_SEED,
+            &chain_entry.eid.to_be_bytes(),
+            &[Tx::CCTrade as u8]
+        ],
+        bump
+    )]
+    pub lz_options: AccountInfo<'info>,
     /// CHECK: destination entry
     #[account(
         seeds = [CCTRADE_ALLOWED_DEST_SEED, &params.order.dest_chain_id.to_be_bytes(), &params.order.maker_asset.to_bytes()],
         bump
     )]
     pub destination_entry: AccountInfo<'info>,
+    /// CHECK: the symbol index entry of the order's maker symbol, natives of the destination have none
+    #[account(
+        seeds = [SYMBOL_SEED, params.order.maker_symbol.as_ref()],
+        bump
+    )]
+    pub maker_symbol_entry: AccountInfo<'info>,
 }
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
 pub struct CrossSwapParams {
     pub order: XChainSwap,
     pub signature: Vec<u8>,
+    pub max_native_fee: u64,
 }
 
 fn check_atas(ctx: &Context<CrossSwap>, params: &CrossSwapParams) -> Result<()> {
@@ -127,8 +176,13 @@ mod tests {
     use crate::consts::{
         COMPLETED_SWAPS_SEED, QUOTE_REMAINING_ACCOUNTS_COUNT, UNUSED_ADDRESS_PUBLIC_KEY,
     };
-    use crate::state::{AllowedDestinationEntry, GlobalConfig, Portfolio, Remote};
-    use crate::test_utils::{create_account_info, create_dummy_account, generate_valid_signature};
+    use crate::state::{
+        AllowedDestinationEntry, GlobalConfig, Portfolio, Remote, SymbolEntry, TokenInventory,
+    };
+    use crate::test_utils::{
+        create_account_info, create_dummy_account, create_not_banned_account,
+        create_portfolio_data, create_token_inventory_data, generate_valid_signature,
+    };
     use anchor_lang::solana_program::{clock::Clock, system_program};
     use anchor_lang::Discriminator;
     use anchor_spl::token::spl_token;
@@ -140,11 +194,14 @@ mod tests {
         let program_id = crate::id();
         let generic_key = Pubkey::new_unique();
 
+        // the maker asset is an EVM address left-padded to 32 bytes
+        let mut maker_asset = [0u8; 32];
+        maker_asset[12..].copy_from_slice(&[0xab; 20]);
         let order = XChainSwap {
             taker: Pubkey::new_unique(),
             dest_trader: Pubkey::new_unique(),
             maker_symbol: [1u8; 32],
-            maker_asset: Pubkey::new_unique(),
+            maker_asset: Pubkey::new_from_array(maker_asset),
             taker_asset: Pubkey::new_unique(),
             maker_amount: 1000,
             taker_amount: 2000,
@@ -157,21 +214,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
+            ..Default::default()
         };
-        let portfolio = Portfolio {
-            admin: Pubkey::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec().unwrap();
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -339,12 +385,75 @@ mod tests {
             .map(|_| create_dummy_account(program_id_static))
             .collect();
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
+        let maker_symbol_entry_key = Pubkey::new_unique();
+        let mut maker_symbol_entry_lamports = 100;
+        let mut maker_symbol_entry_data = SymbolEntry {
+            token_mint: Pubkey::new_unique(),
+        }
+        .try_to_vec()?;
+        let maker_symbol_entry_info = create_account_info(
+            &maker_symbol_entry_key,
+            false,
+            false,
+            &mut maker_symbol_entry_lamports,
+            &mut maker_symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
         let mut ctx = Context {
             accounts: &mut CrossSwap {
                 sender: Signer::try_from(&sender_account)?,
                 taker: taker_account,
                 dest_trader: generic_account.clone(),
                 completed_swaps_entry: completed_swaps_account,
+                taker_banned_account: banned_info.clone(),
+                dest_trader_banned_account: banned_info.clone(),
                 system_program: Program::try_from(&system_program_account)?,
                 clock: Sysvar::from_account_info(&clock_account)?,
                 portfolio: Account::try_from(&portfolio_account)?,
@@ -356,7 +465,11 @@ mod tests {
                 token_program,
                 remote,
                 endpoint_program: endpoint_program_account,
+                lz_options: lz_options_info,
+                chain_entry: Account::try_from(&chain_entry_info).unwrap(),
                 destination_entry: destination_entry_account,
+                maker_symbol_entry: maker_symbol_entry_info.clone(),
+                src_token_inventory: src_token_inventory_info.clone(),
             },
             remaining_accounts: &remaining_accounts,
             program_id: &program_id,
@@ -365,6 +478,7 @@ mod tests {
         let params = CrossSwapParams {
             order: order.clone(),
             signature: generate_valid_signature(&order.to_bytes()).into(),
+            max_native_fee: 0,
         };
 
         let res = cross_swap(&mut ctx, &params);
@@ -394,21 +508,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let mut gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Pubkey::default(),
-            global_config: gc.clone(),
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec().unwrap();
+        let mut portfolio_data = create_portfolio_data(gc.clone());
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -577,11 +680,74 @@ mod tests {
             .collect();
         let portfolio_clone = portfolio_account.clone();
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let chain_entry_key = Pubkey::new_unique();
+        let mut chain_entry_lamports = 100;
+        let mut chain_entry_data = ChainEntry::default().try_to_vec().unwrap();
+        let chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut chain_entry_lamports,
+            &mut chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+
+        let lz_options_key = Pubkey::new_unique();
+        let mut lz_options_lamports = 0;
+        let mut lz_options_data = vec![];
+        let lz_options_info = create_account_info(
+            &lz_options_key,
+            false,
+            false,
+            &mut lz_options_lamports,
+            &mut lz_options_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
+        let maker_symbol_entry_key = Pubkey::new_unique();
+        let mut maker_symbol_entry_lamports = 100;
+        let mut maker_symbol_entry_data = SymbolEntry {
+            token_mint: Pubkey::new_unique(),
+        }
+        .try_to_vec()?;
+        let maker_symbol_entry_info = create_account_info(
+            &maker_symbol_entry_key,
+            false,
+            false,
+            &mut maker_symbol_entry_lamports,
+            &mut maker_symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
         let mut accounts = CrossSwap {
             sender: Signer::try_from(&sender_account)?,
             taker: taker_account,
             dest_trader: generic_account.clone(),
             completed_swaps_entry: completed_swaps_account,
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_account)?,
             clock: Sysvar::from_account_info(&clock_account)?,
             portfolio: Account::try_from(&portfolio_clone)?,
@@ -593,7 +759,11 @@ mod tests {
             token_program,
             remote,
             endpoint_program: endpoint_program_account,
+            lz_options: lz_options_info,
+            chain_entry: Account::try_from(&chain_entry_info).unwrap(),
             destination_entry: destination_entry_account,
+            maker_symbol_entry: maker_symbol_entry_info.clone(),
+            src_token_inventory: src_token_inventory_info.clone(),
         };
         let mut ctx = Context {
             accounts: &mut accounts.clone(),
@@ -604,19 +774,71 @@ mod tests {
         let params = CrossSwapParams {
             order: order.clone(),
             signature: generate_valid_signature(&order.to_bytes()).into(),
+            max_native_fee: 0,
         };
 
         let res = cross_swap(&mut ctx, &params);
         assert_eq!(res.unwrap_err(), DexalotError::InvalidTaker.into());
 
+        // banned takers and dest traders can't swap
+        let mut banned_taker_accounts = accounts.clone();
+        banned_taker_accounts.taker_banned_account = generic_account.clone();
+        ctx.accounts = &mut banned_taker_accounts;
+        let res = cross_swap(&mut ctx, &params);
+        assert_eq!(res.unwrap_err(), DexalotError::AccountBanned.into());
+
+        let mut banned_dest_trader_accounts = accounts.clone();
+        banned_dest_trader_accounts.dest_trader_banned_account = generic_account.clone();
+        ctx.accounts = &mut banned_dest_trader_accounts;
+        let res = cross_swap(&mut ctx, &params);
+        assert_eq!(res.unwrap_err(), DexalotError::AccountBanned.into());
+
+        // the maker symbol is neither registered nor a native of the destination
+        let mut unregistered_symbol_lamports = 0;
+        let mut unregistered_symbol_data = vec![];
+        let unregistered_symbol_info = create_account_info(
+            &maker_symbol_entry_key,
+            false,
+            false,
+            &mut unregistered_symbol_lamports,
+            &mut unregistered_symbol_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+        let mut unregistered_symbol_accounts = accounts.clone();
+        unregistered_symbol_accounts.maker_symbol_entry = unregistered_symbol_info.clone();
+        ctx.accounts = &mut unregistered_symbol_accounts;
+        let res = cross_swap(&mut ctx, &params);
+        assert_eq!(res.unwrap_err(), DexalotError::InvalidMakerSymbol.into());
+
+        // natives of the destination have no symbol entry
+        let mut native_chain_entry_lamports = 100;
+        let mut native_chain_entry_data = ChainEntry {
+            eid: 0,
+            native_symbols: vec![order.maker_symbol],
+        }
+        .try_to_vec()?;
+        let native_chain_entry_info = create_account_info(
+            &chain_entry_key,
+            false,
+            false,
+            &mut native_chain_entry_lamports,
+            &mut native_chain_entry_data,
+            &program_id,
+            false,
+            Some(ChainEntry::discriminator()),
+        );
+        let mut native_symbol_accounts = accounts.clone();
+        native_symbol_accounts.maker_symbol_entry = unregistered_symbol_info;
+        native_symbol_accounts.chain_entry = Account::try_from(&native_chain_entry_info)?;
+        ctx.accounts = &mut native_symbol_accounts;
+        let res = cross_swap(&mut ctx, &params);
+        assert_eq!(res.unwrap_err(), DexalotError::InvalidTaker.into());
+
         gc.program_paused = true;
 
-        let portfolio = Portfolio {
-            admin: Pubkey::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
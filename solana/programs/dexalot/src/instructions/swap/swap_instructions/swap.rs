@@ -1,7 +1,11 @@
 use super::*;
 use crate::{
-    consts::{COMPLETED_SWAPS_SEED, PORTFOLIO_SEED, SOL_VAULT_SEED, SPL_VAULT_SEED},
+    consts::{
+        BANNED_ACCOUNT_SEED, COMPLETED_SWAPS_SEED, PORTFOLIO_SEED, SOL_VAULT_SEED, SPL_VAULT_SEED,
+        TOKEN_INVENTORY_SEED,
+    },
     errors::DexalotError,
+    instructions::check_not_banned,
     state::Portfolio,
 };
 use anchor_lang::prelude::*;
@@ -21,6 +25,12 @@ pub struct Swap<'info> {
     /// CHECK: when calling the instruction
     #[account(mut, seeds = [COMPLETED_SWAPS_SEED, &generate_map_entry_key(params.order.nonce, params.order.dest_trader)?], bump)]
     pub completed_swaps_entry: AccountInfo<'info>,
+    /// CHECK: banned account PDA of the taker, must not exist
+    #[account(seeds = [BANNED_ACCOUNT_SEED, params.order.taker.as_ref()], bump)]
+    pub taker_banned_account: AccountInfo<'info>,
+    /// CHECK: banned account PDA of the dest trader, must not exist
+    #[account(seeds = [BANNED_ACCOUNT_SEED, params.order.dest_trader.as_ref()], bump)]
+    pub dest_trader_banned_account: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
     /// Sysvar for accessing block time
     pub clock: Sysvar<'info, Clock>,
@@ -62,6 +72,12 @@ pub struct Swap<'info> {
     /// CHECK: ATA or Zero PublicKey
     #[account(mut)]
     pub spl_vault_src_asset_ata: AccountInfo<'info>,
+    /// CHECK: inventory of the asset paid by the taker
+    #[account(mut, seeds = [TOKEN_INVENTORY_SEED, params.order.taker_asset.as_ref()], bump)]
+    pub src_token_inventory: AccountInfo<'info>,
+    /// CHECK: inventory of the asset released to the dest trader
+    #[account(mut, seeds = [TOKEN_INVENTORY_SEED, params.order.maker_asset.as_ref()], bump)]
+    pub dest_token_inventory: AccountInfo<'info>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -79,6 +95,8 @@ pub fn swap(ctx: &Context<Swap>, params: &SwapParams) -> Result<()> {
         !global_config.program_paused,
         DexalotError::ProgramPaused
     );
+    check_not_banned(&ctx.accounts.taker_banned_account, ctx.program_id)?;
+    check_not_banned(&ctx.accounts.dest_trader_banned_account, ctx.program_id)?;
 
     check_atas(&ctx, &params)?;
 
@@ -163,9 +181,12 @@ fn check_atas(ctx: &Context<Swap>, params: &SwapParams) -> Result<()> {
 mod tests {
     use super::*;
     use anchor_lang::solana_program::{clock::Clock, system_program};
-    use crate::test_utils::{create_account_info, generate_valid_signature};
+    use crate::test_utils::{
+        create_account_info, create_funded_token_inventory_data, create_not_banned_account,
+        create_portfolio_data, create_token_inventory_data, generate_valid_signature,
+    };
     use crate::consts::{COMPLETED_SWAPS_SEED, UNUSED_ADDRESS_PUBLIC_KEY};
-    use crate::state::{GlobalConfig, Portfolio};
+    use crate::state::{GlobalConfig, Portfolio, TokenInventory};
     use bincode::serialize;
     use std::str::FromStr;
     use anchor_lang::Discriminator;
@@ -190,21 +211,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
+            ..Default::default()
         };
-        let portfolio = Portfolio {
-            admin: Pubkey::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec().unwrap();
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -328,11 +338,43 @@ mod tests {
         );
         let token_program = Program::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let dest_token_inventory_key = Pubkey::new_unique();
+        let mut dest_token_inventory_lamports = 100;
+        let mut dest_token_inventory_data = create_funded_token_inventory_data();
+        let dest_token_inventory_info = create_account_info(
+            &dest_token_inventory_key,
+            false,
+            true,
+            &mut dest_token_inventory_lamports,
+            &mut dest_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut accounts = Swap {
             sender: Signer::try_from(&sender_info)?,
             taker: taker_info,
             dest_trader: generic_info.clone(),
             completed_swaps_entry: completed_swaps_info,
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_info)?,
             clock: Sysvar::from_account_info(&clock_info)?,
             portfolio: Account::try_from(&portfolio_account)?,
@@ -347,6 +389,8 @@ mod tests {
             spl_vault_dest_asset_ata: generic_info.clone(),
             spl_vault_src_asset_ata: generic_info.clone(),
             token_program,
+            src_token_inventory: src_token_inventory_info.clone(),
+            dest_token_inventory: dest_token_inventory_info.clone(),
         };
         let ctx = Context {
             accounts: &mut accounts,
@@ -368,6 +412,9 @@ mod tests {
 
         let res = swap(&ctx, &params);
         assert!(res.is_ok());
+        // the vault receives what take_funds transfers from the taker
+        let inventory = TokenInventory::try_deserialize(&mut &src_token_inventory_info.try_borrow_data()?[..])?;
+        assert_eq!(inventory.rfq_inventory, order.taker_amount);
         Ok(())
     }
 
@@ -391,21 +438,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let mut gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Pubkey::default(),
-            global_config: gc.clone(),
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc.clone());
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -517,11 +553,43 @@ mod tests {
         );
         let token_program = Program::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_funded_token_inventory_data();
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let dest_token_inventory_key = Pubkey::new_unique();
+        let mut dest_token_inventory_lamports = 100;
+        let mut dest_token_inventory_data = create_funded_token_inventory_data();
+        let dest_token_inventory_info = create_account_info(
+            &dest_token_inventory_key,
+            false,
+            true,
+            &mut dest_token_inventory_lamports,
+            &mut dest_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut accounts = Swap {
             sender: Signer::try_from(&sender_info)?,
             taker: generic_info.clone(),
             dest_trader: generic_info.clone(),
             completed_swaps_entry: completed_swaps_info,
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_info)?,
             clock: Sysvar::from_account_info(&clock_info)?,
             portfolio: Account::try_from(&portfolio_account)?,
@@ -536,6 +604,8 @@ mod tests {
             spl_vault_dest_asset_ata: generic_info.clone(),
             spl_vault_src_asset_ata: generic_info.clone(),
             token_program,
+            src_token_inventory: src_token_inventory_info.clone(),
+            dest_token_inventory: dest_token_inventory_info.clone(),
         };
         let mut ctx = Context {
             accounts: &mut accounts.clone(),
@@ -553,14 +623,22 @@ mod tests {
         let res = swap(&ctx, &params);
         assert_eq!(res.unwrap_err(), DexalotError::InvalidTaker.into());
 
+        // banned takers and dest traders can't swap
+        let mut banned_taker_accounts = accounts.clone();
+        banned_taker_accounts.taker_banned_account = generic_info.clone();
+        ctx.accounts = &mut banned_taker_accounts;
+        let res = swap(&ctx, &params);
+        assert_eq!(res.unwrap_err(), DexalotError::AccountBanned.into());
+
+        let mut banned_dest_trader_accounts = accounts.clone();
+        banned_dest_trader_accounts.dest_trader_banned_account = generic_info.clone();
+        ctx.accounts = &mut banned_dest_trader_accounts;
+        let res = swap(&ctx, &params);
+        assert_eq!(res.unwrap_err(), DexalotError::AccountBanned.into());
+
         gc.program_paused = true;
 
-        let portfolio = Portfolio {
-            admin: Pubkey::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
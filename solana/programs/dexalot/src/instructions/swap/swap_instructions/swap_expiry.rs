@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    consts::{COMPLETED_SWAPS_SEED, REBALANCER_SEED},
+    consts::{COMPLETED_SWAPS_SEED, VOLATILITY_ADMIN_SEED},
     errors::DexalotError,
     instructions::generate_map_entry_key, state::CompletedSwapsEntry,
 };
@@ -22,12 +22,12 @@ pub struct UpdateSwapExpiry<'info> {
         bump
     )]
     pub completed_swap_entry: AccountInfo<'info>,
-    /// CHECK: the rebalancer
+    /// CHECK: the volatility admin
     #[account(
-        seeds = [REBALANCER_SEED, authority.key().as_ref()],
+        seeds = [VOLATILITY_ADMIN_SEED, authority.key().as_ref()],
         bump
     )]
-    pub rebalancer: AccountInfo<'info>,
+    pub volatility_admin: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -41,10 +41,10 @@ pub fn update_swap_expiry(
     ctx: &Context<UpdateSwapExpiry>,
     _params: &UpdateSwapExpiryParams,
 ) -> Result<()> {
-    // check is rebalancer
-    let rebalancer = &ctx.accounts.rebalancer;
+    // check is volatility admin
+    let volatility_admin = &ctx.accounts.volatility_admin;
     require!(
-        rebalancer.owner == ctx.program_id,
+        volatility_admin.owner == ctx.program_id,
         DexalotError::UnauthorizedSigner
     );
     Ok(())
@@ -61,7 +61,7 @@ mod tests {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
         let completed_swap_key = Pubkey::new_unique();
-        let rebalancer_key = Pubkey::new_unique();
+        let volatility_admin_key = Pubkey::new_unique();
         let system_program_key = system_program::ID;
 
         let mut authority_lamports = 100;
@@ -90,14 +90,14 @@ mod tests {
             None,
         );
 
-        let mut rebalancer_lamports = 100;
-        let mut rebalancer_data = vec![0u8; 10];
-        let rebalancer_info = create_account_info(
-            &rebalancer_key,
+        let mut volatility_admin_lamports = 100;
+        let mut volatility_admin_data = vec![0u8; 10];
+        let volatility_admin_info = create_account_info(
+            &volatility_admin_key,
             false,
             false,
-            &mut rebalancer_lamports,
-            &mut rebalancer_data,
+            &mut volatility_admin_lamports,
+            &mut volatility_admin_data,
             &program_id,
             false,
             None,
@@ -121,7 +121,7 @@ mod tests {
         let mut update_accounts = UpdateSwapExpiry {
             authority: Signer::try_from(&authority_info)?,
             completed_swap_entry: completed_swap_info,
-            rebalancer: rebalancer_info,
+            volatility_admin: volatility_admin_info,
             system_program,
         };
 
@@ -147,7 +147,7 @@ mod tests {
         let program_id = crate::id();
         let authority_key = Pubkey::new_unique();
         let completed_swap_key = Pubkey::new_unique();
-        let rebalancer_key = Pubkey::new_unique();
+        let volatility_admin_key = Pubkey::new_unique();
         let system_program_key = system_program::ID;
 
         let mut authority_lamports = 100;
@@ -176,15 +176,15 @@ mod tests {
             None,
         );
 
-        let mut rebalancer_lamports = 100;
-        let mut rebalancer_data = vec![0u8; 10];
+        let mut volatility_admin_lamports = 100;
+        let mut volatility_admin_data = vec![0u8; 10];
         let wrong_owner = Pubkey::new_unique();
-        let rebalancer_info = create_account_info(
-            &rebalancer_key,
+        let volatility_admin_info = create_account_info(
+            &volatility_admin_key,
             false,
             false,
-            &mut rebalancer_lamports,
-            &mut rebalancer_data,
+            &mut volatility_admin_lamports,
+            &mut volatility_admin_data,
             &wrong_owner,
             false,
             None,
@@ -207,7 +207,7 @@ mod tests {
         let mut update_accounts = UpdateSwapExpiry {
             authority: Signer::try_from(&authority_info)?,
             completed_swap_entry: completed_swap_info,
-            rebalancer: rebalancer_info,
+            volatility_admin: volatility_admin_info,
             system_program,
         };
 
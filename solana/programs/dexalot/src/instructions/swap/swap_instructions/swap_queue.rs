@@ -3,16 +3,16 @@ use anchor_spl::token::Token;
 
 use crate::{
     consts::{
-        ANCHOR_DISCRIMINATOR, PENDING_SWAPS_SEED, SOL_VAULT_SEED,
-        SPL_VAULT_SEED, AIRDROP_VAULT_SEED
+        ANCHOR_DISCRIMINATOR, BANNED_ACCOUNT_SEED, PENDING_SWAPS_SEED, SOL_VAULT_SEED,
+        SPL_VAULT_SEED, AIRDROP_VAULT_SEED, TOKEN_INVENTORY_SEED
     },
     errors::DexalotError,
     events::{
         SolTransfer, SolTransferTransactions, SolTransferTypes, SwapQueueActions, SwapQueueEvent,
     },
     instructions::{
-        generate_map_entry_key, nonce_to_custom_data, process_xfer_payload_native,
-        process_xfer_payload_spl,
+        check_not_banned, generate_map_entry_key, nonce_to_custom_data, process_xfer_payload_native,
+        process_xfer_payload_spl, XferPayout,
     },
     map_utils::create_entry,
     xfer::{Tx, XFERSolana},
@@ -62,6 +62,19 @@ pub struct RemoveFromSwapQueue<'info> {
             bump,
         )]
     pub airdrop_vault: AccountInfo<'info>,
+    /// CHECK: the token inventory pda
+    #[account(
+            mut,
+            seeds = [TOKEN_INVENTORY_SEED, swap_queue_entry.token_mint.as_ref()],
+            bump,
+        )]
+    pub token_inventory: AccountInfo<'info>,
+    /// CHECK: the banned account pda of the trader, a banned trader's swap stays queued
+    #[account(
+            seeds = [BANNED_ACCOUNT_SEED, swap_queue_entry.trader.as_ref()],
+            bump,
+        )]
+    pub banned_account: AccountInfo<'info>,
 }
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
@@ -80,11 +93,13 @@ pub fn remove_from_swap_queue(
     let airdrop_vault = &ctx.accounts.airdrop_vault;
     let swap_queue_entry = &ctx.accounts.swap_queue_entry;
 
+    check_not_banned(&ctx.accounts.banned_account, ctx.program_id)?;
+
     let xfer = XFERSolana::new(
         Tx::CCTrade,
         swap_queue_entry.trader,
         swap_queue_entry.token_mint,
-        swap_queue_entry.quantity,
+        swap_queue_entry.quantity.into(),
         0, // not used
         nonce_to_custom_data(params.nonce),
         0, // not used
@@ -94,38 +109,33 @@ pub fn remove_from_swap_queue(
 
     if is_native_withdraw {
         // Start native withdraw
-
-        process_xfer_payload_native(
-            &xfer,
-            ctx.bumps.sol_vault,
-            sol_vault,
-            trader,
-            &swap_queue_entry.to_account_info(),
-            system_program,
+        let payout = XferPayout {
+            vault: sol_vault,
+            vault_seed: SOL_VAULT_SEED,
+            vault_bump: ctx.bumps.sol_vault,
+            from: sol_vault,
+            to: trader,
+            swap_queue_entry: &swap_queue_entry.to_account_info(),
             airdrop_vault,
-            ctx.bumps.airdrop_vault,
-            true,
-        )?;
+            airdrop_vault_bump: ctx.bumps.airdrop_vault,
+            system_program,
+            token_inventory: &ctx.accounts.token_inventory,
+        };
+        process_xfer_payload_native(&xfer, &payout, true)?;
     } else {
-        let from = &ctx.accounts.from;
-        let to = &ctx.accounts.to;
-        let token_program = &ctx.accounts.token_program;
-        let spl_vault = &ctx.accounts.spl_vault;
-
-        process_xfer_payload_spl(
-            &xfer,
-            ctx.bumps.spl_vault,
-            SPL_VAULT_SEED.as_ref(),
-            spl_vault,
-            ctx.bumps.sol_vault,
-            sol_vault,
-            from,
-            to,
-            token_program,
-            &swap_queue_entry.to_account_info(),
+        let payout = XferPayout {
+            vault: &ctx.accounts.spl_vault,
+            vault_seed: SPL_VAULT_SEED,
+            vault_bump: ctx.bumps.spl_vault,
+            from: &ctx.accounts.from,
+            to: &ctx.accounts.to,
+            swap_queue_entry: &swap_queue_entry.to_account_info(),
+            airdrop_vault,
+            airdrop_vault_bump: ctx.bumps.airdrop_vault,
             system_program,
-            true,
-        )?;
+            token_inventory: &ctx.accounts.token_inventory,
+        };
+        process_xfer_payload_spl(&xfer, &payout, &ctx.accounts.token_program, true)?;
     }
     emit!(SwapQueueEvent {
         action: SwapQueueActions::Remove,
@@ -202,7 +212,10 @@ mod tests {
     use super::*;
     use anchor_lang::solana_program::system_program;
     use crate::consts::{NATIVE_VAULT_MIN_THRESHOLD, PENDING_SWAPS_SEED};
-    use crate::test_utils::{create_account_info, create_packed_token_account};
+    use crate::test_utils::{
+        create_account_info, create_funded_token_inventory_data, create_not_banned_account,
+        create_packed_token_account,
+    };
 
     #[test]
     fn test_remove_from_swap_queue_native_success() -> Result<()> {
@@ -289,6 +302,22 @@ mod tests {
         let system_program = Program::try_from(&system_info)?;
 
         let params = RemoveFromSwapQueueParams { nonce, dest_trader };
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut accounts = RemoveFromSwapQueue {
             spl_vault: generic_info.clone(),
             sol_vault: generic_info.clone(),
@@ -299,6 +328,8 @@ mod tests {
             system_program,
             swap_queue_entry,
             airdrop_vault,
+            token_inventory: token_inventory_info.clone(),
+            banned_account: banned_info.clone(),
         };
         let ctx = Context {
             accounts: &mut accounts,
@@ -306,6 +337,13 @@ mod tests {
             program_id: &program_id,
             bumps: RemoveFromSwapQueueBumps::default()
         };
+
+        // a banned trader's swap stays queued
+        ctx.accounts.banned_account = generic_info.clone();
+        let res = remove_from_swap_queue(&ctx, &params);
+        assert_eq!(res.unwrap_err(), DexalotError::AccountBanned.into());
+
+        ctx.accounts.banned_account = banned_info.clone();
         let res = remove_from_swap_queue(&ctx, &params);
         assert!(res.is_ok());
         Ok(())
@@ -412,6 +450,22 @@ mod tests {
         );
 
         let params = RemoveFromSwapQueueParams { nonce, dest_trader };
+        let token_inventory_key = Pubkey::new_unique();
+        let mut token_inventory_lamports = 100;
+        let mut token_inventory_data = create_funded_token_inventory_data();
+        let token_inventory_info = create_account_info(
+            &token_inventory_key,
+            false,
+            true,
+            &mut token_inventory_lamports,
+            &mut token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut accounts = RemoveFromSwapQueue {
             spl_vault: generic_info.clone(),
             sol_vault: generic_info.clone(),
@@ -422,6 +476,8 @@ mod tests {
             system_program,
             swap_queue_entry,
             airdrop_vault,
+            token_inventory: token_inventory_info.clone(),
+            banned_account: banned_info.clone(),
         };
         let ctx = Context {
             accounts: &mut accounts,
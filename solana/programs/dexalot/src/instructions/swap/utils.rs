@@ -1,6 +1,7 @@
 use crate::{
     consts::{NATIVE_VAULT_MIN_THRESHOLD, SOL_VAULT_SEED, SPL_VAULT_SEED},
     errors::DexalotError,
+    instructions::update_inventory,
     state::GlobalConfig,
 };
 
@@ -43,6 +44,7 @@ pub struct TakeFunds<'info> {
     pub dest_trader_src_asset_ata: AccountInfo<'info>,
     pub taker_src_asset_ata: AccountInfo<'info>,
     pub spl_vault_src_asset_ata: AccountInfo<'info>,
+    pub src_token_inventory: AccountInfo<'info>,
 }
 impl<'info> TakeFunds<'info> {
     pub fn from_swap_context(ctx: &Context<Swap<'info>>) -> Self {
@@ -55,6 +57,7 @@ impl<'info> TakeFunds<'info> {
             dest_trader_src_asset_ata: ctx.accounts.dest_trader_src_asset_ata.clone(),
             taker_src_asset_ata: ctx.accounts.taker_src_asset_ata.clone(),
             spl_vault_src_asset_ata: ctx.accounts.spl_vault_src_asset_ata.clone(),
+            src_token_inventory: ctx.accounts.src_token_inventory.clone(),
         }
     }
 
@@ -68,6 +71,7 @@ impl<'info> TakeFunds<'info> {
             dest_trader_src_asset_ata: ctx.accounts.taker_src_asset_ata.clone(), // this value is not used so I just pass taker_src_asset_ata
             taker_src_asset_ata: ctx.accounts.taker_src_asset_ata.clone(),
             spl_vault_src_asset_ata: ctx.accounts.spl_vault_src_asset_ata.clone(),
+            src_token_inventory: ctx.accounts.src_token_inventory.clone(),
         }
     }
 }
@@ -142,6 +146,10 @@ pub fn take_funds<'info>(
         }
     }
 
+    update_inventory(&take_funds_accounts.src_token_inventory, |inventory| {
+        inventory.add_rfq_inventory(swap_data.src_amount)
+    })?;
+
     Ok(())
 }
 
@@ -219,6 +227,10 @@ pub fn release_funds(ctx: &Context<Swap>, swap_data: &SwapData) -> Result<()> {
             )?;
         }
     }
+
+    update_inventory(&ctx.accounts.dest_token_inventory, |inventory| {
+        inventory.remove_rfq_inventory(swap_data.dest_amount)
+    })?;
     Ok(())
 }
 
@@ -337,8 +349,11 @@ pub fn nonce_to_custom_data(nonce: [u8; 12]) -> [u8; 18] {
 mod tests {
     use super::*;
     use crate::consts::{COMPLETED_SWAPS_SEED, UNUSED_ADDRESS_PUBLIC_KEY};
-    use crate::state::Portfolio;
-    use crate::test_utils::{create_account_info, create_packed_token_account};
+    use crate::state::{Portfolio, TokenInventory};
+    use crate::test_utils::{
+        create_account_info, create_not_banned_account, create_packed_token_account,
+        create_portfolio_data, create_token_inventory_data,
+    };
     use anchor_lang::solana_program::system_program;
     use anchor_lang::Discriminator;
     use anchor_spl::associated_token;
@@ -426,21 +441,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
+            ..Default::default()
         };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
-        };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -563,12 +567,44 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let dest_token_inventory_key = Pubkey::new_unique();
+        let mut dest_token_inventory_lamports = 100;
+        let mut dest_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let dest_token_inventory_info = create_account_info(
+            &dest_token_inventory_key,
+            false,
+            true,
+            &mut dest_token_inventory_lamports,
+            &mut dest_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut swap_accounts = Swap {
             clock: Sysvar::from_account_info(&clock_info)?,
             sender: Signer::try_from(&sender_info)?,
             taker: generic_info.clone(),
             dest_trader: generic_info.clone(),
             completed_swaps_entry: completed_swaps_info.clone(),
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_info)?,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_info.clone(),
@@ -582,6 +618,8 @@ mod tests {
             spl_vault_dest_asset_ata: generic_info.clone(),
             spl_vault_src_asset_ata: generic_info.clone(),
             token_program,
+            src_token_inventory: src_token_inventory_info.clone(),
+            dest_token_inventory: dest_token_inventory_info.clone(),
         };
 
         let ctx = Context {
@@ -624,21 +662,10 @@ mod tests {
         let bytes = hex::decode(hex_str).unwrap();
         let address: [u8; 20] = bytes.try_into().unwrap();
         let gc = GlobalConfig {
-            allow_deposit: false,
-            program_paused: false,
-            native_deposits_restricted: false,
-            src_chain_id: 0,
-            default_chain_id: 0,
-            airdrop_amount: 0,
             swap_signer: address,
-            out_nonce: 0,
-        };
-        let portfolio = Portfolio {
-            admin: Default::default(),
-            global_config: gc,
-            bump: 0,
+            ..Default::default()
         };
-        let mut portfolio_data = portfolio.try_to_vec()?;
+        let mut portfolio_data = create_portfolio_data(gc);
         let mut portfolio_lamports = 100;
         let portfolio_account = create_account_info(
             &generic_key,
@@ -762,12 +789,44 @@ mod tests {
         );
         let token_program = Program::<Token>::try_from(&token_program_info)?;
 
+        let src_token_inventory_key = Pubkey::new_unique();
+        let mut src_token_inventory_lamports = 100;
+        let mut src_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let src_token_inventory_info = create_account_info(
+            &src_token_inventory_key,
+            false,
+            true,
+            &mut src_token_inventory_lamports,
+            &mut src_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let dest_token_inventory_key = Pubkey::new_unique();
+        let mut dest_token_inventory_lamports = 100;
+        let mut dest_token_inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let dest_token_inventory_info = create_account_info(
+            &dest_token_inventory_key,
+            false,
+            true,
+            &mut dest_token_inventory_lamports,
+            &mut dest_token_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let banned_info = create_not_banned_account();
+
         let mut swap_accounts = Swap {
             clock: Sysvar::from_account_info(&clock_info)?,
             sender: Signer::try_from(&sender_info)?,
             taker: generic_info.clone(),
             dest_trader: generic_info.clone(),
             completed_swaps_entry: completed_swaps_info.clone(),
+            taker_banned_account: banned_info.clone(),
+            dest_trader_banned_account: banned_info.clone(),
             system_program: Program::try_from(&system_program_info)?,
             portfolio: Account::try_from(&portfolio_account)?,
             spl_vault: generic_info.clone(),
@@ -781,6 +840,8 @@ mod tests {
             spl_vault_dest_asset_ata: generic_info.clone(),
             spl_vault_src_asset_ata: generic_info.clone(),
             token_program,
+            src_token_inventory: src_token_inventory_info.clone(),
+            dest_token_inventory: dest_token_inventory_info.clone(),
         };
 
         let ctx = Context {
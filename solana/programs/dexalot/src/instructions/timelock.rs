@@ -0,0 +1,287 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{ADMIN_SEED, MAX_CHANGE_DATA_LEN, PENDING_CHANGE_SEED, PORTFOLIO_SEED};
+use crate::errors::DexalotError;
+use crate::events::{ParameterChangeCancelled, ParameterChangeExecuted, ParameterChangeQueued};
+use crate::state::{PendingChange, Portfolio, TimelockedAction};
+
+fn current_timestamp() -> Result<i64> {
+    Ok(if cfg!(not(test)) {Clock::get()?.unix_timestamp} else {123})
+}
+
+/// Checks that the pending change is for the action and params and its timelock has elapsed
+/// The caller closes the pending change once the change is applied
+pub fn consume_change<T: AnchorSerialize>(
+    pending_change: &PendingChange,
+    action: TimelockedAction,
+    params: &T,
+) -> Result<()> {
+    require!(
+        pending_change.action == action && pending_change.data == params.try_to_vec()?,
+        DexalotError::PendingChangeMismatch
+    );
+    require!(
+        current_timestamp()? >= pending_change.eta,
+        DexalotError::TimelockNotElapsed
+    );
+
+    emit!(ParameterChangeExecuted {
+        id: pending_change.id,
+        action,
+    });
+
+    Ok(())
+}
+
+pub fn queue_change(ctx: &mut Context<QueueChange>, params: &QueueChangeParams) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+    require!(
+        params.data.len() <= MAX_CHANGE_DATA_LEN,
+        DexalotError::ChangeDataTooLong
+    );
+
+    let eta = current_timestamp()? + ctx.accounts.portfolio.global_config.timelock_delay as i64;
+    let pending_change = &mut ctx.accounts.pending_change;
+    pending_change.id = params.id;
+    pending_change.queued_by = ctx.accounts.authority.key();
+    pending_change.action = params.action;
+    pending_change.data = params.data.clone();
+    pending_change.eta = eta;
+
+    emit!(ParameterChangeQueued {
+        id: params.id,
+        action: params.action,
+        data: params.data.clone(),
+        eta,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_change(ctx: &Context<CancelChange>, params: &CancelChangeParams) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    emit!(ParameterChangeCancelled {
+        id: params.id,
+        action: ctx.accounts.pending_change.action,
+    });
+
+    Ok(())
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct QueueChangeParams {
+    pub id: u64,
+    pub action: TimelockedAction,
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct CancelChangeParams {
+    pub id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(params: QueueChangeParams)]
+pub struct QueueChange<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    #[account(seeds = [PORTFOLIO_SEED], bump)]
+    pub portfolio: Account<'info, Portfolio>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PendingChange::LEN,
+        seeds = [PENDING_CHANGE_SEED, &params.id.to_be_bytes()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CancelChangeParams)]
+pub struct CancelChange<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [PENDING_CHANGE_SEED, &params.id.to_be_bytes()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::SetDefaultChainId;
+    use crate::test_utils::{create_account_info, create_pending_change_data};
+    use anchor_lang::{system_program, Discriminator};
+
+    #[test]
+    fn test_consume_change() -> Result<()> {
+        let params = SetDefaultChainId { chain_id: 42 };
+        let mut pending_change = PendingChange {
+            id: 1,
+            queued_by: Pubkey::new_unique(),
+            action: TimelockedAction::SetDefaultChain,
+            data: params.try_to_vec()?,
+            eta: 124,
+        };
+
+        let result = consume_change(&pending_change, TimelockedAction::SetSwapSigner, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::PendingChangeMismatch.into());
+
+        let other_params = SetDefaultChainId { chain_id: 43 };
+        let result = consume_change(&pending_change, TimelockedAction::SetDefaultChain, &other_params);
+        assert_eq!(result.unwrap_err(), DexalotError::PendingChangeMismatch.into());
+
+        let result = consume_change(&pending_change, TimelockedAction::SetDefaultChain, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::TimelockNotElapsed.into());
+
+        pending_change.eta = 123;
+        consume_change(&pending_change, TimelockedAction::SetDefaultChain, &params)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_queue_change() -> Result<()> {
+        let program_id = crate::id();
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(&authority_key, true, true, &mut authority_lamports, &mut authority_data, &program_id, false, None);
+
+        let not_admin_owner = Pubkey::new_unique();
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(&admin_key, false, false, &mut admin_lamports, &mut admin_data, &not_admin_owner, false, None);
+
+        let portfolio_key = Pubkey::new_unique();
+        let mut portfolio_lamports = 100;
+        let mut portfolio_data = vec![0u8; Portfolio::LEN];
+        let portfolio_info = create_account_info(&portfolio_key, false, false, &mut portfolio_lamports, &mut portfolio_data, &program_id, false, Some(Portfolio::discriminator()));
+
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = vec![0u8; PendingChange::LEN];
+        let pending_change_info = create_account_info(&pending_change_key, false, true, &mut pending_change_lamports, &mut pending_change_data, &program_id, false, Some(PendingChange::discriminator()));
+
+        let system_program_key = system_program::ID;
+        let mut sp_lamports = 100;
+        let mut sp_data = vec![0u8; 10];
+        let system_program_info = create_account_info(&system_program_key, false, false, &mut sp_lamports, &mut sp_data, &system_program_key, true, None);
+
+        let mut accounts = QueueChange {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            portfolio: Account::try_from(&portfolio_info)?,
+            pending_change: Account::try_from(&pending_change_info)?,
+            system_program: Program::try_from(&system_program_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: QueueChangeBumps::default(),
+        };
+
+        let mut params = QueueChangeParams {
+            id: 1,
+            action: TimelockedAction::SetDefaultChain,
+            data: SetDefaultChainId { chain_id: 42 }.try_to_vec()?,
+        };
+        let result = queue_change(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        admin_info.assign(&program_id);
+        ctx.accounts.portfolio.global_config.timelock_delay = 48 * 60 * 60;
+        let data = params.data.clone();
+        params.data = vec![0; MAX_CHANGE_DATA_LEN + 1];
+        let result = queue_change(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ChangeDataTooLong.into());
+
+        params.data = data;
+        queue_change(&mut ctx, &params)?;
+        let pending_change = &ctx.accounts.pending_change;
+        assert_eq!(pending_change.queued_by, authority_key);
+        assert_eq!(pending_change.action, TimelockedAction::SetDefaultChain);
+        assert_eq!(pending_change.data, params.data);
+        assert_eq!(pending_change.eta, 123 + 48 * 60 * 60);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cancel_change() -> Result<()> {
+        let program_id = crate::id();
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(&authority_key, true, true, &mut authority_lamports, &mut authority_data, &program_id, false, None);
+
+        let not_admin_owner = Pubkey::new_unique();
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(&admin_key, false, false, &mut admin_lamports, &mut admin_data, &not_admin_owner, false, None);
+
+        let pending_change_key = Pubkey::new_unique();
+        let mut pending_change_lamports = 100;
+        let mut pending_change_data = create_pending_change_data(
+            TimelockedAction::SetDefaultChain,
+            &SetDefaultChainId { chain_id: 42 },
+            123 + 48 * 60 * 60,
+        );
+        let pending_change_info = create_account_info(&pending_change_key, false, true, &mut pending_change_lamports, &mut pending_change_data, &program_id, false, None);
+
+        let mut accounts = CancelChange {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info.clone(),
+            pending_change: Account::try_from(&pending_change_info)?,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: CancelChangeBumps::default(),
+        };
+
+        let params = CancelChangeParams { id: 1 };
+        let result = cancel_change(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        // any admin can cancel, not only the one that queued it
+        admin_info.assign(&program_id);
+        cancel_change(&ctx, &params)?;
+        Ok(())
+    }
+}
@@ -1,63 +1,91 @@
-use std::collections::HashSet;
-
 use anchor_lang::prelude::*;
-use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{spl_token, Mint, Token, TokenAccount};
 
 use crate::consts::{
-    ADMIN_SEED, MAX_TOKENS, PORTFOLIO_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED,
-    TOKEN_DETAILS_SEED,
+    ADMIN_SEED, MULTISIG_CONFIG_SEED, PORTFOLIO_SEED, SPL_USER_FUNDS_VAULT_SEED, SPL_VAULT_SEED,
+    SYMBOL_SEED, TOKEN_DETAILS_SEED, TOKEN_INVENTORY_SEED, TOKEN_LIST_SEED,
 };
 use crate::errors::DexalotError;
 use crate::events::ParameterUpdatedEvent;
-use crate::state::{Admin, Portfolio, TokenDetails, TokenList};
+use crate::instructions::{authorize_action, load_multisig_config};
+use crate::state::{
+    Admin, Portfolio, Proposal, SensitiveAction, SymbolEntry, TokenDetails, TokenInventory,
+    TokenList,
+};
 
 pub fn add_token<'info>(
     ctx: &mut Context<'_, '_, 'info, 'info, AddToken<'info>>,
     params: &AddTokenParams,
 ) -> Result<()> {
-    // Validate that the signer is an admin.
-    let admin = &ctx.accounts.admin;
-    require_keys_eq!(
-        *admin.owner,
-        *ctx.program_id,
-        DexalotError::UnauthorizedSigner
-    );
+    // Validate that the signer is an admin or the action is approved.
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    let proposer = authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut().map(|proposal| &mut **proposal),
+        SensitiveAction::AddToken,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    // the token list pages follow the approver admins of an executed proposal
+    let token_lists = match (proposer, ctx.accounts.proposal.as_ref()) {
+        (Some(_), Some(proposal)) => &ctx.remaining_accounts[proposal.approvals.len()..],
+        _ => ctx.remaining_accounts,
+    };
 
     // require that remaining accounts are not empty
     require!(
-        !ctx.remaining_accounts.is_empty(),
+        !token_lists.is_empty(),
         DexalotError::AccountsNotProvided
     );
 
     // Populate the token details account.
     let token_details = &mut ctx.accounts.token_details;
     token_details.decimals = params.decimals;
+    token_details.l1_decimals = params.l1_decimals;
     token_details.token_address = params.token_address;
     token_details.symbol = params.symbol;
+    token_details.allow_deposit = true;
 
-    let mut iter = ctx.remaining_accounts.iter().peekable();
+    let mut iter = token_lists.iter().peekable();
 
     while let Some(token_list_info) = iter.next() {
-        let mut token_list = Account::<TokenList>::try_from(token_list_info)?;
+        let token_list_loader = AccountLoader::<TokenList>::try_from(token_list_info)?;
+        let mut token_list = token_list_loader.load_mut()?;
         let token_mint = params.token_address;
 
         require!(
-            !token_list.tokens.contains(&token_mint),
+            !token_list.contains(&token_mint),
             DexalotError::TokenAlreadyAdded
         );
 
-        if token_list.tokens.len() < MAX_TOKENS {
-            token_list.tokens.push(token_mint);
-            token_list.exit(&crate::ID)?;
+        if !token_list.is_full() {
+            token_list.insert(token_mint)?;
             break;
-        } else if token_list.next_page.is_some() && iter.peek().is_some() {
+        } else if let (Some(next_page), Some(next_page_info)) = (token_list.next_page(), iter.peek()) {
+            require_keys_eq!(
+                next_page_info.key(),
+                next_page,
+                DexalotError::InvalidTokenListPage
+            );
             continue;
         } else {
             return Err(DexalotError::TokenListFull.into());
         }
     }
 
+    // Index the symbol, a symbol maps to a single mint
+    let symbol_entry = &mut ctx.accounts.symbol_entry;
+    require_keys_eq!(
+        symbol_entry.token_mint,
+        Pubkey::default(),
+        DexalotError::SymbolAlreadyAdded
+    );
+    symbol_entry.token_mint = params.token_address;
 
     emit!(ParameterUpdatedEvent {
         pair: params.symbol,
@@ -73,8 +101,9 @@ pub fn add_token<'info>(
 
 // Remove a token from the system:
 // - Validates caller is admin
+// - Validates bridge fees are collected and both vault token accounts are empty
 // - Removes token from TokenList
-// - Closes TokenDetails PDA
+// - Closes TokenDetails, SymbolEntry and TokenInventory PDAs and the vault token accounts
 pub fn remove_token<'info>(
     ctx: &Context<'_, '_, 'info, 'info, RemoveToken<'info>>,
     params: &RemoveTokenParams,
@@ -90,30 +119,52 @@ pub fn remove_token<'info>(
     require!(global_config.program_paused, DexalotError::ProgramNotPaused);
     let token_mint = params.token_address;
 
+    // Delisting a token can't leave funds behind in its vaults
+    require!(
+        ctx.accounts.token_inventory.bridge_fees == 0,
+        DexalotError::BridgeFeesNotCollected
+    );
+    require!(
+        ctx.accounts.spl_token_account.amount == 0
+            && ctx.accounts.spl_user_funds_token_account.amount == 0,
+        DexalotError::TokenVaultNotEmpty
+    );
+
     let mut iter = ctx.remaining_accounts.iter().peekable();
 
     while let Some(token_list_info) = iter.next() {
-        let mut token_list = Account::<TokenList>::try_from(token_list_info)?;
-
-        // deserialize the token list into a hashset
-        let mut tokens_map = token_list
-            .tokens
-            .iter()
-            .copied()
-            .collect::<HashSet<Pubkey>>();
+        let token_list_loader = AccountLoader::<TokenList>::try_from(token_list_info)?;
+        let mut token_list = token_list_loader.load_mut()?;
 
-        if !tokens_map.contains(&token_mint) && token_list.next_page.is_none() {
-            return Err(DexalotError::TokenNotFound.into());
-        } else if !tokens_map.contains(&token_mint) && token_list.next_page.is_some() && iter.peek().is_some() {
+        if token_list.remove(&token_mint) {
+            break;
+        } else if let (Some(next_page), Some(next_page_info)) = (token_list.next_page(), iter.peek()) {
+            require_keys_eq!(
+                next_page_info.key(),
+                next_page,
+                DexalotError::InvalidTokenListPage
+            );
             continue;
         } else {
-            tokens_map.remove(&token_mint);
-            token_list.tokens = tokens_map.into_iter().collect();
-            // Serialize the updated data back into the account
-            token_list.exit(&crate::ID)?;
+            return Err(DexalotError::TokenNotFound.into());
         }
     }
 
+    close_vault_token_account(
+        &ctx.accounts.spl_token_account.to_account_info(),
+        &ctx.accounts.spl_vault,
+        &[SPL_VAULT_SEED, &[ctx.bumps.spl_vault]],
+        &ctx.accounts.receiver,
+        &ctx.accounts.token_program,
+    )?;
+    close_vault_token_account(
+        &ctx.accounts.spl_user_funds_token_account.to_account_info(),
+        &ctx.accounts.spl_user_funds_vault,
+        &[SPL_USER_FUNDS_VAULT_SEED, &[ctx.bumps.spl_user_funds_vault]],
+        &ctx.accounts.receiver,
+        &ctx.accounts.token_program,
+    )?;
+
     emit!(ParameterUpdatedEvent {
         pair: token_mint.to_bytes(),
         parameter: "P-REMOVETOKEN".to_owned(),
@@ -124,6 +175,256 @@ pub fn remove_token<'info>(
     Ok(())
 }
 
+/// Closes an empty vault token account, returning its rent to the receiver
+fn close_vault_token_account<'info>(
+    token_account: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    vault_seeds: &[&[u8]],
+    receiver: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let ix = spl_token::instruction::close_account(
+        &token_program.key(),
+        &token_account.key(),
+        &receiver.key(),
+        &vault.key(),
+        &[],
+    )?;
+
+    if cfg!(not(test)) {
+        invoke_signed(
+            &ix,
+            &[
+                token_account.clone(),
+                receiver.clone(),
+                vault.clone(),
+                token_program.clone(),
+            ],
+            &[vault_seeds],
+        )?;
+    }
+    Ok(())
+}
+
+/// Updates the symbol, L1 decimals and deposit settings of an added token
+/// A new symbol moves the token's symbol index entry
+pub fn update_token(ctx: &mut Context<UpdateToken>, params: &UpdateTokenParams) -> Result<()> {
+    let multisig_config = load_multisig_config(&ctx.accounts.multisig_config)?;
+    authorize_action(
+        &ctx.accounts.admin,
+        &multisig_config,
+        ctx.accounts.proposal.as_deref_mut().map(|proposal| &mut **proposal),
+        SensitiveAction::UpdateToken,
+        params,
+        ctx.remaining_accounts,
+        ctx.program_id,
+    )?;
+
+    let token_details = &mut ctx.accounts.token_details;
+    let old_symbol = token_details.symbol;
+
+    if params.symbol != old_symbol {
+        let new_symbol_entry = &mut ctx.accounts.new_symbol_entry;
+        require_keys_eq!(
+            new_symbol_entry.token_mint,
+            Pubkey::default(),
+            DexalotError::SymbolAlreadyAdded
+        );
+        new_symbol_entry.token_mint = params.token_address;
+
+        let symbol_entry = &mut ctx.accounts.symbol_entry;
+        symbol_entry.token_mint = Pubkey::default();
+        if cfg!(not(test)) {
+            symbol_entry.close(ctx.accounts.authority.to_account_info())?;
+        }
+        token_details.symbol = params.symbol;
+
+        emit!(ParameterUpdatedEvent {
+            pair: old_symbol,
+            parameter: "P-TOKENSYMBOL".to_owned(),
+            old_value: 1,
+            new_value: 0
+        });
+        emit!(ParameterUpdatedEvent {
+            pair: params.symbol,
+            parameter: "P-TOKENSYMBOL".to_owned(),
+            old_value: 0,
+            new_value: 1
+        });
+    }
+
+    let updates = [
+        (
+            "P-L1DECIMALS",
+            u64::from(token_details.l1_decimals),
+            u64::from(params.l1_decimals),
+        ),
+        (
+            "P-ALLOWDEP",
+            u64::from(token_details.allow_deposit),
+            u64::from(params.allow_deposit),
+        ),
+        ("P-MINDEP", token_details.min_deposit, params.min_deposit),
+        (
+            "P-TOKENPAUSED",
+            u64::from(token_details.paused),
+            u64::from(params.paused),
+        ),
+    ];
+    token_details.l1_decimals = params.l1_decimals;
+    token_details.allow_deposit = params.allow_deposit;
+    token_details.min_deposit = params.min_deposit;
+    token_details.paused = params.paused;
+
+    for (parameter, old_value, new_value) in updates {
+        if old_value != new_value {
+            emit!(ParameterUpdatedEvent {
+                pair: token_details.symbol,
+                parameter: parameter.to_owned(),
+                old_value,
+                new_value
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: UpdateTokenParams)]
+pub struct UpdateToken<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: the admin pda
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [TOKEN_DETAILS_SEED, params.token_address.as_ref()],
+        bump
+    )]
+    pub token_details: Box<Account<'info, TokenDetails>>,
+    // the index entry of the current symbol
+    #[account(
+        mut,
+        seeds = [SYMBOL_SEED, token_details.symbol.as_ref()],
+        bump
+    )]
+    pub symbol_entry: Box<Account<'info, SymbolEntry>>,
+    // the index entry of the new symbol, the current one when the symbol is unchanged
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SymbolEntry::LEN,
+        seeds = [SYMBOL_SEED, params.symbol.as_ref()],
+        bump
+    )]
+    pub new_symbol_entry: Box<Account<'info, SymbolEntry>>,
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(seeds = [MULTISIG_CONFIG_SEED], bump)]
+    pub multisig_config: AccountInfo<'info>,
+    #[account(mut)]
+    pub proposal: Option<Box<Account<'info, Proposal>>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateTokenParams {
+    pub token_address: Pubkey,
+    pub symbol: [u8; 32],
+    pub l1_decimals: u8,
+    pub allow_deposit: bool,
+    pub min_deposit: u64,
+    pub paused: bool,
+}
+
+/// Returns the mint registered for a token symbol
+pub fn get_token_by_symbol(ctx: &Context<GetTokenBySymbol>) -> Result<Pubkey> {
+    Ok(ctx.accounts.symbol_entry.token_mint)
+}
+
+#[derive(Accounts)]
+#[instruction(params: GetTokenBySymbolParams)]
+pub struct GetTokenBySymbol<'info> {
+    #[account(
+        seeds = [SYMBOL_SEED, params.symbol.as_ref()],
+        bump
+    )]
+    pub symbol_entry: Account<'info, SymbolEntry>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct GetTokenBySymbolParams {
+    pub symbol: [u8; 32],
+}
+
+/// Links a new token list page after the last one, once the last page is full
+/// Token list pages are seeded by their index, the first page being `TOKEN_LIST_PAGE_1_SEED`
+pub fn add_token_list_page(
+    ctx: &mut Context<AddTokenListPage>,
+    params: &AddTokenListPageParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    let mut last_page = ctx.accounts.last_page.load_mut()?;
+    require!(
+        last_page.next_page().is_none(),
+        DexalotError::TokenListPageLinked
+    );
+    require!(last_page.is_full(), DexalotError::TokenListPageNotFull);
+    last_page.next_page = ctx.accounts.new_page.key();
+
+    emit!(ParameterUpdatedEvent {
+        pair: ctx.accounts.new_page.key().to_bytes(),
+        parameter: "P-ADDTOKENPAGE".to_owned(),
+        old_value: params.page.saturating_sub(1).into(),
+        new_value: params.page.into()
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: AddTokenListPageParams)]
+pub struct AddTokenListPage<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [TOKEN_LIST_SEED, params.page.saturating_sub(1).to_string().as_bytes()],
+        bump
+    )]
+    pub last_page: AccountLoader<'info, TokenList>,
+    #[account(
+        init,
+        payer = authority,
+        space = TokenList::LEN,
+        seeds = [TOKEN_LIST_SEED, params.page.to_string().as_bytes()],
+        bump
+    )]
+    pub new_page: AccountLoader<'info, TokenList>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct AddTokenListPageParams {
+    // index of the new page, the first page being 0
+    pub page: u8,
+}
+
 #[derive(Accounts)]
 #[instruction(params: AddTokenParams)]
 pub struct AddToken<'info> {
@@ -138,6 +439,16 @@ pub struct AddToken<'info> {
     )]
     pub admin: AccountInfo<'info>,
 
+    /// CHECK: Sensitive actions are unprotected until the multisig config is created
+    #[account(
+        seeds = [MULTISIG_CONFIG_SEED],
+        bump
+    )]
+    pub multisig_config: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub proposal: Option<Box<Account<'info, Proposal>>>,
+
     /// CHECK: Used to set the authority for the associated token account
     #[account(
         constraint = spl_vault.owner == __program_id,
@@ -160,6 +471,14 @@ pub struct AddToken<'info> {
         bump
     )]
     pub token_details: Box<Account<'info, TokenDetails>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SymbolEntry::LEN,
+        seeds = [SYMBOL_SEED, params.symbol.as_ref()],
+        bump
+    )]
+    pub symbol_entry: Box<Account<'info, SymbolEntry>>,
     /// The token mint for the supported token
     pub token_mint: Box<Account<'info, Mint>>,
     #[account(
@@ -176,6 +495,14 @@ pub struct AddToken<'info> {
         associated_token::authority = spl_user_funds_vault,
     )]
     pub spl_user_funds_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TokenInventory::LEN,
+        seeds = [TOKEN_INVENTORY_SEED, params.token_address.as_ref()],
+        bump
+    )]
+    pub token_inventory: Box<Account<'info, TokenInventory>>,
     /// Programs & Sysvars
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -187,6 +514,7 @@ pub struct AddTokenParams {
     symbol: [u8; 32],
     token_address: Pubkey,
     decimals: u8,
+    l1_decimals: u8,
 }
 
 #[derive(Clone, AnchorDeserialize, AnchorSerialize)]
@@ -224,10 +552,58 @@ pub struct RemoveToken<'info> {
     )]
     pub token_details: Account<'info, TokenDetails>,
 
+    #[account(
+        mut,
+        close = receiver,
+        constraint = symbol_entry.token_mint == params.token_address @ DexalotError::TokenNotFound,
+        seeds = [SYMBOL_SEED, token_details.symbol.as_ref()],
+        bump
+    )]
+    pub symbol_entry: Account<'info, SymbolEntry>,
+
+    // a token added again starts with a fresh ledger
+    #[account(
+        mut,
+        close = receiver,
+        seeds = [TOKEN_INVENTORY_SEED, params.token_address.as_ref()],
+        bump
+    )]
+    pub token_inventory: Box<Account<'info, TokenInventory>>,
+
+    /// CHECK: Owner of the vault token account
+    #[account(
+        seeds = [SPL_VAULT_SEED],
+        bump,
+    )]
+    pub spl_vault: AccountInfo<'info>,
+    /// CHECK: Owner of the user funds vault token account
+    #[account(
+        seeds = [SPL_USER_FUNDS_VAULT_SEED],
+        bump,
+    )]
+    pub spl_user_funds_vault: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = spl_token_account.key() == get_associated_token_address(
+            spl_vault.key,
+            &params.token_address,
+        ) @ DexalotError::InvalidDestinationOwner,
+    )]
+    pub spl_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = spl_user_funds_token_account.key() == get_associated_token_address(
+            spl_user_funds_vault.key,
+            &params.token_address,
+        ) @ DexalotError::InvalidDestinationOwner,
+    )]
+    pub spl_user_funds_token_account: Box<Account<'info, TokenAccount>>,
+
     #[account(mut)]
     pub receiver: SystemAccount<'info>,
 
     pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
 }
 
 #[cfg(test)]
@@ -236,8 +612,12 @@ mod tests {
     use anchor_lang::{system_program, Discriminator};
     use anchor_lang::solana_program::program_pack::Pack;
     use anchor_spl::token::spl_token;
-    use crate::state::{TokenDetails, TokenList, Admin, Portfolio};
-    use crate::test_utils::create_account_info;
+    use crate::consts::TOKEN_LIST_PAGE_CAPACITY;
+    use crate::state::{TokenDetails, TokenList, Admin, Portfolio, SymbolEntry};
+    use crate::test_utils::{
+        create_account_info, create_full_token_list_data, create_token_inventory_data,
+        create_token_list_data,
+    };
     use spl_token::state::{Account as SplTokenAccount, AccountState};
 
     #[test]
@@ -274,12 +654,7 @@ mod tests {
         );
 
         let mut generic_token_list_lamports = 100;
-        let mut generic_token_list_data = vec![0u8; 677];
-        let generic_token_list = TokenList {
-            next_page: Some(Pubkey::new_unique()),
-            tokens: vec![Pubkey::new_unique(); 20],
-        };
-        generic_token_list_data.copy_from_slice(&generic_token_list.try_to_vec()?);
+        let mut generic_token_list_data = create_full_token_list_data(Some(generic_pubkey));
         let generic_token_list_account = create_account_info(
             &generic_pubkey,
             false,
@@ -292,12 +667,7 @@ mod tests {
         );
 
         let mut token_list_lamports = 100;
-        let mut token_list_data = vec![0u8; 100];
-        let token_list = TokenList {
-            next_page: None,
-            tokens: vec![Pubkey::new_unique()],
-        };
-        token_list.try_serialize(&mut token_list_data)?;
+        let mut token_list_data = create_token_list_data(&[Pubkey::new_unique()], None);
         let token_list_account = create_account_info(
             &generic_pubkey,
             false,
@@ -407,18 +777,78 @@ mod tests {
         );
         let spl_token_account: Account<TokenAccount> = Account::try_from(&spl_token_info)?;
 
+        let mut inventory_lamports = 100;
+        let mut inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let inventory_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut inventory_lamports,
+            &mut inventory_data,
+            &program_id,
+            false,
+            None
+        );
+
+        let mut symbol_entry_lamports = 100;
+        let mut symbol_entry_data = vec![0u8; SymbolEntry::LEN];
+        let symbol_entry_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut symbol_entry_lamports,
+            &mut symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        // the multisig config is not created, an admin is enough
+
+        let multisig_config_key = Pubkey::new_unique();
+
+        let mut multisig_config_lamports = 0;
+
+        let mut multisig_config_data = vec![];
+
+        let multisig_config = create_account_info(
+
+            &multisig_config_key,
+
+            false,
+
+            false,
+
+            &mut multisig_config_lamports,
+
+            &mut multisig_config_data,
+
+            &program_id,
+
+            false,
+
+            None,
+
+        );
+
+
         let mut add_token_accounts = AddToken {
             authority,
             admin: admin_account.clone(),
+            multisig_config,
+            proposal: None,
             spl_vault: generic_account.clone(),
             spl_user_funds_vault: generic_account.clone(),
             token_details: Box::new(Account::try_from(&token_details_account)?),
+            symbol_entry: Box::new(Account::try_from(&symbol_entry_info)?),
             token_mint: Box::new(Account::try_from(&mint_info)?),
             spl_token_account: Box::new(spl_token_account.clone()),
             spl_user_funds_token_account: Box::new(spl_token_account),
+            token_inventory: Box::new(Account::try_from(&inventory_info)?),
             system_program,
             token_program,
             associated_token_program,
+
         };
 
         let dummy_token_address = Pubkey::new_unique();
@@ -427,6 +857,7 @@ mod tests {
         dummy_symbol[..symbol_bytes.len()].copy_from_slice(symbol_bytes);
         let params = AddTokenParams {
             decimals: 6,
+            l1_decimals: 18,
             token_address: dummy_token_address,
             symbol: dummy_symbol,
         };
@@ -440,6 +871,15 @@ mod tests {
 
         let result = add_token(&mut ctx, &params);
         assert!(result.is_ok());
+        assert_eq!(ctx.accounts.symbol_entry.token_mint, dummy_token_address);
+
+        // the symbol is already mapped to the first mint
+        let duplicate_params = AddTokenParams {
+            token_address: Pubkey::new_unique(),
+            ..params
+        };
+        let result = add_token(&mut ctx, &duplicate_params);
+        assert_eq!(result.unwrap_err(), DexalotError::SymbolAlreadyAdded.into());
         Ok(())
     }
 
@@ -540,12 +980,7 @@ mod tests {
         let system_program = Program::try_from(&system_program_info)?;
 
         let mut generic_token_list_lamports = 100;
-        let mut generic_token_list_data = vec![0u8; 677];
-        let generic_token_list_instance = TokenList {
-            tokens: vec![Pubkey::new_unique(); 20],
-            next_page: Some(Pubkey::new_unique()),
-        };
-        generic_token_list_data[..].copy_from_slice(&generic_token_list_instance.try_to_vec()?);
+        let mut generic_token_list_data = create_full_token_list_data(Some(generic_pubkey));
         let generic_token_list_account = create_account_info(
             &generic_pubkey,
             false,
@@ -558,12 +993,7 @@ mod tests {
         );
 
         let mut token_list_lamports = 100;
-        let mut token_list_data = vec![0u8; 37];
-        let token_list_instance = TokenList {
-            tokens: vec![token_mint],
-            next_page: None,
-        };
-        token_list_data[..].copy_from_slice(&token_list_instance.try_to_vec()?);
+        let mut token_list_data = create_token_list_data(&[token_mint], None);
         let token_list_account = create_account_info(
             &generic_pubkey,
             false,
@@ -575,13 +1005,90 @@ mod tests {
             Some(TokenList::discriminator()),
         );
 
+        let mut symbol_entry_lamports = 100;
+        let mut symbol_entry_data = SymbolEntry { token_mint }.try_to_vec()?;
+        let symbol_entry_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut symbol_entry_lamports,
+            &mut symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        let mut vault_lamports = 100;
+        let mut vault_data = vec![];
+        let vault_info = create_account_info(
+            &generic_pubkey,
+            false,
+            false,
+            &mut vault_lamports,
+            &mut vault_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let vault_token_account = SplTokenAccount {
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut vault_token_data = vec![0u8; SplTokenAccount::LEN];
+        SplTokenAccount::pack_into_slice(&vault_token_account, &mut vault_token_data);
+        let mut vault_token_lamports = 100;
+        let vault_token_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut vault_token_lamports,
+            &mut vault_token_data,
+            &anchor_spl::token::ID,
+            false,
+            None,
+        );
+
+        let mut tp_lamports = 100;
+        let mut tp_data = vec![];
+        let token_program_info = create_account_info(
+            &anchor_spl::token::ID,
+            false,
+            false,
+            &mut tp_lamports,
+            &mut tp_data,
+            &anchor_spl::token::ID,
+            true,
+            None,
+        );
+
+        let mut inventory_lamports = 100;
+        let mut inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let inventory_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut inventory_lamports,
+            &mut inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut remove_token_accounts = RemoveToken {
             authority,
             admin: Account::try_from(&admin_account)?,
             portfolio: Account::try_from(&portfolio_account)?,
             token_details: Account::try_from(&token_details_account)?,
+            symbol_entry: Account::try_from(&symbol_entry_info)?,
+            token_inventory: Box::new(Account::try_from(&inventory_info)?),
+            spl_vault: vault_info.clone(),
+            spl_user_funds_vault: vault_info,
+            spl_token_account: Box::new(Account::try_from(&vault_token_info)?),
+            spl_user_funds_token_account: Box::new(Account::try_from(&vault_token_info)?),
             receiver: SystemAccount::try_from(&receiver_account)?,
             system_program,
+            token_program: Program::try_from(&token_program_info)?,
         };
 
         let ctx = Context {
@@ -598,8 +1105,9 @@ mod tests {
         let result = remove_token(&ctx, &params);
         assert!(result.is_ok());
 
-        let updated_token_list = Account::<TokenList>::try_from(&ctx.remaining_accounts[0])?;
-        assert!(!updated_token_list.tokens.contains(&token_mint));
+        let updated_token_list = AccountLoader::<TokenList>::try_from(&ctx.remaining_accounts[1])?;
+        assert!(!updated_token_list.load()?.contains(&token_mint));
+        assert_eq!(updated_token_list.load()?.len, 0);
 
         Ok(())
     }
@@ -638,12 +1146,7 @@ mod tests {
         );
 
         let mut token_list_lamports = 100;
-        let mut token_list_data = vec![0u8; 645];
-        let mut token_list = TokenList {
-            next_page: None,
-            tokens: vec![Pubkey::new_unique(); 20],
-        };
-        token_list_data.copy_from_slice(&token_list.try_to_vec()?);
+        let mut token_list_data = create_full_token_list_data(None);
         let token_list_key = Pubkey::new_unique();
         let mut token_list_account = create_account_info(
             &token_list_key,
@@ -754,18 +1257,78 @@ mod tests {
         );
         let spl_token_account: Account<TokenAccount> = Account::try_from(&spl_token_info)?;
 
-        let mut add_token_accounts = AddToken {
-            authority,
-            admin: admin_account.clone(),
+        let mut inventory_lamports = 100;
+        let mut inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let inventory_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut inventory_lamports,
+            &mut inventory_data,
+            &program_id,
+            false,
+            None
+        );
+
+        let mut symbol_entry_lamports = 100;
+        let mut symbol_entry_data = vec![0u8; SymbolEntry::LEN];
+        let symbol_entry_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut symbol_entry_lamports,
+            &mut symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        // the multisig config is not created, an admin is enough
+
+        let multisig_config_key = Pubkey::new_unique();
+
+        let mut multisig_config_lamports = 0;
+
+        let mut multisig_config_data = vec![];
+
+        let multisig_config = create_account_info(
+
+            &multisig_config_key,
+
+            false,
+
+            false,
+
+            &mut multisig_config_lamports,
+
+            &mut multisig_config_data,
+
+            &program_id,
+
+            false,
+
+            None,
+
+        );
+
+
+        let mut add_token_accounts = AddToken {
+            authority,
+            admin: admin_account.clone(),
+            multisig_config,
+            proposal: None,
             spl_vault: generic_account.clone(),
             spl_user_funds_vault: generic_account.clone(),
             token_details: Box::new(Account::try_from(&token_details_account)?),
+            symbol_entry: Box::new(Account::try_from(&symbol_entry_info)?),
             token_mint: Box::new(Account::try_from(&mint_info)?),
             spl_token_account: Box::new(spl_token_account.clone()),
             spl_user_funds_token_account: Box::new(spl_token_account),
+            token_inventory: Box::new(Account::try_from(&inventory_info)?),
             system_program,
             token_program,
             associated_token_program,
+
         };
 
         let dummy_token_address = Pubkey::new_unique();
@@ -774,6 +1337,7 @@ mod tests {
         dummy_symbol[..symbol_bytes.len()].copy_from_slice(symbol_bytes);
         let params = AddTokenParams {
             decimals: 6,
+            l1_decimals: 18,
             token_address: dummy_token_address,
             symbol: dummy_symbol,
         };
@@ -788,14 +1352,45 @@ mod tests {
         let mut result = add_token(&mut ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::TokenListFull.into());
 
-        token_list = TokenList {
-            next_page: Some(generic_pubkey),
-            tokens: vec![Pubkey::new_unique(); 19],
+        // the following page has to be the one linked by the full page
+        let mut linked_token_list_data = create_full_token_list_data(Some(Pubkey::new_unique()));
+        let mut linked_token_list_lamports = 100;
+        let linked_token_list_account = create_account_info(
+            &token_list_key,
+            false,
+            true,
+            &mut linked_token_list_lamports,
+            &mut linked_token_list_data,
+            &program_id,
+            false,
+            Some(TokenList::discriminator()),
+        );
+        let mut other_token_list_data = create_full_token_list_data(Some(Pubkey::new_unique()));
+        let mut other_token_list_lamports = 100;
+        let other_token_list_account = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut other_token_list_lamports,
+            &mut other_token_list_data,
+            &program_id,
+            false,
+            Some(TokenList::discriminator()),
+        );
+
+        let mut ctx = Context {
+            program_id: &program_id,
+            accounts: &mut add_token_accounts,
+            remaining_accounts: &[linked_token_list_account, other_token_list_account],
+            bumps: AddTokenBumps::default(),
         };
-        token_list.tokens.push(params.token_address);
-        let mut token_list_data = vec![0u8; 677];
+
+        result = add_token(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::InvalidTokenListPage.into());
+
+        let mut token_list_data =
+            create_token_list_data(&[Pubkey::new_unique(), params.token_address], Some(generic_pubkey));
         let mut token_list_lamports = 100;
-        token_list_data.copy_from_slice(&token_list.try_to_vec()?);
         token_list_account = create_account_info(
             &token_list_key,
             false,
@@ -937,7 +1532,7 @@ mod tests {
         let system_program = Program::try_from(&system_program_info)?;
 
         let mut token_list_lamports = 100;
-        let mut token_list_data = vec![0u8; 37];
+        let mut token_list_data = create_token_list_data(&[], None);
         let token_list_account = create_account_info(
             &generic_pubkey,
             false,
@@ -949,13 +1544,90 @@ mod tests {
             Some(TokenList::discriminator()),
         );
 
+        let mut symbol_entry_lamports = 100;
+        let mut symbol_entry_data = SymbolEntry { token_mint }.try_to_vec()?;
+        let symbol_entry_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut symbol_entry_lamports,
+            &mut symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        let mut vault_lamports = 100;
+        let mut vault_data = vec![];
+        let vault_info = create_account_info(
+            &generic_pubkey,
+            false,
+            false,
+            &mut vault_lamports,
+            &mut vault_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let vault_token_account = SplTokenAccount {
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut vault_token_data = vec![0u8; SplTokenAccount::LEN];
+        SplTokenAccount::pack_into_slice(&vault_token_account, &mut vault_token_data);
+        let mut vault_token_lamports = 100;
+        let vault_token_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut vault_token_lamports,
+            &mut vault_token_data,
+            &anchor_spl::token::ID,
+            false,
+            None,
+        );
+
+        let mut tp_lamports = 100;
+        let mut tp_data = vec![];
+        let token_program_info = create_account_info(
+            &anchor_spl::token::ID,
+            false,
+            false,
+            &mut tp_lamports,
+            &mut tp_data,
+            &anchor_spl::token::ID,
+            true,
+            None,
+        );
+
+        let mut inventory_lamports = 100;
+        let mut inventory_data = create_token_inventory_data(&TokenInventory::default());
+        let inventory_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut inventory_lamports,
+            &mut inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+
         let mut remove_token_accounts = RemoveToken {
             authority,
             admin: Account::try_from(&admin_account)?,
             portfolio: Account::try_from(&portfolio_account)?,
             token_details: Account::try_from(&token_details_account)?,
+            symbol_entry: Account::try_from(&symbol_entry_info)?,
+            token_inventory: Box::new(Account::try_from(&inventory_info)?),
+            spl_vault: vault_info.clone(),
+            spl_user_funds_vault: vault_info,
+            spl_token_account: Box::new(Account::try_from(&vault_token_info)?),
+            spl_user_funds_token_account: Box::new(Account::try_from(&vault_token_info)?),
             receiver: SystemAccount::try_from(&receiver_account)?,
             system_program,
+            token_program: Program::try_from(&token_program_info)?,
         };
 
         let params = RemoveTokenParams {
@@ -982,6 +1654,469 @@ mod tests {
         let result = remove_token(&ctx, &params);
         assert_eq!(result.unwrap_err(), DexalotError::AccountsNotProvided.into());
 
+        // user funds are still held in the vault
+        let funded_token_account = SplTokenAccount {
+            state: AccountState::Initialized,
+            amount: 1,
+            ..Default::default()
+        };
+        let mut funded_token_data = vec![0u8; SplTokenAccount::LEN];
+        SplTokenAccount::pack_into_slice(&funded_token_account, &mut funded_token_data);
+        let mut funded_token_lamports = 100;
+        let funded_token_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut funded_token_lamports,
+            &mut funded_token_data,
+            &anchor_spl::token::ID,
+            false,
+            None,
+        );
+        *remove_token_accounts.spl_user_funds_token_account = Account::try_from(&funded_token_info)?;
+        let mut listed_token_list_lamports = 100;
+        let mut listed_token_list_data = create_token_list_data(&[token_mint], None);
+        let listed_token_list_account = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut listed_token_list_lamports,
+            &mut listed_token_list_data,
+            &program_id,
+            false,
+            Some(TokenList::discriminator()),
+        );
+        let ctx = Context {
+            program_id: &program_id,
+            accounts: &mut remove_token_accounts,
+            remaining_accounts: std::slice::from_ref(&listed_token_list_account),
+            bumps: Default::default(),
+        };
+
+        let result = remove_token(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenVaultNotEmpty.into());
+
+        // bridge fees must be collected first
+        let mut fees_inventory_lamports = 100;
+        let mut fees_inventory_data = create_token_inventory_data(&TokenInventory {
+            bridge_fees: 1,
+            ..Default::default()
+        });
+        let fees_inventory_info = create_account_info(
+            &generic_pubkey,
+            false,
+            true,
+            &mut fees_inventory_lamports,
+            &mut fees_inventory_data,
+            &program_id,
+            false,
+            None,
+        );
+        *remove_token_accounts.token_inventory = Account::try_from(&fees_inventory_info)?;
+        let ctx = Context {
+            program_id: &program_id,
+            accounts: &mut remove_token_accounts,
+            remaining_accounts: std::slice::from_ref(&listed_token_list_account),
+            bumps: Default::default(),
+        };
+
+        let result = remove_token(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::BridgeFeesNotCollected.into());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_token_list_order() -> Result<()> {
+        let program_id = crate::id();
+        let mints: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let mut sorted_mints = mints.clone();
+        sorted_mints.sort();
+
+        let token_list_key = Pubkey::new_unique();
+        let mut token_list_lamports = 100;
+        let mut token_list_data = create_token_list_data(&[], None);
+        let token_list_info = create_account_info(
+            &token_list_key,
+            false,
+            true,
+            &mut token_list_lamports,
+            &mut token_list_data,
+            &program_id,
+            false,
+            Some(TokenList::discriminator()),
+        );
+        let token_list_loader = AccountLoader::<TokenList>::try_from(&token_list_info)?;
+        let mut token_list = token_list_loader.load_mut()?;
+
+        // mints are kept sorted whatever the insertion order
+        for mint in &mints {
+            token_list.insert(*mint)?;
+        }
+        assert_eq!(token_list.tokens(), sorted_mints.as_slice());
+        assert_eq!(
+            token_list.insert(mints[0]).unwrap_err(),
+            DexalotError::TokenAlreadyAdded.into()
+        );
+
+        // removal keeps the order of the remaining mints
+        assert!(token_list.remove(&sorted_mints[1]));
+        sorted_mints.remove(1);
+        assert_eq!(token_list.tokens(), sorted_mints.as_slice());
+        assert!(!token_list.remove(&Pubkey::new_unique()));
+        assert_eq!(token_list.tokens[sorted_mints.len()], Pubkey::default());
+
+        // a full page rejects new mints
+        while !token_list.is_full() {
+            token_list.insert(Pubkey::new_unique())?;
+        }
+        assert_eq!(
+            token_list.insert(Pubkey::new_unique()).unwrap_err(),
+            DexalotError::TokenListFull.into()
+        );
+        assert_eq!(token_list.tokens().len(), TOKEN_LIST_PAGE_CAPACITY);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_token_list_page() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let last_page_key = Pubkey::new_unique();
+        let mut last_page_lamports = 100;
+        let mut last_page_data = create_token_list_data(&[], None);
+        let last_page_info = create_account_info(
+            &last_page_key,
+            false,
+            true,
+            &mut last_page_lamports,
+            &mut last_page_data,
+            &program_id,
+            false,
+            Some(TokenList::discriminator()),
+        );
+
+        let new_page_key = Pubkey::new_unique();
+        let mut new_page_lamports = 100;
+        let mut new_page_data = create_token_list_data(&[], None);
+        let new_page_info = create_account_info(
+            &new_page_key,
+            false,
+            true,
+            &mut new_page_lamports,
+            &mut new_page_data,
+            &program_id,
+            false,
+            Some(TokenList::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = AddTokenListPage {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            last_page: AccountLoader::try_from(&last_page_info)?,
+            new_page: AccountLoader::try_from(&new_page_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: AddTokenListPageBumps::default(),
+        };
+        let params = AddTokenListPageParams { page: 1 };
+
+        // not an admin
+        let result = add_token_list_page(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        // the last page still has room
+        let result = add_token_list_page(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenListPageNotFull.into());
+
+        let mut full_page_lamports = 100;
+        let mut full_page_data = create_full_token_list_data(None);
+        let full_page_info = create_account_info(
+            &last_page_key,
+            false,
+            true,
+            &mut full_page_lamports,
+            &mut full_page_data,
+            &program_id,
+            false,
+            Some(TokenList::discriminator()),
+        );
+        ctx.accounts.last_page = AccountLoader::try_from(&full_page_info)?;
+        add_token_list_page(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.last_page.load()?.next_page(), Some(new_page_key));
+
+        // the last page is already linked
+        let result = add_token_list_page(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::TokenListPageLinked.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_token() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+        let mut old_symbol = [0u8; 32];
+        old_symbol[..4].copy_from_slice(b"TEST");
+        let mut new_symbol = [0u8; 32];
+        new_symbol[..5].copy_from_slice(b"TEST2");
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let token_details_key = Pubkey::new_unique();
+        let mut token_details_lamports = 100;
+        let mut token_details_data = TokenDetails {
+            symbol: old_symbol,
+            token_address: token_mint,
+            decimals: 6,
+            l1_decimals: 6,
+            allow_deposit: true,
+            ..Default::default()
+        }
+        .try_to_vec()?;
+        let token_details_info = create_account_info(
+            &token_details_key,
+            false,
+            true,
+            &mut token_details_lamports,
+            &mut token_details_data,
+            &program_id,
+            false,
+            Some(TokenDetails::discriminator()),
+        );
+
+        let symbol_entry_key = Pubkey::new_unique();
+        let mut symbol_entry_lamports = 100;
+        let mut symbol_entry_data = SymbolEntry { token_mint }.try_to_vec()?;
+        let symbol_entry_info = create_account_info(
+            &symbol_entry_key,
+            false,
+            true,
+            &mut symbol_entry_lamports,
+            &mut symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        let new_symbol_entry_key = Pubkey::new_unique();
+        let mut new_symbol_entry_lamports = 100;
+        let mut new_symbol_entry_data = vec![0u8; SymbolEntry::LEN];
+        let new_symbol_entry_info = create_account_info(
+            &new_symbol_entry_key,
+            false,
+            true,
+            &mut new_symbol_entry_lamports,
+            &mut new_symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        // the multisig config is not created, an admin is enough
+        let multisig_config_key = Pubkey::new_unique();
+        let mut multisig_config_lamports = 0;
+        let mut multisig_config_data = vec![];
+        let multisig_config = create_account_info(
+            &multisig_config_key,
+            false,
+            false,
+            &mut multisig_config_lamports,
+            &mut multisig_config_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let mut accounts = UpdateToken {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            token_details: Box::new(Account::try_from(&token_details_info)?),
+            symbol_entry: Box::new(Account::try_from(&symbol_entry_info)?),
+            new_symbol_entry: Box::new(Account::try_from(&new_symbol_entry_info)?),
+            multisig_config,
+            proposal: None,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: UpdateTokenBumps::default(),
+        };
+        let params = UpdateTokenParams {
+            token_address: token_mint,
+            symbol: new_symbol,
+            l1_decimals: 18,
+            allow_deposit: false,
+            min_deposit: 1000,
+            paused: true,
+        };
+
+        // not an admin
+        let result = update_token(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+
+        // the new symbol is taken by another token
+        ctx.accounts.new_symbol_entry.token_mint = Pubkey::new_unique();
+        let result = update_token(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::SymbolAlreadyAdded.into());
+
+        ctx.accounts.new_symbol_entry.token_mint = Pubkey::default();
+        update_token(&mut ctx, &params)?;
+        let token_details = &ctx.accounts.token_details;
+        assert_eq!(token_details.symbol, new_symbol);
+        assert_eq!(token_details.l1_decimals, 18);
+        assert_eq!(token_details.decimals, 6);
+        assert!(!token_details.allow_deposit);
+        assert_eq!(token_details.min_deposit, 1000);
+        assert!(token_details.paused);
+        assert_eq!(ctx.accounts.new_symbol_entry.token_mint, token_mint);
+        assert_eq!(ctx.accounts.symbol_entry.token_mint, Pubkey::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_token_by_symbol() -> Result<()> {
+        let program_id = crate::id();
+        let token_mint = Pubkey::new_unique();
+
+        let symbol_entry_key = Pubkey::new_unique();
+        let mut symbol_entry_lamports = 100;
+        let mut symbol_entry_data = SymbolEntry { token_mint }.try_to_vec()?;
+        let symbol_entry_info = create_account_info(
+            &symbol_entry_key,
+            false,
+            false,
+            &mut symbol_entry_lamports,
+            &mut symbol_entry_data,
+            &program_id,
+            false,
+            Some(SymbolEntry::discriminator()),
+        );
+
+        let mut accounts = GetTokenBySymbol {
+            symbol_entry: Account::try_from(&symbol_entry_info)?,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: GetTokenBySymbolBumps::default(),
+        };
+
+        assert_eq!(get_token_by_symbol(&ctx)?, token_mint);
         Ok(())
     }
 }
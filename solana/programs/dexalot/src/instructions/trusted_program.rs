@@ -0,0 +1,332 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::{ADMIN_SEED, TRUSTED_PROGRAM_SEED};
+use crate::errors::DexalotError;
+use crate::events::TrustedProgramUpdatedEvent;
+use crate::state::TrustedProgram;
+
+#[derive(Accounts)]
+#[instruction(params: AddTrustedProgramParams)]
+pub struct AddTrustedProgram<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = TrustedProgram::LEN,
+        seeds = [TRUSTED_PROGRAM_SEED, params.program.as_ref()],
+        bump
+    )]
+    pub trusted_program: Account<'info, TrustedProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct AddTrustedProgramParams {
+    pub program: Pubkey,
+    pub organization: [u8; 32],
+}
+
+/// Adds a program allowed to deposit on behalf of traders
+pub fn add_trusted_program(
+    ctx: &mut Context<AddTrustedProgram>,
+    params: &AddTrustedProgramParams,
+) -> Result<()> {
+    require!(
+        params.program != Pubkey::default(),
+        DexalotError::ZeroAccount
+    );
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    ctx.accounts.trusted_program.organization = params.organization;
+
+    emit!(TrustedProgramUpdatedEvent {
+        program: params.program,
+        organization: params.organization,
+        trusted: true,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(params: RemoveTrustedProgramParams)]
+pub struct RemoveTrustedProgram<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Verify that user is an admin by checking their PDA.
+    #[account(
+        seeds = [ADMIN_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub admin: AccountInfo<'info>,
+    #[account(mut)]
+    pub receiver: SystemAccount<'info>,
+    #[account(
+        mut,
+        close = receiver, // Refund lamports to the receiver
+        seeds = [TRUSTED_PROGRAM_SEED, params.program.as_ref()],
+        bump
+    )]
+    pub trusted_program: Account<'info, TrustedProgram>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, AnchorDeserialize, AnchorSerialize)]
+pub struct RemoveTrustedProgramParams {
+    pub program: Pubkey,
+}
+
+/// Removes a program from the trusted programs
+pub fn remove_trusted_program(
+    ctx: &Context<RemoveTrustedProgram>,
+    params: &RemoveTrustedProgramParams,
+) -> Result<()> {
+    let admin = &ctx.accounts.admin;
+    require!(
+        admin.owner == ctx.program_id,
+        DexalotError::UnauthorizedSigner
+    );
+
+    emit!(TrustedProgramUpdatedEvent {
+        program: params.program,
+        organization: ctx.accounts.trusted_program.organization,
+        trusted: false,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_account_info;
+    use anchor_lang::{solana_program::system_program, Discriminator};
+
+    #[test]
+    fn test_add_trusted_program() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let trusted_program_key = Pubkey::new_unique();
+        let mut trusted_program_lamports = 0;
+        let mut trusted_program_data = vec![0u8; TrustedProgram::LEN];
+        let trusted_program_info = create_account_info(
+            &trusted_program_key,
+            false,
+            true,
+            &mut trusted_program_lamports,
+            &mut trusted_program_data,
+            &program_id,
+            false,
+            Some(TrustedProgram::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = AddTrustedProgram {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            trusted_program: Account::try_from(&trusted_program_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let mut ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: AddTrustedProgramBumps::default(),
+        };
+        let mut params = AddTrustedProgramParams {
+            program: Pubkey::new_unique(),
+            organization: [7; 32],
+        };
+
+        // not an admin
+        let result = add_trusted_program(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        add_trusted_program(&mut ctx, &params)?;
+        assert_eq!(ctx.accounts.trusted_program.organization, [7; 32]);
+
+        // zero program
+        params.program = Pubkey::default();
+        let result = add_trusted_program(&mut ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::ZeroAccount.into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_trusted_program() -> Result<()> {
+        let program_id = crate::id();
+
+        let authority_key = Pubkey::new_unique();
+        let mut authority_lamports = 100;
+        let mut authority_data = vec![0u8; 10];
+        let authority_info = create_account_info(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &program_id,
+            false,
+            None,
+        );
+
+        let admin_key = Pubkey::new_unique();
+        let mut admin_lamports = 100;
+        let mut admin_data = vec![0u8; 10];
+        let admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut admin_lamports,
+            &mut admin_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let receiver_key = Pubkey::new_unique();
+        let mut receiver_lamports = 100;
+        let mut receiver_data = vec![];
+        let receiver_info = create_account_info(
+            &receiver_key,
+            false,
+            true,
+            &mut receiver_lamports,
+            &mut receiver_data,
+            &system_program::ID,
+            false,
+            None,
+        );
+
+        let trusted_program_key = Pubkey::new_unique();
+        let mut trusted_program_lamports = 100;
+        let mut trusted_program_data = TrustedProgram {
+            organization: [7; 32],
+        }
+        .try_to_vec()?;
+        let trusted_program_info = create_account_info(
+            &trusted_program_key,
+            false,
+            true,
+            &mut trusted_program_lamports,
+            &mut trusted_program_data,
+            &program_id,
+            false,
+            Some(TrustedProgram::discriminator()),
+        );
+
+        let mut sys_prog_lamports = 100;
+        let mut sys_prog_data = vec![0u8; 10];
+        let system_prog_info = create_account_info(
+            &system_program::ID,
+            false,
+            false,
+            &mut sys_prog_lamports,
+            &mut sys_prog_data,
+            &system_program::ID,
+            true,
+            None,
+        );
+
+        let mut accounts = RemoveTrustedProgram {
+            authority: Signer::try_from(&authority_info)?,
+            admin: admin_info,
+            receiver: SystemAccount::try_from(&receiver_info)?,
+            trusted_program: Account::try_from(&trusted_program_info)?,
+            system_program: Program::try_from(&system_prog_info)?,
+        };
+        let ctx = Context {
+            accounts: &mut accounts,
+            remaining_accounts: &[],
+            program_id: &program_id,
+            bumps: RemoveTrustedProgramBumps::default(),
+        };
+        let params = RemoveTrustedProgramParams {
+            program: Pubkey::new_unique(),
+        };
+
+        // not an admin
+        let result = remove_trusted_program(&ctx, &params);
+        assert_eq!(result.unwrap_err(), DexalotError::UnauthorizedSigner.into());
+
+        let mut program_admin_lamports = 100;
+        let mut program_admin_data = vec![0u8; 10];
+        let program_admin_info = create_account_info(
+            &admin_key,
+            false,
+            false,
+            &mut program_admin_lamports,
+            &mut program_admin_data,
+            &program_id,
+            false,
+            None,
+        );
+        ctx.accounts.admin = program_admin_info;
+        remove_trusted_program(&ctx, &params)?;
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@ mod state;
 mod test_utils;
 mod xfer;
 
+use crate::cpi_utils::MessagingFee;
 use crate::xfer::XFERSolana;
 use anchor_lang::prelude::*;
 use anchor_lang::{declare_id, program};
@@ -67,6 +68,78 @@ pub mod dexalot {
         instructions::set_remote(&mut ctx, &params)
     }
 
+    /// Sets whether the user or the LZ fee vault pays the messaging fee for a destination
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Destination chain ID and whether the user pays the fee
+    pub fn set_user_pays_fee(
+        mut ctx: Context<SetUserPaysFee>,
+        params: SetUserPaysFeeParams,
+    ) -> Result<()> {
+        instructions::set_user_pays_fee(&mut ctx, &params)
+    }
+
+    /// Funds the LZ fee vault paying the messaging fees of protocol-paid destinations
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Amount of lamports to transfer
+    pub fn fund_lz_fee_vault(
+        mut ctx: Context<FundLzFeeVault>,
+        params: FundLzFeeVaultParams,
+    ) -> Result<()> {
+        instructions::fund_lz_fee_vault(&mut ctx, &params)
+    }
+
+    /// Withdraws lamports from the LZ fee vault to the signer
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Amount of lamports to withdraw
+    pub fn withdraw_lz_fee_vault(
+        mut ctx: Context<WithdrawLzFeeVault>,
+        params: WithdrawLzFeeVaultParams,
+    ) -> Result<()> {
+        instructions::withdraw_lz_fee_vault(&mut ctx, &params)
+    }
+
+    /// Maps a chainlist chain ID to its LayerZero endpoint ID and supported natives
+    /// Can be called only by admins, once the queued change's timelock has elapsed
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Chain ID, endpoint ID and native symbols
+    pub fn set_chain(mut ctx: Context<SetChain>, params: SetChainParams) -> Result<()> {
+        instructions::set_chain(&mut ctx, &params)
+    }
+
+    /// Removes a chain from the chain registry
+    /// Can be called only by admins, once the queued change's timelock has elapsed
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Chain ID
+    pub fn remove_chain(mut ctx: Context<RemoveChain>, params: RemoveChainParams) -> Result<()> {
+        instructions::remove_chain(&mut ctx, &params)
+    }
+
+    /// Sets the LayerZero executor options for a destination and message kind
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Destination chain ID, message kind and executor options
+    pub fn set_lz_options(
+        mut ctx: Context<SetLzOptions>,
+        params: SetLzOptionsParams,
+    ) -> Result<()> {
+        instructions::set_lz_options(&mut ctx, &params)
+    }
+
     /// Handles incoming LayerZero messages for cross-chain operations
     ///
     /// Processes:
@@ -80,6 +153,29 @@ pub mod dexalot {
         instructions::lz_receive(&mut ctx, &params)
     }
 
+    /// Retries delivery of a message that failed validation in lz_receive
+    /// Can be called by anyone
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Source eid and nonce of the failed message
+    pub fn retry_message(ctx: Context<RetryMessage>, params: FailedMessageParams) -> Result<()> {
+        instructions::retry_message(&ctx, &params)
+    }
+
+    /// Discards a message that failed validation in lz_receive
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Source eid and nonce of the failed message
+    pub fn discard_message(
+        ctx: Context<DiscardMessage>,
+        params: FailedMessageParams,
+    ) -> Result<()> {
+        instructions::discard_message(&ctx, &params)
+    }
+
     /// Gets required accounts for calling lz_receive
     ///
     /// Returns a list of accounts needed for calling the lz_receive by the LayerZero program
@@ -113,6 +209,8 @@ pub mod dexalot {
     ///
     /// Can only be called when program is paused.
     /// Can only be called by an admin.
+    /// Bridge fees must be collected and both vault token accounts must be empty.
+    /// The vault token accounts and the token inventory are closed with the token.
     pub fn remove_token<'info>(
         ctx: Context<'_, '_, 'info, 'info, RemoveToken<'info>>,
         params: RemoveTokenParams,
@@ -120,6 +218,44 @@ pub mod dexalot {
         instructions::remove_token(&ctx, &params)
     }
 
+    /// Updates the symbol, L1 decimals and deposit settings of a token
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - The new token settings
+    pub fn update_token(mut ctx: Context<UpdateToken>, params: UpdateTokenParams) -> Result<()> {
+        instructions::update_token(&mut ctx, &params)
+    }
+
+    /// Links a new token list page after the last one
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Index of the new page
+    pub fn add_token_list_page(
+        mut ctx: Context<AddTokenListPage>,
+        params: AddTokenListPageParams,
+    ) -> Result<()> {
+        instructions::add_token_list_page(&mut ctx, &params)
+    }
+
+    /// Looks up the mint registered for a token symbol
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - The 32-byte token symbol
+    ///
+    /// # Returns
+    /// The token mint
+    pub fn get_token_by_symbol(
+        ctx: Context<GetTokenBySymbol>,
+        _params: GetTokenBySymbolParams,
+    ) -> Result<Pubkey> {
+        instructions::get_token_by_symbol(&ctx)
+    }
+
     /// Processes SPL token deposits into dexalot
     /// Funds a are deposited in SPL User Funds vault
     ///
@@ -139,6 +275,33 @@ pub mod dexalot {
         instructions::deposit_native(&mut ctx, &params)
     }
 
+    /// Processes SPL token deposits made by a trusted program on behalf of a trader
+    /// Funds are deposited in SPL User Funds vault
+    ///
+    /// Must be invoked directly through CPI by the trusted program
+    pub fn deposit_from_program(
+        mut ctx: Context<DepositFromProgram>,
+        params: DepositFromProgramParams,
+    ) -> Result<()> {
+        instructions::deposit_from_program(&mut ctx, &params)
+    }
+
+    /// Returns the LayerZero fee of a deposit, SOL being `Pubkey::default()`
+    /// Remaining accounts hold the quote accounts
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Deposit parameters
+    ///
+    /// # Returns
+    /// The messaging fee quoted by the endpoint
+    pub fn quote_deposit(
+        ctx: Context<QuoteDeposit>,
+        params: QuoteDepositParams,
+    ) -> Result<MessagingFee> {
+        instructions::quote_deposit(&ctx, &params)
+    }
+
     /// Processes airdrop deposits
     /// to refill the Airdrop SOL Vault
     ///
@@ -153,25 +316,38 @@ pub mod dexalot {
     }
 
     /// Sets the default chain ID for cross-chain operations
-    /// Can be called only by admins
+    /// Can be called only by admins, once the queued change's timelock has elapsed
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
     /// * `params` - Default chain ID parameters
     pub fn set_default_chain(
-        mut ctx: Context<WriteConfig>,
+        mut ctx: Context<SetDefaultChain>,
         params: SetDefaultChainId,
     ) -> Result<()> {
         instructions::set_default_chain(&mut ctx, &params)
     }
 
+    /// Sets the delay of queued config changes, never below the minimum delay
+    /// Can be called only by admins, once the queued change's timelock has elapsed
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Timelock delay in seconds
+    pub fn set_timelock_delay(
+        mut ctx: Context<SetTimelockDelay>,
+        params: SetTimelockDelayParams,
+    ) -> Result<()> {
+        instructions::set_timelock_delay(&mut ctx, &params)
+    }
+
     /// Sets the paused state of the program
-    /// Can be called only by admins
+    /// Can be called only by pausers
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
     /// * `pause` - Boolean indicating whether to pause the program
-    pub fn set_paused(mut ctx: Context<WriteConfig>, pause: bool) -> Result<()> {
+    pub fn set_paused(mut ctx: Context<SetPaused>, pause: bool) -> Result<()> {
         instructions::set_paused(&mut ctx, pause)
     }
 
@@ -225,24 +401,115 @@ pub mod dexalot {
         instructions::set_airdrop(&mut ctx, &params)
     }
 
-    /// Adds a new admin to the program
+    /// Grants a role to an account
     /// Can be called only by admins
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
-    /// * `params` - Admin parameters including the account to add
-    pub fn add_admin(ctx: Context<AddAdmin>, params: AdminParams) -> Result<()> {
-        instructions::add_admin(&ctx, &params)
+    /// * `params` - Role parameters including the role and the account to grant it to
+    pub fn grant_role(mut ctx: Context<GrantRole>, params: RoleParams) -> Result<()> {
+        instructions::grant_role(&mut ctx, &params)
     }
 
-    /// Removes an admin from the program
+    /// Revokes a role from an account
     /// Can be called only by admins
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
-    /// * `params` - Admin parameters including the account to remove
-    pub fn remove_admin(ctx: Context<RemoveAdmin>, params: AdminParams) -> Result<()> {
-        instructions::remove_admin(&ctx, &params)
+    /// * `params` - Role parameters including the role and the account to revoke it from
+    pub fn revoke_role(mut ctx: Context<RevokeRole>, params: RoleParams) -> Result<()> {
+        instructions::revoke_role(&mut ctx, &params)
+    }
+
+    /// Lists a member granted before the role registries existed in its role registry
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Role parameters including the role and the existing member
+    pub fn register_role_member(
+        mut ctx: Context<RegisterRoleMember>,
+        params: RoleParams,
+    ) -> Result<()> {
+        instructions::register_role_member(&mut ctx, &params)
+    }
+
+    /// Proposes a new key to take over the portfolio admin
+    /// Can be called only by the portfolio admin
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - The proposed admin, the default key cancels the proposal
+    pub fn propose_admin(mut ctx: Context<WriteConfig>, params: ProposeAdminParams) -> Result<()> {
+        instructions::propose_admin(&mut ctx, &params)
+    }
+
+    /// Accepts the portfolio admin handover
+    /// Can be called only by the proposed admin once granted the admin role
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    pub fn accept_admin(mut ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(&mut ctx)
+    }
+
+    /// Sets the approval threshold and the admin actions that need an approved proposal
+    /// Can be called only by admins, through a proposal once the threshold is above one
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Threshold and protected actions
+    pub fn set_multisig_config(
+        mut ctx: Context<SetMultisigConfig>,
+        params: SetMultisigConfigParams,
+    ) -> Result<()> {
+        instructions::set_multisig_config(&mut ctx, &params)
+    }
+
+    /// Proposes a sensitive admin action, the proposer approves it
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Proposal id, action and the serialized instruction params
+    pub fn create_proposal(
+        mut ctx: Context<CreateProposal>,
+        params: CreateProposalParams,
+    ) -> Result<()> {
+        instructions::create_proposal(&mut ctx, &params)
+    }
+
+    /// Approves a proposal
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Proposal id
+    pub fn approve_proposal(
+        mut ctx: Context<ApproveProposal>,
+        params: ApproveProposalParams,
+    ) -> Result<()> {
+        instructions::approve_proposal(&mut ctx, &params)
+    }
+
+    /// Queues a timelocked config change, it can be executed once the delay has elapsed
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Change id, action and the serialized instruction params
+    pub fn queue_change(mut ctx: Context<QueueChange>, params: QueueChangeParams) -> Result<()> {
+        instructions::queue_change(&mut ctx, &params)
+    }
+
+    /// Cancels a queued config change
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Change id
+    pub fn cancel_change(ctx: Context<CancelChange>, params: CancelChangeParams) -> Result<()> {
+        instructions::cancel_change(&ctx, &params)
     }
 
     /// Sets whether deposits are allowed in the program
@@ -269,18 +536,31 @@ pub mod dexalot {
     }
 
     /// Sets the swap signer for the program
-    /// Can be called only by admins
+    /// Can be called only by admins, once the queued change's timelock has elapsed
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
     /// * `params` - Swap signer parameters
     pub fn set_swap_signer(
-        mut ctx: Context<WriteConfig>,
+        mut ctx: Context<SetSwapSigner>,
         params: SetSlapSignerParams,
     ) -> Result<()> {
         instructions::set_swap_signer(&mut ctx, &params)
     }
 
+    /// Sets the LayerZero endpoint program the program sends and clears messages through
+    /// Can be called only by admins, once the queued change's timelock has elapsed
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Endpoint program ID
+    pub fn set_endpoint_program(
+        mut ctx: Context<SetEndpointProgram>,
+        params: SetEndpointProgramParams,
+    ) -> Result<()> {
+        instructions::set_endpoint_program(&mut ctx, &params)
+    }
+
     /// Executes a token swap
     /// Can be simple swap or partial swap
     ///
@@ -301,6 +581,22 @@ pub mod dexalot {
         instructions::cross_swap(&mut ctx, &params)
     }
 
+    /// Returns the LayerZero fee of the message sent by a cross-chain swap
+    /// Remaining accounts hold the quote accounts
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Cross-chain swap order
+    ///
+    /// # Returns
+    /// The messaging fee quoted by the endpoint
+    pub fn quote_cross_swap(
+        ctx: Context<QuoteCrossSwap>,
+        params: QuoteCrossSwapParams,
+    ) -> Result<MessagingFee> {
+        instructions::quote_cross_swap(&ctx, &params)
+    }
+
     /// Removes a swap from the queue
     /// Can be called only by rebalancers
     /// Tries to re-execute a cc swap that wasn't executed due to insufficient liquidity
@@ -315,31 +611,47 @@ pub mod dexalot {
         instructions::remove_from_swap_queue(&ctx, &params)
     }
 
-    /// Adds a rebalancer to the program
+    /// Sets how much of a token a rebalancer can claim or rebalance per epoch
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Rebalancer, token mint (default pubkey for SOL) and limit, `u64::MAX` for no cap
+    pub fn set_rebalancer_limit(
+        mut ctx: Context<SetRebalancerLimit>,
+        params: SetRebalancerLimitParams,
+    ) -> Result<()> {
+        instructions::set_rebalancer_limit(&mut ctx, &params)
+    }
+
+    /// Adds a program allowed to deposit on behalf of traders
     /// Can be called only by admins
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
-    /// * `params` - Rebalancer parameters
-    pub fn add_rebalancer(ctx: Context<AddRebalancer>, params: RebalancerParams) -> Result<()> {
-        instructions::add_rebalancer(&ctx, &params)
+    /// * `params` - Program id and the organization it belongs to
+    pub fn add_trusted_program(
+        mut ctx: Context<AddTrustedProgram>,
+        params: AddTrustedProgramParams,
+    ) -> Result<()> {
+        instructions::add_trusted_program(&mut ctx, &params)
     }
 
-    /// Removes a rebalancer from the program
+    /// Removes a program from the trusted programs
     /// Can be called only by admins
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
-    /// * `params` - Rebalancer parameters
-    pub fn remove_rebalancer(
-        ctx: Context<RemoveRebalancer>,
-        params: RebalancerParams,
+    /// * `params` - Program id
+    pub fn remove_trusted_program(
+        ctx: Context<RemoveTrustedProgram>,
+        params: RemoveTrustedProgramParams,
     ) -> Result<()> {
-        instructions::remove_rebalancer(&ctx, &params)
+        instructions::remove_trusted_program(&ctx, &params)
     }
 
     /// Marks a swap as completed
-    /// Can be called only by rebalancers
+    /// Can be called only by volatility admins
     ///
     /// # Arguments
     /// * `ctx` - Context containing the accounts
@@ -364,6 +676,20 @@ pub mod dexalot {
         instructions::claim_spl_balance(&ctx, &params)
     }
 
+    /// Claims several SPL token balances and optionally SOL in a single call
+    /// Can be called only by rebalancers
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts, with a vault ATA and destination ATA
+    ///   pair per token passed as remaining accounts
+    /// * `params` - Token claims and optional native amount
+    pub fn batch_claim_balance<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchClaimBalance<'info>>,
+        params: BatchClaimBalanceParams,
+    ) -> Result<()> {
+        instructions::batch_claim_balance(&ctx, &params)
+    }
+
     /// Claims native token balance from the program's SOL vault
     /// Can be called only by rebalancers
     ///
@@ -422,6 +748,45 @@ pub mod dexalot {
         instructions::add_destination(&ctx, &params)
     }
 
+    /// Allows deposits of a token to a registered chain
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Chain ID and token mint, default pubkey for SOL
+    pub fn add_deposit_destination(
+        ctx: Context<AddDepositDestination>,
+        params: DepositDestinationParams,
+    ) -> Result<()> {
+        instructions::add_deposit_destination(&ctx, &params)
+    }
+
+    /// Disallows deposits of a token to a chain
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Chain ID and token mint, default pubkey for SOL
+    pub fn remove_deposit_destination(
+        ctx: Context<RemoveDepositDestination>,
+        params: DepositDestinationParams,
+    ) -> Result<()> {
+        instructions::remove_deposit_destination(&ctx, &params)
+    }
+
+    /// Sets the chain deposits of a token are sent to when none is requested
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Chain ID, 0 for the global default chain, and token mint
+    pub fn set_default_deposit_destination(
+        mut ctx: Context<SetDefaultDepositDestination>,
+        params: DepositDestinationParams,
+    ) -> Result<()> {
+        instructions::set_default_deposit_destination(&mut ctx, &params)
+    }
+
     /// Claims native token balance from the program's Airdrop vault
     /// Can be called only by admin
     ///
@@ -429,9 +794,185 @@ pub mod dexalot {
     /// * `ctx` - Context containing the accounts
     /// * `params` - Claim parameters
     pub fn claim_airdrop_balance(
-        ctx: Context<ClaimAirdropBalance>,
+        mut ctx: Context<ClaimAirdropBalance>,
         params: ClaimAirdropBalanceParams,
     ) -> Result<()> {
-        instructions::claim_airdrop_balance(&ctx, &params)
+        instructions::claim_airdrop_balance(&mut ctx, &params)
+    }
+
+    /// Sets the amount a deposit of the token has to exceed
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint and minimum amount
+    pub fn set_min_deposit(
+        mut ctx: Context<SetMinDeposit>,
+        params: SetMinDepositParams,
+    ) -> Result<()> {
+        instructions::set_min_deposit(&mut ctx, &params)
+    }
+
+    /// Sets the amount in lamports a SOL deposit has to exceed
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Minimum amount
+    pub fn set_min_deposit_native(
+        mut ctx: Context<WriteConfig>,
+        params: SetMinDepositNativeParams,
+    ) -> Result<()> {
+        instructions::set_min_deposit_native(&mut ctx, &params)
+    }
+
+    /// Returns the amount a deposit of the token has to exceed
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `token_mint` - Token mint, default pubkey for SOL
+    pub fn get_min_deposit(ctx: Context<GetMinDeposit>, token_mint: Pubkey) -> Result<u64> {
+        instructions::get_min_deposit(&ctx, token_mint)
+    }
+
+    /// Sets the bridge fee withheld from every deposit of the token
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint and fee
+    pub fn set_bridge_fee(
+        mut ctx: Context<SetBridgeFee>,
+        params: SetBridgeFeeParams,
+    ) -> Result<()> {
+        instructions::set_bridge_fee(&mut ctx, &params)
+    }
+
+    /// Sets the bridge fee in lamports withheld from every SOL deposit
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Fee
+    pub fn set_bridge_fee_native(
+        mut ctx: Context<WriteConfig>,
+        params: SetBridgeFeeNativeParams,
+    ) -> Result<()> {
+        instructions::set_bridge_fee_native(&mut ctx, &params)
+    }
+
+    /// Transfers the bridge fees collected for a token to the admin
+    /// The fees are paid out of the user funds vault that withheld them
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint, default pubkey for SOL
+    pub fn collect_bridge_fees(
+        ctx: Context<CollectBridgeFees>,
+        params: CollectBridgeFeesParams,
+    ) -> Result<()> {
+        instructions::collect_bridge_fees(&ctx, &params)
+    }
+
+    /// Sets the minimum RFQ inventory of a token that claims must leave
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint (default pubkey for SOL) and floor amount
+    pub fn set_inventory_floor(
+        mut ctx: Context<SetInventoryFloor>,
+        params: SetInventoryFloorParams,
+    ) -> Result<()> {
+        instructions::set_inventory_floor(&mut ctx, &params)
+    }
+
+    /// Sets the maximum amount of a token moved between the vaults per epoch
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint (default pubkey for SOL) and cap amount
+    pub fn set_rebalance_cap(
+        mut ctx: Context<SetRebalanceCap>,
+        params: SetRebalanceCapParams,
+    ) -> Result<()> {
+        instructions::set_rebalance_cap(&mut ctx, &params)
+    }
+
+    /// Seeds the inventory of a token from its vault balances, creating it if needed
+    /// Can be called only by admins
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint (default pubkey for SOL)
+    pub fn reconcile_inventory(
+        mut ctx: Context<ReconcileInventory>,
+        params: ReconcileInventoryParams,
+    ) -> Result<()> {
+        instructions::reconcile_inventory(&mut ctx, &params)
+    }
+
+    /// Grows a portfolio written by the first release to the current layout
+    /// and creates the accounts the LZ executor passes to lz_receive_types
+    /// Can be called only by admins, once
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Default chainlist ID, endpoint program and timelock delay missing from the legacy config
+    pub fn migrate_portfolio(
+        mut ctx: Context<MigratePortfolio>,
+        params: MigratePortfolioParams,
+    ) -> Result<()> {
+        instructions::migrate_portfolio(&mut ctx, &params)
+    }
+
+    /// Grows a remote written by the first release to the current layout
+    /// Can be called only by admins, once per remote
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Destination endpoint ID of the remote
+    pub fn migrate_remote(
+        mut ctx: Context<MigrateRemote>,
+        params: MigrateRemoteParams,
+    ) -> Result<()> {
+        instructions::migrate_remote(&mut ctx, &params)
+    }
+
+    /// Grows token details written by the first release to the current layout and indexes the symbol
+    /// Can be called only by admins, once per token
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint, its stored symbol and L1 decimals
+    pub fn migrate_token_details(
+        mut ctx: Context<MigrateTokenDetails>,
+        params: MigrateTokenDetailsParams,
+    ) -> Result<()> {
+        instructions::migrate_token_details(&mut ctx, &params)
+    }
+
+    /// Rewrites the first token list page written by the first release as a zero-copy page
+    /// Can be called only by admins, once
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    pub fn migrate_token_list(mut ctx: Context<MigrateTokenList>) -> Result<()> {
+        instructions::migrate_token_list(&mut ctx)
+    }
+
+    /// Moves funds between the user funds vaults and the RFQ vaults
+    /// Can be called only by rebalancers
+    ///
+    /// # Arguments
+    /// * `ctx` - Context containing the accounts
+    /// * `params` - Token mint (default pubkey for SOL), amount and direction
+    pub fn rebalance_vaults(
+        ctx: Context<RebalanceVaults>,
+        params: RebalanceVaultsParams,
+    ) -> Result<()> {
+        instructions::rebalance_vaults(&ctx, &params)
     }
 }
@@ -1,5 +1,12 @@
-use crate::consts::{ANCHOR_DISCRIMINATOR, MAX_TOKENS};
+use crate::consts::{
+    ADMIN_SEED, ANCHOR_DISCRIMINATOR, MAX_CHAIN_NATIVES, MAX_CHANGE_DATA_LEN, MAX_LZ_OPTIONS_LEN,
+    MAX_PROPOSAL_DATA_LEN, MAX_ROLE_MEMBERS, MAX_SENSITIVE_ACTIONS, PAUSER_SEED, REBALANCER_SEED,
+    TOKEN_LIST_PAGE_CAPACITY, VOLATILITY_ADMIN_SEED, XFER_SIZE,
+};
+use crate::errors::DexalotError;
+use crate::xfer::Tx;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 
 #[account]
 #[derive(InitSpace, Default)]
@@ -7,17 +14,32 @@ pub struct Portfolio {
     pub admin: Pubkey,
     pub global_config: GlobalConfig,
     pub bump: u8,
+    // key proposed to take over `admin`, default when there is no proposal
+    pub pending_admin: Pubkey,
 }
 
 impl Portfolio {
     pub const LEN: usize = ANCHOR_DISCRIMINATOR + Portfolio::INIT_SPACE; // DISCRIMINATOR + sum of each field's len
 }
 
+/// Accounts the LZ executor passes to `lz_receive_types`
+#[account]
+#[derive(InitSpace, Default)]
+pub struct LzReceiveTypesAccounts {
+    pub portfolio: Pubkey,
+}
+
+impl LzReceiveTypesAccounts {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + LzReceiveTypesAccounts::INIT_SPACE;
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Remote {
     pub address: [u8; 32],
     pub bump: u8,
+    // the LZ fee vault pays the messaging fee instead of the user
+    pub protocol_pays_fee: bool,
 }
 
 impl Remote {
@@ -30,11 +52,15 @@ pub struct GlobalConfig {
     pub program_paused: bool,
     pub native_deposits_restricted: bool,
     pub src_chain_id: u16,
-    pub default_chain_id: u32, // Dexalot L1
+    pub default_chain_id: u32, // Dexalot L1 chainlist ID
     pub airdrop_amount: u64,
     // ETH address
     pub swap_signer: [u8; 20],
     pub out_nonce: u64,
+    pub min_deposit_native: u64, // in lamports
+    pub bridge_fee_native: u64,  // in lamports
+    pub endpoint_program: Pubkey,
+    pub timelock_delay: u32, // seconds before a queued config change can be executed
 }
 
 impl GlobalConfig {
@@ -43,7 +69,7 @@ impl GlobalConfig {
 }
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Debug)]
 pub struct Admin {}
 
 impl Admin {
@@ -51,13 +77,246 @@ impl Admin {
 }
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Debug)]
 pub struct Rebalancer {}
 
 impl Rebalancer {
     pub const LEN: usize = ANCHOR_DISCRIMINATOR + Rebalancer::INIT_SPACE; // discriminator_admin (u8)
 }
 
+/// Per epoch withdrawal limit of one rebalancer for one token
+#[account]
+#[derive(InitSpace, Default, Debug)]
+pub struct RebalancerLimit {
+    // maximum amount claimable per epoch, UNLIMITED for no cap
+    pub limit: u64,
+    // epoch of the last recorded withdrawal
+    pub epoch: u64,
+    // amount claimed during `epoch`
+    pub withdrawn: u64,
+}
+
+impl RebalancerLimit {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + RebalancerLimit::INIT_SPACE;
+    pub const UNLIMITED: u64 = u64::MAX;
+
+    /// Records a withdrawal against the limit for the given epoch
+    pub fn record_withdrawal(&mut self, amount: u64, epoch: u64) -> Result<()> {
+        if self.epoch != epoch {
+            self.epoch = epoch;
+            self.withdrawn = 0;
+        }
+        let withdrawn = self
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(DexalotError::WithdrawalLimitExceeded)?;
+        require!(
+            self.limit == Self::UNLIMITED || withdrawn <= self.limit,
+            DexalotError::WithdrawalLimitExceeded
+        );
+        self.withdrawn = withdrawn;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq, InitSpace)]
+pub enum RoleType {
+    Admin,
+    Rebalancer,
+    VolatilityAdmin,
+    Pauser,
+}
+
+impl RoleType {
+    /// Seed of the role's member PDAs, admins and rebalancers keep their original seeds
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            RoleType::Admin => ADMIN_SEED,
+            RoleType::Rebalancer => REBALANCER_SEED,
+            RoleType::VolatilityAdmin => VOLATILITY_ADMIN_SEED,
+            RoleType::Pauser => PAUSER_SEED,
+        }
+    }
+
+    /// Role id emitted in role events, the same bytes32 the EVM contracts use for the role
+    pub fn role_id(&self) -> [u8; 32] {
+        let name: &[u8] = match self {
+            // AccessControl's DEFAULT_ADMIN_ROLE is zero rather than a hash
+            RoleType::Admin => return [0; 32],
+            RoleType::Rebalancer => b"REBALANCER_ADMIN_ROLE",
+            RoleType::VolatilityAdmin => b"VOLATILITY_ADMIN_ROLE",
+            RoleType::Pauser => b"PAUSER_ROLE",
+        };
+        keccak::hash(name).to_bytes()
+    }
+}
+
+/// Member PDA of roles without role specific data
+#[account]
+#[derive(InitSpace, Default, Debug)]
+pub struct Role {}
+
+impl Role {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + Role::INIT_SPACE;
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoleRegistry {
+    pub role: RoleType,
+    #[max_len(MAX_ROLE_MEMBERS)]
+    pub members: Vec<Pubkey>,
+}
+
+impl RoleRegistry {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + RoleRegistry::INIT_SPACE;
+
+    pub fn member_count(&self) -> u32 {
+        self.members.len() as u32
+    }
+
+    pub fn add_member(&mut self, account: Pubkey) -> Result<()> {
+        if self.members.contains(&account) {
+            return Ok(());
+        }
+        require!(
+            self.members.len() < MAX_ROLE_MEMBERS,
+            DexalotError::RoleMembersFull
+        );
+        self.members.push(account);
+        Ok(())
+    }
+
+    /// Members granted before the registry existed may not be listed yet, so missing members are ignored
+    pub fn remove_member(&mut self, account: &Pubkey) {
+        self.members.retain(|member| member != account);
+    }
+}
+
+/// Admin instructions that can be put behind multisig approval
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq, InitSpace)]
+pub enum SensitiveAction {
+    SetSwapSigner,
+    SetEndpointProgram,
+    SetRemote,
+    AddToken,
+    ClaimAirdropBalance,
+    GrantRole,
+    RevokeRole,
+    SetMultisigConfig,
+    WithdrawLzFeeVault,
+    UpdateToken,
+    SetChain,
+    RemoveChain,
+}
+
+#[account]
+#[derive(InitSpace, Default, Debug)]
+pub struct MultisigConfig {
+    // approvals a proposal needs, 0 or 1 disables the multisig
+    pub threshold: u8,
+    #[max_len(MAX_SENSITIVE_ACTIONS)]
+    pub protected_actions: Vec<SensitiveAction>,
+}
+
+impl MultisigConfig {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + MultisigConfig::INIT_SPACE;
+
+    /// The config itself stays behind approval while the multisig is enabled
+    pub fn requires_proposal(&self, action: SensitiveAction) -> bool {
+        self.threshold > 1
+            && (action == SensitiveAction::SetMultisigConfig
+                || self.protected_actions.contains(&action))
+    }
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub action: SensitiveAction,
+    // serialized params of the instruction
+    #[max_len(MAX_PROPOSAL_DATA_LEN)]
+    pub data: Vec<u8>,
+    #[max_len(MAX_ROLE_MEMBERS)]
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + Proposal::INIT_SPACE;
+}
+
+/// Config changes that are queued behind a timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Eq, PartialEq, InitSpace)]
+pub enum TimelockedAction {
+    SetSwapSigner,
+    SetRemote,
+    SetDefaultChain,
+    AddDestination,
+    SetTimelockDelay,
+    SetChain,
+    SetEndpointProgram,
+    RemoveChain,
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct PendingChange {
+    pub id: u64,
+    pub queued_by: Pubkey,
+    pub action: TimelockedAction,
+    // serialized params of the instruction
+    #[max_len(MAX_CHANGE_DATA_LEN)]
+    pub data: Vec<u8>,
+    // earliest unix timestamp the change can be executed at
+    pub eta: i64,
+}
+
+impl PendingChange {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + PendingChange::INIT_SPACE;
+}
+
+#[account]
+#[derive(InitSpace, Default)]
+pub struct TrustedProgram {
+    // the integrator the program belongs to
+    pub organization: [u8; 32],
+}
+
+impl TrustedProgram {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + TrustedProgram::INIT_SPACE;
+}
+
+#[account]
+#[derive(InitSpace, Default)]
+pub struct LzOptions {
+    // TYPE_3 options sent with every message of its kind to its destination
+    #[max_len(MAX_LZ_OPTIONS_LEN)]
+    pub options: Vec<u8>,
+}
+
+impl LzOptions {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + LzOptions::INIT_SPACE;
+}
+
+// the messages sent to the L1 that executor options can be set for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum LzMessageKind {
+    Deposit,
+    CCTrade,
+}
+
+impl From<LzMessageKind> for Tx {
+    fn from(kind: LzMessageKind) -> Self {
+        match kind {
+            LzMessageKind::Deposit => Tx::Deposit,
+            LzMessageKind::CCTrade => Tx::CCTrade,
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct BannedAccount {
@@ -78,30 +337,94 @@ pub enum BanReason {
     Terms,
 }
 
-#[account]
-#[derive(InitSpace)]
+#[account(zero_copy)]
 pub struct TokenList {
-    pub next_page: Option<Pubkey>,
-    #[max_len(MAX_TOKENS)]
-    pub tokens: Vec<Pubkey>,
+    // the following page, the default key on the last page
+    pub next_page: Pubkey,
+    pub len: u32,
+    // mints sorted by key, only the first `len` are set
+    pub tokens: [Pubkey; TOKEN_LIST_PAGE_CAPACITY],
 }
 
 impl TokenList {
-    pub const LEN: usize = ANCHOR_DISCRIMINATOR + TokenList::INIT_SPACE;
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + std::mem::size_of::<TokenList>();
+
+    pub fn tokens(&self) -> &[Pubkey] {
+        &self.tokens[..self.len as usize]
+    }
+
+    pub fn next_page(&self) -> Option<Pubkey> {
+        (self.next_page != Pubkey::default()).then_some(self.next_page)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len as usize == TOKEN_LIST_PAGE_CAPACITY
+    }
+
+    pub fn contains(&self, token_mint: &Pubkey) -> bool {
+        self.tokens().binary_search(token_mint).is_ok()
+    }
+
+    /// Inserts a mint at its sorted position
+    pub fn insert(&mut self, token_mint: Pubkey) -> Result<()> {
+        let len = self.len as usize;
+        let index = match self.tokens().binary_search(&token_mint) {
+            Ok(_) => return Err(DexalotError::TokenAlreadyAdded.into()),
+            Err(index) => index,
+        };
+        require!(!self.is_full(), DexalotError::TokenListFull);
+        self.tokens.copy_within(index..len, index + 1);
+        self.tokens[index] = token_mint;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes a mint, shifting the following ones so the page stays sorted
+    /// Returns whether the mint was on the page
+    pub fn remove(&mut self, token_mint: &Pubkey) -> bool {
+        let len = self.len as usize;
+        let Ok(index) = self.tokens().binary_search(token_mint) else {
+            return false;
+        };
+        self.tokens.copy_within(index + 1..len, index);
+        self.tokens[len - 1] = Pubkey::default();
+        self.len -= 1;
+        true
+    }
 }
 
 #[account]
 #[derive(Default, InitSpace)]
 pub struct TokenDetails {
     pub decimals: u8,
+    pub l1_decimals: u8, // decimals of the token on the Dexalot L1
     pub symbol: [u8; 32],
     pub token_address: Pubkey,
+    pub min_deposit: u64,
+    // withheld from every deposit, in mint decimals
+    pub bridge_fee: u64,
+    // chainlist ID deposits are sent to, 0 for the global default chain
+    pub default_dest_chain_id: u32,
+    pub allow_deposit: bool,
+    // a paused token can't be deposited or withdrawn
+    pub paused: bool,
 }
 
 impl TokenDetails {
     pub const LEN: usize = ANCHOR_DISCRIMINATOR + TokenDetails::INIT_SPACE;
 }
 
+// Maps a token symbol to its mint, seeded by the symbol
+#[account]
+#[derive(InitSpace, Default, Debug)]
+pub struct SymbolEntry {
+    pub token_mint: Pubkey,
+}
+
+impl SymbolEntry {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + SymbolEntry::INIT_SPACE;
+}
+
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct CompletedSwapsEntry {}
@@ -110,10 +433,151 @@ impl CompletedSwapsEntry {
     pub const LEN: usize = ANCHOR_DISCRIMINATOR + CompletedSwapsEntry::INIT_SPACE;
 }
 
+#[account]
+#[derive(InitSpace, Default, Debug)]
+pub struct ChainEntry {
+    // LayerZero endpoint ID of the chain, its Remote PDA holds the peer address
+    pub eid: u32,
+    #[max_len(MAX_CHAIN_NATIVES)]
+    pub native_symbols: Vec<[u8; 32]>,
+}
+
+impl ChainEntry {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + ChainEntry::INIT_SPACE;
+}
+
 #[account]
 #[derive(InitSpace, Debug)]
 pub struct AllowedDestinationEntry {}
 
 impl AllowedDestinationEntry {
     pub const LEN: usize = ANCHOR_DISCRIMINATOR + AllowedDestinationEntry::INIT_SPACE;
-}
\ No newline at end of file
+}
+
+#[account]
+#[derive(InitSpace, Debug)]
+pub struct FailedMessage {
+    pub src_eid: u32,
+    pub sender: [u8; 32],
+    pub nonce: u64,
+    pub guid: [u8; 32],
+    #[max_len(XFER_SIZE)]
+    pub message: Vec<u8>,
+}
+
+impl FailedMessage {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + FailedMessage::INIT_SPACE;
+}
+
+#[account]
+#[derive(InitSpace, Default, Debug)]
+pub struct TokenInventory {
+    // amount held in the user funds vault on behalf of users
+    pub user_funds: u64,
+    // amount held in the RFQ vault as swap inventory
+    pub rfq_inventory: u64,
+    // minimum RFQ inventory that claims must leave behind
+    pub rfq_floor: u64,
+    // maximum amount moved between the vaults per epoch
+    pub rebalance_cap: u64,
+    // bridge fees withheld in the user funds vault until collected
+    pub bridge_fees: u64,
+    // epoch of the last rebalance
+    pub rebalance_epoch: u64,
+    // amount moved between the vaults during `rebalance_epoch`
+    pub rebalanced: u64,
+}
+
+impl TokenInventory {
+    pub const LEN: usize = ANCHOR_DISCRIMINATOR + TokenInventory::INIT_SPACE;
+
+    pub fn add_user_funds(&mut self, amount: u64) -> Result<()> {
+        self.user_funds = self
+            .user_funds
+            .checked_add(amount)
+            .ok_or(DexalotError::InventoryOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove_user_funds(&mut self, amount: u64) -> Result<()> {
+        self.user_funds = self
+            .user_funds
+            .checked_sub(amount)
+            .ok_or(DexalotError::InsufficientInventory)?;
+        Ok(())
+    }
+
+    pub fn add_bridge_fees(&mut self, amount: u64) -> Result<()> {
+        self.bridge_fees = self
+            .bridge_fees
+            .checked_add(amount)
+            .ok_or(DexalotError::InventoryOverflow)?;
+        Ok(())
+    }
+
+    /// Returns the collected bridge fees and resets them
+    pub fn take_bridge_fees(&mut self) -> u64 {
+        std::mem::take(&mut self.bridge_fees)
+    }
+
+    pub fn add_rfq_inventory(&mut self, amount: u64) -> Result<()> {
+        self.rfq_inventory = self
+            .rfq_inventory
+            .checked_add(amount)
+            .ok_or(DexalotError::InventoryOverflow)?;
+        Ok(())
+    }
+
+    pub fn remove_rfq_inventory(&mut self, amount: u64) -> Result<()> {
+        self.rfq_inventory = self
+            .rfq_inventory
+            .checked_sub(amount)
+            .ok_or(DexalotError::InsufficientInventory)?;
+        Ok(())
+    }
+
+    /// Removes RFQ inventory on a claim, rejecting claims that would go below the floor
+    pub fn claim_rfq_inventory(&mut self, amount: u64) -> Result<()> {
+        let remaining = self
+            .rfq_inventory
+            .checked_sub(amount)
+            .ok_or(DexalotError::InsufficientInventory)?;
+        require!(
+            remaining >= self.rfq_floor,
+            DexalotError::InventoryBelowFloor
+        );
+        self.rfq_inventory = remaining;
+        Ok(())
+    }
+
+    /// Resets the ledger to the vault balances, the withheld bridge fees being part of the user funds vault
+    pub fn reconcile(
+        &mut self,
+        user_funds_vault_balance: u64,
+        rfq_vault_balance: u64,
+    ) -> Result<()> {
+        self.user_funds = user_funds_vault_balance
+            .checked_sub(self.bridge_fees)
+            .ok_or(DexalotError::InsufficientInventory)?;
+        self.rfq_inventory = rfq_vault_balance;
+        Ok(())
+    }
+
+    /// Records a rebalance against the cap for the given epoch
+    pub fn record_rebalance(&mut self, amount: u64, epoch: u64) -> Result<()> {
+        if self.rebalance_epoch != epoch {
+            self.rebalance_epoch = epoch;
+            self.rebalanced = 0;
+        }
+        let rebalanced = self
+            .rebalanced
+            .checked_add(amount)
+            .ok_or(DexalotError::RebalanceCapExceeded)?;
+        require!(
+            rebalanced <= self.rebalance_cap,
+            DexalotError::RebalanceCapExceeded
+        );
+        self.rebalanced = rebalanced;
+        Ok(())
+    }
+}
@@ -1,12 +1,18 @@
 #![cfg(test)]
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use anchor_spl::token::spl_token;
 use libsecp256k1::{sign, Message, SecretKey};
 use solana_program::keccak::hash;
-use crate::consts::UNUSED_ADDRESS_PRIVATE_KEY;
+use crate::consts::{TOKEN_LIST_PAGE_CAPACITY, UNUSED_ADDRESS_PRIVATE_KEY};
+use crate::state::{
+    GlobalConfig, PendingChange, Portfolio, RebalancerLimit, TimelockedAction, TokenInventory,
+    TokenList,
+};
 use spl_token::state::{Account as SplAccount, AccountState};
 use anchor_lang::solana_program::program_option::COption;
 use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
 
 /// Creates an `AccountInfo` instance for testing purposes.
 /// This helper is only compiled when running tests.
@@ -38,6 +44,47 @@ pub fn create_dummy_account(program_id: &'static Pubkey) -> AccountInfo<'static>
     create_account_info(key, false, true, lamports, data, program_id, false, None)
 }
 
+/// Creates a writable `AccountInfo` laid out like the runtime's serialized input so `realloc` can grow it
+pub fn create_reallocable_account_info<'a>(
+    key: Pubkey,
+    lamports: u64,
+    data: &[u8],
+    owner: &'a Pubkey,
+) -> AccountInfo<'a> {
+    // padding | original data len (u32) | key | data len (u64) | data | realloc headroom
+    let buffer_len = 48 + data.len() + MAX_PERMITTED_DATA_INCREASE;
+    let words: &'a mut [u64] = Box::leak(vec![0u64; buffer_len.div_ceil(8)].into_boxed_slice());
+    let buffer: &'a mut [u8] = bytemuck::cast_slice_mut(words);
+    buffer[4..8].copy_from_slice(&(data.len() as u32).to_le_bytes());
+    buffer[8..40].copy_from_slice(key.as_ref());
+    buffer[40..48].copy_from_slice(&(data.len() as u64).to_le_bytes());
+    buffer[48..48 + data.len()].copy_from_slice(data);
+
+    let (header, account_data) = buffer.split_at_mut(48);
+    // realloc reads the original data len right before the key
+    let key: &'a Pubkey = unsafe { &*(header[8..40].as_ptr() as *const Pubkey) };
+    let lamports: &'a mut u64 = Box::leak(Box::new(lamports));
+    AccountInfo::new(
+        key,
+        false,
+        true,
+        lamports,
+        &mut account_data[..data.len()],
+        owner,
+        false,
+        0,
+    )
+}
+
+/// Creates the banned PDA of an account that is not banned, i.e. not owned by the program
+pub fn create_not_banned_account<'a>() -> AccountInfo<'a> {
+    let key: &'a Pubkey = Box::leak(Box::new(Pubkey::new_unique()));
+    let owner: &'a Pubkey = Box::leak(Box::new(Pubkey::new_unique()));
+    let lamports: &'a mut u64 = Box::leak(Box::new(100));
+    let data: &'a mut Vec<u8> = Box::leak(Box::new(vec![0u8; 10]));
+    create_account_info(key, false, false, lamports, data, owner, false, None)
+}
+
 /// Generates a valid signature only for the test consts public/private key combination
 pub fn generate_valid_signature(message: &[u8]) -> [u8; 65] {
     let hash = hash(message);
@@ -74,4 +121,88 @@ pub fn create_packed_token_account(
     };
     SplAccount::pack(token_account, &mut data[..])?;
     Ok(data)
-}
\ No newline at end of file
+}
+
+/// Handy function for initializing a TokenInventory account as a byte[]
+pub fn create_token_inventory_data(inventory: &TokenInventory) -> Vec<u8> {
+    let mut data = Vec::with_capacity(TokenInventory::LEN);
+    inventory
+        .try_serialize(&mut data)
+        .expect("TokenInventory serializes");
+    data.resize(TokenInventory::LEN, 0);
+    data
+}
+
+/// Serializes a portfolio holding the given global config
+pub fn create_portfolio_data(global_config: GlobalConfig) -> Vec<u8> {
+    let mut data = Vec::with_capacity(Portfolio::LEN);
+    Portfolio {
+        global_config,
+        ..Default::default()
+    }
+    .try_serialize(&mut data)
+    .expect("Portfolio serializes");
+    data
+}
+
+/// Serializes an inventory whose ledger covers any payout made by the tests
+pub fn create_funded_token_inventory_data() -> Vec<u8> {
+    create_token_inventory_data(&TokenInventory {
+        user_funds: u64::MAX / 2,
+        rfq_inventory: u64::MAX / 2,
+        ..Default::default()
+    })
+}
+
+/// Serializes a token list page holding the given mints
+pub fn create_token_list_data(tokens: &[Pubkey], next_page: Option<Pubkey>) -> Vec<u8> {
+    let mut token_list: TokenList = bytemuck::Zeroable::zeroed();
+    token_list.next_page = next_page.unwrap_or_default();
+    for token_mint in tokens {
+        token_list.insert(*token_mint).expect("TokenList has room");
+    }
+    let mut data = TokenList::discriminator().to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&token_list));
+    data
+}
+
+/// Serializes a token list page filled with unique mints
+pub fn create_full_token_list_data(next_page: Option<Pubkey>) -> Vec<u8> {
+    let tokens: Vec<Pubkey> = (0..TOKEN_LIST_PAGE_CAPACITY)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    create_token_list_data(&tokens, next_page)
+}
+
+/// Serializes a rebalancer withdrawal limit with nothing withdrawn yet
+pub fn create_rebalancer_limit_data(limit: u64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(RebalancerLimit::LEN);
+    RebalancerLimit {
+        limit,
+        ..Default::default()
+    }
+    .try_serialize(&mut data)
+    .expect("RebalancerLimit serializes");
+    data
+}
+
+/// Serializes a pending change of the params that can be executed at `eta`
+pub fn create_pending_change_data<T: AnchorSerialize>(
+    action: TimelockedAction,
+    params: &T,
+    eta: i64,
+) -> Vec<u8> {
+    let pending_change = PendingChange {
+        id: 1,
+        queued_by: Pubkey::new_unique(),
+        action,
+        data: params.try_to_vec().expect("params serialize"),
+        eta,
+    };
+    let mut data = Vec::with_capacity(PendingChange::LEN);
+    pending_change
+        .try_serialize(&mut data)
+        .expect("PendingChange serializes");
+    data.resize(PendingChange::LEN, 0);
+    data
+}
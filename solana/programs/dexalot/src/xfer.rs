@@ -10,7 +10,7 @@ pub struct XFERSolana {
     pub transaction: Tx,             // IPortfolio.Tx -> Tx (u8 repr)
     pub trader: Pubkey,              // address -> Pubkey
     pub token_mint: Pubkey,          // bytes32 -> [u8; 32]
-    pub quantity: u64,               // uint64
+    pub quantity: u128,              // uint256 -> u128, in L1 decimals for token withdrawals
     pub timestamp: u32,              // uint256 -> u32
     pub custom_data: [u8; 18],       // bytes18 -> [u8; 18]
     pub message_type: XChainMsgType, // IPortfolio.XChainMsgType -> XChainMsgType (u8 repr)
@@ -21,7 +21,7 @@ impl XFERSolana {
         transaction: Tx,
         trader: Pubkey,
         token_mint: Pubkey,
-        quantity: u64,
+        quantity: u128,
         timestamp: u32,
         custom_data: [u8; 18],
         nonce: u64,
@@ -42,7 +42,7 @@ impl XFERSolana {
         let mut slot0 = [0u8; 32]; // 18 (custom_data) | 4 (timestamp) | 8 (nonce)  | 1 (Tx) | 1 (XChainMsgType)
         let mut slot1 = [0u8; 32]; // 32 (trader)
         let mut slot2 = [0u8; 32]; // 32 (token_mint)
-        let mut slot3 = [0u8; 8]; // 8 (quantity)
+        let mut slot3 = [0u8; 32]; // 32 (quantity)
 
         slot0[0..18].copy_from_slice(&self.custom_data);
         slot0[18..22].copy_from_slice(&self.timestamp.to_be_bytes());
@@ -52,7 +52,7 @@ impl XFERSolana {
 
         slot1.copy_from_slice(&self.trader.to_bytes());
         slot2.copy_from_slice(&self.token_mint.as_ref());
-        slot3.copy_from_slice(&self.quantity.to_be_bytes());
+        slot3.copy_from_slice(&pad_u128_to_32_bytes(self.quantity));
 
         Ok([
             slot0.to_vec(),
@@ -74,8 +74,8 @@ impl XFERSolana {
         slot1.copy_from_slice(&payload[32..64]);
         let mut slot2 = [0u8; 32];
         slot2.copy_from_slice(&payload[64..96]);
-        let mut slot3 = [0u8; 8];
-        slot3.copy_from_slice(&payload[96..104]);
+        let mut slot3 = [0u8; 32];
+        slot3.copy_from_slice(&payload[96..128]);
 
         let mut custom_data = [0u8; 18];
         custom_data.copy_from_slice(&slot0[..18]);
@@ -94,7 +94,16 @@ impl XFERSolana {
 
         let trader = Pubkey::new_from_array(slot1);
         let token_mint = Pubkey::new_from_array(slot2);
-        let quantity = u64::from_be_bytes(slot3);
+        // the quantity is a uint256, only its lower 16 bytes can be set
+        require!(
+            slot3[..16].iter().all(|byte| *byte == 0),
+            DexalotError::XFERError
+        );
+        let quantity = u128::from_be_bytes(
+            slot3[16..]
+                .try_into()
+                .map_err(|_| DexalotError::XFERError)?,
+        );
 
         Ok(XFERSolana {
             nonce,
@@ -107,6 +116,11 @@ impl XFERSolana {
             message_type,
         })
     }
+
+    /// Quantity as a SOL or SPL token amount, fails if it doesn't fit one
+    pub fn amount(&self) -> Result<u64> {
+        u64::try_from(self.quantity).map_err(|_| DexalotError::UnrepresentableQuantity.into())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
@@ -180,7 +194,7 @@ pub struct XFER {
     pub message_type: XChainMsgType,
     pub trader: [u8; 32],
     pub symbol: [u8; 32],
-    pub quantity: u64,
+    pub quantity: u128,
 }
 
 impl XFER {
@@ -188,7 +202,7 @@ impl XFER {
         transaction: Tx,
         trader: [u8; 32],
         symbol: [u8; 32],
-        quantity: u64,
+        quantity: u128,
         timestamp: u32,
         custom_data: [u8; 18],
         nonce: u64,
@@ -220,7 +234,7 @@ impl XFER {
         slot1.copy_from_slice(&self.trader);
         slot2.copy_from_slice(&self.symbol);
 
-        let quantity_bytes32 = pad_u64_to_32_bytes(self.quantity);
+        let quantity_bytes32 = pad_u128_to_32_bytes(self.quantity);
         slot3.copy_from_slice(&quantity_bytes32);
 
         Ok([
@@ -233,13 +247,35 @@ impl XFER {
     }
 }
 
-fn pad_u64_to_32_bytes(n: u64) -> [u8; 32] {
+fn pad_u128_to_32_bytes(n: u128) -> [u8; 32] {
     let mut padded = [0u8; 32]; // Create a 32-byte array filled with zeros
-    let bytes = n.to_be_bytes(); // Convert u64 to big-endian byte array (8 bytes)
-    padded[32 - bytes.len()..].copy_from_slice(&bytes); // Copy u64 bytes to the end
+    let bytes = n.to_be_bytes(); // Convert u128 to big-endian byte array (16 bytes)
+    padded[32 - bytes.len()..].copy_from_slice(&bytes); // Copy u128 bytes to the end
     padded
 }
 
+/// Rust equivalent of PortfolioMain's scaleQuantity
+/// Converts a quantity between decimals, rejecting quantities with dust below the target precision
+pub fn scale_quantity(quantity: u128, from_decimals: u8, to_decimals: u8) -> Result<u128> {
+    if from_decimals == to_decimals {
+        return Ok(quantity);
+    }
+    if from_decimals > to_decimals {
+        let factor = 10u128
+            .checked_pow(u32::from(from_decimals - to_decimals))
+            .ok_or(DexalotError::UnrepresentableQuantity)?;
+        require!(
+            quantity.is_multiple_of(factor),
+            DexalotError::UnrepresentableQuantity
+        );
+        return Ok(quantity / factor);
+    }
+    10u128
+        .checked_pow(u32::from(to_decimals - from_decimals))
+        .and_then(|factor| quantity.checked_mul(factor))
+        .ok_or(DexalotError::UnrepresentableQuantity.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,7 +285,7 @@ mod tests {
         let transaction = Tx::Deposit;
         let trader = Pubkey::new_unique();
         let token_mint = Pubkey::default();
-        let quantity = 40u64;
+        let quantity = 40u128;
         let timestamp = 1627545600;
         let custom_data = [0u8; 18];
         let xfer = XFERSolana::new(
@@ -271,7 +307,7 @@ mod tests {
         let transaction = Tx::Deposit;
         let trader = Pubkey::new_unique();
         let token_mint = Pubkey::default();
-        let quantity = 40u64;
+        let quantity = 40u128;
         let timestamp = 1627545600;
         let custom_data = [0u8; 18];
         let xfer = XFERSolana::new(
@@ -297,12 +333,54 @@ mod tests {
         assert_eq!(xfer.nonce, unpacked_xfer.nonce);
     }
 
+    #[test]
+    fn test_unpack_xfer_message_quantity() {
+        // 100 tokens in 18 decimals don't fit a u64
+        let quantity = 100_000_000_000_000_000_000u128;
+        let xfer = XFERSolana::new(
+            Tx::Withdraw,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            quantity,
+            1627545600,
+            [0u8; 18],
+            0,
+        );
+        let mut packed_message = xfer._pack_xfer_message().unwrap();
+        let unpacked_xfer = XFERSolana::unpack_xfer_message(&packed_message).unwrap();
+        assert_eq!(unpacked_xfer.quantity, quantity);
+        assert_eq!(
+            unpacked_xfer.amount().unwrap_err(),
+            DexalotError::UnrepresentableQuantity.into()
+        );
+
+        // quantities beyond a u128 are rejected
+        packed_message[96] = 1;
+        let result = XFERSolana::unpack_xfer_message(&packed_message);
+        assert_eq!(result.unwrap_err(), DexalotError::XFERError.into());
+    }
+
     #[test]
     fn test_pack_unpack_xfer_message_wrong_length() {
         let result = XFERSolana::unpack_xfer_message(&[0u8; 100]);
         assert_eq!(result.unwrap_err(), DexalotError::XFERError.into());
     }
 
+    #[test]
+    fn test_scale_quantity() {
+        assert_eq!(scale_quantity(1_500_000, 6, 6).unwrap(), 1_500_000);
+        assert_eq!(scale_quantity(1_500_000, 6, 18).unwrap(), 1_500_000_000_000_000_000);
+        assert_eq!(scale_quantity(1_500_000_000_000_000_000, 18, 6).unwrap(), 1_500_000);
+        assert_eq!(
+            scale_quantity(1_500_000_000_000_000_001, 18, 6).unwrap_err(),
+            DexalotError::UnrepresentableQuantity.into()
+        );
+        assert_eq!(
+            scale_quantity(u128::MAX, 0, 1).unwrap_err(),
+            DexalotError::UnrepresentableQuantity.into()
+        );
+    }
+
     #[test]
     fn test_tx_try_from_variants() {
         assert_eq!(Tx::try_from(0).unwrap(), Tx::Withdraw);
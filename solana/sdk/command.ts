@@ -15,6 +15,11 @@ export enum Commands {
   DisableNativeDeposits = "8.  Disable Native Deposits",
   SetSwapSigner = "9.  Set swap signer",
   GetGlobalConfig = "10. Get global config",
+  QueueChange = "11. Queue config change",
+  CancelChange = "12. Cancel config change",
+  SetTimelockDelay = "13. Set timelock delay",
+  MigrateRemote = "14. Migrate legacy remote",
+  MigratePortfolio = "15. Migrate legacy portfolio",
 
   // Layerzero
   SetRemote = "1. Set Remote",
@@ -30,12 +35,16 @@ export enum Commands {
   Airdrop = "6. Airdrop SOL",
 
   // Account management
-  AddAdmin = "1. Add Admin",
-  RemoveAdmin = "2. Remove Admin",
+  GrantRole = "1. Grant Role",
+  RevokeRole = "2. Revoke Role",
   BanAccount = "3. Ban Account",
   UnbanAccount = "4. Unban Account",
-  AddRebalancer = "5. Add Rebalancer",
-  RemoveRebalancer = "6. Remove Rebalancer",
+  ProposeAdmin = "5. Propose Portfolio Admin",
+  AcceptAdmin = "6. Accept Portfolio Admin",
+  SetMultisigConfig = "7. Set Multisig Config",
+  CreateProposal = "8. Create Proposal",
+  ApproveProposal = "9. Approve Proposal",
+  RegisterRoleMember = "10. Register Role Member",
 
   // SOL vaults
   SolVaultBalance = "1. Get SOL Vault Balance",
@@ -54,6 +63,11 @@ export enum Commands {
   GetSPLTokenUserFundsVaultBalance = "9.  Get program SPL token user funds balance",
   CheckSPLTokenBalanceOfPubkey = "10. Check SPL token balance of pubkey",
   CreateAccount = "11. Create account",
+  AddTokenListPage = "12. Add token list page (only admin)",
+  GetTokenBySymbol = "13. Get token by symbol",
+  UpdateToken = "14. Update token (only admin)",
+  MigrateTokenDetails = "15. Migrate legacy token details (only admin)",
+  MigrateTokenList = "16. Migrate legacy token list (only admin)",
 
   // Deposits
   DepositSol = "1. Deposit SOL (only unpaused)",
@@ -64,7 +78,7 @@ export enum Commands {
   PartialSwap = "2. Partial swap",
   CrossSwap = "3. Cross swap",
   RemoveFromSwapQueue = "4. Remove from swap queue",
-  UpdateSwapExpiry = "5. Update swap expiry (only rebalancer)",
+  UpdateSwapExpiry = "5. Update swap expiry (only volatility admin)",
   AddAllowedDestination = "6. Add allowed destination",
 
   ClaimSplBalance = "1. Claim SPL balance",
@@ -112,6 +126,11 @@ const sectionCommands = {
     Commands.DisableNativeDeposits,
     Commands.SetSwapSigner,
     Commands.GetGlobalConfig,
+    Commands.QueueChange,
+    Commands.CancelChange,
+    Commands.SetTimelockDelay,
+    Commands.MigrateRemote,
+    Commands.MigratePortfolio,
   ],
   [Sections.layerZero]: [
     Commands.SetRemote,
@@ -127,12 +146,16 @@ const sectionCommands = {
     Commands.Airdrop,
   ],
   [Sections.accountManagement]: [
-    Commands.AddAdmin,
-    Commands.RemoveAdmin,
+    Commands.GrantRole,
+    Commands.RevokeRole,
     Commands.BanAccount,
     Commands.UnbanAccount,
-    Commands.AddRebalancer,
-    Commands.RemoveRebalancer,
+    Commands.ProposeAdmin,
+    Commands.AcceptAdmin,
+    Commands.SetMultisigConfig,
+    Commands.CreateProposal,
+    Commands.ApproveProposal,
+    Commands.RegisterRoleMember,
   ],
   [Sections.solVault]: [
     Commands.SolVaultBalance,
@@ -151,6 +174,11 @@ const sectionCommands = {
     Commands.GetSPLTokenUserFundsVaultBalance,
     Commands.CheckSPLTokenBalanceOfPubkey,
     Commands.CreateAccount,
+    Commands.AddTokenListPage,
+    Commands.GetTokenBySymbol,
+    Commands.UpdateToken,
+    Commands.MigrateTokenDetails,
+    Commands.MigrateTokenList,
   ],
   [Sections.deposits]: [
     Commands.DepositSol,
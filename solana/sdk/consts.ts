@@ -2,23 +2,38 @@ export const PORTFOLIO_SEED = "Pfl";
 export const REMOTE_SEED = "Remote";
 export const ADMIN_SEED = "Admin";
 export const REBALANCER_SEED = "Rebalancer";
+export const VOLATILITY_ADMIN_SEED = "VolatilityAdmin";
+export const PAUSER_SEED = "Pauser";
+export const ROLE_REGISTRY_SEED = "RoleRegistry";
+export const MULTISIG_CONFIG_SEED = "Multisig";
+export const PROPOSAL_SEED = "Proposal";
+export const PENDING_CHANGE_SEED = "PendingChange";
 export const BANNED_ACCOUNT_SEED = "Banned";
 export const SOL_VAULT_SEED = "Solv";
 export const SOL_USER_FUNDS_VAULT_SEED = "Soufv";
 export const SPL_VAULT_SEED = "Splv";
 export const SPL_USER_FUNDS_VAULT_SEED = "Sufv";
 export const AIRDROP_VAULT_SEED = "Adv";
+export const LZ_FEE_VAULT_SEED = "Lzfv";
 export const TOKEN_DETAILS_SEED = "TokenDetails";
 export const TOKEN_LIST_SEED = "TokenList";
 export const COMPLETED_SWAPS_SEED = "CompletedSwaps";
 export const EXPIRED_SWAPS_SEED = "ExpiredSwaps";
 export const PENDING_SWAPS_SEED = "PendingSwaps";
 export const CCTRADE_ALLOWED_DEST_SEED = "Cads";
+export const DEPOSIT_ALLOWED_DEST_SEED = "Dads";
+export const LZ_OPTIONS_SEED = "LzOptions";
+export const CHAIN_SEED = "Chain";
+export const SYMBOL_SEED = "Symbol";
 
 export const ORDER_TYPE =
   "Order(maker_asset: Pubkey, taker_asset: Pubkey, taker: Pubkey, maker_amount: u64, taker_amount: u64, expiry: u128, dest_trader: Pubkey, nonce: u128)";
 export const CROSS_SWAP_TYPE =
   "XChainSwap(taker: Pubkey, dest_trader: Pubkey, maker_symbol: [u8; 32], maker_asset: Pubkey, taker_asset: Pubkey, maker_amount: u64, taker_amount: u64, nonce: u128, expiry: u128, dest_chaind_id: u64)";
 
+// message kinds the LZ options are set for
+export const DEPOSIT_MESSAGE_KIND = 1;
+export const CCTRADE_MESSAGE_KIND = 11;
+
 export const DEST_ID = 40267;
 export const SOLANA_ID = 40168;
@@ -3,6 +3,7 @@ import { Dexalot } from "../../target/types/dexalot";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { createSpinner, getAccountPubKey, getUserInput } from "../utils";
 import { ADMIN_SEED, CCTRADE_ALLOWED_DEST_SEED } from "../consts";
+import { getPendingChangeInput } from "./timelock";
 
 const spinner = createSpinner();
 
@@ -10,12 +11,13 @@ export const addDestination = async (
   program: Program<Dexalot>,
   authority: Keypair
 ) => {
-  const eid = Number(await getUserInput("Enter the Endpoint ID: "));
+  const chainId = Number(await getUserInput("Enter the chain ID: "));
   const tokenAddressInput = await getUserInput("Enter the token address: ");
   let tokenAddress = PublicKey.default;
   if (tokenAddressInput) {
     tokenAddress = new PublicKey(tokenAddressInput);
   }
+  const pendingChange = await getPendingChangeInput();
 
   const adminPDA = getAccountPubKey(program, [
     Buffer.from(ADMIN_SEED),
@@ -24,7 +26,7 @@ export const addDestination = async (
 
   const destinationEntry = getAccountPubKey(program, [
     Buffer.from(CCTRADE_ALLOWED_DEST_SEED),
-    new BN(eid).toArrayLike(Buffer, "be", 4),
+    new BN(chainId).toArrayLike(Buffer, "be", 4),
     tokenAddress.toBytes(),
   ]);
 
@@ -32,13 +34,14 @@ export const addDestination = async (
   try {
     spinner.start();
     await program.methods
-      .addDestination({ eid, tokenAddress })
+      .addDestination({ chainId, tokenAddress })
       .accounts({
         payer: authority.publicKey,
         // @ts-ignore
         systemProgram: web3.SystemProgram.programId,
         destinationEntry: destinationEntry,
         admin: adminPDA,
+        pendingChange,
       })
       .signers([authority])
       .rpc({ commitment: "finalized" });
@@ -46,7 +49,7 @@ export const addDestination = async (
     spinner.stop();
     console.clear();
     console.log(
-      `New allowed destination added: ${tokenAddress.toBase58()} on network eid: ${eid}`
+      `New allowed destination added: ${tokenAddress.toBase58()} on chain ID: ${chainId}`
     );
   } catch (error) {
     spinner.stop(true);
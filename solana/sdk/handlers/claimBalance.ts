@@ -16,6 +16,8 @@ import {
   SPL_VAULT_SEED,
   TOKEN_DETAILS_SEED,
 } from "../consts";
+import { getApproverAdmins, getProposalInput } from "./multisig";
+import pdaDeriver from "../pda-deriver";
 
 import {
   getOrCreateAssociatedTokenAccount,
@@ -157,6 +159,7 @@ export const claimAirdropBalance = async (
   const amount =
     Number(await getUserInput("Enter the amount of SOL to claim: ")) *
     LAMPORTS_PER_SOL;
+  const proposal = await getProposalInput();
 
   try {
     spinner.start();
@@ -178,9 +181,12 @@ export const claimAirdropBalance = async (
         authority: authority.publicKey,
         //@ts-ignore
         admin: adminPDA,
+        multisigConfig: pdaDeriver.multisigConfig()[0],
+        proposal,
         airdropVault: airdropVaultPDA,
         systemProgram: web3.SystemProgram.programId,
       })
+      .remainingAccounts(await getApproverAdmins(program, proposal))
       .signers([authority])
       .rpc({ commitment: "finalized" });
 
@@ -17,6 +17,7 @@ import { Dexalot } from "../../target/types/dexalot";
 import { BN, Program, web3 } from "@coral-xyz/anchor";
 import {
   CCTRADE_ALLOWED_DEST_SEED,
+  CCTRADE_MESSAGE_KIND,
   CROSS_SWAP_TYPE,
   PORTFOLIO_SEED,
   SOL_VAULT_SEED,
@@ -186,7 +187,7 @@ export const crossSwap = async (
     const sendRemainingAccounts =
       await endpointProgram.getSendIXAccountMetaForCPI(
         connection as any,
-        payer.publicKey,
+        remote.protocolPaysFee ? pdaDeriver.lzFeeVault()[0] : payer.publicKey,
         packetPath,
         msgLibProgram,
         "finalized"
@@ -243,12 +244,15 @@ export const crossSwap = async (
       .crossSwap({
         order: crossOrder,
         signature: signatureBytes,
+        maxNativeFee: new BN(web3.LAMPORTS_PER_SOL),
       })
       .accounts({
         sender: payer.publicKey,
         taker: taker,
         destTrader: destTrader,
         completedSwapsEntry: completedSwapsEntryPDA,
+        takerBannedAccount: pdaDeriver.bannedAccount(taker)[0],
+        destTraderBannedAccount: pdaDeriver.bannedAccount(destTrader)[0],
         //@ts-ignore
         systemProgram: web3.SystemProgram.programId,
         clock: web3.SYSVAR_CLOCK_PUBKEY,
@@ -260,8 +264,11 @@ export const crossSwap = async (
         splVaultSrcAssetAta: vaultSrcAssetATA.address,
         tokenProgram: TOKEN_PROGRAM_ID,
         remote: remotePDA,
+        chainEntry: pdaDeriver.chain(destId)[0],
+        lzOptions: pdaDeriver.lzOptions(destId, CCTRADE_MESSAGE_KIND)[0],
         endpointProgram: endpointProgram.program,
         destinationEntry: destinationEntryPDA,
+        makerSymbolEntry: pdaDeriver.symbolEntry(crossOrder.makerSymbol)[0],
       })
       .preInstructions([modifyComputeLimitIx])
       .remainingAccounts([...quoteRemainingAccounts, ...sendRemainingAccounts])
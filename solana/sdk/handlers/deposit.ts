@@ -17,6 +17,7 @@ import {
   ADMIN_SEED,
   AIRDROP_VAULT_SEED,
   BANNED_ACCOUNT_SEED,
+  DEPOSIT_MESSAGE_KIND,
   DEST_ID,
   SOL_USER_FUNDS_VAULT_SEED,
   SOL_VAULT_SEED,
@@ -35,6 +36,7 @@ import {
 import { generateLookupTable } from "./lookupTable";
 
 const srcEid = 40168;
+const MAX_NATIVE_FEE = web3.LAMPORTS_PER_SOL;
 
 const spinner = createSpinner();
 
@@ -85,7 +87,9 @@ export const depositToSolVault = async (
     const sendRemainingAccounts =
       await endpointProgram.getSendIXAccountMetaForCPI(
         connection as any,
-        authority.publicKey,
+        remote.protocolPaysFee
+          ? pdaDeriver.lzFeeVault()[0]
+          : authority.publicKey,
         packetPath,
         msgLibProgram,
         "finalized"
@@ -107,6 +111,8 @@ export const depositToSolVault = async (
       .depositNative({
         amount: new BN(lamports),
         trader: Array.from(traderPublicKey),
+        maxNativeFee: new BN(MAX_NATIVE_FEE),
+        destChainId: null,
       })
       .accounts({
         user: authority.publicKey,
@@ -115,6 +121,12 @@ export const depositToSolVault = async (
         solVault: solUserFundsVaultPDA,
         systemProgram: web3.SystemProgram.programId,
         remote: remotePDA,
+        chainEntry: pdaDeriver.chain(DEST_ID)[0],
+        depositDestination: pdaDeriver.depositDestination(
+          DEST_ID,
+          PublicKey.default
+        )[0],
+        lzOptions: pdaDeriver.lzOptions(DEST_ID, DEPOSIT_MESSAGE_KIND)[0],
         bannedAccount: bannedAccountPDA,
         endpointProgram: endpointProgram.program,
       })
@@ -265,7 +277,9 @@ export const depositSPLToken = async (
     const sendRemainingAccounts =
       await endpointProgram.getSendIXAccountMetaForCPI(
         connection as any,
-        authority.publicKey,
+        remote.protocolPaysFee
+          ? pdaDeriver.lzFeeVault()[0]
+          : authority.publicKey,
         packetPath,
         msgLibProgram,
         "finalized"
@@ -308,6 +322,8 @@ export const depositSPLToken = async (
         tokenMint: tokenMintAddress,
         amount: new BN(amount * 10 ** tokenDetails.decimals),
         trader: Array.from(traderPublicKey),
+        maxNativeFee: new BN(MAX_NATIVE_FEE),
+        destChainId: null,
       })
       .accounts({
         user: authority.publicKey,
@@ -320,6 +336,12 @@ export const depositSPLToken = async (
         tokenProgram: TOKEN_PROGRAM_ID,
         bannedAccount: bannedAccountPDA,
         remote: remotePDA,
+        chainEntry: pdaDeriver.chain(DEST_ID)[0],
+        depositDestination: pdaDeriver.depositDestination(
+          DEST_ID,
+          tokenMintAddress
+        )[0],
+        lzOptions: pdaDeriver.lzOptions(DEST_ID, DEPOSIT_MESSAGE_KIND)[0],
         endpointProgram: endpointProgram.program,
       })
       .preInstructions([modifyComputeLimitIx])
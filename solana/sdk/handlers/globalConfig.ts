@@ -4,6 +4,8 @@ import { Dexalot } from "../../target/types/dexalot";
 import { Keypair } from "@solana/web3.js";
 import { ADMIN_SEED, PORTFOLIO_SEED } from "../consts";
 import { green } from "kleur";
+import { getApproverAdmins, getProposalInput } from "./multisig";
+import { getPendingChangeInput } from "./timelock";
 import pdaDeriver from "../pda-deriver";
 
 const spinner = createSpinner();
@@ -14,10 +16,7 @@ export const pauseProgram = async (
 ) => {
   try {
     spinner.start();
-    const adminPDA = getAccountPubKey(program, [
-      Buffer.from(ADMIN_SEED),
-      authority.publicKey.toBuffer(),
-    ]);
+    const [pauserPDA] = pdaDeriver.roleMember("pauser", authority.publicKey);
     const portfolio = getAccountPubKey(program, [Buffer.from(PORTFOLIO_SEED)]);
 
     const tx = await program.methods
@@ -25,7 +24,7 @@ export const pauseProgram = async (
       .accounts({
         authority: authority.publicKey,
         // @ts-ignore
-        admin: adminPDA,
+        pauser: pauserPDA,
         systemProgram: web3.SystemProgram.programId,
         portfolio,
       })
@@ -47,10 +46,7 @@ export const unpauseProgram = async (
 ) => {
   try {
     spinner.start();
-    const adminPDA = getAccountPubKey(program, [
-      Buffer.from(ADMIN_SEED),
-      authority.publicKey.toBuffer(),
-    ]);
+    const [pauserPDA] = pdaDeriver.roleMember("pauser", authority.publicKey);
     const portfolio = getAccountPubKey(program, [Buffer.from(PORTFOLIO_SEED)]);
 
     await program.methods
@@ -58,7 +54,7 @@ export const unpauseProgram = async (
       .accounts({
         authority: authority.publicKey,
         // @ts-ignore
-        admin: adminPDA,
+        pauser: pauserPDA,
         systemProgram: web3.SystemProgram.programId,
         portfolio,
       })
@@ -79,6 +75,7 @@ export const setDefaultChainEid = async (
   admin: Keypair
 ) => {
   const dstEid = parseInt(await getUserInput("Enter the default chain EID: "));
+  const pendingChange = await getPendingChangeInput();
   try {
     spinner.start();
 
@@ -95,6 +92,7 @@ export const setDefaultChainEid = async (
         //@ts-ignore
         portfolio: portfolioPDA,
         admin: adminPDA,
+        pendingChange,
       })
       .signers([admin])
       .rpc({ commitment: "finalized" });
@@ -108,6 +106,44 @@ export const setDefaultChainEid = async (
   }
 };
 
+export const setTimelockDelay = async (
+  program: Program<Dexalot>,
+  admin: Keypair
+) => {
+  const timelockDelay = parseInt(
+    await getUserInput("Enter the timelock delay in seconds: ")
+  );
+  const pendingChange = await getPendingChangeInput();
+  try {
+    spinner.start();
+
+    const [portfolioPDA] = pdaDeriver.portfolio();
+    const adminPDA = getAccountPubKey(program, [
+      Buffer.from(ADMIN_SEED),
+      admin.publicKey.toBuffer(),
+    ]);
+
+    await program.methods
+      .setTimelockDelay({ timelockDelay })
+      .accounts({
+        authority: admin.publicKey,
+        //@ts-ignore
+        portfolio: portfolioPDA,
+        admin: adminPDA,
+        pendingChange,
+      })
+      .signers([admin])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Timelock delay set to ${timelockDelay} seconds`);
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
+
 export const setAirdropAmount = async (
   program: Program<Dexalot>,
   admin: Keypair
@@ -273,6 +309,8 @@ export const setSwapSigner = async (
   const swapSigner = await getUserInput(
     "Enter the swap signer public key (without starting 0x): \n"
   );
+  const proposal = await getProposalInput();
+  const pendingChange = await getPendingChangeInput();
   try {
     spinner.start();
     const [portfolioPDA] = pdaDeriver.portfolio();
@@ -287,7 +325,11 @@ export const setSwapSigner = async (
         //@ts-ignore
         portfolio: portfolioPDA,
         admin: adminPDA,
+        multisigConfig: pdaDeriver.multisigConfig()[0],
+        proposal,
+        pendingChange,
       })
+      .remainingAccounts(await getApproverAdmins(program, proposal))
       .signers([admin])
       .rpc({ commitment: "finalized" });
 
@@ -13,12 +13,14 @@ import {
   SPL_VAULT_SEED,
   TOKEN_LIST_SEED,
 } from "../consts";
+import pdaDeriver from "../pda-deriver";
 import { endpointProgram } from "../layerzero";
 
 const spinner = createSpinner();
 const solana_eid = 40168;
 
 const signer_pubkey = "4747b7f5c40599E1C5CF5a72C535D953B64916b6";
+const timelockDelay = 48 * 60 * 60; // seconds before a queued config change can be executed
 
 export const initialize = async (program: Program<Dexalot>, admin: Keypair) => {
   spinner.start();
@@ -46,12 +48,14 @@ export const initialize = async (program: Program<Dexalot>, admin: Keypair) => {
         srcChainId: solana_eid,
         defaultChainId: DEST_ID,
         swapSigner: Array.from(Buffer.from(signer_pubkey, "hex")),
+        timelockDelay,
       })
       .accounts({
         //@ts-ignore
         portfolio: portfolioPDA,
         tokenList: tokenListPDA,
         admin: adminPDA,
+        adminRegistry: pdaDeriver.roleRegistry("admin")[0],
         authority: admin.publicKey,
         systemProgram: web3.SystemProgram.programId,
         endpointProgram: endpointProgram.program,
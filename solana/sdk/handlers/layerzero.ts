@@ -7,6 +7,7 @@ import { remotePeers } from "../layerzero";
 import { arrayify, hexZeroPad } from "@ethersproject/bytes";
 import { EndpointId } from "@layerzerolabs/lz-definitions";
 import { ADMIN_SEED } from "../consts";
+import { getPendingChangeInput } from "./timelock";
 
 const spinner = createSpinner();
 
@@ -14,6 +15,14 @@ export const setRemote = async (
   program: Program<Dexalot>,
   authority: Keypair
 ) => {
+  // every peer is set through its own queued change
+  const pendingChanges: Record<string, PublicKey> = {};
+  for (const remoteStr of Object.keys(remotePeers)) {
+    pendingChanges[remoteStr] = await getPendingChangeInput(
+      `Enter the queued change id for remote ${remoteStr}: `
+    );
+  }
+
   spinner.start();
   try {
     const [portfolioPDA] = pdaDeriver.portfolio();
@@ -37,6 +46,9 @@ export const setRemote = async (
           remote: remotePDA,
           portfolio: portfolioPDA,
           admin: adminPDA,
+          multisigConfig: pdaDeriver.multisigConfig()[0],
+          proposal: null, // protected peers are set through a proposal each
+          pendingChange: pendingChanges[remoteStr],
           systemProgram: web3.SystemProgram.programId,
         })
         .signers([authority])
@@ -0,0 +1,177 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { Dexalot } from "../../target/types/dexalot";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { green } from "kleur";
+import {
+  createSpinner,
+  getAccountPubKey,
+  getUserInput,
+  padSymbol,
+} from "../utils";
+import { ADMIN_SEED, TOKEN_DETAILS_SEED } from "../consts";
+import { endpointProgram } from "../layerzero";
+import pdaDeriver from "../pda-deriver";
+
+const spinner = createSpinner();
+
+// Accounts written by the first release are grown to the current layout once
+
+export const migratePortfolio = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const defaultChainId = Number(
+    await getUserInput("Enter the Dexalot L1 chainlist ID: ")
+  );
+  const timelockDelay = Number(
+    await getUserInput("Enter the timelock delay in seconds: ")
+  );
+  try {
+    spinner.start();
+    const adminPDA = getAccountPubKey(program, [
+      Buffer.from(ADMIN_SEED),
+      authority.publicKey.toBuffer(),
+    ]);
+
+    await program.methods
+      .migratePortfolio({
+        defaultChainId,
+        endpointProgram: endpointProgram.program,
+        timelockDelay,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        //@ts-ignore
+        admin: adminPDA,
+        portfolio: pdaDeriver.portfolio()[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(green(`Portfolio migrated\n\n`));
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
+
+export const migrateRemote = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const dstEid = Number(
+    await getUserInput("Enter the destination endpoint id: ")
+  );
+  try {
+    spinner.start();
+    const adminPDA = getAccountPubKey(program, [
+      Buffer.from(ADMIN_SEED),
+      authority.publicKey.toBuffer(),
+    ]);
+
+    await program.methods
+      .migrateRemote({ dstEid })
+      .accounts({
+        authority: authority.publicKey,
+        //@ts-ignore
+        admin: adminPDA,
+        remote: pdaDeriver.remote(dstEid)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(green(`Remote ${dstEid} migrated\n\n`));
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
+
+export const migrateTokenDetails = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const tokenMint = new PublicKey(
+    await getUserInput("Enter the token address: ")
+  );
+  const symbol = (
+    await getUserInput("Enter the symbol of the token: ")
+  ).toUpperCase();
+  const symbolPadded = padSymbol(symbol);
+  const l1Decimals = Number(
+    await getUserInput("Enter the decimals of the token on the Dexalot L1: ")
+  );
+  try {
+    spinner.start();
+    const adminPDA = getAccountPubKey(program, [
+      Buffer.from(ADMIN_SEED),
+      authority.publicKey.toBuffer(),
+    ]);
+    const tokenDetailsPDA = getAccountPubKey(program, [
+      Buffer.from(TOKEN_DETAILS_SEED),
+      tokenMint.toBuffer(),
+    ]);
+
+    await program.methods
+      .migrateTokenDetails({
+        tokenAddress: tokenMint,
+        symbol: Array.from(symbolPadded),
+        l1Decimals,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        //@ts-ignore
+        admin: adminPDA,
+        tokenDetails: tokenDetailsPDA,
+        symbolEntry: pdaDeriver.symbolEntry(symbolPadded)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(green(`Token details of ${tokenMint} migrated\n\n`));
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
+
+export const migrateTokenList = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  try {
+    spinner.start();
+    const adminPDA = getAccountPubKey(program, [
+      Buffer.from(ADMIN_SEED),
+      authority.publicKey.toBuffer(),
+    ]);
+
+    await program.methods
+      .migrateTokenList()
+      .accounts({
+        authority: authority.publicKey,
+        //@ts-ignore
+        admin: adminPDA,
+        tokenList: pdaDeriver.tokenListPage(0)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(green(`Token list migrated\n\n`));
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
@@ -0,0 +1,191 @@
+import { BN, Program, web3 } from "@coral-xyz/anchor";
+import { Dexalot } from "../../target/types/dexalot";
+import { AccountMeta, Keypair, PublicKey } from "@solana/web3.js";
+import { createSpinner, getAccountPubKey, getUserInput } from "../utils";
+import { ADMIN_SEED } from "../consts";
+import pdaDeriver from "../pda-deriver";
+
+const spinner = createSpinner();
+
+export const SENSITIVE_ACTIONS = [
+  "setSwapSigner",
+  "setEndpointProgram",
+  "setRemote",
+  "addToken",
+  "claimAirdropBalance",
+  "grantRole",
+  "revokeRole",
+  "setMultisigConfig",
+  "withdrawLzFeeVault",
+  "updateToken",
+  "setChain",
+  "removeChain",
+];
+
+const getActionInput = async (prompt: string): Promise<string> => {
+  const action = await getUserInput(prompt);
+  if (!SENSITIVE_ACTIONS.includes(action)) {
+    throw new Error(`Unknown action: ${action}`);
+  }
+  return action;
+};
+
+// Protected actions are executed through an approved proposal
+export const getProposalInput = async (): Promise<PublicKey | null> => {
+  const id = await getUserInput(
+    "Enter the approved proposal id (leave empty if not protected): "
+  );
+  return id === "" ? null : pdaDeriver.proposal(new BN(id))[0];
+};
+
+// Approvals are re-checked on execution, the approvers' admin PDAs lead the remaining accounts
+export const getApproverAdmins = async (
+  program: Program<Dexalot>,
+  proposal: PublicKey | null
+): Promise<AccountMeta[]> => {
+  if (proposal === null) {
+    return [];
+  }
+  const { approvals } = await program.account.proposal.fetch(proposal);
+  return approvals.map((approver) => ({
+    pubkey: pdaDeriver.roleMember("admin", approver)[0],
+    isSigner: false,
+    isWritable: false,
+  }));
+};
+
+export const setMultisigConfig = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const threshold = Number(
+    await getUserInput("Enter the number of required approvals: ")
+  );
+  const actions = await getUserInput(
+    `Enter the protected actions separated by commas (${SENSITIVE_ACTIONS.join(
+      ", "
+    )}): `
+  );
+  const protectedActions = actions
+    .split(",")
+    .map((a) => a.trim())
+    .filter((a) => a !== "");
+  for (const action of protectedActions) {
+    if (!SENSITIVE_ACTIONS.includes(action)) {
+      throw new Error(`Unknown action: ${action}`);
+    }
+  }
+  const proposal = await getProposalInput();
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .setMultisigConfig({
+        threshold,
+        protectedActions: protectedActions.map((a) => ({ [a]: {} })) as any,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        multisigConfig: pdaDeriver.multisigConfig()[0],
+        adminRegistry: pdaDeriver.roleRegistry("admin")[0],
+        proposal,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .remainingAccounts(await getApproverAdmins(program, proposal))
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(
+      `Multisig threshold set to ${threshold} for: ${protectedActions.join(", ")}`
+    );
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
+
+export const createProposal = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const id = new BN(await getUserInput("Enter the proposal id: "));
+  const action = await getActionInput(
+    `Enter the action (${SENSITIVE_ACTIONS.join(", ")}): `
+  );
+  const data = await getUserInput(
+    "Enter the borsh encoded instruction params (hex, without starting 0x): \n"
+  );
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .createProposal({
+        id,
+        action: { [action]: {} } as any,
+        data: Buffer.from(data, "hex"),
+      })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        proposal: pdaDeriver.proposal(id)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Proposal ${id.toString()} created for ${action}`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
+
+export const approveProposal = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const id = new BN(await getUserInput("Enter the proposal id: "));
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .approveProposal({ id })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        proposal: pdaDeriver.proposal(id)[0],
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Proposal ${id.toString()} approved`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
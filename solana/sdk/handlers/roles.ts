@@ -0,0 +1,208 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { Dexalot } from "../../target/types/dexalot";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { createSpinner, getAccountPubKey, getUserInput } from "../utils";
+import { ADMIN_SEED } from "../consts";
+import { getApproverAdmins, getProposalInput } from "./multisig";
+import pdaDeriver, { RoleName, ROLE_SEEDS } from "../pda-deriver";
+
+const spinner = createSpinner();
+
+const getRoleInput = async (): Promise<RoleName> => {
+  const role = await getUserInput(
+    `Enter the role (${Object.keys(ROLE_SEEDS).join(", ")}): `
+  );
+  if (!(role in ROLE_SEEDS)) {
+    throw new Error(`Unknown role: ${role}`);
+  }
+  return role as RoleName;
+};
+
+export const grantRole = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const role = await getRoleInput();
+  const account = new PublicKey(
+    await getUserInput(`Enter the public key of the new ${role}: `)
+  );
+  const proposal = await getProposalInput();
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .grantRole({ role: { [role]: {} } as any, account })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        multisigConfig: pdaDeriver.multisigConfig()[0],
+        proposal,
+        roleMember: pdaDeriver.roleMember(role, account)[0],
+        roleRegistry: pdaDeriver.roleRegistry(role)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .remainingAccounts(await getApproverAdmins(program, proposal))
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Role ${role} granted to: ${account.toBase58()}`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
+
+export const revokeRole = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const role = await getRoleInput();
+  const account = new PublicKey(
+    await getUserInput(`Enter the public key of the ${role} to remove: `)
+  );
+  const proposal = await getProposalInput();
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .revokeRole({ role: { [role]: {} } as any, account })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        multisigConfig: pdaDeriver.multisigConfig()[0],
+        proposal,
+        receiver: authority.publicKey, // Refund lamports to the remover
+        roleMember: pdaDeriver.roleMember(role, account)[0],
+        roleRegistry: pdaDeriver.roleRegistry(role)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .remainingAccounts(await getApproverAdmins(program, proposal))
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Role ${role} revoked from: ${account.toBase58()}`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
+
+export const registerRoleMember = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const role = await getRoleInput();
+  const account = new PublicKey(
+    await getUserInput(`Enter the public key of the existing ${role}: `)
+  );
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .registerRoleMember({ role: { [role]: {} } as any, account })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        roleMember: pdaDeriver.roleMember(role, account)[0],
+        roleRegistry: pdaDeriver.roleRegistry(role)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Registered ${role}: ${account.toBase58()}`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
+
+export const proposeAdmin = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const newAdmin = new PublicKey(
+    await getUserInput("Enter the public key of the new portfolio admin: ")
+  );
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .proposeAdmin({ newAdmin })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        portfolio: pdaDeriver.portfolio()[0],
+        admin: adminPDA,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Portfolio admin proposed: ${newAdmin.toBase58()}`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
+
+export const acceptAdmin = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        portfolio: pdaDeriver.portfolio()[0],
+        admin: adminPDA,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Portfolio admin is now: ${authority.publicKey.toBase58()}`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
@@ -198,6 +198,8 @@ export const simpleSwap = async (
         taker,
         destTrader,
         completedSwapsEntry: completedSwapsEntryPDA,
+        takerBannedAccount: pdaDeriver.bannedAccount(taker)[0],
+        destTraderBannedAccount: pdaDeriver.bannedAccount(destTrader)[0],
         //@ts-ignore
         systemProgram: web3.SystemProgram.programId,
         clock: web3.SYSVAR_CLOCK_PUBKEY,
@@ -411,6 +413,8 @@ export const partialSwap = async (
         taker,
         destTrader,
         completedSwapsEntry: completedSwapsEntryPDA,
+        takerBannedAccount: pdaDeriver.bannedAccount(taker)[0],
+        destTraderBannedAccount: pdaDeriver.bannedAccount(destTrader)[0],
         //@ts-ignore
         systemProgram: web3.SystemProgram.programId,
         clock: web3.SYSVAR_CLOCK_PUBKEY,
@@ -0,0 +1,194 @@
+import { BN, Program } from "@coral-xyz/anchor";
+import { Dexalot } from "../../target/types/dexalot";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { createSpinner, getAccountPubKey, getUserInput } from "../utils";
+import { arrayify, hexZeroPad } from "@ethersproject/bytes";
+import { ADMIN_SEED } from "../consts";
+import pdaDeriver from "../pda-deriver";
+
+const spinner = createSpinner();
+
+export type TimelockedAction =
+  | "setSwapSigner"
+  | "setRemote"
+  | "setDefaultChain"
+  | "addDestination"
+  | "setTimelockDelay"
+  | "setChain"
+  | "setEndpointProgram"
+  | "removeChain";
+
+const TIMELOCKED_ACTIONS: TimelockedAction[] = [
+  "setSwapSigner",
+  "setRemote",
+  "setDefaultChain",
+  "addDestination",
+  "setTimelockDelay",
+  "setChain",
+  "setEndpointProgram",
+  "removeChain",
+];
+
+const u32 = (value: number) => new BN(value).toArrayLike(Buffer, "le", 4);
+
+// Borsh encoded params of the instruction the change executes
+export const encodeSwapSigner = (swapSigner: string) =>
+  Buffer.from(swapSigner, "hex");
+export const encodeRemote = (dstEid: number, remotePeer: string) =>
+  Buffer.concat([u32(dstEid), Buffer.from(arrayify(hexZeroPad(remotePeer, 32)))]);
+export const encodeDefaultChain = (chainId: number) => u32(chainId);
+export const encodeDestination = (chainId: number, tokenAddress: PublicKey) =>
+  Buffer.concat([u32(chainId), tokenAddress.toBuffer()]);
+export const encodeTimelockDelay = (timelockDelay: number) => u32(timelockDelay);
+export const encodeChain = (
+  chainId: number,
+  eid: number,
+  nativeSymbols: string[]
+) =>
+  Buffer.concat([
+    u32(chainId),
+    u32(eid),
+    u32(nativeSymbols.length),
+    ...nativeSymbols.map((symbol) => {
+      const buffer = Buffer.alloc(32);
+      buffer.write(symbol);
+      return buffer;
+    }),
+  ]);
+export const encodeEndpointProgram = (endpointProgram: PublicKey) =>
+  endpointProgram.toBuffer();
+export const encodeRemovedChain = (chainId: number) => u32(chainId);
+
+// Timelocked instructions are executed against a matured queued change
+export const getPendingChangeInput = async (
+  prompt = "Enter the queued change id: "
+): Promise<PublicKey> => {
+  const id = new BN(await getUserInput(prompt));
+  return pdaDeriver.pendingChange(id)[0];
+};
+
+const getChangeData = async (action: TimelockedAction): Promise<Buffer> => {
+  switch (action) {
+    case "setSwapSigner":
+      return encodeSwapSigner(
+        await getUserInput(
+          "Enter the swap signer public key (without starting 0x): \n"
+        )
+      );
+    case "setRemote":
+      return encodeRemote(
+        Number(await getUserInput("Enter the destination endpoint id: ")),
+        await getUserInput("Enter the remote peer address: ")
+      );
+    case "setDefaultChain":
+      return encodeDefaultChain(
+        Number(await getUserInput("Enter the default chain EID: "))
+      );
+    case "addDestination": {
+      const chainId = Number(await getUserInput("Enter the chain ID: "));
+      const tokenAddress = await getUserInput("Enter the token address: ");
+      return encodeDestination(
+        chainId,
+        tokenAddress ? new PublicKey(tokenAddress) : PublicKey.default
+      );
+    }
+    case "setTimelockDelay":
+      return encodeTimelockDelay(
+        Number(await getUserInput("Enter the timelock delay in seconds: "))
+      );
+    case "setChain": {
+      const chainId = Number(await getUserInput("Enter the chain ID: "));
+      const eid = Number(await getUserInput("Enter the endpoint id: "));
+      const natives = await getUserInput(
+        "Enter the native symbols separated by commas: "
+      );
+      return encodeChain(
+        chainId,
+        eid,
+        natives ? natives.split(",").map((symbol) => symbol.trim()) : []
+      );
+    }
+    case "setEndpointProgram":
+      return encodeEndpointProgram(
+        new PublicKey(await getUserInput("Enter the endpoint program id: "))
+      );
+    case "removeChain":
+      return encodeRemovedChain(
+        Number(await getUserInput("Enter the chain ID: "))
+      );
+  }
+};
+
+export const queueChange = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const id = new BN(await getUserInput("Enter the change id: "));
+  const action = (await getUserInput(
+    `Enter the action (${TIMELOCKED_ACTIONS.join(", ")}): `
+  )) as TimelockedAction;
+  if (!TIMELOCKED_ACTIONS.includes(action)) {
+    throw new Error(`Unknown action: ${action}`);
+  }
+  const data = await getChangeData(action);
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .queueChange({ id, action: { [action]: {} } as any, data })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        portfolio: pdaDeriver.portfolio()[0],
+        pendingChange: pdaDeriver.pendingChange(id)[0],
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Change ${id.toString()} queued for ${action}`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
+
+export const cancelChange = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const id = new BN(await getUserInput("Enter the change id: "));
+
+  const adminPDA = getAccountPubKey(program, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+
+  try {
+    spinner.start();
+    await program.methods
+      .cancelChange({ id })
+      .accounts({
+        authority: authority.publicKey,
+        // @ts-ignore
+        admin: adminPDA,
+        pendingChange: pdaDeriver.pendingChange(id)[0],
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(`Change ${id.toString()} cancelled`);
+  } catch (error) {
+    spinner.stop(true);
+    throw error;
+  }
+};
@@ -1,4 +1,4 @@
-import { Program, web3 } from "@coral-xyz/anchor";
+import { BN, Program, web3 } from "@coral-xyz/anchor";
 import { Dexalot } from "../../target/types/dexalot";
 import {
   createSpinner,
@@ -28,21 +28,42 @@ import {
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import * as fs from "fs";
+import { getApproverAdmins, getProposalInput } from "./multisig";
+import pdaDeriver from "../pda-deriver";
 
 const spinner = createSpinner();
 
+// Follows the next page links from the first token list page
+const getTokenListPages = async (program: Program<Dexalot>) => {
+  const pages = [];
+  let page: PublicKey | null = getAccountPubKey(program, [
+    Buffer.from(TOKEN_LIST_SEED),
+    Buffer.from("0"),
+  ]);
+  while (page) {
+    const tokenListAccount = await program.account.tokenList.fetch(page);
+    // only the first len slots of a page hold mints
+    pages.push({
+      pubkey: page,
+      tokens: tokenListAccount.tokens.slice(0, tokenListAccount.len),
+    });
+    page = tokenListAccount.nextPage.equals(PublicKey.default)
+      ? null
+      : tokenListAccount.nextPage;
+  }
+  return pages;
+};
+
 export const getTokenList = async (program: Program<Dexalot>) => {
   try {
     spinner.start();
-    const tokenListPDA = getAccountPubKey(program, [
-      Buffer.from(TOKEN_LIST_SEED),
-      Buffer.from("0"),
-    ]);
-    let tokenListAccount = await program.account.tokenList.fetch(tokenListPDA);
+    const pages = await getTokenListPages(program);
 
-    const tokens = tokenListAccount.tokens.map((tokenMintAddress) => {
-      return tokenMintAddress.toBase58().replace(/\0/g, "");
-    });
+    const tokens = pages
+      .flatMap((page) => page.tokens)
+      .map((tokenMintAddress) => {
+        return tokenMintAddress.toBase58().replace(/\0/g, "");
+      });
     spinner.stop();
     console.clear();
     console.log(green(`Supported tokens: ${tokens.join(", ")}\n\n`));
@@ -75,7 +96,11 @@ export const getTokenDetails = async (program: Program<Dexalot>) => {
           .toString("utf8")
           .replace(/\0/g, "")}\nToken decimals: ${
           tokenDetailsAccount.decimals
-        }\nToken address: ${tokenDetailsAccount.tokenAddress}\n`
+        }\nToken address: ${
+          tokenDetailsAccount.tokenAddress
+        }\nAllow deposit: ${tokenDetailsAccount.allowDeposit}\nPaused: ${
+          tokenDetailsAccount.paused
+        }\n`
       )
     );
   } catch (err) {
@@ -84,6 +109,31 @@ export const getTokenDetails = async (program: Program<Dexalot>) => {
   }
 };
 
+export const getTokenBySymbol = async (program: Program<Dexalot>) => {
+  const symbol = (
+    await getUserInput("Enter the symbol of the token: ")
+  ).toUpperCase();
+  const symbolPadded = padSymbol(symbol);
+
+  try {
+    spinner.start();
+    const tokenMint = await program.methods
+      .getTokenBySymbol({ symbol: Array.from(symbolPadded) })
+      .accounts({
+        //@ts-ignore
+        symbolEntry: pdaDeriver.symbolEntry(symbolPadded)[0],
+      })
+      .view({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(green(`Token ${symbol} mint address: ${tokenMint}\n`));
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
+
 export const addToken = async (
   program: Program<Dexalot>,
   connection: Connection,
@@ -99,6 +149,11 @@ export const addToken = async (
   ).toUpperCase();
   const symbolPadded = padSymbol(symbol);
 
+  const l1Decimals = Number(
+    await getUserInput("Enter the decimals of the token on the Dexalot L1: ")
+  );
+  const proposal = await getProposalInput();
+
   try {
     spinner.start();
     const mintAccount = await getMint(
@@ -121,10 +176,7 @@ export const addToken = async (
       tokenMint.toBuffer(),
     ]);
 
-    const tokenListPDA = getAccountPubKey(program, [
-      Buffer.from(TOKEN_LIST_SEED),
-      Buffer.from("0"),
-    ]);
+    const tokenListPages = await getTokenListPages(program);
 
     const splVaultPDA = getAccountPubKey(program, [
       Buffer.from(SPL_VAULT_SEED),
@@ -142,14 +194,18 @@ export const addToken = async (
         symbol: Array.from(symbolPadded),
         tokenAddress: tokenMint,
         decimals: tokenDecimals,
+        l1Decimals,
       })
       .accounts({
         authority: authority.publicKey,
         //@ts-ignore
         admin: adminPDA,
+        multisigConfig: pdaDeriver.multisigConfig()[0],
+        proposal,
         splVault: splVaultPDA,
         splUserFundsVault: splUserFundsVaultPDA,
         tokenDetails: tokenDetailsPDA,
+        symbolEntry: pdaDeriver.symbolEntry(symbolPadded)[0],
         tokenMint: tokenMint,
         splTokenAccount: await getAssociatedTokenAddress(
           tokenMint,
@@ -166,7 +222,12 @@ export const addToken = async (
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
-        { pubkey: tokenListPDA, isSigner: false, isWritable: true },
+        ...(await getApproverAdmins(program, proposal)),
+        ...tokenListPages.map((page) => ({
+          pubkey: page.pubkey,
+          isSigner: false,
+          isWritable: true,
+        })),
       ])
       .signers([authority])
       .rpc({ commitment: "finalized" });
@@ -208,11 +269,19 @@ export const removeToken = async (
         program.programId
       );
 
-    const [tokenList, tokenListBump] = web3.PublicKey.findProgramAddressSync(
-      [Buffer.from(TOKEN_LIST_SEED), Buffer.from("0")],
-      program.programId
+    const tokenDetailsAccount = await program.account.tokenDetails.fetch(
+      tokenDetails
     );
 
+    const tokenListPages = await getTokenListPages(program);
+
+    const splVaultPDA = getAccountPubKey(program, [
+      Buffer.from(SPL_VAULT_SEED),
+    ]);
+    const splUserFundsVaultPDA = getAccountPubKey(program, [
+      Buffer.from(SPL_USER_FUNDS_VAULT_SEED),
+    ]);
+
     const tx = await program.methods
 
       .removeToken({ tokenAddress: tokenMint })
@@ -220,17 +289,31 @@ export const removeToken = async (
         authority: authority.publicKey, // Pass the authority (payer account)
         //@ts-ignore
         tokenDetails,
+        symbolEntry: pdaDeriver.symbolEntry(tokenDetailsAccount.symbol)[0],
         admin: adminPDA,
         receiver: authority.publicKey,
         systemProgram: web3.SystemProgram.programId,
+        splVault: splVaultPDA,
+        splUserFundsVault: splUserFundsVaultPDA,
+        splTokenAccount: await getAssociatedTokenAddress(
+          tokenMint,
+          splVaultPDA,
+          true // allow owner off curve
+        ),
+        splUserFundsTokenAccount: await getAssociatedTokenAddress(
+          tokenMint,
+          splUserFundsVaultPDA,
+          true // allow owner off curve
+        ),
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
-      .remainingAccounts([
-        {
-          pubkey: tokenList,
+      .remainingAccounts(
+        tokenListPages.map((page) => ({
+          pubkey: page.pubkey,
           isWritable: true,
           isSigner: false,
-        },
-      ])
+        }))
+      )
       .signers([authority])
       .rpc({ commitment: "finalized" });
 
@@ -546,3 +629,110 @@ export const createNewToken = async (
     throw err;
   }
 };
+
+export const addTokenListPage = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  try {
+    spinner.start();
+    const adminPDA = getAccountPubKey(program, [
+      Buffer.from(ADMIN_SEED),
+      authority.publicKey.toBuffer(),
+    ]);
+    const tokenListPages = await getTokenListPages(program);
+    const page = tokenListPages.length;
+
+    const tx = await program.methods
+      .addTokenListPage({ page })
+      .accounts({
+        authority: authority.publicKey,
+        //@ts-ignore
+        admin: adminPDA,
+        lastPage: tokenListPages[page - 1].pubkey,
+        newPage: pdaDeriver.tokenListPage(page)[0],
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(green(`Token list page ${page} added successfully\n\n`));
+    await printTransactionEvents(program, tx);
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
+
+export const updateToken = async (
+  program: Program<Dexalot>,
+  authority: Keypair
+) => {
+  const tokenMint = new PublicKey(
+    await getUserInput("Enter the token mint address of the token to update: ")
+  );
+  const symbol = (
+    await getUserInput("Enter the symbol of the token: ")
+  ).toUpperCase();
+  const symbolPadded = padSymbol(symbol);
+  const l1Decimals = Number(
+    await getUserInput("Enter the decimals of the token on the Dexalot L1: ")
+  );
+  const allowDeposit =
+    (await getUserInput("Allow deposits? (y/n): ")).toLowerCase() === "y";
+  const minDeposit = new BN(
+    await getUserInput("Enter the minimum deposit in mint decimals: ")
+  );
+  const paused =
+    (await getUserInput("Pause the token? (y/n): ")).toLowerCase() === "y";
+  const proposal = await getProposalInput();
+
+  try {
+    spinner.start();
+    const adminPDA = getAccountPubKey(program, [
+      Buffer.from(ADMIN_SEED),
+      authority.publicKey.toBuffer(),
+    ]);
+    const tokenDetailsPDA = getAccountPubKey(program, [
+      Buffer.from(TOKEN_DETAILS_SEED),
+      tokenMint.toBuffer(),
+    ]);
+    const tokenDetailsAccount = await program.account.tokenDetails.fetch(
+      tokenDetailsPDA
+    );
+
+    const tx = await program.methods
+      .updateToken({
+        tokenAddress: tokenMint,
+        symbol: Array.from(symbolPadded),
+        l1Decimals,
+        allowDeposit,
+        minDeposit,
+        paused,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        //@ts-ignore
+        admin: adminPDA,
+        tokenDetails: tokenDetailsPDA,
+        symbolEntry: pdaDeriver.symbolEntry(tokenDetailsAccount.symbol)[0],
+        newSymbolEntry: pdaDeriver.symbolEntry(symbolPadded)[0],
+        multisigConfig: pdaDeriver.multisigConfig()[0],
+        proposal,
+        systemProgram: web3.SystemProgram.programId,
+      })
+      .remainingAccounts(await getApproverAdmins(program, proposal))
+      .signers([authority])
+      .rpc({ commitment: "finalized" });
+
+    spinner.stop();
+    console.clear();
+    console.log(green(`Token ${tokenMint} updated successfully\n\n`));
+    await printTransactionEvents(program, tx);
+  } catch (err) {
+    spinner.stop(true);
+    throw err;
+  }
+};
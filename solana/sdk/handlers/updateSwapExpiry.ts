@@ -3,12 +3,10 @@ import { Dexalot } from "../../target/types/dexalot";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import {
   createSpinner,
-  getAccountPubKey,
   getUserInput,
   printTransactionEvents,
 } from "../utils";
 import { green } from "kleur";
-import { REBALANCER_SEED } from "../consts";
 import pdaDeriver from "../pda-deriver";
 
 const spinner = createSpinner();
@@ -29,10 +27,10 @@ export const updateSwapExpiry = async (
 
   try {
     spinner.start();
-    const rebalancerPDA = getAccountPubKey(program, [
-      Buffer.from(REBALANCER_SEED),
-      authority.publicKey.toBuffer(),
-    ]);
+    const [volatilityAdminPDA] = pdaDeriver.roleMember(
+      "volatilityAdmin",
+      authority.publicKey
+    );
 
     const [completedSwapsEntryPDA] = pdaDeriver.completedSwapsEntry(
       nonce,
@@ -45,7 +43,7 @@ export const updateSwapExpiry = async (
         authority: authority.publicKey,
         completedSwapEntry: completedSwapsEntryPDA,
         //@ts-ignore
-        rebalancer: rebalancerPDA,
+        volatilityAdmin: volatilityAdminPDA,
         systemProgram: web3.SystemProgram.programId,
       })
       .signers([authority])
@@ -5,7 +5,6 @@ import {
   Wallet,
 } from "@coral-xyz/anchor";
 import { EndpointId } from "@layerzerolabs/lz-definitions";
-import { addAdmin, removeAdmin } from "./handlers/admin";
 import { banAccount, unbanAccount } from "./handlers/ban";
 import {
   depositSPLToken,
@@ -22,8 +21,10 @@ import {
   setAirdropAmount,
   setDefaultChainEid,
   setSwapSigner,
+  setTimelockDelay,
   unpauseProgram,
 } from "./handlers/globalConfig";
+import { cancelChange, queueChange } from "./handlers/timelock";
 import {
   initialize,
   initializeSolVaults,
@@ -37,15 +38,18 @@ import {
 } from "./handlers/solVaults";
 import {
   addToken,
+  addTokenListPage,
   createNewToken,
   getSPLTokenBalanceOfActiveWallet,
   getSPLTokenBalanceOfPubkey,
   getSPLTokenUserFundsVaultBalance,
   getSPLTokenVaultBalance,
+  getTokenBySymbol,
   getTokenDetails,
   getTokenList,
   mintSPLToken,
   removeToken,
+  updateToken,
 } from "./handlers/token";
 import { createAccount } from "./handlers/create";
 import {
@@ -75,7 +79,18 @@ import { arrayify, hexZeroPad } from "@ethersproject/bytes";
 import { partialSwap, simpleSwap } from "./handlers/swap";
 import { crossSwap } from "./handlers/crossSwap";
 import { removeFromSwapQueue } from "./handlers/removeFromSwapQueue";
-import { addRebalancer, removeRebalancer } from "./handlers/rebalancer";
+import {
+  acceptAdmin,
+  grantRole,
+  proposeAdmin,
+  registerRoleMember,
+  revokeRole,
+} from "./handlers/roles";
+import {
+  approveProposal,
+  createProposal,
+  setMultisigConfig,
+} from "./handlers/multisig";
 import { updateSwapExpiry } from "./handlers/updateSwapExpiry";
 import {
   claimAirdropBalance,
@@ -85,6 +100,12 @@ import {
 import { fundSol, fundSpl } from "./handlers/fund";
 import { generateIntegrationTestsRemainingAccounts } from "./handlers/testsRA";
 import { addDestination } from "./handlers/addDestination";
+import {
+  migratePortfolio,
+  migrateRemote,
+  migrateTokenDetails,
+  migrateTokenList,
+} from "./handlers/migrate";
 
 const DEFAULT_WALLET_PATH = "./admin.json";
 
@@ -232,6 +253,18 @@ class Interactor {
     }
   };
 
+  getTokenBySymbol = async () => {
+    if (!this.program || !this.connection) {
+      console.error(red("Program not found\n\n"));
+      return;
+    }
+    try {
+      await getTokenBySymbol(this.program);
+    } catch (error) {
+      console.error(red("Error getting token by symbol\n\n"));
+    }
+  };
+
   addToken = async () => {
     if (!this.program || !this.connection || !this.wallet || !this.keypair) {
       console.error(red("Program, connection, or wallet not found\n\n"));
@@ -244,6 +277,54 @@ class Interactor {
     }
   };
 
+  updateToken = async () => {
+    if (!this.program || !this.connection || !this.wallet || !this.keypair) {
+      console.error(red("Program, connection, or wallet not found\n\n"));
+      return;
+    }
+    try {
+      await updateToken(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error updating token: ${error}\n\n`));
+    }
+  };
+
+  migrateTokenDetails = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await migrateTokenDetails(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error migrating token details: ${error}\n\n`));
+    }
+  };
+
+  migrateTokenList = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await migrateTokenList(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error migrating token list: ${error}\n\n`));
+    }
+  };
+
+  addTokenListPage = async () => {
+    if (!this.program || !this.connection || !this.wallet || !this.keypair) {
+      console.error(red("Program, connection, or wallet not found\n\n"));
+      return;
+    }
+    try {
+      await addTokenListPage(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error adding token list page: ${error}\n\n`));
+    }
+  };
+
   removeToken = async () => {
     if (!this.program || !this.connection || !this.wallet || !this.keypair) {
       console.error(red("Program, connection, or wallet not found\n\n"));
@@ -354,27 +435,99 @@ class Interactor {
     }
   };
 
-  addAdmin = async () => {
+  grantRole = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await grantRole(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error granting role: ${error}\n\n`));
+    }
+  };
+
+  revokeRole = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await revokeRole(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error revoking role: ${error}\n\n`));
+    }
+  };
+
+  registerRoleMember = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await registerRoleMember(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error registering role member: ${error}\n\n`));
+    }
+  };
+
+  proposeAdmin = async () => {
     if (!this.program || !this.keypair) {
       console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await addAdmin(this.program, this.keypair);
+      await proposeAdmin(this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error adding admin: ${error}\n\n`));
+      console.error(red(`Error proposing admin: ${error}\n\n`));
     }
   };
 
-  removeAdmin = async () => {
+  acceptAdmin = async () => {
     if (!this.program || !this.keypair) {
       console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await removeAdmin(this.program, this.keypair);
+      await acceptAdmin(this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error removing admin: ${error}\n\n`));
+      console.error(red(`Error accepting admin: ${error}\n\n`));
+    }
+  };
+
+  setMultisigConfig = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await setMultisigConfig(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error setting multisig config: ${error}\n\n`));
+    }
+  };
+
+  createProposal = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await createProposal(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error creating proposal: ${error}\n\n`));
+    }
+  };
+
+  approveProposal = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await approveProposal(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error approving proposal: ${error}\n\n`));
     }
   };
 
@@ -724,75 +877,111 @@ class Interactor {
     }
   };
 
-  addRebalancer = async () => {
+  updateSwapExpiry = async () => {
     if (!this.program || !this.keypair) {
       console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await addRebalancer(this.program, this.keypair);
+      await updateSwapExpiry(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error updating swap expiry: ${error}\n\n`));
+    }
+  };
+
+  claimSplBalance = async () => {
+    if (!this.connection || !this.program || !this.keypair) {
+      console.error(red("Connection, program, or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await claimSplBalance(this.connection, this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error adding rebalancer: ${error}\n\n`));
+      console.error(red(`Error claiming SPL balance: ${error}\n\n`));
     }
   };
 
-  removeRebalancer = async () => {
+  claimNativeBalance = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program, or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await claimNativeBalance(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error claiming SOL balance: ${error}\n\n`));
+    }
+  };
+
+  getGlobalConfig = async () => {
     if (!this.program || !this.keypair) {
       console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await removeRebalancer(this.program, this.keypair);
+      await getGlobalConfig(this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error removing rebalancer: ${error}\n\n`));
+      console.error(red(`Error getting global config: ${error}\n\n`));
     }
   };
 
-  updateSwapExpiry = async () => {
+  queueChange = async () => {
     if (!this.program || !this.keypair) {
       console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await updateSwapExpiry(this.program, this.keypair);
+      await queueChange(this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error updating swap expiry: ${error}\n\n`));
+      console.error(red(`Error queueing config change: ${error}\n\n`));
     }
   };
 
-  claimSplBalance = async () => {
-    if (!this.connection || !this.program || !this.keypair) {
-      console.error(red("Connection, program, or keypair not found\n\n"));
+  cancelChange = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await claimSplBalance(this.connection, this.program, this.keypair);
+      await cancelChange(this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error claiming SPL balance: ${error}\n\n`));
+      console.error(red(`Error cancelling config change: ${error}\n\n`));
     }
   };
 
-  claimNativeBalance = async () => {
+  setTimelockDelay = async () => {
     if (!this.program || !this.keypair) {
-      console.error(red("Program, or keypair not found\n\n"));
+      console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await claimNativeBalance(this.program, this.keypair);
+      await setTimelockDelay(this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error claiming SOL balance: ${error}\n\n`));
+      console.error(red(`Error setting timelock delay: ${error}\n\n`));
     }
   };
 
-  getGlobalConfig = async () => {
+  migrateRemote = async () => {
     if (!this.program || !this.keypair) {
       console.error(red("Program or keypair not found\n\n"));
       return;
     }
     try {
-      await getGlobalConfig(this.program, this.keypair);
+      await migrateRemote(this.program, this.keypair);
     } catch (error) {
-      console.error(red(`Error getting global config: ${error}\n\n`));
+      console.error(red(`Error migrating remote: ${error}\n\n`));
+    }
+  };
+
+  migratePortfolio = async () => {
+    if (!this.program || !this.keypair) {
+      console.error(red("Program or keypair not found\n\n"));
+      return;
+    }
+    try {
+      await migratePortfolio(this.program, this.keypair);
+    } catch (error) {
+      console.error(red(`Error migrating portfolio: ${error}\n\n`));
     }
   };
 
@@ -77,6 +77,21 @@ const main = async () => {
           case 10:
             await Interactor.getGlobalConfig();
             break;
+          case 11:
+            await Interactor.queueChange();
+            break;
+          case 12:
+            await Interactor.cancelChange();
+            break;
+          case 13:
+            await Interactor.setTimelockDelay();
+            break;
+          case 14:
+            await Interactor.migrateRemote();
+            break;
+          case 15:
+            await Interactor.migratePortfolio();
+            break;
           default:
             console.error(red("\n\nInvalid command!\n\n"));
         }
@@ -132,10 +147,10 @@ const main = async () => {
         }
         switch (command) {
           case 1:
-            await Interactor.addAdmin();
+            await Interactor.grantRole();
             break;
           case 2:
-            await Interactor.removeAdmin();
+            await Interactor.revokeRole();
             break;
           case 3:
             await Interactor.banAccount();
@@ -144,10 +159,22 @@ const main = async () => {
             await Interactor.unbanAccount();
             break;
           case 5:
-            await Interactor.addRebalancer();
+            await Interactor.proposeAdmin();
             break;
           case 6:
-            await Interactor.removeRebalancer();
+            await Interactor.acceptAdmin();
+            break;
+          case 7:
+            await Interactor.setMultisigConfig();
+            break;
+          case 8:
+            await Interactor.createProposal();
+            break;
+          case 9:
+            await Interactor.approveProposal();
+            break;
+          case 10:
+            await Interactor.registerRoleMember();
             break;
           default:
             console.error(red("\n\nInvalid command!\n\n"));
@@ -209,6 +236,21 @@ const main = async () => {
           case 11:
             await Interactor.createAccount();
             break;
+          case 12:
+            await Interactor.addTokenListPage();
+            break;
+          case 13:
+            await Interactor.getTokenBySymbol();
+            break;
+          case 14:
+            await Interactor.updateToken();
+            break;
+          case 15:
+            await Interactor.migrateTokenDetails();
+            break;
+          case 16:
+            await Interactor.migrateTokenList();
+            break;
           default:
             console.error(red("\n\nInvalid command!\n\n"));
         }
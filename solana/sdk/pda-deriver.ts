@@ -3,13 +3,37 @@ import BN from "bn.js";
 
 import { oappIDPDA } from "@layerzerolabs/lz-solana-sdk-v2";
 import {
+  ADMIN_SEED,
+  BANNED_ACCOUNT_SEED,
+  CHAIN_SEED,
   COMPLETED_SWAPS_SEED,
+  DEPOSIT_ALLOWED_DEST_SEED,
+  LZ_FEE_VAULT_SEED,
+  LZ_OPTIONS_SEED,
+  MULTISIG_CONFIG_SEED,
+  PAUSER_SEED,
+  PENDING_CHANGE_SEED,
   PENDING_SWAPS_SEED,
   PORTFOLIO_SEED,
+  PROPOSAL_SEED,
+  REBALANCER_SEED,
   REMOTE_SEED,
+  ROLE_REGISTRY_SEED,
+  SYMBOL_SEED,
+  TOKEN_LIST_SEED,
+  VOLATILITY_ADMIN_SEED,
 } from "./consts";
 import { keccak256 } from "@layerzerolabs/lz-v2-utilities";
 
+export type RoleName = "admin" | "rebalancer" | "volatilityAdmin" | "pauser";
+
+export const ROLE_SEEDS: Record<RoleName, string> = {
+  admin: ADMIN_SEED,
+  rebalancer: REBALANCER_SEED,
+  volatilityAdmin: VOLATILITY_ADMIN_SEED,
+  pauser: PAUSER_SEED,
+};
+
 const PORTFOLIO_PROGRAM_ID = new PublicKey(
   "2wF7VoXvkMwvMpN1GYETaUvaWth3CqyTyiYQqTFYhgx7"
 );
@@ -30,6 +54,98 @@ class PortfolioPDADeriver {
     );
   }
 
+  chain(chainId: number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(CHAIN_SEED), new BN(chainId).toArrayLike(Buffer, "be", 4)],
+      this.program
+    );
+  }
+
+  depositDestination(chainId: number, tokenMint: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(DEPOSIT_ALLOWED_DEST_SEED),
+        new BN(chainId).toArrayLike(Buffer, "be", 4),
+        tokenMint.toBuffer(),
+      ],
+      this.program
+    );
+  }
+
+  tokenListPage(page: number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(TOKEN_LIST_SEED), Buffer.from(page.toString())],
+      this.program
+    );
+  }
+
+  symbolEntry(symbol: Uint8Array | number[]): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(SYMBOL_SEED), Buffer.from(symbol)],
+      this.program
+    );
+  }
+
+  roleMember(role: RoleName, account: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(ROLE_SEEDS[role]), account.toBuffer()],
+      this.program
+    );
+  }
+
+  roleRegistry(role: RoleName): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(ROLE_REGISTRY_SEED), Buffer.from(ROLE_SEEDS[role])],
+      this.program
+    );
+  }
+
+  multisigConfig(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(MULTISIG_CONFIG_SEED)],
+      this.program
+    );
+  }
+
+  proposal(id: BN | number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(PROPOSAL_SEED), new BN(id).toArrayLike(Buffer, "be", 8)],
+      this.program
+    );
+  }
+
+  pendingChange(id: BN | number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(PENDING_CHANGE_SEED), new BN(id).toArrayLike(Buffer, "be", 8)],
+      this.program
+    );
+  }
+
+  lzFeeVault(): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(LZ_FEE_VAULT_SEED)],
+      this.program
+    );
+  }
+
+  lzOptions(dstEid: number, kind: number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(LZ_OPTIONS_SEED),
+        new BN(dstEid).toArrayLike(Buffer, "be", 4),
+        Buffer.from([kind]),
+      ],
+      this.program
+    );
+  }
+
+  bannedAccount(account: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(BANNED_ACCOUNT_SEED), account.toBuffer()],
+      this.program
+    );
+  }
+
   completedSwapsEntry(
     nonce: Buffer,
     destTrader: PublicKey
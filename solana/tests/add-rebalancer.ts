@@ -2,7 +2,8 @@ import { Program, web3 } from "@coral-xyz/anchor";
 import { Dexalot } from "../target/types/dexalot";
 import { Keypair } from "@solana/web3.js";
 import { getAccountPubKey } from "../sdk/utils";
-import { ADMIN_SEED, REBALANCER_SEED } from "../sdk/consts";
+import { ADMIN_SEED } from "../sdk/consts";
+import pdaDeriver from "../sdk/pda-deriver";
 
 export const addRebalancer = async (
   dexalotProgram: Program<Dexalot>,
@@ -13,18 +14,41 @@ export const addRebalancer = async (
     admin.publicKey.toBuffer(),
   ]);
 
-  const rebalancerPDA = getAccountPubKey(dexalotProgram, [
-    Buffer.from(REBALANCER_SEED),
+  await dexalotProgram.methods
+    .grantRole({ role: { rebalancer: {} }, account: admin.publicKey })
+    .accounts({
+      authority: admin.publicKey,
+      // @ts-ignore
+      admin: adminPDA,
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
+      roleMember: pdaDeriver.roleMember("rebalancer", admin.publicKey)[0],
+      roleRegistry: pdaDeriver.roleRegistry("rebalancer")[0],
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .signers([admin])
+    .rpc();
+};
+
+export const addPauser = async (
+  dexalotProgram: Program<Dexalot>,
+  admin: Keypair
+) => {
+  const adminPDA = getAccountPubKey(dexalotProgram, [
+    Buffer.from(ADMIN_SEED),
     admin.publicKey.toBuffer(),
   ]);
 
   await dexalotProgram.methods
-    .addRebalancer({ account: admin.publicKey })
+    .grantRole({ role: { pauser: {} }, account: admin.publicKey })
     .accounts({
       authority: admin.publicKey,
       // @ts-ignore
       admin: adminPDA,
-      newRebalancer: rebalancerPDA,
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
+      roleMember: pdaDeriver.roleMember("pauser", admin.publicKey)[0],
+      roleRegistry: pdaDeriver.roleRegistry("pauser")[0],
       systemProgram: web3.SystemProgram.programId,
     })
     .signers([admin])
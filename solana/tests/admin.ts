@@ -3,28 +3,27 @@ import { Dexalot } from "../target/types/dexalot";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { getAccountPubKey } from "../sdk/utils";
 import { ADMIN_SEED } from "../sdk/consts";
+import pdaDeriver from "../sdk/pda-deriver";
 
 export const addAdmin = async (
   dexalotProgram: Program<Dexalot>,
   authority: Keypair,
   admin: PublicKey
 ) => {
-  const newAdminPDA = getAccountPubKey(dexalotProgram, [
-    Buffer.from(ADMIN_SEED),
-    admin.toBuffer(),
-  ]);
-
   const adminPDA = getAccountPubKey(dexalotProgram, [
     Buffer.from(ADMIN_SEED),
     authority.publicKey.toBuffer(),
   ]);
 
   await dexalotProgram.methods
-    .addAdmin({ account: admin })
+    .grantRole({ role: { admin: {} }, account: admin })
     .accounts({
       // @ts-ignore
       admin: adminPDA,
-      newAdmin: newAdminPDA,
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
+      roleMember: pdaDeriver.roleMember("admin", admin)[0],
+      roleRegistry: pdaDeriver.roleRegistry("admin")[0],
       systemProgram: web3.SystemProgram.programId,
       authority: authority.publicKey,
     })
@@ -37,25 +36,23 @@ export const removeAdmin = async (
     authority: Keypair,
     admin: PublicKey
   ) => {
-    const adminToRemovePDA = getAccountPubKey(dexalotProgram, [
-        Buffer.from(ADMIN_SEED),
-        admin.toBuffer(),
-      ]);
-    
       const adminPDA = getAccountPubKey(dexalotProgram, [
         Buffer.from(ADMIN_SEED),
         authority.publicKey.toBuffer(),
       ]);
     await dexalotProgram.methods
-    .removeAdmin({ account: admin })
+    .revokeRole({ role: { admin: {} }, account: admin })
     .accounts({
       // @ts-ignore
       admin: adminPDA,
-      adminToRemove: adminToRemovePDA,
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
+      roleMember: pdaDeriver.roleMember("admin", admin)[0],
+      roleRegistry: pdaDeriver.roleRegistry("admin")[0],
       systemProgram: web3.SystemProgram.programId,
       receiver: authority.publicKey, // Refund lamports to the remover
       authority: authority.publicKey,
     })
     .signers([authority])
     .rpc();
-  }
\ No newline at end of file
+  }
@@ -6,6 +6,7 @@ import {
   ADMIN_SEED,
   AIRDROP_VAULT_SEED,
   BANNED_ACCOUNT_SEED,
+  DEPOSIT_MESSAGE_KIND,
   DEST_ID,
   PORTFOLIO_SEED,
   SOL_USER_FUNDS_VAULT_SEED,
@@ -83,6 +84,8 @@ export const depositSol = async (
     .depositNative({
       amount: new BN(lamports),
       trader: Array.from(traderPublicKey),
+      maxNativeFee: new BN(web3.LAMPORTS_PER_SOL),
+      destChainId: null,
     })
     .accounts({
       user: authority.publicKey,
@@ -91,6 +94,12 @@ export const depositSol = async (
       solVault: nativeVaultPDA,
       systemProgram: web3.SystemProgram.programId,
       remote: remotePDA,
+      chainEntry: pdaDeriver.chain(DEST_ID)[0],
+      depositDestination: pdaDeriver.depositDestination(
+        DEST_ID,
+        PublicKey.default
+      )[0],
+      lzOptions: pdaDeriver.lzOptions(DEST_ID, DEPOSIT_MESSAGE_KIND)[0],
       bannedAccount: bannedAccountPDA,
       endpointProgram: LZ_MOCK_PROGRAM_ID,
     })
@@ -146,6 +155,8 @@ export const depositSpl = async (
       tokenMint: tokenMintAddress,
       amount: new BN(amount * 10 ** tokenDecimals),
       trader: Array.from(traderPublicKey),
+      maxNativeFee: new BN(web3.LAMPORTS_PER_SOL),
+      destChainId: null,
     })
     .accounts({
       user: authority.publicKey,
@@ -158,6 +169,12 @@ export const depositSpl = async (
       tokenProgram: TOKEN_PROGRAM_ID,
       bannedAccount: bannedAccountPDA,
       remote: remotePDA,
+      chainEntry: pdaDeriver.chain(DEST_ID)[0],
+      depositDestination: pdaDeriver.depositDestination(
+        DEST_ID,
+        tokenMintAddress
+      )[0],
+      lzOptions: pdaDeriver.lzOptions(DEST_ID, DEPOSIT_MESSAGE_KIND)[0],
       endpointProgram: endpointProgram.program,
     })
     .remainingAccounts(remainingAccounts)
@@ -10,11 +10,14 @@ import {
   SPL_VAULT_SEED,
   TOKEN_LIST_SEED,
 } from "../sdk/consts";
+import pdaDeriver from "../sdk/pda-deriver";
 import { endpointProgram } from "../sdk/layerzero";
 import { getAccountPubKey } from "../sdk/utils";
 import { Dexalot } from "../target/types/dexalot";
 import { Keypair, PublicKey } from "@solana/web3.js";
 
+export const TIMELOCK_DELAY = 24 * 60 * 60; // the program minimum, queueChange warps past it
+
 export const initialize = async (
   dexalotProgram: Program<Dexalot>,
   admin: Keypair
@@ -44,12 +47,14 @@ export const initialize = async (
       srcChainId: 40168,
       defaultChainId: DEST_ID,
       swapSigner: Array.from(Buffer.from(signer_pubkey, "hex")),
+      timelockDelay: TIMELOCK_DELAY,
     })
     .accounts({
       //@ts-ignore
       portfolio: portfolioPDA,
       tokenList: tokenListPDA,
       admin: adminPDA,
+      adminRegistry: pdaDeriver.roleRegistry("admin")[0],
       authority: admin.publicKey,
       systemProgram: web3.SystemProgram.programId,
       endpointProgram: endpointProgram.program,
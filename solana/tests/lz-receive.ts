@@ -8,7 +8,6 @@ import {
   DEST_ID,
   PORTFOLIO_SEED,
   SOL_VAULT_SEED,
-  TOKEN_LIST_SEED,
 } from "../sdk/consts";
 import pdaDeriver from "../sdk/pda-deriver";
 import {
@@ -39,10 +38,6 @@ export const callDexalot = async (
   const from = await getAssociatedTokenAddress(tokenMint, tokenVaultPDA, true);
 
   const to = await getAssociatedTokenAddress(tokenMint, authority.publicKey);
-  const tokenListPDA = getAccountPubKey(dexalotProgram, [
-    Buffer.from(TOKEN_LIST_SEED),
-    Buffer.from("0"),
-  ]);
   const airdropVaultPDA = getAccountPubKey(dexalotProgram, [
     Buffer.from(AIRDROP_VAULT_SEED),
   ]);
@@ -85,7 +80,6 @@ export const callDexalot = async (
       to: to,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenList: tokenListPDA,
       trader: authority.publicKey,
       airdropVault: airdropVaultPDA,
       //@ts-ignore
@@ -24,7 +24,7 @@ import {
 } from "./initalize";
 import { getGlobalConfig } from "./get-global-config";
 import { fundSol, fundSpl } from "./fund";
-import { addRebalancer } from "./add-rebalancer";
+import { addPauser, addRebalancer } from "./add-rebalancer";
 import {
   setAirdropAmount,
   setAllowDeposit,
@@ -36,6 +36,7 @@ import {
 import { ProgramTestContext } from "solana-bankrun";
 import pdaDeriver from "../sdk/pda-deriver";
 import { setRemote } from "./set-remote";
+import { setChain } from "./set-chain";
 import { addAdmin, removeAdmin } from "./admin";
 import { addToken, removeToken } from "./token";
 import {
@@ -156,6 +157,9 @@ describe("dexalot_tests", () => {
     const globalConfigBefore = await getGlobalConfig(dexalotProgram, authority);
     expect(globalConfigBefore.programPaused).toBeFalsy();
 
+    // make the admin a pauser
+    await addPauser(dexalotProgram, authority);
+
     // pause program
     await setPause(dexalotProgram, authority, true);
 
@@ -327,6 +331,18 @@ describe("dexalot_tests", () => {
     );
   });
 
+  test("set_chain", async () => {
+    const [chainPDA] = pdaDeriver.chain(DEST_ID);
+    let chainAccount = await context.banksClient.getAccount(chainPDA);
+    expect(chainAccount).toBeNull();
+
+    await setChain(dexalotProgram, authority, DEST_ID, DEST_ID);
+    chainAccount = await context.banksClient.getAccount(chainPDA);
+    expect(chainAccount?.owner.toBase58()).toBe(
+      dexalotProgram.programId.toBase58()
+    );
+  });
+
   test("add_token", async () => {
     const mintKeypair = tokenA;
     const mint = await createMint(
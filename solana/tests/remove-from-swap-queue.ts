@@ -58,6 +58,7 @@ export const removeFromSwapQueue = async (
       systemProgram: web3.SystemProgram.programId,
       swapQueueEntry: pendingSwapPDA,
       airdropVault: airdropVaultPDA,
+      bannedAccount: pdaDeriver.bannedAccount(pendingSwap.trader)[0],
     })
     .signers([authority])
     .rpc();
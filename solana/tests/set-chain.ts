@@ -0,0 +1,46 @@
+import { Program, web3 } from "@coral-xyz/anchor";
+import { Dexalot } from "../target/types/dexalot";
+import { Keypair } from "@solana/web3.js";
+import { getAccountPubKey } from "../sdk/utils";
+import { ADMIN_SEED } from "../sdk/consts";
+import pdaDeriver from "../sdk/pda-deriver";
+import { encodeChain } from "../sdk/handlers/timelock";
+import { queueChange } from "./timelock";
+
+export const setChain = async (
+  dexalotProgram: Program<Dexalot>,
+  authority: Keypair,
+  chainId: number,
+  eid: number
+) => {
+  const adminPDA = getAccountPubKey(dexalotProgram, [
+    Buffer.from(ADMIN_SEED),
+    authority.publicKey.toBuffer(),
+  ]);
+  const pendingChange = await queueChange(
+    dexalotProgram,
+    authority,
+    "setChain",
+    encodeChain(chainId, eid, [])
+  );
+
+  await dexalotProgram.methods
+    .setChain({
+      chainId,
+      eid,
+      nativeSymbols: [],
+    })
+    .accounts({
+      authority: authority.publicKey,
+      //@ts-ignore
+      admin: adminPDA,
+      remote: pdaDeriver.remote(eid)[0],
+      chainEntry: pdaDeriver.chain(chainId)[0],
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
+      pendingChange,
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc();
+};
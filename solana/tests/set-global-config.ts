@@ -3,16 +3,16 @@ import { ADMIN_SEED, PORTFOLIO_SEED } from "../sdk/consts";
 import { getAccountPubKey } from "../sdk/utils";
 import { Dexalot } from "../target/types/dexalot";
 import { Keypair } from "@solana/web3.js";
+import pdaDeriver from "../sdk/pda-deriver";
+import { encodeDefaultChain, encodeSwapSigner } from "../sdk/handlers/timelock";
+import { queueChange } from "./timelock";
 
 export const setPause = async (
   dexalotProgram: Program<Dexalot>,
-  admin: Keypair,
+  pauser: Keypair,
   pause: boolean
 ) => {
-  const adminPDA = getAccountPubKey(dexalotProgram, [
-    Buffer.from(ADMIN_SEED),
-    admin.publicKey.toBuffer(),
-  ]);
+  const [pauserPDA] = pdaDeriver.roleMember("pauser", pauser.publicKey);
   const portfolio = getAccountPubKey(dexalotProgram, [
     Buffer.from(PORTFOLIO_SEED),
   ]);
@@ -20,13 +20,13 @@ export const setPause = async (
   const tx = await dexalotProgram.methods
     .setPaused(pause)
     .accounts({
-      authority: admin.publicKey,
+      authority: pauser.publicKey,
       // @ts-ignore
-      admin: adminPDA,
+      pauser: pauserPDA,
       systemProgram: web3.SystemProgram.programId,
       portfolio,
     })
-    .signers([admin])
+    .signers([pauser])
     .rpc();
 };
 
@@ -94,6 +94,12 @@ export const setSwapSigner = async (
     Buffer.from(ADMIN_SEED),
     admin.publicKey.toBuffer(),
   ]);
+  const pendingChange = await queueChange(
+    dexalotProgram,
+    admin,
+    "setSwapSigner",
+    encodeSwapSigner(swapSigner)
+  );
   await dexalotProgram.methods
     .setSwapSigner({ swapSigner: Array.from(Buffer.from(swapSigner, "hex")) })
     .accounts({
@@ -101,6 +107,9 @@ export const setSwapSigner = async (
       //@ts-ignore
       portfolio: portfolio,
       admin: adminPDA,
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
+      pendingChange,
     })
     .signers([admin])
     .rpc();
@@ -118,6 +127,12 @@ export const setDefaultChainEid = async (
     Buffer.from(ADMIN_SEED),
     admin.publicKey.toBuffer(),
   ]);
+  const pendingChange = await queueChange(
+    dexalotProgram,
+    admin,
+    "setDefaultChain",
+    encodeDefaultChain(defaultChainEid)
+  );
   await dexalotProgram.methods
     .setDefaultChain({ chainId: defaultChainEid })
     .accounts({
@@ -125,6 +140,7 @@ export const setDefaultChainEid = async (
       //@ts-ignore
       portfolio: portfolio,
       admin: adminPDA,
+      pendingChange,
     })
     .signers([admin])
     .rpc();
@@ -5,6 +5,8 @@ import { getAccountPubKey } from "../sdk/utils";
 import { ADMIN_SEED, PORTFOLIO_SEED } from "../sdk/consts";
 import { arrayify, hexZeroPad } from "@ethersproject/bytes";
 import pdaDeriver from "../sdk/pda-deriver";
+import { encodeRemote } from "../sdk/handlers/timelock";
+import { queueChange } from "./timelock";
 
 export const setRemote = async (
   dexalotProgram: Program<Dexalot>,
@@ -22,6 +24,13 @@ export const setRemote = async (
     authority.publicKey.toBuffer(),
   ]);
 
+  const pendingChange = await queueChange(
+    dexalotProgram,
+    authority,
+    "setRemote",
+    encodeRemote(dstId, remotePeer)
+  );
+
   await dexalotProgram.methods
     .setRemote({
       dstEid: dstId,
@@ -33,6 +42,9 @@ export const setRemote = async (
       remote: remotePDA,
       portfolio: portfolio,
       admin: adminPDA,
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
+      pendingChange,
       systemProgram: web3.SystemProgram.programId,
     })
     .signers([authority])
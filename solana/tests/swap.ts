@@ -123,6 +123,8 @@ export const simpleSwap = async (
       taker: authority.publicKey,
       destTrader: account2.publicKey,
       completedSwapsEntry: completedSwapsEntryPDA,
+      takerBannedAccount: pdaDeriver.bannedAccount(authority.publicKey)[0],
+      destTraderBannedAccount: pdaDeriver.bannedAccount(account2.publicKey)[0],
       //@ts-ignore
       systemProgram: web3.SystemProgram.programId,
       clock: web3.SYSVAR_CLOCK_PUBKEY,
@@ -258,6 +260,8 @@ export const partialSwap = async (
       taker: authority.publicKey,
       destTrader: account2.publicKey,
       completedSwapsEntry: completedSwapsEntryPDA,
+      takerBannedAccount: pdaDeriver.bannedAccount(authority.publicKey)[0],
+      destTraderBannedAccount: pdaDeriver.bannedAccount(account2.publicKey)[0],
       //@ts-ignore
       systemProgram: web3.SystemProgram.programId,
       clock: web3.SYSVAR_CLOCK_PUBKEY,
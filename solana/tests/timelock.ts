@@ -0,0 +1,53 @@
+import { BN, Program, web3 } from "@coral-xyz/anchor";
+import { Dexalot } from "../target/types/dexalot";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAccountPubKey } from "../sdk/utils";
+import { ADMIN_SEED } from "../sdk/consts";
+import pdaDeriver from "../sdk/pda-deriver";
+import { TimelockedAction } from "../sdk/handlers/timelock";
+import { Clock } from "solana-bankrun";
+import { contextPromise } from "./context";
+import { TIMELOCK_DELAY } from "./initalize";
+
+let nextChangeId = 0;
+
+// queues the change and warps the clock past the timelock delay so it matures right away
+export const queueChange = async (
+  dexalotProgram: Program<Dexalot>,
+  admin: Keypair,
+  action: TimelockedAction,
+  data: Buffer
+): Promise<PublicKey> => {
+  const id = new BN(nextChangeId++);
+  const adminPDA = getAccountPubKey(dexalotProgram, [
+    Buffer.from(ADMIN_SEED),
+    admin.publicKey.toBuffer(),
+  ]);
+
+  await dexalotProgram.methods
+    .queueChange({ id, action: { [action]: {} } as any, data })
+    .accounts({
+      authority: admin.publicKey,
+      //@ts-ignore
+      admin: adminPDA,
+      portfolio: pdaDeriver.portfolio()[0],
+      pendingChange: pdaDeriver.pendingChange(id)[0],
+      systemProgram: web3.SystemProgram.programId,
+    })
+    .signers([admin])
+    .rpc();
+
+  const context = await contextPromise;
+  const clock = await context.banksClient.getClock();
+  context.setClock(
+    new Clock(
+      clock.slot,
+      clock.epochStartTimestamp,
+      clock.epoch,
+      clock.leaderScheduleEpoch,
+      clock.unixTimestamp + BigInt(TIMELOCK_DELAY)
+    )
+  );
+
+  return pdaDeriver.pendingChange(id)[0];
+};
@@ -15,9 +15,11 @@ export const getTokenList = async (
     tokenListPDA
   );
 
-  const tokens = tokenListAccount.tokens.map((tokenMintAddress) => {
-    return tokenMintAddress.toBase58().replace(/\0/g, "");
-  });
+  const tokens = tokenListAccount.tokens
+    .slice(0, tokenListAccount.len)
+    .map((tokenMintAddress) => {
+      return tokenMintAddress.toBase58().replace(/\0/g, "");
+    });
 
   return tokens;
 };
@@ -9,6 +9,7 @@ import {
   TOKEN_DETAILS_SEED,
   TOKEN_LIST_SEED,
 } from "../sdk/consts";
+import pdaDeriver from "../sdk/pda-deriver";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAssociatedTokenAddress,
@@ -20,7 +21,8 @@ export const addToken = async (
   authority: Keypair,
   tokenMint: PublicKey,
   tokenSymbol: string,
-  tokenDecimals: number
+  tokenDecimals: number,
+  l1Decimals: number = tokenDecimals
 ) => {
   const adminPDA = getAccountPubKey(dexalotProgram, [
     Buffer.from(ADMIN_SEED),
@@ -51,14 +53,18 @@ export const addToken = async (
       symbol: Array.from(symbolPadded),
       tokenAddress: tokenMint,
       decimals: tokenDecimals,
+      l1Decimals,
     })
     .accounts({
       authority: authority.publicKey,
       //@ts-ignore
       admin: adminPDA,
+      multisigConfig: pdaDeriver.multisigConfig()[0],
+      proposal: null,
       splVault: splVaultPDA,
       splUserFundsVault: splUserFundsVaultPDA,
       tokenDetails: tokenDetailsPDA,
+      symbolEntry: pdaDeriver.symbolEntry(symbolPadded)[0],
       tokenMint: tokenMint,
       splTokenAccount: await getAssociatedTokenAddress(
         tokenMint,
@@ -97,20 +103,45 @@ export const removeToken = async (
     dexalotProgram.programId
   );
 
+  const tokenDetailsAccount = await dexalotProgram.account.tokenDetails.fetch(
+    tokenDetails
+  );
+
   const [tokenList, tokenListBump] = web3.PublicKey.findProgramAddressSync(
     [Buffer.from(TOKEN_LIST_SEED), Buffer.from("0")],
     dexalotProgram.programId
   );
 
+  const splVaultPDA = getAccountPubKey(dexalotProgram, [
+    Buffer.from(SPL_VAULT_SEED),
+  ]);
+  const splUserFundsVaultPDA = getAccountPubKey(dexalotProgram, [
+    Buffer.from(SPL_USER_FUNDS_VAULT_SEED),
+  ]);
+
   const tx = await dexalotProgram.methods
     .removeToken({ tokenAddress: tokenMint })
     .accounts({
       authority: authority.publicKey,
       //@ts-ignore
       tokenDetails,
+      symbolEntry: pdaDeriver.symbolEntry(tokenDetailsAccount.symbol)[0],
       admin: adminPDA,
       receiver: authority.publicKey,
       systemProgram: web3.SystemProgram.programId,
+      splVault: splVaultPDA,
+      splUserFundsVault: splUserFundsVaultPDA,
+      splTokenAccount: await getAssociatedTokenAddress(
+        tokenMint,
+        splVaultPDA,
+        true // allow owner off curve
+      ),
+      splUserFundsTokenAccount: await getAssociatedTokenAddress(
+        tokenMint,
+        splUserFundsVaultPDA,
+        true // allow owner off curve
+      ),
+      tokenProgram: TOKEN_PROGRAM_ID,
     })
     .remainingAccounts([
       {